@@ -1,12 +1,16 @@
 use leptos::prelude::*;
 use leptos_use::docs::{demo_or_body, Note};
-use leptos_use::signal_debounced;
-use std::cell::RefCell;
+use leptos_use::{signal_debounced, signal_debounced_with_options, DebounceOptions};
 
 #[component]
 fn Demo() -> impl IntoView {
     let (input, set_input) = signal("".to_string());
     let debounced: Signal<String> = signal_debounced(input, 1000.0);
+    let debounced_max_wait: Signal<String> = signal_debounced_with_options(
+        input,
+        1000.0,
+        DebounceOptions::default().max_wait(Some(3000.0)),
+    );
 
     view! {
         <div>
@@ -19,6 +23,7 @@ fn Demo() -> impl IntoView {
             <Note>Delay is set to 1000ms for this demo.</Note>
             <p>Input signal: {input}</p>
             <p>Debounced signal: {debounced}</p>
+            <p>Debounced signal with max wait of 3000ms: {debounced_max_wait}</p>
         </div>
     }
 }
@@ -1,12 +1,19 @@
 use leptos::prelude::*;
 use leptos_use::docs::{demo_or_body, Note};
-use leptos_use::signal_throttled;
+use leptos_use::{
+    signal_throttled, signal_throttled_with_options, use_mouse, ThrottleOptions, UseMouseReturn,
+};
 
 #[component]
 fn Demo() -> impl IntoView {
     let (input, set_input) = signal("".to_string());
     let throttled: Signal<String> = signal_throttled(input, 1000.0);
 
+    let UseMouseReturn { x, y, .. } = use_mouse();
+    let position = Signal::derive(move || format!("{}, {}", x.get(), y.get()));
+    let throttled_position: Signal<String> =
+        signal_throttled_with_options(position, 500.0, ThrottleOptions::default().leading(false));
+
     view! {
         <div>
             <input
@@ -18,6 +25,10 @@ fn Demo() -> impl IntoView {
             <Note>Delay is set to 1000ms for this demo.</Note>
             <p>Input signal: {input}</p>
             <p>Throttled signal: {throttled}</p>
+            <hr />
+            <Note>Move the mouse. Throttled by 500ms without the leading edge.</Note>
+            <p>Mouse position: {position}</p>
+            <p>Throttled mouse position: {throttled_position}</p>
         </div>
     }
 }
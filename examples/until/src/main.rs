@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::demo_or_body;
+use leptos_use::until;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (count, set_count) = signal(0);
+    let (log, set_log) = signal("".to_string());
+
+    let append = move |line: String| set_log.update(|log| *log = format!("{log}{line}\n"));
+
+    let wait_for_five = move |_| {
+        append("Waiting for count to be 5...".to_string());
+
+        spawn_local(async move {
+            until(count).is(5).await;
+            append("Count is 5".to_string());
+        });
+    };
+
+    let wait_for_changes = move |_| {
+        append("Waiting for 3 changes within 5 seconds...".to_string());
+
+        spawn_local(async move {
+            match until::<i32>(count).timeout(5000.0).changed_times(3).await {
+                Some(count) => append(format!("Changed 3 times. Count is {count}")),
+                None => append("Timed out".to_string()),
+            }
+        });
+    };
+
+    view! {
+        <p>"Count: " {count}</p>
+        <button on:click=move |_| set_count.update(|count| *count += 1)>"Increment"</button>
+        <button on:click=move |_| set_count.set(0)>"Reset"</button>
+        <br />
+        <button on:click=wait_for_five>"Wait for 5"</button>
+        <button on:click=wait_for_changes>"Wait for 3 changes"</button>
+        <pre>{log}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,90 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_animate_with_options, Keyframe, UseAnimateOptions, UseAnimateReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let animate = use_animate_with_options(
+        el,
+        vec![
+            Keyframe::new()
+                .set("transform", "translateX(0) rotate(0deg)")
+                .set("background-color", "#26ccff"),
+            Keyframe::new()
+                .set("transform", "translateX(200px) rotate(180deg)")
+                .set("background-color", "#a25afd"),
+        ],
+        UseAnimateOptions::default()
+            .duration(2000.0)
+            .iterations(f64::INFINITY)
+            .easing("ease-in-out")
+            .direction(web_sys::PlaybackDirection::Alternate),
+    );
+
+    let UseAnimateReturn {
+        play_state,
+        current_time,
+        set_current_time,
+        playback_rate,
+        set_playback_rate,
+        ..
+    } = animate;
+
+    view! {
+        <div node_ref=el class="w-16 h-16 rounded my-8"></div>
+        <div class="flex gap-2 flex-wrap">
+            <button on:click=move |_| animate.play()>"Play"</button>
+            <button on:click=move |_| animate.pause()>"Pause"</button>
+            <button on:click=move |_| animate.reverse()>"Reverse"</button>
+            <button on:click=move |_| animate.cancel()>"Cancel"</button>
+        </div>
+        <p>"Play state: " {move || format!("{:?}", play_state.get())}</p>
+        <p>
+            "Current time: "
+            {move || current_time.get().map(|time| format!("{time:.0} ms")).unwrap_or_default()}
+        </p>
+        <label>
+            "Seek "
+            <input
+                type="range"
+                min="0"
+                max="4000"
+                prop:value=move || current_time.get().unwrap_or_default() % 4000.0
+                on:input=move |e| set_current_time.set(event_target_value(&e).parse().ok())
+            />
+        </label>
+        <label class="block">
+            "Playback rate "
+            <select on:change=move |e| {
+                if let Ok(rate) = event_target_value(&e).parse() {
+                    set_playback_rate.set(rate);
+                }
+            }>
+                <option value="0.5" selected=move || playback_rate.get() == 0.5>
+                    "0.5x"
+                </option>
+                <option value="1" selected=move || playback_rate.get() == 1.0>
+                    "1x"
+                </option>
+                <option value="2" selected=move || playback_rate.get() == 2.0>
+                    "2x"
+                </option>
+            </select>
+        </label>
+        <Note>"Pause the animation and drag the slider to scrub through it."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,84 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_async_queue_with_options, AsyncQueueTask, AsyncQueueTaskState, UseAsyncQueueOptions,
+    UseAsyncQueueReturn,
+};
+
+/// Pretends to upload a file. Files with "broken" in their name fail.
+async fn upload(file: &'static str, duration: u32) -> Result<String, String> {
+    gloo_timers::future::TimeoutFuture::new(duration).await;
+
+    if file.contains("broken") {
+        Err(format!("Couldn't upload {file}"))
+    } else {
+        Ok(format!("{file} uploaded"))
+    }
+}
+
+const FILES: [(&str, u32); 5] = [
+    ("cat.png", 1000),
+    ("dog.png", 2000),
+    ("broken.png", 1500),
+    ("bird.png", 1000),
+    ("fish.png", 500),
+];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (finished_message, set_finished_message) = signal(String::new());
+
+    let UseAsyncQueueReturn {
+        results,
+        active_index,
+        is_finished,
+        abort,
+    } = use_async_queue_with_options(
+        FILES
+            .into_iter()
+            .map(|(file, duration)| AsyncQueueTask::new(move || upload(file, duration)))
+            .collect(),
+        UseAsyncQueueOptions::default()
+            .concurrency(2)
+            .interrupt(false)
+            .on_finished(move || set_finished_message.set("All uploads settled".to_string())),
+    );
+
+    view! {
+        <button on:click=move |_| abort()>"Abort"</button>
+        <ul>
+            {move || {
+                results
+                    .get()
+                    .into_iter()
+                    .zip(FILES)
+                    .map(|(state, (file, _))| {
+                        let state = match state {
+                            AsyncQueueTaskState::Pending => "pending".to_string(),
+                            AsyncQueueTaskState::Running => "uploading...".to_string(),
+                            AsyncQueueTaskState::Fulfilled(message) => message,
+                            AsyncQueueTaskState::Rejected(err) => err,
+                            AsyncQueueTaskState::Aborted => "aborted".to_string(),
+                        };
+
+                        view! { <li>{file} ": " {state}</li> }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+        <p>"Active index: " {move || format!("{:?}", active_index.get())}</p>
+        <p>"Finished: " <BooleanDisplay value=is_finished /></p>
+        <p>{finished_message}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
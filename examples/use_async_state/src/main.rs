@@ -0,0 +1,61 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_async_state_with_options, UseAsyncStateOptions, UseAsyncStateReturn};
+
+/// Pretends to do some slow work that fails for odd numbers.
+async fn double_even(number: u32) -> Result<u32, String> {
+    gloo_timers::future::TimeoutFuture::new(1000).await;
+
+    if number % 2 == 0 {
+        Ok(number * 2)
+    } else {
+        Err(format!("{number} is odd"))
+    }
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (number, set_number) = signal(2_u32);
+
+    let UseAsyncStateReturn {
+        state,
+        is_ready,
+        is_loading,
+        error,
+        execute,
+    } = use_async_state_with_options(
+        double_even,
+        0,
+        UseAsyncStateOptions::default().reset_on_execute(false),
+    );
+
+    view! {
+        <input
+            type="number"
+            prop:value=number
+            on:input=move |e| set_number.set(event_target_value(&e).parse().unwrap_or_default())
+        />
+        <button on:click={
+            let execute = execute.clone();
+            move |_| execute(0.0, number.get_untracked())
+        }>"Double"</button>
+        <button on:click=move |_| execute(1000.0, number.get_untracked())>
+            "Double in 1 second"
+        </button>
+        <p>"State: " {move || state.get()}</p>
+        <p>"Ready: " <BooleanDisplay value=is_ready /></p>
+        <p>"Loading: " <BooleanDisplay value=is_loading /></p>
+        <p>"Error: " {move || error.get().unwrap_or_default()}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
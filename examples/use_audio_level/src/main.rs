@@ -0,0 +1,46 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_audio_level, use_user_media_with_options, UseAudioLevelReturn, UseUserMediaOptions,
+    UseUserMediaReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media_with_options(UseUserMediaOptions::default().audio(true).video(false));
+
+    let UseAudioLevelReturn { level, is_speaking } =
+        use_audio_level(Signal::derive_local(move || {
+            stream.get().and_then(Result::ok)
+        }));
+
+    view! {
+        <button on:click=move |_| {
+            set_enabled.set(!enabled.get_untracked())
+        }>{move || if enabled.get() { "Stop microphone" } else { "Start microphone" }}</button>
+        <div class="w-64 h-4 my-4 rounded bg-gray-500/20 overflow-hidden">
+            <div
+                class="h-full bg-green-500"
+                style:width=move || format!("{:.0}%", level.get() * 100.0)
+            />
+        </div>
+        <p>"Speaking: " <BooleanDisplay value=is_speaking /></p>
+        <Note>"Say something to see the level change."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,77 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_auto_scroll_with_options, UseAutoScrollOptions, UseAutoScrollReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let (speed, set_speed) = signal(60.0);
+
+    let auto_scroll =
+        use_auto_scroll_with_options(el, speed, UseAutoScrollOptions::default().seamless(true));
+    let UseAutoScrollReturn {
+        is_active,
+        is_running,
+        ..
+    } = auto_scroll;
+
+    let items = || {
+        [
+            "Leptos",
+            "Rust",
+            "WebAssembly",
+            "Signals",
+            "Reactivity",
+            "Components",
+        ]
+        .into_iter()
+        .map(|item| view! { <span class="px-6 py-2">{item}</span> })
+        .collect_view()
+    };
+
+    view! {
+        <div node_ref=el class="flex overflow-hidden whitespace-nowrap border rounded">
+            {items()}
+            {items()}
+        </div>
+        <Note>"Hover the ticker to pause it."</Note>
+        <div class="flex gap-4 items-center mt-4">
+            <label>
+                "Speed "
+                <input
+                    type="range"
+                    min="10"
+                    max="300"
+                    prop:value=move || speed.get().to_string()
+                    on:input=move |e| {
+                        if let Ok(value) = event_target_value(&e).parse() {
+                            set_speed.set(value);
+                        }
+                    }
+                />
+                " " {speed} " px/s"
+            </label>
+            <button on:click=move |_| {
+                if is_active.get_untracked() {
+                    auto_scroll.pause()
+                } else {
+                    auto_scroll.resume()
+                }
+            }>{move || if is_active.get() { "Pause" } else { "Resume" }}</button>
+        </div>
+        <p>"Running: " {move || is_running.get().to_string()}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
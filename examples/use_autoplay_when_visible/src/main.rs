@@ -0,0 +1,45 @@
+use leptos::html::Video;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_autoplay_when_visible, UseAutoplayWhenVisibleReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video = NodeRef::<Video>::new();
+
+    let UseAutoplayWhenVisibleReturn {
+        is_visible,
+        is_playing,
+        is_allowed,
+    } = use_autoplay_when_visible(video);
+
+    view! {
+        <div class="fixed top-4 right-4 p-2 bg-[--bg] z-10">
+            <p>"Visible: " <BooleanDisplay value=is_visible /></p>
+            <p>"Playing: " <BooleanDisplay value=is_playing /></p>
+            <p>"Autoplay allowed: " <BooleanDisplay value=is_allowed /></p>
+        </div>
+        <p>"Scroll down to the video and back up again."</p>
+        <div class="h-[120vh]"></div>
+        <video
+            node_ref=video
+            src="https://interactive-examples.mdn.mozilla.net/media/cc0-videos/flower.webm"
+            width="400"
+            loop
+            controls
+            playsinline
+        />
+        <div class="h-[120vh]"></div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,69 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_browser_location, UseBrowserLocationReturn};
+use wasm_bindgen::JsValue;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let location = use_browser_location();
+    let UseBrowserLocationReturn {
+        href,
+        pathname,
+        search,
+        hash,
+        origin,
+        state,
+        length,
+        ..
+    } = location;
+
+    let push_state = move |_| {
+        if let Ok(history) = window().history() {
+            let count = length.get_untracked();
+            let _ = history.push_state_with_url(
+                &JsValue::from_f64(count as f64),
+                "",
+                Some(&format!("?page={count}")),
+            );
+        }
+
+        location.refresh();
+    };
+
+    let change_hash = move |_| {
+        let _ = window()
+            .location()
+            .set_hash(&format!("section-{}", length.get_untracked()));
+    };
+
+    view! {
+        <div class="flex gap-2">
+            <button on:click=push_state>"Push state"</button>
+            <button on:click=change_hash>"Change hash"</button>
+            <button on:click=move |_| {
+                let _ = window().history().and_then(|history| history.back());
+            }>"Back"</button>
+        </div>
+        <Note>"Use the buttons or the browser's back and forward buttons to change the location."</Note>
+        <pre>
+            "href: " {href} "\n"
+            "pathname: " {pathname} "\n"
+            "search: " {search} "\n"
+            "hash: " {hash} "\n"
+            "origin: " {origin} "\n"
+            "state: " {move || format!("{:?}", state.get().map(|state| state.take()))} "\n"
+            "length: " {length}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
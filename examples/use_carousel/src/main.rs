@@ -0,0 +1,78 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_carousel_with_options, UseCarouselOptions, UseCarouselReturn};
+
+const COLORS: [&str; 5] = ["#f87171", "#fb923c", "#facc15", "#4ade80", "#60a5fa"];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let container = NodeRef::<Div>::new();
+
+    let carousel =
+        use_carousel_with_options(container, UseCarouselOptions::default().autoplay(3000));
+    let UseCarouselReturn {
+        index,
+        count,
+        is_playing,
+        ..
+    } = carousel;
+
+    view! {
+        <div
+            node_ref=container
+            class="flex overflow-x-auto rounded w-[400px] max-w-full"
+            style="scroll-snap-type: x mandatory; scrollbar-width: none"
+        >
+            {COLORS
+                .into_iter()
+                .enumerate()
+                .map(|(slide, color)| {
+                    view! {
+                        <div
+                            class="flex-none w-full h-[200px] flex items-center justify-center text-4xl text-white"
+                            style="scroll-snap-align: start"
+                            style:background-color=color
+                        >
+                            {slide + 1}
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+        <div class="flex gap-2 items-center mt-2">
+            <button on:click=move |_| carousel.prev()>"Previous"</button>
+            {move || {
+                (0..count.get())
+                    .map(|slide| {
+                        view! {
+                            <button
+                                class="w-3 h-3 p-0 rounded-full"
+                                class=(["opacity-30"], move || index.get() != slide)
+                                on:click=move |_| carousel.go_to(slide)
+                            />
+                        }
+                    })
+                    .collect_view()
+            }}
+            <button on:click=move |_| carousel.next()>"Next"</button>
+            <button on:click=move |_| {
+                if is_playing.get() { carousel.pause() } else { carousel.play() }
+            }>{move || if is_playing.get() { "Pause" } else { "Play" }}</button>
+        </div>
+        <Note class="mt-2">
+            "Swipe or scroll horizontally to change the slide. Autoplay pauses while hovering the slides."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
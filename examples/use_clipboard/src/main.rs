@@ -12,6 +12,7 @@ fn Demo() -> impl IntoView {
         is_supported,
         text,
         copied,
+        strategy,
         copy,
     } = use_clipboard_with_options(UseClipboardOptions::default().read(true));
 
@@ -29,6 +30,7 @@ fn Demo() -> impl IntoView {
                 write <b>{move || permission_write().to_string()}</b>
             </Note>
             <p>Currently copied: <code>{move || text().unwrap_or("none".to_owned())}</code></p>
+            <p>Copied via: <code>{move || strategy().map(|s| format!("{s:?}")).unwrap_or("none".to_owned())}</code></p>
             <input value=input on:input=move |e| set_input(event_target_value(&e)) type="text" />
             <button on:click={
                 let copy = copy.clone();
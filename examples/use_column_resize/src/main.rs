@@ -0,0 +1,93 @@
+use leptos::html::Th;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_column_resize_with_options, UseColumnResizeOptions, UseColumnResizeReturn};
+
+const COLUMNS: [&str; 3] = ["Name", "Email", "Role"];
+
+const ROWS: [[&str; 3]; 4] = [
+    ["Ada Lovelace", "ada@example.com", "Admin"],
+    ["Alan Turing", "alan.turing@example.com", "Editor"],
+    ["Grace Hopper", "grace@example.com", "Viewer"],
+    ["Edsger Dijkstra", "edsger.w.dijkstra@example.com", "Editor"],
+];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let resize = use_column_resize_with_options(
+        UseColumnResizeOptions::default()
+            .min_width(60.0)
+            .max_width(500.0)
+            .storage_key("use-column-resize-demo"),
+    );
+    let UseColumnResizeReturn {
+        widths, resizing, ..
+    } = resize;
+
+    let header = move |column: &'static str| {
+        let th = NodeRef::<Th>::new();
+        resize.register(column, th);
+
+        view! {
+            <th
+                node_ref=th
+                class="relative text-left px-2 py-1 border border-gray-400/30 overflow-hidden whitespace-nowrap"
+                style:width=move || resize.width(column).map(|width| format!("{width}px"))
+            >
+                {column}
+                <span
+                    class="absolute right-0 top-0 h-full w-2 cursor-col-resize hover:bg-[--brand-color]"
+                    class=(["bg-[--brand-color]"], move || resizing.get().as_deref() == Some(column))
+                    style="touch-action: none"
+                    on:pointerdown=move |e| resize.start_resize(column, &e)
+                    on:dblclick=move |_| resize.auto_fit(column)
+                />
+            </th>
+        }
+    };
+
+    view! {
+        <Note class="mb-2">
+            "Drag the right edge of a header to resize the column. Double-click it to fit the content. The widths are stored in local storage."
+        </Note>
+        <table class="border-collapse" style="table-layout: fixed; width: max-content">
+            <thead>
+                <tr>{COLUMNS.into_iter().map(header).collect_view()}</tr>
+            </thead>
+            <tbody>
+                {ROWS
+                    .into_iter()
+                    .map(|row| {
+                        view! {
+                            <tr>
+                                {row
+                                    .into_iter()
+                                    .map(|cell| {
+                                        view! {
+                                            <td class="px-2 py-1 border border-gray-400/30 overflow-hidden whitespace-nowrap text-ellipsis">
+                                                {cell}
+                                            </td>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+        <button class="mt-2" on:click=move |_| resize.reset()>"Reset"</button>
+        <pre>{move || format!("{:#?}", widths.get())}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,62 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_confirm_dialog, ConfirmDialogResult, UseConfirmDialogReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let dialog = use_confirm_dialog::<String, String, ()>();
+    let UseConfirmDialogReturn {
+        is_revealed, data, ..
+    } = dialog;
+
+    let (message, set_message) = signal(String::new());
+
+    let on_click = move |_| {
+        spawn_local(async move {
+            set_message.set(String::new());
+
+            let result = dialog
+                .reveal("Which name should the new file get?".to_string())
+                .await;
+
+            set_message.set(match result {
+                ConfirmDialogResult::Confirmed(name) => format!("Created file \"{name}\""),
+                ConfirmDialogResult::Canceled(()) => "Canceled".to_string(),
+            });
+        });
+    };
+
+    let (name, set_name) = signal("untitled.txt".to_string());
+
+    view! {
+        <button on:click=on_click disabled=is_revealed>
+            "New file"
+        </button>
+        <p>{message}</p>
+        <Show when=move || is_revealed.get()>
+            <div class="p-4 my-4 rounded border border-gray-500/40">
+                <p>{move || data.get()}</p>
+                <input
+                    type="text"
+                    prop:value=name
+                    on:input=move |e| set_name.set(event_target_value(&e))
+                />
+                <button on:click=move |_| dialog.confirm(name.get_untracked())>"Create"</button>
+                <button on:click=move |_| dialog.cancel(())>"Cancel"</button>
+            </div>
+        </Show>
+        <Note>"The result of the dialog is awaited in the click handler of the button."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,92 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::storage::{use_consent, ConsentStatus};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let consent = use_consent(["analytics", "marketing"]);
+
+    let has_decided = consent.has_decided;
+    let analytics = consent.is_granted("analytics");
+    let marketing = consent.is_granted("marketing");
+    let analytics_status = consent.status("analytics");
+    let marketing_status = consent.status("marketing");
+
+    let (analytics_loaded, set_analytics_loaded) = signal(false);
+
+    consent.when_granted("analytics", move || {
+        set_analytics_loaded.set(true);
+    });
+
+    let status_text = |status: Signal<ConsentStatus>| {
+        move || match status.get() {
+            ConsentStatus::Pending => "pending",
+            ConsentStatus::Granted => "granted",
+            ConsentStatus::Denied => "denied",
+        }
+    };
+
+    let banner_consent = consent.clone();
+
+    view! {
+        <Show when=move || !has_decided.get()>
+            <div class="p-4 mb-4 rounded border border-solid border-gray-500/30">
+                <p class="mt-0">"We'd like to use analytics and marketing cookies."</p>
+                <button on:click={
+                    let consent = banner_consent.clone();
+                    move |_| consent.grant_all()
+                }>"Accept all"</button>
+                <button on:click={
+                    let consent = banner_consent.clone();
+                    move |_| consent.deny_all()
+                }>"Only necessary"</button>
+            </div>
+        </Show>
+
+        <div>"Analytics: " {status_text(analytics_status)}</div>
+        <div>"Marketing: " {status_text(marketing_status)}</div>
+        <div>"Analytics loaded: " <BooleanDisplay value=analytics_loaded /></div>
+
+        <div class="mt-4">
+            <button on:click={
+                let consent = consent.clone();
+                move |_| {
+                    if analytics.get_untracked() {
+                        consent.deny("analytics")
+                    } else {
+                        consent.grant("analytics")
+                    }
+                }
+            }>"Toggle analytics"</button>
+            <button on:click={
+                let consent = consent.clone();
+                move |_| {
+                    if marketing.get_untracked() {
+                        consent.deny("marketing")
+                    } else {
+                        consent.grant("marketing")
+                    }
+                }
+            }>"Toggle marketing"</button>
+            <button on:click={
+                let consent = consent.clone();
+                move |_| consent.reset()
+            }>"Reset"</button>
+        </div>
+
+        <Note>
+            "The decisions are stored in local storage. Reload the page or open a second tab to see them persist."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
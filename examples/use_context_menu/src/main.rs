@@ -0,0 +1,60 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_context_menu, UseContextMenuReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let target = NodeRef::<Div>::new();
+    let menu = NodeRef::<Div>::new();
+
+    let (last_action, set_last_action) = signal("none");
+
+    let UseContextMenuReturn {
+        is_open,
+        x,
+        y,
+        close,
+    } = use_context_menu(target, menu);
+
+    let action = move |name: &'static str| {
+        let close = close.clone();
+
+        move |_| {
+            set_last_action.set(name);
+            close();
+        }
+    };
+
+    view! {
+        <div node_ref=target class="area p-8 select-none">
+            "Right click inside this area"
+        </div>
+        <p>"Last action: " <b>{last_action}</b></p>
+
+        <div
+            node_ref=menu
+            class="area shadow-lg flex flex-col p-2"
+            style:display=move || if is_open.get() { "flex" } else { "none" }
+            style:position="fixed"
+            style:z-index="10"
+            style:left=move || format!("{}px", x.get())
+            style:top=move || format!("{}px", y.get())
+        >
+            <button on:click=action("copy")>"Copy"</button>
+            <button on:click=action("paste")>"Paste"</button>
+            <button on:click=action("delete")>"Delete"</button>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
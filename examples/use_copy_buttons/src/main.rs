@@ -0,0 +1,55 @@
+use leptos::html::Article;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_copy_buttons, UseCopyButtonsReturn};
+
+const RENDERED_MARKDOWN: &str = r#"
+<h3>Installation</h3>
+<pre class="relative"><code>cargo add leptos-use</code></pre>
+<h3>Usage</h3>
+<pre class="relative"><code>use leptos_use::use_copy_buttons;
+
+use_copy_buttons(article, "pre");</code></pre>
+"#;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let article = NodeRef::<Article>::new();
+
+    let (snippets, set_snippets) = signal(0);
+
+    let UseCopyButtonsReturn {
+        is_supported,
+        count,
+        copied,
+    } = use_copy_buttons(article, "pre");
+
+    view! {
+        <p>"Clipboard API supported: " <BooleanDisplay value=is_supported /></p>
+        <button on:click=move |_| set_snippets.update(|n| *n += 1)>"Add snippet"</button>
+        <article node_ref=article>
+            <div inner_html=RENDERED_MARKDOWN></div>
+            <For each=move || 1..=snippets.get() key=|i| *i let:i>
+                <pre class="relative">
+                    <code>{format!("println!(\"Snippet {i}\");")}</code>
+                </pre>
+            </For>
+        </article>
+        <p>"Blocks with a copy button: " {count}</p>
+        <p>
+            "Copied block: "
+            {move || copied.get().map(|index| index.to_string()).unwrap_or("none".to_string())}
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
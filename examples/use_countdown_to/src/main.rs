@@ -0,0 +1,55 @@
+use chrono::{Local, TimeDelta, Utc};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_countdown_to_with_options, UseCountdownToOptions, UseCountdownToReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (target, set_target) = signal(Utc::now() + TimeDelta::seconds(90));
+    let (log, set_log) = signal("".to_string());
+
+    let UseCountdownToReturn {
+        days,
+        hours,
+        minutes,
+        seconds,
+        is_finished,
+        ..
+    } = use_countdown_to_with_options(
+        target,
+        UseCountdownToOptions::default().on_finished(move || {
+            set_log.update(|log| *log = format!("{log}Finished\n"));
+        }),
+    );
+
+    let add = move |delta: TimeDelta| set_target.update(|target| *target += delta);
+
+    view! {
+        <p class="text-2xl">
+            {days} "d " {hours} "h " {minutes} "m " {seconds} "s"
+        </p>
+        <p>
+            "Target: "
+            {move || target.get().with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string()}
+        </p>
+        <p>"Finished: " <BooleanDisplay value=is_finished /></p>
+        <button on:click=move |_| set_target.set(Utc::now() + TimeDelta::seconds(10))>
+            "In 10 seconds"
+        </button>
+        <button on:click=move |_| add(TimeDelta::minutes(1))>"+1 minute"</button>
+        <button on:click=move |_| add(TimeDelta::days(1))>"+1 day"</button>
+        <Note>"Switch to another tab for a while and come back. The countdown stays correct."</Note>
+        <pre>{log}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,31 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_counter_with_options, UseCounterOptions, UseCounterReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let counter = use_counter_with_options(5, UseCounterOptions::default().min(0).max(16));
+    let UseCounterReturn { count, .. } = counter;
+
+    view! {
+        <p>"Count: " {count}</p>
+        <button on:click=move |_| counter.inc(1)>"Increment"</button>
+        <button on:click=move |_| counter.dec(1)>"Decrement"</button>
+        <button on:click=move |_| counter.inc(5)>"Increment (+5)"</button>
+        <button on:click=move |_| counter.dec(5)>"Decrement (-5)"</button>
+        <button on:click=move |_| counter.set(10)>"Set (10)"</button>
+        <button on:click=move |_| counter.reset()>"Reset"</button>
+        <Note>"min: 0, max: 16"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,48 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::use_debounced_history;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let text = RwSignal::new(String::new());
+
+    let history = use_debounced_history(text, 1000.0);
+
+    view! {
+        <textarea
+            placeholder="Type something and pause for a second..."
+            prop:value=text
+            on:input=move |e| text.set(event_target_value(&e))
+        />
+        <div>
+            <button disabled=move || !history.can_undo.get() on:click=move |_| history.undo()>
+                "Undo"
+            </button>
+            <button disabled=move || !history.can_redo.get() on:click=move |_| history.redo()>
+                "Redo"
+            </button>
+        </div>
+        <p>"Undo steps"</p>
+        <ul>
+            {move || {
+                history
+                    .undo_stack
+                    .get()
+                    .into_iter()
+                    .map(|record| view! { <li>{format!("{:?}", record.snapshot)}</li> })
+                    .collect_view()
+            }}
+        </ul>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,75 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_dominant_color, UseDominantColorReturn};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (url, set_url) = signal(None::<String>);
+
+    let UseDominantColorReturn {
+        color,
+        average,
+        palette,
+        is_loading,
+        error,
+    } = use_dominant_color(url);
+
+    let on_change = move |e: leptos::ev::Event| {
+        let input = e
+            .target()
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlInputElement>();
+
+        if let Some(url) = url.get_untracked() {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        }
+
+        set_url.set(
+            input
+                .files()
+                .and_then(|files| files.get(0))
+                .and_then(|file| web_sys::Url::create_object_url_with_blob(&file).ok()),
+        );
+    };
+
+    view! {
+        <input type="file" accept="image/*" on:change=on_change />
+        <p>"Loading: " <BooleanDisplay value=is_loading /></p>
+        <div class="p-8 my-4 rounded" style:background-color=move || color.get()>
+            <Show when=move || url.get().is_some()>
+                <img src=move || url.get() class="max-w-xs max-h-64 mx-auto" />
+            </Show>
+        </div>
+        <p>"Dominant: " {move || color.get()}</p>
+        <p>
+            "Average: " {move || average.get()} " "
+            <span
+                class="inline-block w-6 h-6 align-middle rounded"
+                style:background-color=move || average.get()
+            ></span>
+        </p>
+        <div class="flex gap-2 my-4">
+            <For each=move || palette.get() key=|color| color.clone() let:color>
+                <span
+                    class="inline-block w-10 h-10 rounded"
+                    style:background-color=color.clone()
+                    title=color
+                ></span>
+            </For>
+        </div>
+        <p>{move || error.with(|error| error.as_ref().map(ToString::to_string))}</p>
+        <Note>"Pick an image to extract its colors."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,64 @@
+use leptos::html::Article;
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{
+    use_external_links_with_options, UseExternalLinksOptions, UseExternalLinksReturn,
+};
+
+const RENDERED_MARKDOWN: &str = r##"
+<p>Read the <a href="https://leptos.dev">Leptos book</a> or the
+<a href="https://docs.rs/leptos-use">API docs</a>.</p>
+<p>Jump to the <a href="#links">links</a> or <a href="mailto:someone@example.com">write an email</a>.</p>
+"##;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let article = NodeRef::<Article>::new();
+
+    let (confirm, set_confirm) = signal(true);
+    let (extra_links, set_extra_links) = signal(0);
+
+    let UseExternalLinksReturn { count, .. } = use_external_links_with_options(
+        article,
+        UseExternalLinksOptions::default().on_leave(move |url: String| {
+            !confirm.get_untracked()
+                || window()
+                    .confirm_with_message(&format!("Leave this page for {url}?"))
+                    .unwrap_or(false)
+        }),
+    );
+
+    view! {
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=confirm
+                on:change=move |e| set_confirm.set(event_target_checked(&e))
+            />
+            " Confirm before leaving"
+        </label>
+        <button on:click=move |_| set_extra_links.update(|n| *n += 1)>"Add link"</button>
+        <article node_ref=article id="links">
+            <div inner_html=RENDERED_MARKDOWN></div>
+            <ul>
+                <For each=move || 1..=extra_links.get() key=|i| *i let:i>
+                    <li>
+                        <a href=format!("https://example.com/{i}")>"Added link " {i}</a>
+                    </li>
+                </For>
+            </ul>
+        </article>
+        <p>"External links: " {count}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (id, set_id) = signal(1);
+
+    let UseFetchReturn {
+        data,
+        status_code,
+        is_fetching,
+        is_finished,
+        aborted,
+        error,
+        execute,
+        abort,
+        ..
+    } = use_fetch_with_options(
+        Signal::derive(move || format!("https://jsonplaceholder.typicode.com/todos/{}", id.get())),
+        UseFetchOptions::default().refetch(true),
+    );
+
+    view! {
+        <div>
+            <button on:click=move |_| set_id.update(|id| *id = (*id).max(2) - 1)>"Previous"</button>
+            <button on:click=move |_| set_id.update(|id| *id += 1)>"Next"</button>
+            <button on:click=move |_| execute()>"Reload"</button>
+            <button on:click=move |_| abort() disabled=move || !is_fetching.get()>
+                "Abort"
+            </button>
+        </div>
+        <p>"Todo #" {id}</p>
+        <p>"Is fetching: " <BooleanDisplay value=is_fetching /></p>
+        <p>"Is finished: " <BooleanDisplay value=is_finished /></p>
+        <p>"Aborted: " <BooleanDisplay value=aborted /></p>
+        <p>"Status code: " {move || status_code.get().map(|code| code.to_string())}</p>
+        <p>"Error: " {move || error.with(|error| error.as_ref().map(ToString::to_string))}</p>
+        <pre>{move || data.get()}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,69 @@
+use leptos::html::{Div, Span};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_fit_text, use_fit_text_with_options, UseFitTextOptions, UseFitTextReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = signal("Fit me!".to_string());
+    let (width, set_width) = signal(100);
+
+    let heading = NodeRef::<Div>::new();
+    let UseFitTextReturn { font_size, .. } = use_fit_text(heading);
+
+    let badge = NodeRef::<Span>::new();
+    let UseFitTextReturn {
+        font_size: badge_font_size,
+        ..
+    } = use_fit_text_with_options(
+        badge,
+        UseFitTextOptions::default()
+            .min_font_size(10.0)
+            .max_font_size(40.0)
+            .max_lines(2),
+    );
+
+    view! {
+        <div class="flex flex-col gap-2">
+            <input
+                prop:value=text
+                on:input=move |e| set_text.set(event_target_value(&e))
+            />
+            <label>
+                "Container width "
+                <input
+                    type="range"
+                    min="10"
+                    max="100"
+                    prop:value=move || width.get().to_string()
+                    on:input=move |e| set_width.set(event_target_value(&e).parse().unwrap_or(100))
+                />
+            </label>
+        </div>
+        <div
+            class="mt-4 border border-gray-400/30 rounded p-2"
+            style:width=move || format!("{}%", width.get())
+        >
+            <div node_ref=heading class="font-bold" style="white-space: nowrap">
+                {text}
+            </div>
+            <span node_ref=badge class="block mt-2">
+                {move || format!("{} (up to two lines)", text.get())}
+            </span>
+        </div>
+        <Note class="mt-2">
+            {move || format!("Heading: {:?}px, Badge: {:?}px", font_size.get(), badge_font_size.get())}
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
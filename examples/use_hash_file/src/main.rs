@@ -0,0 +1,55 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_hash_file, HashAlgorithm, UseHashFileReturn};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (file, set_file) = signal_local(None::<web_sys::File>);
+
+    let UseHashFileReturn {
+        hash: sha256,
+        progress,
+        is_hashing,
+        abort,
+        ..
+    } = use_hash_file(file, HashAlgorithm::Sha256);
+
+    let UseHashFileReturn { hash: md5, .. } = use_hash_file(file, HashAlgorithm::Md5);
+
+    let on_change = move |e: leptos::ev::Event| {
+        let input = e
+            .target()
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlInputElement>();
+
+        set_file.set(input.files().and_then(|files| files.get(0)));
+    };
+
+    view! {
+        <input type="file" on:change=on_change />
+        <button on:click=move |_| abort() disabled=move || !is_hashing.get()>
+            "Abort"
+        </button>
+        <div class="my-4">
+            <progress max="1" value=move || progress.get().to_string() />
+            " "
+            {move || format!("{:.0}%", progress.get() * 100.0)}
+        </div>
+        <p>"Hashing: " <BooleanDisplay value=is_hashing /></p>
+        <p>"SHA-256: " <code>{move || sha256.get().unwrap_or_default()}</code></p>
+        <p>"MD5: " <code>{move || md5.get().unwrap_or_default()}</code></p>
+        <Note>"Pick a large file to see the progress."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,55 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_history_with_options, UseHistoryOptions};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let count = RwSignal::new(0);
+
+    let history = use_history_with_options(count, UseHistoryOptions::default().capacity(10));
+
+    view! {
+        <p>"Count: " {count}</p>
+        <button on:click=move |_| count.update(|count| *count += 1)>"Increment"</button>
+        <button on:click=move |_| count.update(|count| *count -= 1)>"Decrement"</button>
+        <span class="ml-2">"/"</span>
+        <button disabled=move || !history.can_undo.get() on:click=move |_| history.undo()>
+            "Undo"
+        </button>
+        <button disabled=move || !history.can_redo.get() on:click=move |_| history.redo()>
+            "Redo"
+        </button>
+        <button on:click=move |_| history.clear()>"Clear"</button>
+        <p>"History (limited to 10 records)"</p>
+        <ul>
+            {move || {
+                history
+                    .history
+                    .get()
+                    .into_iter()
+                    .map(|record| {
+                        view! {
+                            <li>
+                                {record.snapshot} " at "
+                                {js_sys::Date::new(&record.timestamp.into())
+                                    .to_locale_time_string("en-US")
+                                    .as_string()}
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,53 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::core::HistoryWriteMode;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_history_state, use_history_state_with_options, UseHistoryStateOptions};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = use_history_state::<String, FromToStringCodec>("demo-text");
+
+    let (step, set_step) = use_history_state_with_options::<u32, FromToStringCodec>(
+        "demo-step",
+        UseHistoryStateOptions::default()
+            .initial_value(1)
+            .write_mode(HistoryWriteMode::Push),
+    );
+
+    view! {
+        <input
+            placeholder="Type something"
+            prop:value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+        />
+        <Note>"The text is stored in the current history entry and survives a reload."</Note>
+        <div class="flex gap-2 items-center mt-4">
+            <button
+                disabled=move || step.get() <= 1
+                on:click=move |_| set_step.update(|step| *step -= 1)
+            >
+                "Previous"
+            </button>
+            <span>"Step " {step} " of 5"</span>
+            <button
+                disabled=move || step.get() >= 5
+                on:click=move |_| set_step.update(|step| *step += 1)
+            >
+                "Next"
+            </button>
+        </div>
+        <Note>"Every step is a new history entry. Use the back button of the browser to go back."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,46 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_idle_prefetch_with_options, IdlePrefetchTask, UseIdlePrefetchOptions, UseIdlePrefetchReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (done, set_done) = signal(Vec::<String>::new());
+
+    let task =
+        move |name: String| IdlePrefetchTask::new(move || set_done.update(|done| done.push(name)));
+
+    let UseIdlePrefetchReturn {
+        pending,
+        is_active,
+        enqueue,
+    } = use_idle_prefetch_with_options(
+        (1..=5).map(|i| task(format!("Page {i}"))).collect(),
+        UseIdlePrefetchOptions::default().idle_timeout(1000),
+    );
+
+    let (count, set_count) = signal(5);
+
+    view! {
+        <p>"Stop moving the mouse for a second to run the prefetch tasks."</p>
+        <p>"Active: " <BooleanDisplay value=is_active /></p>
+        <p>"Pending: " {pending}</p>
+        <button on:click=move |_| {
+            set_count.update(|count| *count += 1);
+            enqueue(task(format!("Page {}", count.get_untracked())));
+        }>"Add task"</button>
+        <p>"Prefetched: " {move || done.get().join(", ")}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
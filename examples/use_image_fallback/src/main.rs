@@ -0,0 +1,45 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_image_fallback_with_options, UseImageFallbackOptions};
+
+const FALLBACK_SRC: &str = "https://placehold.co/120x80?text=Fallback";
+
+#[component]
+fn Demo() -> impl IntoView {
+    let gallery = NodeRef::<Div>::new();
+
+    let (images, set_images) = signal(vec![
+        "https://placehold.co/120x80?text=Works".to_string(),
+        "https://example.com/missing.png".to_string(),
+    ]);
+
+    let failed = use_image_fallback_with_options(
+        gallery,
+        FALLBACK_SRC,
+        UseImageFallbackOptions::default().placeholder_class("placeholder"),
+    );
+
+    view! {
+        <button on:click=move |_| {
+            set_images.update(|images| images.push("https://example.com/broken.png".to_string()))
+        }>"Add broken image"</button>
+        <div node_ref=gallery class="flex gap-2 mt-4">
+            <For each=move || images.get().into_iter().enumerate() key=|(i, _)| *i let:image>
+                <img src=image.1 alt=format!("Image {}", image.0 + 1) width="120" height="80" />
+            </For>
+        </div>
+        <p>{failed} " images failed to load"</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,79 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_image_metadata, UseImageMetadataReturn};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (file, set_file) = signal_local(None::<web_sys::File>);
+
+    let UseImageMetadataReturn {
+        metadata,
+        is_loading,
+        error,
+    } = use_image_metadata(file);
+
+    let url = Signal::derive_local(move || {
+        file.get()
+            .and_then(|file| web_sys::Url::create_object_url_with_blob(&file).ok())
+    });
+
+    let transform = move || {
+        metadata
+            .get()
+            .map(|metadata| metadata.orientation.css_transform())
+            .unwrap_or_default()
+    };
+
+    let on_change = move |e: leptos::ev::Event| {
+        let input = e
+            .target()
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlInputElement>();
+
+        set_file.set(input.files().and_then(|files| files.get(0)));
+    };
+
+    view! {
+        <input type="file" accept="image/*" on:change=on_change />
+        <p>"Loading: " <BooleanDisplay value=is_loading /></p>
+        {move || {
+            metadata
+                .get()
+                .map(|metadata| {
+                    view! {
+                        <p>"Format: " {metadata.format.to_string()}</p>
+                        <p>
+                            "Size: " {metadata.width} "x" {metadata.height} " (displayed as "
+                            {metadata.oriented_size().map(|(w, h)| format!("{w}x{h}"))} ")"
+                        </p>
+                        <p>"Orientation: " {format!("{:?}", metadata.orientation)}</p>
+                        <p>"Captured: " {metadata.capture_date.unwrap_or_default()}</p>
+                    }
+                })
+        }}
+        <p>{move || error.with(|error| error.as_ref().map(ToString::to_string))}</p>
+        <Show when=move || url.get().is_some()>
+            <img
+                src=move || url.get()
+                class="my-8 max-w-xs max-h-64"
+                style:image-orientation="none"
+                style:transform=transform
+            />
+        </Show>
+        <Note>
+            "The preview ignores the browser's own orientation handling and is rotated with the parsed orientation instead."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
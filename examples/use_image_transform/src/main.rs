@@ -0,0 +1,70 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_image_transform_with_options, ImageFormat, UseImageTransformOptions,
+    UseImageTransformReturn,
+};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (file, set_file) = signal_local(None::<web_sys::File>);
+
+    let UseImageTransformReturn {
+        blob,
+        width,
+        height,
+        is_transforming,
+        error,
+    } = use_image_transform_with_options(
+        file,
+        UseImageTransformOptions::default()
+            .max_width(320)
+            .max_height(320)
+            .format(ImageFormat::Webp)
+            .quality(0.8),
+    );
+
+    let url = Signal::derive_local(move || {
+        blob.get()
+            .and_then(|blob| web_sys::Url::create_object_url_with_blob(&blob).ok())
+    });
+
+    let on_change = move |e: leptos::ev::Event| {
+        let input = e
+            .target()
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlInputElement>();
+
+        set_file.set(input.files().and_then(|files| files.get(0)));
+    };
+
+    view! {
+        <input type="file" accept="image/*" on:change=on_change />
+        <p>"Transforming: " <BooleanDisplay value=is_transforming /></p>
+        <p>
+            "Original: "
+            {move || file.get().map(|file| format!("{:.0} KB", file.size() / 1024.0))}
+        </p>
+        <p>
+            "Thumbnail: " {move || width.get()} "x" {move || height.get()} ", "
+            {move || blob.get().map(|blob| format!("{:.0} KB", blob.size() / 1024.0))}
+        </p>
+        <Show when=move || url.get().is_some()>
+            <img src=move || url.get() class="my-4" />
+        </Show>
+        <p>{move || error.with(|error| error.as_ref().map(ToString::to_string))}</p>
+        <Note>"The image is scaled down to fit into 320x320 and encoded as WebP."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
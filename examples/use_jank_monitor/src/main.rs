@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_jank_monitor, UseJankMonitorReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseJankMonitorReturn {
+        is_supported,
+        is_busy,
+        total_blocking_time,
+        long_task_count,
+    } = use_jank_monitor();
+
+    let block_main_thread = move |ms: f64| {
+        let start = js_sys::Date::now();
+        while js_sys::Date::now() - start < ms {}
+    };
+
+    view! {
+        <Show
+            when=move || is_supported.get()
+            fallback=|| view! { <p>"Long tasks aren't reported by this browser"</p> }
+        >
+            <p>"Busy: " <BooleanDisplay value=is_busy /></p>
+            <p>"Long tasks in the last 5 seconds: " {long_task_count}</p>
+            <p>
+                "Total blocking time: "
+                {move || format!("{:.0}", total_blocking_time.get())} "ms"
+            </p>
+            <button on:click=move |_| block_main_thread(100.0)>"Block for 100ms"</button>
+            <button on:click=move |_| block_main_thread(400.0)>"Block for 400ms"</button>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
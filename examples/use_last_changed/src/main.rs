@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_last_changed, use_timestamp};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = signal(String::new());
+
+    let last_changed = use_last_changed::<String>(text);
+    let timestamp = use_timestamp();
+
+    view! {
+        <input
+            type="text"
+            placeholder="Type something..."
+            prop:value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+        />
+        <p>
+            {move || match last_changed.get() {
+                Some(last_changed) => {
+                    let seconds = ((timestamp.get() - last_changed) / 1000.0).max(0.0).floor();
+                    format!("Last edited {seconds} seconds ago")
+                }
+                None => "Not edited yet".to_string(),
+            }}
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{dynamic_import, use_lazy_component, UseLazyComponentReturn};
+use wasm_bindgen::{JsCast, JsValue};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseLazyComponentReturn {
+        data: confetti,
+        is_loaded,
+        is_loading,
+        error,
+        load,
+    } = use_lazy_component(|| dynamic_import("https://esm.sh/canvas-confetti@1"));
+
+    let celebrate = move |_| {
+        confetti.with(|confetti| {
+            let confetti = confetti
+                .as_ref()
+                .and_then(|module| js_sys::Reflect::get(module, &"default".into()).ok())
+                .and_then(|confetti| confetti.dyn_into::<js_sys::Function>().ok());
+
+            if let Some(confetti) = confetti {
+                let _ = confetti.call0(&JsValue::NULL);
+            }
+        });
+    };
+
+    view! {
+        <button on:click=move |_| load() disabled=move || is_loaded.get()>
+            "Load confetti"
+        </button>
+        <button on:click=celebrate disabled=move || !is_loaded.get()>
+            "Celebrate"
+        </button>
+        <p>"Loading: " <BooleanDisplay value=is_loading /></p>
+        <p>"Loaded: " <BooleanDisplay value=is_loaded /></p>
+        <p>{move || error.get().and_then(|error| error.as_string())}</p>
+        <Note>"The confetti module is only downloaded after you click the first button."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,79 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_marquee_select, UseMarqueeSelectReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let container = NodeRef::<Div>::new();
+
+    let marquee = use_marquee_select::<usize, _, _>(container);
+    let UseMarqueeSelectReturn {
+        rect,
+        is_selecting,
+        selected,
+        ..
+    } = marquee;
+
+    let selected_text = move || {
+        let mut selected = selected.get().into_iter().collect::<Vec<_>>();
+        selected.sort();
+
+        selected
+            .into_iter()
+            .map(|i| format!("File {i}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    view! {
+        <Note class="mb-2">"Drag inside the area to select files"</Note>
+        <div
+            node_ref=container
+            class="area relative select-none grid grid-cols-6 gap-4 p-4"
+        >
+            {(0..24)
+                .map(|i| {
+                    let item = NodeRef::<Div>::new();
+                    marquee.register(i, item);
+
+                    view! {
+                        <div
+                            node_ref=item
+                            class="rounded p-4 text-center border border-transparent"
+                            class=(
+                                ["bg-blue-500/30", "border-blue-500"],
+                                move || selected.with(|s| s.contains(&i)),
+                            )
+                        >
+                            {format!("File {i}")}
+                        </div>
+                    }
+                })
+                .collect_view()}
+
+            <Show when=move || is_selecting.get()>
+                <div
+                    class="absolute pointer-events-none border border-blue-500 bg-blue-500/20"
+                    style:left=move || format!("{}px", rect.get().unwrap_or_default().left)
+                    style:top=move || format!("{}px", rect.get().unwrap_or_default().top)
+                    style:width=move || format!("{}px", rect.get().unwrap_or_default().width)
+                    style:height=move || format!("{}px", rect.get().unwrap_or_default().height)
+                />
+            </Show>
+        </div>
+        <p>"Selected: " {selected_text}</p>
+        <button on:click=move |_| marquee.clear()>"Clear selection"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
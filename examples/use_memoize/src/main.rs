@@ -0,0 +1,57 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_memoize_with_options, UseMemoizeOptions, UseMemoizeReturn};
+
+/// Pretends to fetch the name of a user over a slow network.
+async fn fetch_user_name(id: u32, calls: WriteSignal<u32>) -> String {
+    calls.update(|calls| *calls += 1);
+    gloo_timers::future::TimeoutFuture::new(1000).await;
+    format!("User #{id}")
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (calls, set_calls) = signal(0);
+    let (user_id, set_user_id) = signal(1_u32);
+
+    let user_names = use_memoize_with_options(
+        move |id: u32| fetch_user_name(id, set_calls),
+        UseMemoizeOptions::default().max_size(5),
+    );
+    let UseMemoizeReturn { size, .. } = user_names;
+
+    let user_name = LocalResource::new(move || user_names.load(user_id.get()));
+
+    view! {
+        <div>
+            <button on:click=move |_| set_user_id.update(|id| *id = id.saturating_sub(1).max(1))>
+                "Previous user"
+            </button>
+            <button on:click=move |_| set_user_id.update(|id| *id += 1)>"Next user"</button>
+        </div>
+        <p>
+            <Suspense fallback=|| "Loading...">
+                {move || user_name.get().map(|name| name.take())}
+            </Suspense>
+        </p>
+        <div>
+            <button on:click=move |_| user_names.delete(&user_id.get_untracked())>
+                "Forget this user"
+            </button>
+            <button on:click=move |_| user_names.clear()>"Clear cache"</button>
+        </div>
+        <p>"Cached users: " {size} " (at most 5)"</p>
+        <p>"Network calls: " {calls}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
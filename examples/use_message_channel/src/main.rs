@@ -0,0 +1,53 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_message_channel, UseMessageChannelReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseMessageChannelReturn {
+        message1,
+        message2,
+        post1,
+        post2,
+        ..
+    } = use_message_channel::<String, FromToStringCodec>();
+
+    // the second port answers every message it receives
+    let _ = Effect::watch(
+        move || message2.get(),
+        move |message, _, _| {
+            if let Some(message) = message {
+                post2(&message.to_uppercase());
+            }
+        },
+        false,
+    );
+
+    let (input, set_input) = signal("Hello port".to_string());
+
+    view! {
+        <Note class="mb-2">
+            "Port 2 replies to every message from port 1 in upper case."
+        </Note>
+        <input
+            type="text"
+            prop:value=input
+            on:input=move |e| set_input.set(event_target_value(&e))
+        />
+        <button on:click=move |_| post1(&input.get_untracked())>"Send through port 1"</button>
+        <p>"Port 2 received: " <code>{move || message2.get().unwrap_or_default()}</code></p>
+        <p>"Port 1 received: " <code>{move || message1.get().unwrap_or_default()}</code></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
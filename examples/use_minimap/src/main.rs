@@ -0,0 +1,48 @@
+use leptos::html::{Canvas, Div};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_minimap_with_options, UseMinimapOptions, UseMinimapReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let content = NodeRef::<Div>::new();
+    let minimap = NodeRef::<Canvas>::new();
+
+    let (paragraphs, set_paragraphs) = signal(20);
+
+    let UseMinimapReturn { is_dragging, .. } = use_minimap_with_options(
+        content,
+        minimap,
+        UseMinimapOptions::default().block_selector("h2, p"),
+    );
+
+    view! {
+        <p>"Dragging: " <BooleanDisplay value=is_dragging /></p>
+        <button on:click=move |_| set_paragraphs.update(|n| *n += 5)>"Add paragraphs"</button>
+        <button on:click=move |_| set_paragraphs.update(|n| *n = (*n).max(10) - 5)>
+            "Remove paragraphs"
+        </button>
+        <div class="flex gap-2 mt-4">
+            <div node_ref=content class="w-[400px] h-[300px] overflow-auto bg-gray-500/5 rounded p-2">
+                <For each=move || 0..paragraphs.get() key=|i| *i let:i>
+                    {(i % 5 == 0).then(|| view! { <h2>"Section " {i / 5 + 1}</h2> })}
+                    <p style:width=format!("{}%", 40 + (i * 37) % 60)>
+                        "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor."
+                    </p>
+                </For>
+            </div>
+            <canvas node_ref=minimap class="w-[60px] h-[300px] bg-gray-500/5 rounded cursor-pointer" />
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
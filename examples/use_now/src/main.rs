@@ -0,0 +1,45 @@
+use chrono::Local;
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_now_with_controls_and_options, UseNowOptions, UseNowReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseNowReturn {
+        now,
+        is_active,
+        pause,
+        resume,
+    } = use_now_with_controls_and_options(UseNowOptions::default().interval(1000));
+
+    let loaded_at = Local::now();
+
+    let ago = move || {
+        let seconds = (now.get() - loaded_at).num_seconds();
+
+        match seconds {
+            0..=4 => "just now".to_string(),
+            5..=59 => format!("{seconds} seconds ago"),
+            _ => format!("{} minutes ago", seconds / 60),
+        }
+    };
+
+    view! {
+        <div>"Now: " {move || now.get().format("%H:%M:%S").to_string()}</div>
+        <div>"Page loaded " {ago}</div>
+        <button on:click=move |_| {
+            if is_active.get() { pause() } else { resume() }
+        }>{move || if is_active.get() { "Pause" } else { "Resume" }}</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
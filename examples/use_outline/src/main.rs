@@ -0,0 +1,63 @@
+use leptos::html::Article;
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_outline, OutlineItem, UseOutlineReturn};
+
+fn table_of_contents(items: Vec<OutlineItem>) -> AnyView {
+    view! {
+        <ul class="pl-4">
+            {items
+                .into_iter()
+                .map(|item| {
+                    view! {
+                        <li>
+                            <a href=format!("#{}", item.id)>{item.text}</a>
+                            {(!item.children.is_empty()).then(|| table_of_contents(item.children))}
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+    .into_any()
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let article = NodeRef::<Article>::new();
+
+    let (sections, set_sections) = signal(2);
+
+    let UseOutlineReturn { outline, ids, .. } = use_outline(article);
+
+    view! {
+        <button on:click=move |_| set_sections.update(|n| *n += 1)>"Add section"</button>
+        <button on:click=move |_| set_sections.update(|n| *n = n.saturating_sub(1))>
+            "Remove section"
+        </button>
+        <div class="flex gap-4 mt-4">
+            <nav class="w-[200px]">{move || table_of_contents(outline.get())}</nav>
+            <article node_ref=article class="h-[300px] overflow-auto">
+                <h1>"Getting Started"</h1>
+                <For each=move || 1..=sections.get() key=|i| *i let:i>
+                    <h2>"Section " {i}</h2>
+                    <p>"Some text of the section."</p>
+                    <h3>"Details"</h3>
+                    <p>"More text."</p>
+                </For>
+            </article>
+        </div>
+        <p class="mt-4">"Ids: " {move || ids.get().join(", ")}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
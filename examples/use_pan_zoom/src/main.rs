@@ -0,0 +1,84 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::core::Position;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_pan_zoom, UsePanZoomReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let container = NodeRef::<Div>::new();
+    let content = NodeRef::<Div>::new();
+
+    let UsePanZoomReturn {
+        zoom,
+        offset,
+        is_panning,
+        zoom_to_point,
+        fit_to_view,
+        reset,
+    } = use_pan_zoom(container, content);
+
+    let zoom_by = move |factor: f64| {
+        let zoom_to_point = zoom_to_point.clone();
+
+        move |_| {
+            let center = container
+                .get_untracked()
+                .map(|el| Position {
+                    x: el.client_width() as f64 / 2.0,
+                    y: el.client_height() as f64 / 2.0,
+                })
+                .unwrap_or_default();
+
+            zoom_to_point(zoom.get_untracked() * factor, center);
+        }
+    };
+
+    view! {
+        <Note class="mb-2">"Drag to pan, use the mouse wheel or pinch to zoom"</Note>
+        <div
+            node_ref=container
+            class="area relative overflow-hidden h-96 cursor-grab select-none"
+            style="touch-action: none"
+        >
+            <div node_ref=content class="grid grid-cols-8 gap-2 p-4 w-[800px]">
+                {(0..64)
+                    .map(|i| {
+                        view! {
+                            <div class="h-20 rounded bg-blue-500/30 flex items-center justify-center">
+                                {i}
+                            </div>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+
+        <div class="mt-2">
+            <button on:click=zoom_by(1.25)>"Zoom in"</button>
+            <button on:click=zoom_by(0.8)>"Zoom out"</button>
+            <button on:click=move |_| fit_to_view()>"Fit"</button>
+            <button on:click=move |_| reset()>"Reset"</button>
+        </div>
+        <p>"Zoom: " {move || format!("{:.0}%", zoom.get() * 100.0)}</p>
+        <p>
+            "Offset: "
+            {move || {
+                let offset = offset.get();
+                format!("{:.0}, {:.0}", offset.x, offset.y)
+            }}
+        </p>
+        <p>"Panning: " <BooleanDisplay value=is_panning /></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,48 @@
+use leptos::html::Canvas;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_particles, ParticleBurst, UseParticlesReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let canvas = NodeRef::<Canvas>::new();
+
+    let particles = use_particles(canvas);
+    let UseParticlesReturn { is_active, .. } = particles;
+
+    view! {
+        <canvas
+            node_ref=canvas
+            style="position: fixed; inset: 0; width: 100%; height: 100%; pointer-events: none; z-index: 100"
+        />
+        <div class="flex gap-2 flex-wrap">
+            <button on:click=move |_| particles.fire(ParticleBurst::default())>"Confetti"</button>
+            <button on:click=move |_| {
+                particles.fire(ParticleBurst::from_left());
+                particles.fire(ParticleBurst::from_right());
+            }>"Side cannons"</button>
+            <button on:click=move |_| {
+                particles
+                    .fire(
+                        ParticleBurst::explosion()
+                            .origin((0.2 + js_sys::Math::random() * 0.6, 0.3))
+                            .colors(vec!["gold".to_string(), "orange".to_string(), "white".to_string()]),
+                    )
+            }>"Firework"</button>
+            <button on:click=move |_| particles.clear()>"Clear"</button>
+        </div>
+        <p>"Animating: " {move || is_active.get().to_string()}</p>
+        <Note>"Nothing happens if your system is set to reduce motion."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
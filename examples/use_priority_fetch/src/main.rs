@@ -0,0 +1,80 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_element_visibility, use_priority_fetch_with_options, FetchPriority, FetchQueue,
+    UsePriorityFetchOptions, UsePriorityFetchReturn,
+};
+use wasm_bindgen_futures::JsFuture;
+
+/// Simulates a request that takes `ms` milliseconds to respond.
+async fn fake_request(index: usize, ms: i32) -> String {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            .unwrap();
+    });
+
+    let _ = JsFuture::from(promise).await;
+
+    format!("Response #{index}")
+}
+
+#[component]
+fn Item(index: usize) -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+    let is_visible = use_element_visibility(el);
+
+    let UsePriorityFetchReturn {
+        data,
+        is_queued,
+        is_loading,
+        ..
+    } = use_priority_fetch_with_options(
+        move || fake_request(index, 1000),
+        UsePriorityFetchOptions::default()
+            .priority(FetchPriority::Low)
+            .visible(is_visible),
+    );
+
+    let status = move || {
+        if is_queued.get() {
+            "queued".to_string()
+        } else if is_loading.get() {
+            "loading...".to_string()
+        } else {
+            data.get().unwrap_or_default()
+        }
+    };
+
+    view! {
+        <div node_ref=el class="area my-2 p-4">
+            "Item " {index} ": " <b>{status}</b>
+        </div>
+    }
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    provide_context(FetchQueue::new(2));
+
+    view! {
+        <Note class="mb-4">
+            "At most 2 requests run at the same time. Scroll down: visible items are loaded first."
+        </Note>
+        <div class="max-h-96 overflow-y-auto">
+            {(0..30).map(|index| view! { <Item index=index /> }).collect_view()}
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,34 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_privacy_signals, UsePrivacySignalsReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UsePrivacySignalsReturn {
+        do_not_track,
+        global_privacy_control,
+        cookies_enabled,
+        opted_out,
+    } = use_privacy_signals();
+
+    view! {
+        <div>"Do Not Track: " <BooleanDisplay value=do_not_track /></div>
+        <div>"Global Privacy Control: " <BooleanDisplay value=global_privacy_control /></div>
+        <div>"Cookies enabled: " <BooleanDisplay value=cookies_enabled /></div>
+        <div>"Opted out of tracking: " <BooleanDisplay value=opted_out /></div>
+        <Note>
+            "Change the privacy settings of your browser and come back to this tab to see the values update."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
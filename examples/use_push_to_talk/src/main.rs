@@ -0,0 +1,58 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_audio_level, use_push_to_talk, use_user_media_with_options, UseAudioLevelReturn,
+    UsePushToTalkReturn, UseUserMediaOptions, UseUserMediaReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media_with_options(UseUserMediaOptions::default().audio(true).video(false));
+
+    let stream = Signal::derive_local(move || stream.get().and_then(Result::ok));
+
+    let push_to_talk = use_push_to_talk(stream, "Space");
+    let UsePushToTalkReturn {
+        is_transmitting, ..
+    } = push_to_talk;
+
+    // disabled tracks are silent so the level shows what is transmitted
+    let UseAudioLevelReturn { level, .. } = use_audio_level(stream);
+
+    view! {
+        <button on:click=move |_| {
+            set_enabled.set(!enabled.get_untracked())
+        }>{move || if enabled.get() { "Stop microphone" } else { "Start microphone" }}</button>
+        <button
+            on:pointerdown=move |_| push_to_talk.start()
+            on:pointerup=move |_| push_to_talk.stop()
+            on:pointerleave=move |_| push_to_talk.stop()
+        >
+            "Hold to talk"
+        </button>
+        <p>"Transmitting: " <BooleanDisplay value=is_transmitting /></p>
+        <div class="w-64 h-4 my-4 rounded bg-gray-500/20 overflow-hidden">
+            <div
+                class="h-full bg-green-500"
+                style:width=move || format!("{:.0}%", level.get() * 100.0)
+            />
+        </div>
+        <Note>"Start the microphone and hold the Space key or the button to talk."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_responsive_source, ResponsiveSource};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let src = use_responsive_source(vec![
+        ResponsiveSource::new("https://placehold.co/320x180?text=Small"),
+        ResponsiveSource::new("https://placehold.co/640x360?text=Small@2x").density(2.0),
+        ResponsiveSource::new("https://placehold.co/640x360?text=Medium").min_width(640),
+        ResponsiveSource::new("https://placehold.co/1280x720?text=Medium@2x")
+            .min_width(640)
+            .density(2.0),
+        ResponsiveSource::new("https://placehold.co/1024x576?text=Large").min_width(1024),
+        ResponsiveSource::new("https://placehold.co/2048x1152?text=Large@2x")
+            .min_width(1024)
+            .density(2.0),
+    ]);
+
+    view! {
+        <p>"Resize the window or zoom in and out to see the source change."</p>
+        <p>"Current source: " <code>{move || src.get().unwrap_or_default()}</code></p>
+        <img src=src class="max-w-full" />
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
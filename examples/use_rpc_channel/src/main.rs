@@ -0,0 +1,59 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::use_rpc_channel;
+
+#[component]
+fn Demo() -> impl IntoView {
+    // both ends live in this page to keep the demo self-contained. Usually one of the ports is
+    // transferred to an iframe or a worker.
+    let message_channel = web_sys::MessageChannel::new().unwrap();
+
+    let server = use_rpc_channel::<u64, u64, FromToStringCodec>(message_channel.port2());
+    server.handle(|n| async move { (1..=n).product() });
+
+    let client = use_rpc_channel::<u64, u64, FromToStringCodec>(message_channel.port1());
+
+    let (input, set_input) = signal(5_u64);
+    let (result, set_result) = signal(String::new());
+
+    let on_click = move |_| {
+        spawn_local(async move {
+            let n = input.get_untracked();
+
+            set_result.set(match client.call(&n).await {
+                Ok(factorial) => format!("{n}! = {factorial}"),
+                Err(err) => format!("Error: {err}"),
+            });
+        });
+    };
+
+    view! {
+        <input
+            type="number"
+            min="0"
+            max="20"
+            prop:value=move || input.get().to_string()
+            on:input=move |e| {
+                if let Ok(n) = event_target_value(&e).parse() {
+                    set_input.set(n);
+                }
+            }
+        />
+        <button on:click=on_click>"Call"</button>
+        <p>{result}</p>
+        <Note>"The factorial is computed by the handler on the other end of the channel."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,86 @@
+use chrono::{Local, NaiveTime, TimeDelta, Timelike, Weekday};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_schedule, ScheduleRule, UseScheduleReturn};
+
+fn hm(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+fn format_duration(duration: TimeDelta) -> String {
+    let seconds = duration.num_seconds();
+    format!(
+        "{}h {:02}m {:02}s",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let business_hours = vec![
+        ScheduleRule::new(
+            [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            hm(9, 0),
+            hm(18, 0),
+        ),
+        ScheduleRule::new([Weekday::Sat], hm(10, 0), hm(14, 0)),
+    ];
+
+    let (rules, set_rules) = signal(business_hours.clone());
+
+    let UseScheduleReturn {
+        is_open,
+        next_change_at,
+        time_until_change,
+    } = use_schedule(rules);
+
+    let open_this_minute = move |_| {
+        let now = Local::now().time();
+        let open = hm(now.hour(), now.minute());
+        set_rules.set(vec![ScheduleRule::every_day(
+            open,
+            open + TimeDelta::minutes(1),
+        )]);
+    };
+
+    view! {
+        <p>"Open: " <BooleanDisplay value=is_open /></p>
+        <p>
+            "Next change: "
+            {move || {
+                next_change_at
+                    .get()
+                    .map(|at| at.with_timezone(&Local).format("%a %H:%M").to_string())
+                    .unwrap_or_else(|| "never".to_string())
+            }}
+        </p>
+        <p>"In: " {move || time_until_change.get().map(format_duration)}</p>
+        <button on:click=move |_| set_rules.set(business_hours.clone())>
+            "Business hours"
+        </button>
+        <button on:click=open_this_minute>"Open this minute"</button>
+        <button on:click=move |_| {
+            set_rules.set(vec![ScheduleRule::every_day(hm(0, 0), hm(0, 0))])
+        }>"Always open"</button>
+        <button on:click=move |_| set_rules.set(vec![])>"Closed"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
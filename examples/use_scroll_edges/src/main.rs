@@ -0,0 +1,81 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_scroll_edges, UseScrollEdgesReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+    let (count, set_count) = signal(20);
+
+    let UseScrollEdgesReturn {
+        top,
+        bottom,
+        left,
+        right,
+    } = use_scroll_edges(el);
+
+    let shadow = |style: &'static str, visible: Signal<bool>| {
+        view! {
+            <div
+                class="absolute pointer-events-none transition-opacity"
+                class=(["opacity-0"], move || !visible.get())
+                style=style
+            />
+        }
+    };
+
+    view! {
+        <div class="flex gap-2 mb-2">
+            <button on:click=move |_| set_count.update(|count| *count += 5)>"Add rows"</button>
+            <button on:click=move |_| set_count.update(|count| *count = count.saturating_sub(5))>
+                "Remove rows"
+            </button>
+        </div>
+        <div class="relative w-[300px] h-[200px] border border-gray-400/30 rounded">
+            <div node_ref=el class="w-full h-full overflow-auto">
+                <div class="w-[500px] p-2">
+                    <For each=move || 0..count.get() key=|row| *row let:row>
+                        <div>"Row " {row + 1} " with some text that is wider than the container"</div>
+                    </For>
+                </div>
+            </div>
+            {shadow(
+                "top: 0; left: 0; right: 0; height: 16px; background: linear-gradient(to bottom, rgba(0,0,0,.3), transparent)",
+                top,
+            )}
+            {shadow(
+                "bottom: 0; left: 0; right: 0; height: 16px; background: linear-gradient(to top, rgba(0,0,0,.3), transparent)",
+                bottom,
+            )}
+            {shadow(
+                "top: 0; bottom: 0; left: 0; width: 16px; background: linear-gradient(to right, rgba(0,0,0,.3), transparent)",
+                left,
+            )}
+            {shadow(
+                "top: 0; bottom: 0; right: 0; width: 16px; background: linear-gradient(to left, rgba(0,0,0,.3), transparent)",
+                right,
+            )}
+        </div>
+        <Note class="mt-2">
+            {move || format!(
+                "More content: top {}, bottom {}, left {}, right {}",
+                top.get(),
+                bottom.get(),
+                left.get(),
+                right.get(),
+            )}
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
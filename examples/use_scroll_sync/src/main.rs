@@ -0,0 +1,74 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{
+    use_scroll_sync_with_options, ScrollSyncAxis, ScrollSyncMode, UseScrollSyncOptions,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let left = NodeRef::<Div>::new();
+    let right = NodeRef::<Div>::new();
+
+    let (enabled, set_enabled) = signal(true);
+    let (absolute, set_absolute) = signal(false);
+
+    let mode = Signal::derive(move || {
+        if absolute.get() {
+            ScrollSyncMode::Absolute
+        } else {
+            ScrollSyncMode::Proportional
+        }
+    });
+
+    use_scroll_sync_with_options(
+        left,
+        right,
+        UseScrollSyncOptions::default()
+            .mode(mode)
+            .axis(ScrollSyncAxis::Both)
+            .enabled(enabled),
+    );
+
+    view! {
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=enabled
+                on:input=move |e| set_enabled.set(event_target_checked(&e))
+            />
+            " Sync"
+        </label>
+        <label class="ml-4">
+            <input
+                type="checkbox"
+                prop:checked=absolute
+                on:input=move |e| set_absolute.set(event_target_checked(&e))
+            />
+            " Absolute"
+        </label>
+        <div class="flex gap-4 mt-4">
+            <div node_ref=left class="w-[250px] h-[300px] overflow-auto bg-gray-500/5 rounded">
+                <div class="w-[400px] p-2">
+                    {(1..=40).map(|i| view! { <p>"Line " {i}</p> }).collect_view()}
+                </div>
+            </div>
+            <div node_ref=right class="w-[250px] h-[300px] overflow-auto bg-gray-500/5 rounded">
+                <div class="w-[800px] p-2">
+                    {(1..=100).map(|i| view! { <p>"Line " {i}</p> }).collect_view()}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
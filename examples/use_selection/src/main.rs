@@ -0,0 +1,65 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_selection, UseSelectionReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (items, set_items) = signal((1..=12).map(|i| format!("Item {i}")).collect::<Vec<_>>());
+
+    let selection = use_selection(items);
+    let UseSelectionReturn {
+        selected, anchor, ..
+    } = selection;
+
+    let remove_selected = move |_| {
+        let selected = selected.get_untracked();
+        set_items.update(|items| items.retain(|item| !selected.contains(item)));
+    };
+
+    view! {
+        <Note class="mb-2">
+            "Click to select, Ctrl/Cmd-click to toggle and Shift-click to select a range."
+        </Note>
+        <div class="grid grid-cols-4 gap-2 select-none">
+            <For each=move || items.get() key=|item| item.clone() let:item>
+                {
+                    let key = item.clone();
+                    let is_selected = {
+                        let key = key.clone();
+                        move || selection.is_selected(&key)
+                    };
+
+                    view! {
+                        <div
+                            class="px-3 py-2 rounded border cursor-pointer"
+                            class=(["bg-[--brand-color]", "text-white"], is_selected.clone())
+                            class=(["border-gray-400/30"], move || !is_selected())
+                            on:click=move |e| selection.select_with_event(key.clone(), &e)
+                        >
+                            {item}
+                        </div>
+                    }
+                }
+            </For>
+        </div>
+        <div class="mt-4">
+            <button on:click=move |_| selection.select_all()>"Select all"</button>
+            <button on:click=move |_| selection.invert()>"Invert"</button>
+            <button on:click=move |_| selection.clear()>"Clear"</button>
+            <button on:click=remove_selected>"Remove selected"</button>
+        </div>
+        <p>"Anchor: " {move || anchor.get().unwrap_or_else(|| "-".to_string())}</p>
+        <p>"Selected: " {move || selected.get().join(", ")}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,21 @@
+// Counter that is shared between all connected tabs.
+const ports = [];
+let count = 0;
+
+const broadcast = () => ports.forEach((port) => port.postMessage(String(count)));
+
+self.onconnect = (event) => {
+  const port = event.ports[0];
+  ports.push(port);
+
+  port.onmessage = (event) => {
+    if (event.data === "increment") {
+      count += 1;
+    } else if (event.data === "reset") {
+      count = 0;
+    }
+    broadcast();
+  };
+
+  port.postMessage(String(count));
+};
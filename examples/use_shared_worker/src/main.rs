@@ -0,0 +1,42 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_shared_worker, UseSharedWorkerReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseSharedWorkerReturn {
+        data,
+        status,
+        post,
+        close,
+        ..
+    } = use_shared_worker::<String, u64, FromToStringCodec>("/demo/shared-worker.js");
+
+    let increment = {
+        let post = post.clone();
+        move |_| post(&"increment".to_string())
+    };
+
+    view! {
+        <Note class="mb-2">
+            "Open this page in several tabs. They all share the same counter in one worker."
+        </Note>
+        <button on:click=increment>"Increment"</button>
+        <button on:click=move |_| post(&"reset".to_string())>"Reset"</button>
+        <button on:click=move |_| close()>"Disconnect"</button>
+        <p>"Status: " <b>{move || status.get().to_string()}</b></p>
+        <p>"Count: " <code>{move || data.get().unwrap_or_default()}</code></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
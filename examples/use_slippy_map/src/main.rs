@@ -0,0 +1,104 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_slippy_map, LatLng, MapViewport, UseSlippyMapReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (viewport, set_viewport) = signal(MapViewport {
+        center: LatLng::new(47.3769, 8.5417),
+        zoom: 13.0,
+        width: 500.0,
+        height: 350.0,
+    });
+
+    let UseSlippyMapReturn {
+        tiles,
+        bounds,
+        pixel_to_lat_lng,
+        ..
+    } = use_slippy_map(viewport);
+
+    let (clicked, set_clicked) = signal(None::<LatLng>);
+
+    let pan = Callback::new({
+        let pixel_to_lat_lng = pixel_to_lat_lng.clone();
+
+        move |(dx, dy): (f64, f64)| {
+            let center = viewport.with_untracked(|viewport| {
+                pixel_to_lat_lng((viewport.width / 2.0 + dx, viewport.height / 2.0 + dy))
+            });
+            set_viewport.update(|viewport| viewport.center = center);
+        }
+    });
+
+    let zoom = move |delta: f64| {
+        set_viewport.update(|viewport| viewport.zoom = (viewport.zoom + delta).clamp(0.0, 19.0));
+    };
+
+    view! {
+        <div
+            style="position: relative; overflow: hidden; width: 500px; height: 350px"
+            on:click=move |event| {
+                set_clicked.set(Some(pixel_to_lat_lng((event.offset_x() as f64, event.offset_y() as f64))));
+            }
+        >
+            {move || {
+                tiles
+                    .get()
+                    .into_iter()
+                    .map(|tile| {
+                        view! {
+                            <img
+                                src=tile.url("https://tile.openstreetmap.org/{z}/{x}/{y}.png")
+                                style="position: absolute; pointer-events: none; max-width: none"
+                                style:left=format!("{}px", tile.left)
+                                style:top=format!("{}px", tile.top)
+                                style:width=format!("{}px", tile.size)
+                                style:height=format!("{}px", tile.size)
+                            />
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+        <div>
+            <button on:click=move |_| pan.run((-100.0, 0.0))>"←"</button>
+            <button on:click=move |_| pan.run((0.0, -100.0))>"↑"</button>
+            <button on:click=move |_| pan.run((0.0, 100.0))>"↓"</button>
+            <button on:click=move |_| pan.run((100.0, 0.0))>"→"</button>
+            <button on:click=move |_| zoom(1.0)>"+"</button>
+            <button on:click=move |_| zoom(-1.0)>"-"</button>
+            <button on:click=move |_| zoom(0.5)>"+½"</button>
+        </div>
+        <pre>
+            {move || {
+                let bounds = bounds.get();
+                format!(
+                    "zoom: {}\nbounds: N {:.4} S {:.4} W {:.4} E {:.4}\ntiles: {}",
+                    viewport.get().zoom,
+                    bounds.north,
+                    bounds.south,
+                    bounds.west,
+                    bounds.east,
+                    tiles.with(Vec::len),
+                )
+            }}
+        </pre>
+        <p>
+            "Clicked at: "
+            {move || clicked.get().map(|lat_lng| format!("{:.5}, {:.5}", lat_lng.lat, lat_lng.lng))}
+        </p>
+        <Note>"Map data © OpenStreetMap contributors"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,94 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::core::Position;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_draggable_with_options, use_snap_with_options, Guide, UseDraggableOptions,
+    UseDraggableReturn, UseSnapOptions, UseSnapReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let (grid, set_grid) = signal(20.0);
+    let guides = vec![Guide::Vertical(300.0), Guide::Horizontal(250.0)];
+
+    let UseDraggableReturn { position, .. } = use_draggable_with_options(
+        el,
+        UseDraggableOptions::default()
+            .initial_value(Position { x: 100.0, y: 150.0 })
+            .prevent_default(true),
+    );
+
+    let UseSnapReturn {
+        x,
+        y,
+        active_guides,
+        ..
+    } = use_snap_with_options(
+        position,
+        grid,
+        UseSnapOptions::default().guides(guides.clone()),
+    );
+
+    let is_active = move |guide: Guide| active_guides.with(|active| active.contains(&guide));
+
+    view! {
+        <Note class="mb-2">
+            "Drag the box. It snaps to the grid and to the dashed guides when close to them."
+        </Note>
+        <label>
+            "Grid size: "
+            <input
+                type="number"
+                prop:value=move || grid.get().to_string()
+                on:input=move |e| set_grid.set(event_target_value(&e).parse().unwrap_or_default())
+            />
+        </label>
+
+        {guides
+            .into_iter()
+            .map(|guide| {
+                let (left, top, width, height) = match guide {
+                    Guide::Vertical(x) => (format!("{x}px"), "0".to_string(), "0", "100vh"),
+                    Guide::Horizontal(y) => ("0".to_string(), format!("{y}px"), "100vw", "0"),
+                };
+
+                view! {
+                    <div
+                        class="fixed border border-dashed pointer-events-none"
+                        class=(["border-red-500"], move || is_active(guide))
+                        class=(["border-gray-400/50"], move || !is_active(guide))
+                        style:left=left
+                        style:top=top
+                        style:width=width
+                        style:height=height
+                    />
+                }
+            })
+            .collect_view()}
+
+        <div
+            node_ref=el
+            class="px-4 py-2 border border-gray-400/30 rounded shadow fixed bg-[--bg] select-none cursor-move z-24"
+            style="touch-action: none"
+            style:left=move || format!("{}px", x.get())
+            style:top=move || format!("{}px", y.get())
+        >
+            "Drag me!"
+            <div class="text-sm opacity-50">{move || format!("{}, {}", x.get(), y.get())}</div>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,78 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_splitter_with_options, SplitterSize, UseSplitterOptions};
+
+const PANES: [&str; 3] = ["Files", "Editor", "Preview"];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let container = NodeRef::<Div>::new();
+
+    let splitter = use_splitter_with_options(
+        container,
+        PANES.len(),
+        UseSplitterOptions::default()
+            .initial_sizes(vec![20.0, 50.0, 30.0])
+            .min_size(SplitterSize::Px(100.0))
+            .collapse_threshold(SplitterSize::Px(50.0))
+            .storage_key("use-splitter-demo"),
+    );
+
+    let divider = move |divider: usize| {
+        view! {
+            <div
+                role="separator"
+                tabindex="0"
+                aria-orientation="vertical"
+                aria-valuemin="0"
+                aria-valuemax="100"
+                aria-valuenow=move || splitter.size(divider).round().to_string()
+                class="w-2 -mx-1 z-10 shrink-0 cursor-col-resize hover:bg-[--brand-color] focus:bg-[--brand-color] outline-none"
+                class=(["bg-[--brand-color]"], move || splitter.dragging.get() == Some(divider))
+                style="touch-action: none"
+                on:pointerdown=move |e| splitter.start_resize(divider, &e)
+                on:keydown=move |e| splitter.handle_keydown(divider, &e)
+                on:dblclick=move |_| splitter.toggle_collapse(divider)
+            />
+        }
+    };
+
+    view! {
+        <Note class="mb-2">
+            "Drag the dividers or focus them and use the arrow keys, Home, End and Enter. Drag a pane below 50px to collapse it."
+        </Note>
+        <div node_ref=container class="flex h-[300px] border border-gray-400/30 rounded">
+            {PANES
+                .into_iter()
+                .enumerate()
+                .map(|(pane, name)| {
+                    view! {
+                        {(pane > 0).then(|| divider(pane - 1))}
+                        <div
+                            class="overflow-hidden p-2 border-gray-400/30"
+                            class=(["border-l"], pane > 0)
+                            style:width=move || format!("{}%", splitter.size(pane))
+                            style:display=move || if splitter.is_collapsed(pane) { "none" } else { "block" }
+                        >
+                            <strong>{name}</strong>
+                            <p>{move || format!("{:.1}%", splitter.size(pane))}</p>
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+        <button class="mt-2" on:click=move |_| splitter.reset()>"Reset"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
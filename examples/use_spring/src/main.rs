@@ -0,0 +1,94 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_mouse_in_element, use_spring_with_options, UseMouseInElementReturn, UseSpringOptions,
+    UseSpringReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let UseMouseInElementReturn {
+        element_x,
+        element_y,
+        ..
+    } = use_mouse_in_element(el);
+
+    let (stiffness, set_stiffness) = signal(170.0);
+    let (damping, set_damping) = signal(26.0);
+
+    let target = Signal::derive(move || [element_x.get(), element_y.get()]);
+
+    let spring = move || {
+        let UseSpringReturn {
+            value,
+            is_animating,
+        } = use_spring_with_options(
+            target,
+            UseSpringOptions::default()
+                .stiffness(stiffness.get())
+                .damping(damping.get()),
+        );
+
+        view! {
+            <div
+                class="absolute w-6 h-6 rounded-full bg-green-500 pointer-events-none"
+                style:transform=move || {
+                    let [x, y] = value.get();
+                    format!("translate({}px, {}px)", x - 12.0, y - 12.0)
+                }
+            />
+            <p class="absolute bottom-2 left-2">
+                "Animating: " <BooleanDisplay value=is_animating />
+            </p>
+        }
+    };
+
+    view! {
+        <div node_ref=el class="relative w-full h-64 rounded bg-gray-500/20 overflow-hidden">
+            {spring}
+        </div>
+        <label class="block mt-4">
+            "Stiffness "
+            <input
+                type="range"
+                min="10"
+                max="400"
+                prop:value=stiffness
+                on:input=move |e| {
+                    if let Ok(value) = event_target_value(&e).parse() {
+                        set_stiffness.set(value);
+                    }
+                }
+            />
+        </label>
+        <label class="block">
+            "Damping "
+            <input
+                type="range"
+                min="1"
+                max="60"
+                prop:value=damping
+                on:input=move |e| {
+                    if let Ok(value) = event_target_value(&e).parse() {
+                        set_damping.set(value);
+                    }
+                }
+            />
+        </label>
+        <Note>"Move the mouse over the box. The dot follows it on a spring."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,70 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_stream_pipeline_with_options, UseStreamPipelineOptions, UseStreamPipelineReturn,
+};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (file, set_file) = signal_local(None::<web_sys::File>);
+
+    let UseStreamPipelineReturn {
+        progress,
+        result,
+        is_running,
+        error,
+        abort,
+        ..
+    } = use_stream_pipeline_with_options(
+        file,
+        "/demo/uppercase-worker.js",
+        UseStreamPipelineOptions::default().mime_type("text/plain"),
+    );
+
+    let download_url = Signal::derive_local(move || {
+        result
+            .get()
+            .and_then(|blob| web_sys::Url::create_object_url_with_blob(&blob).ok())
+    });
+
+    let on_change = move |e: leptos::ev::Event| {
+        let input = e
+            .target()
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlInputElement>();
+
+        set_file.set(input.files().and_then(|files| files.get(0)));
+    };
+
+    view! {
+        <input type="file" accept="text/*" on:change=on_change />
+        <button on:click=move |_| abort() disabled=move || !is_running.get()>
+            "Abort"
+        </button>
+        <div class="my-4">
+            <progress max="1" value=move || progress.get().to_string() />
+            " "
+            {move || format!("{:.0}%", progress.get() * 100.0)}
+        </div>
+        <p>"Running: " <BooleanDisplay value=is_running /></p>
+        <Show when=move || download_url.get().is_some()>
+            <a href=move || download_url.get().unwrap_or_default() download="uppercase.txt">
+                "Download result"
+            </a>
+        </Show>
+        <p class="text-red-500">{move || error.get().map(|err| err.to_string())}</p>
+        <Note>"Pick a text file. A worker turns it into upper case chunk by chunk."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
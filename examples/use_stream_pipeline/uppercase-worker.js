@@ -0,0 +1,14 @@
+// Turns the text of every file it receives into upper case.
+self.onmessage = ({ data: { readable, writable } }) => {
+  readable
+    .pipeThrough(new TextDecoderStream())
+    .pipeThrough(
+      new TransformStream({
+        transform(chunk, controller) {
+          controller.enqueue(chunk.toUpperCase());
+        },
+      }),
+    )
+    .pipeThrough(new TextEncoderStream())
+    .pipeTo(writable);
+};
@@ -0,0 +1,150 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_table, SortDirection, TableColumn, UseTableReturn};
+
+#[derive(Clone)]
+struct Employee {
+    id: usize,
+    name: String,
+    department: &'static str,
+    age: u32,
+}
+
+const NAMES: [&str; 10] = [
+    "Ada", "Alan", "Barbara", "Dennis", "Edsger", "Grace", "John", "Ken", "Linus", "Margaret",
+];
+const DEPARTMENTS: [&str; 3] = ["Engineering", "Research", "Operations"];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let employees = (0..47)
+        .map(|id| Employee {
+            id,
+            name: format!("{} {}", NAMES[id % NAMES.len()], id),
+            department: DEPARTMENTS[id % DEPARTMENTS.len()],
+            age: 20 + (id as u32 * 7) % 45,
+        })
+        .collect::<Vec<_>>();
+
+    let table = use_table(
+        employees,
+        vec![
+            TableColumn::new("name")
+                .sort_by_key(|e: &Employee| e.name.clone())
+                .filter_text(|e: &Employee| e.name.clone()),
+            TableColumn::new("department")
+                .sort_by_key(|e: &Employee| e.department)
+                .filter_text(|e: &Employee| e.department.to_string()),
+            TableColumn::new("age").sort_by_key(|e: &Employee| e.age),
+        ],
+    );
+    let UseTableReturn {
+        rows,
+        page,
+        page_count,
+        filtered_count,
+        visible_columns,
+        ..
+    } = table;
+
+    let header = move |column: &'static str, label: &'static str| {
+        view! {
+            <Show when=move || table.is_column_visible(column)>
+                <th
+                    class="cursor-pointer select-none"
+                    on:click=move |e| table.toggle_sort(column, e.shift_key())
+                >
+                    {label}
+                    {move || match table.sort_direction(column) {
+                        Some(SortDirection::Ascending) => " ▲",
+                        Some(SortDirection::Descending) => " ▼",
+                        None => "",
+                    }}
+                </th>
+            </Show>
+        }
+    };
+
+    let cell = move |column: &'static str, value: String| {
+        view! {
+            <Show when=move || table.is_column_visible(column)>
+                <td>{value.clone()}</td>
+            </Show>
+        }
+    };
+
+    view! {
+        <Note class="mb-2">
+            "Click a header to sort, Shift-click to sort by multiple columns."
+        </Note>
+        <div>
+            <input
+                placeholder="Search..."
+                on:input=move |e| table.set_filter(event_target_value(&e))
+            />
+            <select on:change=move |e| {
+                table.set_column_filter("department", event_target_value(&e))
+            }>
+                <option value="">"All departments"</option>
+                {DEPARTMENTS
+                    .into_iter()
+                    .map(|department| view! { <option value=department>{department}</option> })
+                    .collect_view()}
+            </select>
+        </div>
+        <div>
+            "Columns: "
+            {["name", "department", "age"]
+                .into_iter()
+                .map(|column| {
+                    view! {
+                        <label class="mr-2">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || table.is_column_visible(column)
+                                on:change=move |_| table.toggle_column(column)
+                            />
+                            {column}
+                        </label>
+                    }
+                })
+                .collect_view()}
+        </div>
+        <table>
+            <thead>
+                <tr>
+                    {header("name", "Name")} {header("department", "Department")}
+                    {header("age", "Age")}
+                </tr>
+            </thead>
+            <tbody>
+                <For each=move || rows.get() key=|e| e.id let:e>
+                    <tr>
+                        {cell("name", e.name)} {cell("department", e.department.to_string())}
+                        {cell("age", e.age.to_string())}
+                    </tr>
+                </For>
+            </tbody>
+        </table>
+        <div>
+            <button on:click=move |_| table.prev_page()>"Prev"</button>
+            " Page " {page} " of " {page_count} " "
+            <button on:click=move |_| table.next_page()>"Next"</button>
+        </div>
+        <p>
+            {filtered_count} " matching rows, visible columns: "
+            {move || visible_columns.get().join(", ")}
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,32 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::use_theme_color;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (color, set_color) = use_theme_color(Some("#4f46e5".to_string()));
+
+    view! {
+        <input
+            type="color"
+            prop:value=move || color.get().unwrap_or_default()
+            on:input=move |event| set_color.set(Some(event_target_value(&event)))
+        />
+        <button on:click=move |_| set_color.set(None)>"Reset"</button>
+        <p>"Theme color: " {move || color.get()}</p>
+        <Note>
+            "Open this page in a mobile browser or install it as an app to see the effect on the browser UI."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,43 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::use_throttled_history;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let position = RwSignal::new((0, 0));
+
+    let history = use_throttled_history(position, 500.0);
+
+    view! {
+        <div
+            class="h-40 border border-dashed rounded flex items-center justify-center"
+            on:pointermove=move |e| position.set((e.offset_x(), e.offset_y()))
+        >
+            {move || {
+                let (x, y) = position.get();
+                format!("Move the mouse here: {x}, {y}")
+            }}
+        </div>
+        <div>
+            <button disabled=move || !history.can_undo.get() on:click=move |_| history.undo()>
+                "Undo"
+            </button>
+            <button disabled=move || !history.can_redo.get() on:click=move |_| history.redo()>
+                "Redo"
+            </button>
+            <button on:click=move |_| history.clear()>"Clear"</button>
+        </div>
+        <p>"Recorded positions: " {move || history.history.with(Vec::len)}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
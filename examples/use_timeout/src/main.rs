@@ -0,0 +1,34 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_timeout_with_controls, UseTimeoutReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseTimeoutReturn {
+        ready,
+        is_pending,
+        start,
+        stop,
+    } = use_timeout_with_controls(2000.0);
+
+    view! {
+        <p>"Ready: " <BooleanDisplay value=ready /></p>
+        <div class="flex gap-2">
+            <button on:click=move |_| start()>"Restart"</button>
+            <button on:click=move |_| stop() disabled=move || !is_pending.get()>
+                "Stop"
+            </button>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
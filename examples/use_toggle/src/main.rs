@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 use leptos_use::docs::{demo_or_body, BooleanDisplay};
-use leptos_use::{use_toggle, UseToggleReturn};
+use leptos_use::{use_toggle, use_toggle_with_options, UseToggleOptions, UseToggleReturn};
 
 #[component]
 fn Demo() -> impl IntoView {
@@ -10,11 +10,19 @@ fn Demo() -> impl IntoView {
         set_value,
     } = use_toggle(true);
 
+    let UseToggleReturn {
+        toggle: toggle_theme,
+        value: theme,
+        ..
+    } = use_toggle_with_options("light", UseToggleOptions::new("dark", "light"));
+
     view! {
         <p>Value: <BooleanDisplay value=value /></p>
         <button on:click=move |_| toggle()>Toggle</button>
         <button on:click=move |_| set_value(true)>Set <code>true</code></button>
         <button on:click=move |_| set_value(false)>Set <code>false</code></button>
+        <p class="mt-4">Theme: {theme}</p>
+        <button on:click=move |_| toggle_theme()>Toggle theme</button>
     }
 }
 
@@ -0,0 +1,74 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_torch, use_user_media_with_options, ConstraintFacingMode, FacingMode, UseTorchReturn,
+    UseUserMediaOptions, UseUserMediaReturn, VideoTrackConstraints,
+};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = NodeRef::<leptos::html::Video>::new();
+
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media_with_options(
+        UseUserMediaOptions::default().video(
+            VideoTrackConstraints::default()
+                .facing_mode(ConstraintFacingMode::from(FacingMode::Environment)),
+        ),
+    );
+
+    Effect::new(move |_| {
+        let stream = stream.get().and_then(Result::ok);
+
+        video_ref.with(|video| {
+            if let Some(video) = video {
+                video.set_src_object(stream.as_ref());
+            }
+        });
+    });
+
+    let video_track = Signal::derive_local(move || {
+        stream
+            .get()
+            .and_then(Result::ok)
+            .and_then(|stream| stream.get_video_tracks().get(0).dyn_into().ok())
+    });
+
+    let torch = use_torch(video_track);
+    let UseTorchReturn {
+        is_supported,
+        is_on,
+        ..
+    } = torch;
+
+    view! {
+        <div class="flex gap-2">
+            <button on:click=move |_| {
+                set_enabled.set(!enabled.get_untracked())
+            }>{move || if enabled.get() { "Stop camera" } else { "Start camera" }}</button>
+            <button disabled=move || !is_supported.get() on:click=move |_| torch.toggle()>
+                {move || if is_on.get() { "Torch off" } else { "Torch on" }}
+            </button>
+        </div>
+        <p>"Supported: " <BooleanDisplay value=is_supported /></p>
+        <p>"On: " <BooleanDisplay value=is_on /></p>
+        <Note>"The torch is usually only available for the back camera of phones."</Note>
+        <video node_ref=video_ref controls=false autoplay=true muted=true class="h-96 w-auto"></video>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,131 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_track_constraints, use_user_media_with_options, ConstraintFacingMode, FacingMode,
+    MediaTrackRange, UseTrackConstraintsReturn, UseUserMediaOptions, UseUserMediaReturn,
+    VideoTrackConstraints,
+};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = NodeRef::<leptos::html::Video>::new();
+
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media_with_options(
+        UseUserMediaOptions::default().video(
+            VideoTrackConstraints::default()
+                .facing_mode(ConstraintFacingMode::from(FacingMode::Environment)),
+        ),
+    );
+
+    Effect::new(move |_| {
+        let stream = stream.get().and_then(Result::ok);
+
+        video_ref.with(|video| {
+            if let Some(video) = video {
+                video.set_src_object(stream.as_ref());
+            }
+        });
+    });
+
+    let video_track = Signal::derive_local(move || {
+        stream
+            .get()
+            .and_then(Result::ok)
+            .and_then(|stream| stream.get_video_tracks().get(0).dyn_into().ok())
+    });
+
+    let constraints = use_track_constraints(video_track);
+    let UseTrackConstraintsReturn {
+        zoom_range,
+        focus_distance_range,
+        exposure_compensation_range,
+        zoom,
+        focus_distance,
+        exposure_compensation,
+        focus_mode,
+        ..
+    } = constraints;
+
+    view! {
+        <button on:click=move |_| {
+            set_enabled.set(!enabled.get_untracked())
+        }>{move || if enabled.get() { "Stop camera" } else { "Start camera" }}</button>
+        <div class="flex flex-col gap-2 my-4">
+            <RangeControl
+                label="Zoom"
+                range=zoom_range
+                value=zoom
+                on_change=move |value| constraints.set_zoom(value)
+            />
+            <RangeControl
+                label="Focus distance"
+                range=focus_distance_range
+                value=focus_distance
+                on_change=move |value| constraints.set_focus_distance(value)
+            />
+            <RangeControl
+                label="Exposure compensation"
+                range=exposure_compensation_range
+                value=exposure_compensation
+                on_change=move |value| constraints.set_exposure_compensation(value)
+            />
+            <p>
+                "Focus mode: " {move || focus_mode.get().unwrap_or_else(|| "unknown".to_string())}
+                " " <button on:click=move |_| constraints.set_focus_mode("continuous")>"Autofocus"</button>
+            </p>
+        </div>
+        <Note>"Most controls are only available on phones and some webcams."</Note>
+        <video node_ref=video_ref controls=false autoplay=true muted=true class="h-96 w-auto"></video>
+    }
+}
+
+#[component]
+fn RangeControl(
+    label: &'static str,
+    range: Signal<Option<MediaTrackRange>>,
+    value: Signal<Option<f64>>,
+    #[prop(into)] on_change: Callback<f64>,
+) -> impl IntoView {
+    view! {
+        <label>
+            {label} ": "
+            {move || match range.get() {
+                Some(range) => {
+                    view! {
+                        <input
+                            type="range"
+                            min=range.min
+                            max=range.max
+                            step=range.step
+                            prop:value=move || value.get().unwrap_or(range.min)
+                            on:input=move |e| {
+                                if let Ok(value) = event_target_value(&e).parse() {
+                                    on_change.run(value);
+                                }
+                            }
+                        />
+                    }
+                        .into_any()
+                }
+                None => view! { <span>"not supported"</span> }.into_any(),
+            }}
+        </label>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,77 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_transform, UseTransformReturn};
+
+#[component]
+fn Slider(
+    label: &'static str,
+    min: f64,
+    max: f64,
+    step: f64,
+    value: Signal<f64>,
+    set_value: WriteSignal<f64>,
+) -> impl IntoView {
+    view! {
+        <label class="block">
+            <span class="inline-block w-24">{label}</span>
+            <input
+                type="range"
+                min=min
+                max=max
+                step=step
+                prop:value=move || value.get().to_string()
+                on:input=move |e| set_value.set(event_target_value(&e).parse().unwrap_or_default())
+            />
+            <span class="ml-2">{move || format!("{:.1}", value.get())}</span>
+        </label>
+    }
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let UseTransformReturn {
+        translate_x,
+        set_translate_x,
+        translate_y,
+        set_translate_y,
+        scale,
+        set_scale,
+        rotate,
+        set_rotate,
+        transform,
+    } = use_transform(el);
+
+    view! {
+        <Note class="mb-2">"The initial transform is parsed from the element's style"</Note>
+        <div class="area h-64 overflow-hidden flex items-center justify-center mb-4">
+            <div
+                node_ref=el
+                class="w-24 h-24 bg-blue-500 rounded flex items-center justify-center"
+                style="transform: translate(20px, 10px) rotate(15deg) scale(1.2)"
+            >
+                "Box"
+            </div>
+        </div>
+
+        <Slider label="Translate X" min=-200.0 max=200.0 step=1.0 value=translate_x set_value=set_translate_x />
+        <Slider label="Translate Y" min=-100.0 max=100.0 step=1.0 value=translate_y set_value=set_translate_y />
+        <Slider label="Scale" min=0.1 max=3.0 step=0.1 value=scale set_value=set_scale />
+        <Slider label="Rotate" min=-180.0 max=180.0 step=1.0 value=rotate set_value=set_rotate />
+
+        <p class="mt-2"><code>{transform}</code></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,73 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_transition_with_options, TransitionEasing, UseTransitionOptions};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (target, set_target) = signal([0.0, 0.0]);
+    let (enabled, set_enabled) = signal(true);
+    let (is_running, set_running) = signal(false);
+
+    let linear: Signal<f64> = use_transition_with_options(
+        Signal::derive(move || target.get()[0]),
+        UseTransitionOptions::default()
+            .duration(1500.0)
+            .enabled(enabled)
+            .on_started(move || set_running.set(true))
+            .on_finished(move || set_running.set(false)),
+    );
+
+    let eased: Signal<[f64; 2]> = use_transition_with_options(
+        target,
+        UseTransitionOptions::default()
+            .duration(1500.0)
+            .enabled(enabled)
+            .easing(TransitionEasing::EASE_IN_OUT_BACK),
+    );
+
+    let toggle = move |_| {
+        set_target.update(|[x, y]| {
+            *x = if *x == 0.0 { 100.0 } else { 0.0 };
+            *y = if *y == 0.0 { 100.0 } else { 0.0 };
+        })
+    };
+
+    view! {
+        <button on:click=toggle>"Transition"</button>
+        <label class="ml-4">
+            <input
+                type="checkbox"
+                prop:checked=enabled
+                on:change=move |e| set_enabled.set(event_target_checked(&e))
+            />
+            " Enabled"
+        </label>
+        <p>"Linear: " {move || format!("{:.2}", linear.get())}</p>
+        <div class="relative w-64 h-4 my-2 rounded bg-gray-500/20">
+            <div
+                class="absolute w-4 h-4 rounded-full bg-blue-500"
+                style:left=move || format!("calc({}% - 0.5rem)", linear.get())
+            />
+        </div>
+        <p>"Ease in out back (x, y): " {move || format!("{:.2?}", eased.get())}</p>
+        <div class="relative w-64 h-16 my-2 rounded bg-gray-500/20">
+            <div
+                class="absolute w-4 h-4 rounded-full bg-green-500"
+                style:left=move || format!("calc({}% - 0.5rem)", eased.get()[0])
+                style:top=move || format!("calc({}% - 0.5rem)", eased.get()[1])
+            />
+        </div>
+        <p>"Running: " <BooleanDisplay value=is_running /></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
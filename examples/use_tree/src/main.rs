@@ -0,0 +1,121 @@
+use gloo_timers::future::sleep;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_tree_with_options, TreeNode, UseTreeOptions, UseTreeReturn};
+use std::time::Duration;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let nodes = vec![
+        TreeNode::branch(
+            "src".to_string(),
+            vec![
+                TreeNode::branch(
+                    "src/core".to_string(),
+                    vec![
+                        TreeNode::leaf("src/core/mod.rs".to_string()),
+                        TreeNode::leaf("src/core/position.rs".to_string()),
+                    ],
+                ),
+                TreeNode::leaf("src/lib.rs".to_string()),
+            ],
+        ),
+        TreeNode::lazy("remote".to_string()),
+        TreeNode::leaf("Cargo.toml".to_string()),
+    ];
+
+    let tree = use_tree_with_options(
+        nodes,
+        UseTreeOptions::default().load_children(|id: String| async move {
+            sleep(Duration::from_millis(1000)).await;
+
+            (1..=3)
+                .map(|i| {
+                    let child = format!("{id}/folder-{i}");
+                    if i == 1 {
+                        TreeNode::lazy(child)
+                    } else {
+                        TreeNode::leaf(child)
+                    }
+                })
+                .collect()
+        }),
+    );
+    let UseTreeReturn {
+        visible, selected, ..
+    } = tree;
+
+    view! {
+        <Note class="mb-2">
+            "Click the tree and navigate it with the arrow keys. Nodes under \"remote\" are loaded lazily."
+        </Note>
+        <div>
+            <button on:click=move |_| tree.expand_all()>"Expand all"</button>
+            <button on:click=move |_| tree.collapse_all()>"Collapse all"</button>
+        </div>
+        <ul
+            tabindex="0"
+            class="list-none p-2 border border-gray-400/30 rounded select-none"
+            on:keydown=move |e| tree.handle_keydown(&e)
+        >
+            <For each=move || visible.get() key=|node| node.id.clone() let:node>
+                {
+                    let id = node.id.clone();
+                    let name = id.rsplit('/').next().unwrap_or_default().to_string();
+                    let is_expanded = {
+                        let id = id.clone();
+                        move || tree.is_expanded(&id)
+                    };
+                    let is_loading = {
+                        let id = id.clone();
+                        move || tree.is_loading(&id)
+                    };
+                    let is_selected = {
+                        let id = id.clone();
+                        move || tree.is_selected(&id)
+                    };
+
+                    view! {
+                        <li
+                            class="px-2 rounded cursor-pointer"
+                            class=(["bg-[--brand-color]", "text-white"], is_selected)
+                            style:padding-left=format!("{}em", node.depth + 1)
+                            on:click=move |_| {
+                                tree.select(id.clone());
+                                tree.toggle(id.clone());
+                            }
+                        >
+                            <span class="inline-block w-4">
+                                {move || {
+                                    if !node.is_expandable {
+                                        ""
+                                    } else if is_expanded() {
+                                        "▾"
+                                    } else {
+                                        "▸"
+                                    }
+                                }}
+                            </span>
+                            {name}
+                            <Show when=is_loading>
+                                <span class="opacity-50">" loading..."</span>
+                            </Show>
+                        </li>
+                    }
+                }
+            </For>
+        </ul>
+        <p>"Selected: " {move || selected.get().unwrap_or_else(|| "-".to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
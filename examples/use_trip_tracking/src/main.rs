@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_trip_tracking, TrackPoint, UseTripTrackingReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseTripTrackingReturn {
+        distance,
+        speed,
+        heading,
+        track,
+        is_tracking,
+        error,
+        pause,
+        resume,
+        reset,
+    } = use_trip_tracking();
+
+    view! {
+        <p>"Tracking: " <BooleanDisplay value=is_tracking /></p>
+        <p>"Distance: " {move || format!("{:.0} m", distance.get())}</p>
+        <p>
+            "Speed: "
+            {move || {
+                speed
+                    .get()
+                    .map(|speed| format!("{:.1} km/h", speed * 3.6))
+                    .unwrap_or_else(|| "-".to_string())
+            }}
+        </p>
+        <p>
+            "Heading: "
+            {move || {
+                heading
+                    .get()
+                    .map(|heading| format!("{heading:.0}°"))
+                    .unwrap_or_else(|| "-".to_string())
+            }}
+        </p>
+        <p>"Points: " {move || track.with(Vec::len)}</p>
+        <p>"Error: " {move || error.get().map(|error| error.message())}</p>
+
+        <svg viewBox="0 0 200 200" width="200" height="200" class="border">
+            <polyline
+                points=move || track.with(|track| polyline_points(track))
+                fill="none"
+                stroke="currentColor"
+                stroke-width="2"
+            />
+        </svg>
+
+        <div>
+            <button on:click=move |_| pause() disabled=move || !is_tracking.get()>
+                "Pause"
+            </button>
+            <button on:click=move |_| resume() disabled=move || is_tracking.get()>
+                "Resume"
+            </button>
+            <button on:click=move |_| reset()>"Reset"</button>
+        </div>
+
+        <Note>"Take your device for a walk to see the track grow."</Note>
+    }
+}
+
+/// Scales the track to fit into the 200x200 SVG, north up.
+fn polyline_points(track: &[TrackPoint]) -> String {
+    let (mut min_x, mut max_x) = (f64::INFINITY, f64::NEG_INFINITY);
+    let (mut min_y, mut max_y) = (f64::INFINITY, f64::NEG_INFINITY);
+
+    for point in track {
+        min_x = min_x.min(point.longitude);
+        max_x = max_x.max(point.longitude);
+        min_y = min_y.min(point.latitude);
+        max_y = max_y.max(point.latitude);
+    }
+
+    let scale = 180.0 / (max_x - min_x).max(max_y - min_y).max(f64::EPSILON);
+
+    track
+        .iter()
+        .map(|point| {
+            let x = 10.0 + (point.longitude - min_x) * scale;
+            let y = 190.0 - (point.latitude - min_y) * scale;
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
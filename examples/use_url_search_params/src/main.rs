@@ -0,0 +1,66 @@
+use leptos::prelude::*;
+use leptos_use::core::HistoryWriteMode;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_url_search_params_with_options, UrlSearchParamsMode, UseUrlSearchParamsOptions,
+};
+
+const TAGS: [&str; 4] = ["rust", "leptos", "wasm", "web"];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let search_params = use_url_search_params_with_options(
+        UrlSearchParamsMode::History,
+        UseUrlSearchParamsOptions::default().write_mode(HistoryWriteMode::Push),
+    );
+
+    let tags = Signal::derive(move || search_params.get_all("tag"));
+
+    view! {
+        <input
+            placeholder="Search"
+            prop:value=move || search_params.get("q").unwrap_or_default()
+            on:change=move |e| search_params.set("q", event_target_value(&e))
+        />
+        <div class="flex gap-4">
+            {TAGS
+                .into_iter()
+                .map(|tag| {
+                    view! {
+                        <label>
+                            <input
+                                type="checkbox"
+                                prop:checked=move || tags.with(|tags| tags.iter().any(|t| t == tag))
+                                on:change=move |_| {
+                                    let mut tags = tags.get_untracked();
+                                    if let Some(index) = tags.iter().position(|t| t == tag) {
+                                        tags.remove(index);
+                                    } else {
+                                        tags.push(tag.to_string());
+                                    }
+                                    search_params.set_all("tag", tags);
+                                }
+                            />
+                            " "
+                            {tag}
+                        </label>
+                    }
+                })
+                .collect_view()}
+        </div>
+        <button on:click=move |_| search_params.clear()>"Clear"</button>
+        <Note>"Every change creates a new history entry. Use the back button of the browser to undo it."</Note>
+        <pre>{move || format!("{:#?}", search_params.params.get())}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
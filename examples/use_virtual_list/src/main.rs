@@ -0,0 +1,80 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_virtual_list, UseVirtualListReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (items, _) = signal(
+        (0..100_000)
+            .map(|i| (i, 1 + i % 4))
+            .collect::<Vec<(usize, usize)>>(),
+    );
+    let (index, set_index) = signal(0);
+
+    let container = NodeRef::<Div>::new();
+
+    let virtual_list = use_virtual_list(items, container);
+    let UseVirtualListReturn {
+        list,
+        total_height,
+        offset_top,
+        range,
+        ..
+    } = virtual_list;
+
+    view! {
+        <Note class="mb-2">"100,000 rows of different heights. Only the visible ones are rendered."</Note>
+        <div>
+            <input
+                type="number"
+                prop:value=move || index.get().to_string()
+                on:input=move |e| set_index.set(event_target_value(&e).parse().unwrap_or_default())
+            />
+            <button on:click=move |_| virtual_list.scroll_to_index(index.get())>"Scroll to"</button>
+        </div>
+        <div
+            node_ref=container
+            class="h-[300px] overflow-auto border border-gray-400/30 rounded"
+        >
+            <div
+                style:height=move || format!("{}px", total_height.get())
+                style:padding-top=move || format!("{}px", offset_top.get())
+                style="box-sizing: border-box"
+            >
+                <For each=move || list.get() key=|item| item.index let:item>
+                    {
+                        let row = NodeRef::<Div>::new();
+                        let (number, lines) = item.data;
+
+                        Effect::new(move || {
+                            if let Some(el) = row.get() {
+                                virtual_list.measure_element(item.index, &el);
+                            }
+                        });
+
+                        view! {
+                            <div node_ref=row class="px-2 border-b border-gray-400/30">
+                                {(0..lines)
+                                    .map(|line| view! { <div>"Row " {number} " line " {line + 1}</div> })
+                                    .collect_view()}
+                            </div>
+                        }
+                    }
+                </For>
+            </div>
+        </div>
+        <p>{move || { let (start, end) = range.get(); format!("Rendering rows {start} to {end}") }}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,41 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_web_worker, UseWebWorkerReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (input, set_input) = signal("Hello worker".to_string());
+
+    let UseWebWorkerReturn {
+        data,
+        status,
+        post,
+        terminate,
+        ..
+    } = use_web_worker::<String, String, FromToStringCodec>("/demo/worker.js");
+
+    view! {
+        <Note class="mb-2">"The worker replies with the message reversed."</Note>
+        <input
+            type="text"
+            prop:value=input
+            on:input=move |e| set_input.set(event_target_value(&e))
+        />
+        <button on:click=move |_| post(&input.get_untracked())>"Send"</button>
+        <button on:click=move |_| terminate()>"Terminate"</button>
+        <p>"Status: " <b>{move || status.get().to_string()}</b></p>
+        <p>"Received: " <code>{move || data.get().unwrap_or_default()}</code></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,4 @@
+// Replies to every message with the message reversed.
+self.onmessage = (event) => {
+  self.postMessage(event.data.split("").reverse().join(""));
+};
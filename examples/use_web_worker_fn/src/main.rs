@@ -0,0 +1,70 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_interval_fn, use_web_worker_fn_with_options, UseWebWorkerFnOptions, WebWorkerFnStatus,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let fib = use_web_worker_fn_with_options::<u32, u64, FromToStringCodec>(
+        r#"(input) => {
+            const fib = (n) => n < 2 ? n : fib(n - 1) + fib(n - 2);
+            return fib(Number(input)).toString();
+        }"#,
+        UseWebWorkerFnOptions::default().timeout(10_000),
+    );
+
+    let (n, set_n) = signal(40_u32);
+    let (result, set_result) = signal(String::new());
+
+    // keeps ticking while the worker is busy to show that the UI isn't blocked
+    let (ticks, set_ticks) = signal(0);
+    let _ = use_interval_fn(move || set_ticks.update(|t| *t += 1), 100);
+
+    let on_run = move |_| {
+        spawn_local(async move {
+            let res = fib.run(&n.get_untracked()).await;
+            set_result.set(match res {
+                Ok(value) => value.to_string(),
+                Err(err) => err.to_string(),
+            });
+        });
+    };
+
+    view! {
+        <Note class="mb-2">"Computes the n-th Fibonacci number naively in a web worker"</Note>
+        <input
+            type="number"
+            prop:value=move || n.get().to_string()
+            on:input=move |e| set_n.set(event_target_value(&e).parse().unwrap_or_default())
+        />
+        <button
+            on:click=on_run
+            disabled=move || fib.status.get() == WebWorkerFnStatus::Running
+        >
+            "Run"
+        </button>
+        <button
+            on:click=move |_| fib.abort()
+            disabled=move || fib.status.get() != WebWorkerFnStatus::Running
+        >
+            "Abort"
+        </button>
+        <p>"Status: " <b>{move || fib.status.get().to_string()}</b></p>
+        <p>"Result: " <b>{result}</b></p>
+        <p>"UI ticks: " {ticks}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
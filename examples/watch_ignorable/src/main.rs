@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::watch_ignorable;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (log, set_log) = signal("".to_string());
+    let (source, set_source) = signal(0);
+
+    let watcher = watch_ignorable(
+        move || source.get(),
+        move |v, _, _| {
+            set_log.update(|log| *log = format!("{log}Changed to {v}\n"));
+        },
+    );
+
+    let increment = move |_| set_source.update(|value| *value += 1);
+
+    let ignored_increment = move |_| {
+        watcher.ignore_updates(|| set_source.update(|value| *value += 1));
+        set_log.update(|log| *log = format!("{log}Ignored increment\n"));
+    };
+
+    let clear = move |_| set_log.set("".to_string());
+
+    view! {
+        <p>"Value: " {source}</p>
+        <button on:click=increment>"Increment"</button>
+        <button class="orange" on:click=ignored_increment>
+            "Ignored Increment"
+        </button>
+        <button on:click=clear>"Clear Log"</button>
+        <br/>
+        <br/>
+        <Note>"Log"</Note>
+        <pre>{log}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
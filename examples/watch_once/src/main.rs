@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::watch_once;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (log, set_log) = signal("".to_string());
+    let (source, set_source) = signal(0);
+
+    let stop = watch_once(
+        move || source.get(),
+        move |v, prev| {
+            set_log.update(|log| *log = format!("{log}Changed from {prev:?} to {v}\n"));
+        },
+    );
+
+    view! {
+        <Note class="mb-2">"Only the first change is logged"</Note>
+        <p>"Value: " {source}</p>
+        <button on:click=move |_| set_source.update(|value| *value += 1)>"Increment"</button>
+        <button class="orange" on:click=move |_| stop()>
+            "Stop"
+        </button>
+        <br/>
+        <br/>
+        <Note>"Log"</Note>
+        <pre>{log}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
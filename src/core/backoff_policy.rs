@@ -0,0 +1,144 @@
+use crate::core::ReconnectLimit;
+use default_struct_builder::DefaultBuilder;
+use std::time::Duration;
+
+/// Policy that determines how long to wait before each reconnection or retry attempt and how
+/// many attempts are made in total.
+///
+/// The same policy objects are accepted by all networking functions that reconnect or retry
+/// automatically like [`fn@crate::use_websocket`], [`fn@crate::use_event_source`] or
+/// [`fn@crate::use_fetch`].
+///
+/// ```
+/// # use leptos_use::core::{BackoffPolicy, ReconnectLimit};
+/// #
+/// // wait 500ms, 1s, 2s, 4s, ... but never longer than 30 seconds
+/// // and randomize each delay by up to 20% in either direction.
+/// let policy = BackoffPolicy::exponential(500)
+///     .max_delay(30_000_u64)
+///     .jitter(0.2)
+///     .max_attempts(ReconnectLimit::Limited(10));
+///
+/// // always wait 3 seconds but never give up
+/// let policy = BackoffPolicy::fixed(3000).max_attempts(ReconnectLimit::Infinite);
+/// ```
+#[derive(DefaultBuilder, Clone, Copy, Debug, PartialEq)]
+pub struct BackoffPolicy {
+    /// How the delay grows with every attempt. Defaults to `BackoffStrategy::Fixed(3000)`.
+    strategy: BackoffStrategy,
+
+    /// Upper bound of the delay in ms (before jitter is applied). Defaults to `None`.
+    #[builder(into)]
+    max_delay: Option<u64>,
+
+    /// Fraction (between `0.0` and `1.0`) by which every delay is randomly lengthened or
+    /// shortened. This avoids many clients reconnecting at the same instant after a server
+    /// restart. Defaults to `0.0`.
+    jitter: f64,
+
+    /// Maximum number of attempts. Defaults to `ReconnectLimit::Limited(3)`.
+    max_attempts: ReconnectLimit,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            strategy: BackoffStrategy::Fixed(3000),
+            max_delay: None,
+            jitter: 0.0,
+            max_attempts: ReconnectLimit::default(),
+        }
+    }
+}
+
+impl BackoffPolicy {
+    /// Always wait `interval` ms between attempts.
+    pub fn fixed(interval: u64) -> Self {
+        Self::default().strategy(BackoffStrategy::Fixed(interval))
+    }
+
+    /// Wait `initial` ms before the first attempt and add `step` ms for every further attempt.
+    pub fn linear(initial: u64, step: u64) -> Self {
+        Self::default().strategy(BackoffStrategy::Linear { initial, step })
+    }
+
+    /// Wait `initial` ms before the first attempt and double the delay for every further attempt.
+    pub fn exponential(initial: u64) -> Self {
+        Self::default().strategy(BackoffStrategy::Exponential {
+            initial,
+            factor: 2.0,
+        })
+    }
+
+    /// The maximum number of attempts of this policy.
+    pub fn limit(&self) -> ReconnectLimit {
+        self.max_attempts
+    }
+
+    /// Returns the delay to wait before the attempt with the given (zero based) index or `None`
+    /// if `attempt` exceeds the maximum number of attempts.
+    pub fn delay(&self, attempt: u64) -> Option<Duration> {
+        if self.max_attempts.is_exceeded_by(attempt) {
+            return None;
+        }
+
+        let mut delay = self.strategy.delay_ms(attempt);
+
+        if let Some(max_delay) = self.max_delay {
+            delay = delay.min(max_delay as f64);
+        }
+
+        if self.jitter > 0.0 {
+            let jitter = self.jitter.min(1.0);
+            delay *= 1.0 - jitter + 2.0 * jitter * random();
+        }
+
+        Some(Duration::from_millis(delay.max(0.0) as u64))
+    }
+}
+
+impl From<u64> for BackoffPolicy {
+    fn from(interval: u64) -> Self {
+        Self::fixed(interval)
+    }
+}
+
+/// How the delay of a [`BackoffPolicy`] grows with every attempt.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BackoffStrategy {
+    /// Always the same delay in ms.
+    Fixed(u64),
+
+    /// `initial + attempt * step` ms.
+    Linear { initial: u64, step: u64 },
+
+    /// `initial * factor ^ attempt` ms.
+    Exponential { initial: u64, factor: f64 },
+}
+
+impl BackoffStrategy {
+    fn delay_ms(&self, attempt: u64) -> f64 {
+        match *self {
+            BackoffStrategy::Fixed(interval) => interval as f64,
+            BackoffStrategy::Linear { initial, step } => {
+                initial as f64 + attempt as f64 * step as f64
+            }
+            BackoffStrategy::Exponential { initial, factor } => {
+                initial as f64 * factor.powi(attempt.min(i32::MAX as u64) as i32)
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn random() -> f64 {
+    #[cfg(not(feature = "ssr"))]
+    {
+        js_sys::Math::random()
+    }
+
+    #[cfg(feature = "ssr")]
+    {
+        0.5
+    }
+}
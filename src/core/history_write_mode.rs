@@ -0,0 +1,10 @@
+/// How changes are written to the browser history.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum HistoryWriteMode {
+    /// Replace the current history entry.
+    #[default]
+    Replace,
+
+    /// Push a new history entry.
+    Push,
+}
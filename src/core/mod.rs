@@ -1,3 +1,4 @@
+mod backoff_policy;
 mod connection_ready_state;
 mod datetime;
 mod direction;
@@ -5,6 +6,7 @@ mod direction;
 mod element_maybe_signal;
 #[cfg(feature = "element")]
 mod elements_maybe_signal;
+mod history_write_mode;
 mod maybe_rw_signal;
 mod pointer_type;
 mod position;
@@ -15,6 +17,7 @@ mod ssr_safe_method;
 pub(crate) mod url;
 mod use_rw_signal;
 
+pub use backoff_policy::*;
 pub use connection_ready_state::*;
 pub(crate) use datetime::*;
 pub use direction::*;
@@ -22,6 +25,7 @@ pub use direction::*;
 pub use element_maybe_signal::*;
 #[cfg(feature = "element")]
 pub use elements_maybe_signal::*;
+pub use history_write_mode::*;
 pub use maybe_rw_signal::*;
 pub use pointer_type::*;
 pub use position::*;
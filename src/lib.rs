@@ -11,12 +11,7 @@ pub mod math;
 pub mod storage;
 pub mod utils;
 
-pub use core::ReconnectLimit;
-
-// #[cfg(web_sys_unstable_apis)]
-// mod use_webtransport;
-// #[cfg(web_sys_unstable_apis)]
-// pub use use_webtransport::*;
+pub use core::{BackoffPolicy, ReconnectLimit};
 
 #[cfg(feature = "is_err")]
 mod is_err;
@@ -34,26 +29,59 @@ mod signal_debounced;
 mod signal_throttled;
 #[cfg(feature = "sync_signal")]
 mod sync_signal;
+#[cfg(feature = "until")]
+mod until;
 #[cfg(feature = "use_active_element")]
 mod use_active_element;
+#[cfg(feature = "use_animate")]
+#[cfg(web_sys_unstable_apis)]
+mod use_animate;
+#[cfg(feature = "use_async_queue")]
+mod use_async_queue;
+#[cfg(feature = "use_async_state")]
+mod use_async_state;
+#[cfg(feature = "use_audio_level")]
+mod use_audio_level;
+#[cfg(feature = "use_auto_scroll")]
+mod use_auto_scroll;
+#[cfg(feature = "use_autoplay_when_visible")]
+mod use_autoplay_when_visible;
 #[cfg(feature = "use_breakpoints")]
 mod use_breakpoints;
 #[cfg(feature = "use_broadcast_channel")]
 mod use_broadcast_channel;
+#[cfg(feature = "use_browser_location")]
+mod use_browser_location;
 #[cfg(feature = "use_calendar")]
 mod use_calendar;
+#[cfg(feature = "use_carousel")]
+mod use_carousel;
 #[cfg(feature = "use_clipboard")]
 mod use_clipboard;
 #[cfg(feature = "use_color_mode")]
 mod use_color_mode;
+#[cfg(feature = "use_column_resize")]
+mod use_column_resize;
+#[cfg(feature = "use_confirm_dialog")]
+mod use_confirm_dialog;
+#[cfg(feature = "use_context_menu")]
+mod use_context_menu;
 #[cfg(feature = "use_cookie")]
 mod use_cookie;
+#[cfg(feature = "use_copy_buttons")]
+mod use_copy_buttons;
+#[cfg(feature = "use_countdown_to")]
+mod use_countdown_to;
+#[cfg(feature = "use_counter")]
+mod use_counter;
 #[cfg(feature = "use_css_var")]
 mod use_css_var;
 #[cfg(feature = "use_cycle_list")]
 mod use_cycle_list;
 #[cfg(feature = "use_debounce_fn")]
 mod use_debounce_fn;
+#[cfg(feature = "use_debounced_history")]
+mod use_debounced_history;
 #[cfg(feature = "use_device_orientation")]
 mod use_device_orientation;
 #[cfg(feature = "use_device_pixel_ratio")]
@@ -64,6 +92,8 @@ mod use_display_media;
 mod use_document;
 #[cfg(feature = "use_document_visibility")]
 mod use_document_visibility;
+#[cfg(feature = "use_dominant_color")]
+mod use_dominant_color;
 #[cfg(feature = "use_draggable")]
 mod use_draggable;
 #[cfg(feature = "use_drop_zone")]
@@ -80,12 +110,32 @@ mod use_element_visibility;
 mod use_event_listener;
 #[cfg(feature = "use_event_source")]
 mod use_event_source;
+#[cfg(feature = "use_external_links")]
+mod use_external_links;
 #[cfg(feature = "use_favicon")]
 mod use_favicon;
+#[cfg(feature = "use_fetch")]
+mod use_fetch;
+#[cfg(feature = "use_fit_text")]
+mod use_fit_text;
 #[cfg(feature = "use_geolocation")]
 mod use_geolocation;
+#[cfg(feature = "use_hash_file")]
+mod use_hash_file;
+#[cfg(feature = "use_history")]
+mod use_history;
+#[cfg(feature = "use_history_state")]
+mod use_history_state;
 #[cfg(feature = "use_idle")]
 mod use_idle;
+#[cfg(feature = "use_idle_prefetch")]
+mod use_idle_prefetch;
+#[cfg(feature = "use_image_fallback")]
+mod use_image_fallback;
+#[cfg(feature = "use_image_metadata")]
+mod use_image_metadata;
+#[cfg(feature = "use_image_transform")]
+mod use_image_transform;
 #[cfg(feature = "use_infinite_scroll")]
 mod use_infinite_scroll;
 #[cfg(feature = "use_intersection_observer")]
@@ -96,18 +146,40 @@ mod use_interval;
 mod use_interval_fn;
 #[cfg(feature = "use_intl_number_format")]
 mod use_intl_number_format;
+#[cfg(feature = "use_jank_monitor")]
+mod use_jank_monitor;
+#[cfg(feature = "use_last_changed")]
+mod use_last_changed;
+#[cfg(feature = "use_lazy_component")]
+mod use_lazy_component;
 #[cfg(feature = "use_locale")]
 mod use_locale;
 #[cfg(feature = "use_locales")]
 mod use_locales;
+#[cfg(feature = "use_marquee_select")]
+mod use_marquee_select;
 #[cfg(feature = "use_media_query")]
 mod use_media_query;
+#[cfg(feature = "use_memoize")]
+mod use_memoize;
+#[cfg(feature = "use_message_channel")]
+mod use_message_channel;
+#[cfg(feature = "use_minimap")]
+mod use_minimap;
 #[cfg(feature = "use_mouse")]
 mod use_mouse;
 #[cfg(feature = "use_mouse_in_element")]
 mod use_mouse_in_element;
 #[cfg(feature = "use_mutation_observer")]
 mod use_mutation_observer;
+#[cfg(feature = "use_now")]
+mod use_now;
+#[cfg(feature = "use_outline")]
+mod use_outline;
+#[cfg(feature = "use_pan_zoom")]
+mod use_pan_zoom;
+#[cfg(feature = "use_particles")]
+mod use_particles;
 #[cfg(feature = "use_permission")]
 mod use_permission;
 #[cfg(feature = "use_preferred_contrast")]
@@ -116,22 +188,60 @@ mod use_preferred_contrast;
 mod use_preferred_dark;
 #[cfg(feature = "use_prefers_reduced_motion")]
 mod use_prefers_reduced_motion;
+#[cfg(feature = "use_priority_fetch")]
+mod use_priority_fetch;
+#[cfg(feature = "use_privacy_signals")]
+mod use_privacy_signals;
+#[cfg(feature = "use_push_to_talk")]
+mod use_push_to_talk;
 #[cfg(feature = "use_raf_fn")]
 mod use_raf_fn;
 #[cfg(feature = "use_resize_observer")]
 mod use_resize_observer;
+#[cfg(feature = "use_responsive_source")]
+mod use_responsive_source;
+#[cfg(feature = "use_rpc_channel")]
+mod use_rpc_channel;
+#[cfg(feature = "use_schedule")]
+mod use_schedule;
 #[cfg(feature = "use_scroll")]
 mod use_scroll;
+#[cfg(feature = "use_scroll_edges")]
+mod use_scroll_edges;
+#[cfg(feature = "use_scroll_sync")]
+mod use_scroll_sync;
+#[cfg(feature = "use_selection")]
+mod use_selection;
 #[cfg(feature = "use_service_worker")]
 mod use_service_worker;
+#[cfg(feature = "use_shared_worker")]
+mod use_shared_worker;
+#[cfg(feature = "use_slippy_map")]
+mod use_slippy_map;
+#[cfg(feature = "use_snap")]
+mod use_snap;
 #[cfg(feature = "use_sorted")]
 mod use_sorted;
+#[cfg(feature = "use_splitter")]
+mod use_splitter;
+#[cfg(feature = "use_spring")]
+mod use_spring;
+#[cfg(feature = "use_stream_pipeline")]
+mod use_stream_pipeline;
 #[cfg(feature = "use_supported")]
 mod use_supported;
+#[cfg(feature = "use_table")]
+mod use_table;
 #[cfg(feature = "use_textarea_autosize")]
 mod use_textarea_autosize;
+#[cfg(feature = "use_theme_color")]
+mod use_theme_color;
 #[cfg(feature = "use_throttle_fn")]
 mod use_throttle_fn;
+#[cfg(feature = "use_throttled_history")]
+mod use_throttled_history;
+#[cfg(feature = "use_timeout")]
+mod use_timeout;
 #[cfg(feature = "use_timeout_fn")]
 mod use_timeout_fn;
 #[cfg(feature = "use_timestamp")]
@@ -140,15 +250,38 @@ mod use_timestamp;
 mod use_to_string;
 #[cfg(feature = "use_toggle")]
 mod use_toggle;
+#[cfg(feature = "use_torch")]
+mod use_torch;
+#[cfg(feature = "use_track_constraints")]
+mod use_track_constraints;
+#[cfg(feature = "use_transform")]
+mod use_transform;
+#[cfg(feature = "use_transition")]
+mod use_transition;
+#[cfg(feature = "use_tree")]
+mod use_tree;
+#[cfg(feature = "use_trip_tracking")]
+mod use_trip_tracking;
+#[cfg(feature = "use_url_search_params")]
+mod use_url_search_params;
 #[cfg(feature = "use_user_media")]
 mod use_user_media;
+#[cfg(feature = "use_virtual_list")]
+mod use_virtual_list;
 #[cfg(feature = "use_web_lock")]
 #[cfg(web_sys_unstable_apis)]
 mod use_web_lock;
 #[cfg(feature = "use_web_notification")]
 mod use_web_notification;
+#[cfg(feature = "use_web_worker")]
+mod use_web_worker;
+#[cfg(feature = "use_web_worker_fn")]
+mod use_web_worker_fn;
 #[cfg(feature = "use_websocket")]
 mod use_websocket;
+#[cfg(feature = "use_webtransport")]
+#[cfg(web_sys_unstable_apis)]
+mod use_webtransport;
 #[cfg(feature = "use_window")]
 mod use_window;
 #[cfg(feature = "use_window_focus")]
@@ -159,6 +292,10 @@ mod use_window_scroll;
 mod use_window_size;
 #[cfg(feature = "watch_debounced")]
 mod watch_debounced;
+#[cfg(feature = "watch_ignorable")]
+mod watch_ignorable;
+#[cfg(feature = "watch_once")]
+mod watch_once;
 #[cfg(feature = "watch_pausable")]
 mod watch_pausable;
 #[cfg(feature = "watch_throttled")]
@@ -184,26 +321,59 @@ pub use signal_debounced::*;
 pub use signal_throttled::*;
 #[cfg(feature = "sync_signal")]
 pub use sync_signal::*;
+#[cfg(feature = "until")]
+pub use until::*;
 #[cfg(feature = "use_active_element")]
 pub use use_active_element::*;
+#[cfg(feature = "use_animate")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_animate::*;
+#[cfg(feature = "use_async_queue")]
+pub use use_async_queue::*;
+#[cfg(feature = "use_async_state")]
+pub use use_async_state::*;
+#[cfg(feature = "use_audio_level")]
+pub use use_audio_level::*;
+#[cfg(feature = "use_auto_scroll")]
+pub use use_auto_scroll::*;
+#[cfg(feature = "use_autoplay_when_visible")]
+pub use use_autoplay_when_visible::*;
 #[cfg(feature = "use_breakpoints")]
 pub use use_breakpoints::*;
 #[cfg(feature = "use_broadcast_channel")]
 pub use use_broadcast_channel::*;
+#[cfg(feature = "use_browser_location")]
+pub use use_browser_location::*;
 #[cfg(feature = "use_calendar")]
 pub use use_calendar::*;
+#[cfg(feature = "use_carousel")]
+pub use use_carousel::*;
 #[cfg(feature = "use_clipboard")]
 pub use use_clipboard::*;
 #[cfg(feature = "use_color_mode")]
 pub use use_color_mode::*;
+#[cfg(feature = "use_column_resize")]
+pub use use_column_resize::*;
+#[cfg(feature = "use_confirm_dialog")]
+pub use use_confirm_dialog::*;
+#[cfg(feature = "use_context_menu")]
+pub use use_context_menu::*;
 #[cfg(feature = "use_cookie")]
 pub use use_cookie::*;
+#[cfg(feature = "use_copy_buttons")]
+pub use use_copy_buttons::*;
+#[cfg(feature = "use_countdown_to")]
+pub use use_countdown_to::*;
+#[cfg(feature = "use_counter")]
+pub use use_counter::*;
 #[cfg(feature = "use_css_var")]
 pub use use_css_var::*;
 #[cfg(feature = "use_cycle_list")]
 pub use use_cycle_list::*;
 #[cfg(feature = "use_debounce_fn")]
 pub use use_debounce_fn::*;
+#[cfg(feature = "use_debounced_history")]
+pub use use_debounced_history::*;
 #[cfg(feature = "use_device_orientation")]
 pub use use_device_orientation::*;
 #[cfg(feature = "use_device_pixel_ratio")]
@@ -214,6 +384,8 @@ pub use use_display_media::*;
 pub use use_document::*;
 #[cfg(feature = "use_document_visibility")]
 pub use use_document_visibility::*;
+#[cfg(feature = "use_dominant_color")]
+pub use use_dominant_color::*;
 #[cfg(feature = "use_draggable")]
 pub use use_draggable::*;
 #[cfg(feature = "use_drop_zone")]
@@ -230,12 +402,32 @@ pub use use_element_visibility::*;
 pub use use_event_listener::*;
 #[cfg(feature = "use_event_source")]
 pub use use_event_source::*;
+#[cfg(feature = "use_external_links")]
+pub use use_external_links::*;
 #[cfg(feature = "use_favicon")]
 pub use use_favicon::*;
+#[cfg(feature = "use_fetch")]
+pub use use_fetch::*;
+#[cfg(feature = "use_fit_text")]
+pub use use_fit_text::*;
 #[cfg(feature = "use_geolocation")]
 pub use use_geolocation::*;
+#[cfg(feature = "use_hash_file")]
+pub use use_hash_file::*;
+#[cfg(feature = "use_history")]
+pub use use_history::*;
+#[cfg(feature = "use_history_state")]
+pub use use_history_state::*;
 #[cfg(feature = "use_idle")]
 pub use use_idle::*;
+#[cfg(feature = "use_idle_prefetch")]
+pub use use_idle_prefetch::*;
+#[cfg(feature = "use_image_fallback")]
+pub use use_image_fallback::*;
+#[cfg(feature = "use_image_metadata")]
+pub use use_image_metadata::*;
+#[cfg(feature = "use_image_transform")]
+pub use use_image_transform::*;
 #[cfg(feature = "use_infinite_scroll")]
 pub use use_infinite_scroll::*;
 #[cfg(feature = "use_intersection_observer")]
@@ -246,18 +438,40 @@ pub use use_interval::*;
 pub use use_interval_fn::*;
 #[cfg(feature = "use_intl_number_format")]
 pub use use_intl_number_format::*;
+#[cfg(feature = "use_jank_monitor")]
+pub use use_jank_monitor::*;
+#[cfg(feature = "use_last_changed")]
+pub use use_last_changed::*;
+#[cfg(feature = "use_lazy_component")]
+pub use use_lazy_component::*;
 #[cfg(feature = "use_locale")]
 pub use use_locale::*;
 #[cfg(feature = "use_locales")]
 pub use use_locales::*;
+#[cfg(feature = "use_marquee_select")]
+pub use use_marquee_select::*;
 #[cfg(feature = "use_media_query")]
 pub use use_media_query::*;
+#[cfg(feature = "use_memoize")]
+pub use use_memoize::*;
+#[cfg(feature = "use_message_channel")]
+pub use use_message_channel::*;
+#[cfg(feature = "use_minimap")]
+pub use use_minimap::*;
 #[cfg(feature = "use_mouse")]
 pub use use_mouse::*;
 #[cfg(feature = "use_mouse_in_element")]
 pub use use_mouse_in_element::*;
 #[cfg(feature = "use_mutation_observer")]
 pub use use_mutation_observer::*;
+#[cfg(feature = "use_now")]
+pub use use_now::*;
+#[cfg(feature = "use_outline")]
+pub use use_outline::*;
+#[cfg(feature = "use_pan_zoom")]
+pub use use_pan_zoom::*;
+#[cfg(feature = "use_particles")]
+pub use use_particles::*;
 #[cfg(feature = "use_permission")]
 pub use use_permission::*;
 #[cfg(feature = "use_preferred_contrast")]
@@ -266,22 +480,60 @@ pub use use_preferred_contrast::*;
 pub use use_preferred_dark::*;
 #[cfg(feature = "use_prefers_reduced_motion")]
 pub use use_prefers_reduced_motion::*;
+#[cfg(feature = "use_priority_fetch")]
+pub use use_priority_fetch::*;
+#[cfg(feature = "use_privacy_signals")]
+pub use use_privacy_signals::*;
+#[cfg(feature = "use_push_to_talk")]
+pub use use_push_to_talk::*;
 #[cfg(feature = "use_raf_fn")]
 pub use use_raf_fn::*;
 #[cfg(feature = "use_resize_observer")]
 pub use use_resize_observer::*;
+#[cfg(feature = "use_responsive_source")]
+pub use use_responsive_source::*;
+#[cfg(feature = "use_rpc_channel")]
+pub use use_rpc_channel::*;
+#[cfg(feature = "use_schedule")]
+pub use use_schedule::*;
 #[cfg(feature = "use_scroll")]
 pub use use_scroll::*;
+#[cfg(feature = "use_scroll_edges")]
+pub use use_scroll_edges::*;
+#[cfg(feature = "use_scroll_sync")]
+pub use use_scroll_sync::*;
+#[cfg(feature = "use_selection")]
+pub use use_selection::*;
 #[cfg(feature = "use_service_worker")]
 pub use use_service_worker::*;
+#[cfg(feature = "use_shared_worker")]
+pub use use_shared_worker::*;
+#[cfg(feature = "use_slippy_map")]
+pub use use_slippy_map::*;
+#[cfg(feature = "use_snap")]
+pub use use_snap::*;
 #[cfg(feature = "use_sorted")]
 pub use use_sorted::*;
+#[cfg(feature = "use_splitter")]
+pub use use_splitter::*;
+#[cfg(feature = "use_spring")]
+pub use use_spring::*;
+#[cfg(feature = "use_stream_pipeline")]
+pub use use_stream_pipeline::*;
 #[cfg(feature = "use_supported")]
 pub use use_supported::*;
+#[cfg(feature = "use_table")]
+pub use use_table::*;
 #[cfg(feature = "use_textarea_autosize")]
 pub use use_textarea_autosize::*;
+#[cfg(feature = "use_theme_color")]
+pub use use_theme_color::*;
 #[cfg(feature = "use_throttle_fn")]
 pub use use_throttle_fn::*;
+#[cfg(feature = "use_throttled_history")]
+pub use use_throttled_history::*;
+#[cfg(feature = "use_timeout")]
+pub use use_timeout::*;
 #[cfg(feature = "use_timeout_fn")]
 pub use use_timeout_fn::*;
 #[cfg(feature = "use_timestamp")]
@@ -290,15 +542,38 @@ pub use use_timestamp::*;
 pub use use_to_string::*;
 #[cfg(feature = "use_toggle")]
 pub use use_toggle::*;
+#[cfg(feature = "use_torch")]
+pub use use_torch::*;
+#[cfg(feature = "use_track_constraints")]
+pub use use_track_constraints::*;
+#[cfg(feature = "use_transform")]
+pub use use_transform::*;
+#[cfg(feature = "use_transition")]
+pub use use_transition::*;
+#[cfg(feature = "use_tree")]
+pub use use_tree::*;
+#[cfg(feature = "use_trip_tracking")]
+pub use use_trip_tracking::*;
+#[cfg(feature = "use_url_search_params")]
+pub use use_url_search_params::*;
 #[cfg(feature = "use_user_media")]
 pub use use_user_media::*;
+#[cfg(feature = "use_virtual_list")]
+pub use use_virtual_list::*;
 #[cfg(feature = "use_web_lock")]
 #[cfg(web_sys_unstable_apis)]
 pub use use_web_lock::*;
 #[cfg(feature = "use_web_notification")]
 pub use use_web_notification::*;
+#[cfg(feature = "use_web_worker")]
+pub use use_web_worker::*;
+#[cfg(feature = "use_web_worker_fn")]
+pub use use_web_worker_fn::*;
 #[cfg(feature = "use_websocket")]
 pub use use_websocket::*;
+#[cfg(feature = "use_webtransport")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_webtransport::*;
 #[cfg(feature = "use_window")]
 pub use use_window::*;
 #[cfg(feature = "use_window_focus")]
@@ -309,6 +584,10 @@ pub use use_window_scroll::*;
 pub use use_window_size::*;
 #[cfg(feature = "watch_debounced")]
 pub use watch_debounced::*;
+#[cfg(feature = "watch_ignorable")]
+pub use watch_ignorable::*;
+#[cfg(feature = "watch_once")]
+pub use watch_once::*;
 #[cfg(feature = "watch_pausable")]
 pub use watch_pausable::*;
 #[cfg(feature = "watch_throttled")]
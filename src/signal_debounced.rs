@@ -33,7 +33,9 @@ signal_filtered!(
     ///
     /// ### Options
     ///
-    /// The usual debounce option `max_wait` is available.
+    /// The usual debounce options `max_wait`, `leading` and `trailing` are available. With
+    /// `max_wait` the signal is updated at least that often while the source keeps changing, e.g.
+    /// to show intermediate search results while the user is still typing.
     ///
     /// ```
     /// # use leptos::prelude::*;
@@ -68,7 +70,7 @@ signal_filtered!(
     /// ## Server-Side Rendering
     ///
     /// Internally this uses `setTimeout` which is not supported on the server. So usually
-    /// a throttled signal on the server will simply be ignored.
+    /// a debounced signal on the server will simply be ignored.
     debounce
     /// [`signal_debounced`]
     /// [`DebounceOptions`]
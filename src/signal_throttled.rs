@@ -6,6 +6,9 @@ use leptos::reactive::wrappers::read::Signal;
 signal_filtered!(
     /// Throttle changing of a `Signal` value.
     ///
+    /// Useful for values that change very often, like the mouse position or the scroll offset,
+    /// when whatever depends on them is expensive to update.
+    ///
     /// Use `*_local` variants for values that are not `Send + Sync`.
     ///
     /// ## Demo
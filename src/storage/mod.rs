@@ -1,7 +1,15 @@
+#[cfg(feature = "use_consent")]
+mod use_consent;
+mod use_idb_signal;
+mod use_indexed_db;
 mod use_local_storage;
 mod use_session_storage;
 mod use_storage;
 
+#[cfg(feature = "use_consent")]
+pub use use_consent::*;
+pub use use_idb_signal::*;
+pub use use_indexed_db::*;
 pub use use_local_storage::*;
 pub use use_session_storage::*;
 pub use use_storage::*;
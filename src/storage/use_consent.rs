@@ -0,0 +1,302 @@
+use super::{use_storage_with_options, StorageType, UseStorageOptions};
+use codee::string::FromToStringCodec;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Granular, persisted consent for categories like analytics or marketing.
+///
+/// The decisions of the user are stored in local storage so they are remembered across visits and
+/// synced across tabs. Use `when_granted` to defer the initialization of tracking hooks or
+/// third party scripts until the user has consented.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::storage::{use_consent, UseConsentReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let consent = use_consent(["analytics", "marketing"]);
+///
+/// let has_decided = consent.has_decided;
+/// let analytics = consent.is_granted("analytics");
+///
+/// consent.when_granted("analytics", || {
+///     // load the analytics script or call the tracking hooks here
+/// });
+///
+/// view! {
+///     <Show when=move || !has_decided.get()>
+///         <div class="cookie-banner">
+///             <button on:click={
+///                 let consent = consent.clone();
+///                 move |_| consent.grant_all()
+///             }>"Accept all"</button>
+///             <button on:click={
+///                 let consent = consent.clone();
+///                 move |_| consent.deny_all()
+///             }>"Only necessary"</button>
+///         </div>
+///     </Show>
+///     <p>"Analytics: " {move || if analytics.get() { "on" } else { "off" }}</p>
+/// }
+/// # }
+/// ```
+///
+/// Every category is [`ConsentStatus::Pending`] until the user grants or denies it with `grant`,
+/// `deny`, `grant_all` or `deny_all`. `reset` forgets all decisions, for example to show the
+/// consent banner again. Category names must not contain `,` or `:`.
+///
+/// `when_granted` runs its function at most once: as soon as the category is granted, which can be
+/// right away if the user has consented on a previous visit. If consent is revoked later, anything
+/// that was initialized stays active. Use `is_granted` to react to that.
+///
+/// ## Server-Side Rendering
+///
+/// On the server every category is pending and the functions passed to `when_granted` are never
+/// run.
+pub fn use_consent<C>(categories: impl IntoIterator<Item = C>) -> UseConsentReturn
+where
+    C: Into<String>,
+{
+    use_consent_with_options(categories, UseConsentOptions::default())
+}
+
+/// Version of [`use_consent`] that takes a `UseConsentOptions`. See [`use_consent`] for how to use.
+pub fn use_consent_with_options<C>(
+    categories: impl IntoIterator<Item = C>,
+    options: UseConsentOptions,
+) -> UseConsentReturn
+where
+    C: Into<String>,
+{
+    let UseConsentOptions {
+        storage_key,
+        storage,
+        listen_to_storage_changes,
+    } = options;
+
+    let categories = Arc::new(categories.into_iter().map(Into::into).collect::<Vec<_>>());
+
+    let (consent, set_consent, remove) = use_storage_with_options::<ConsentState, FromToStringCodec>(
+        storage,
+        storage_key,
+        UseStorageOptions::default().listen_to_storage_changes(listen_to_storage_changes),
+    );
+
+    let has_decided = Signal::derive({
+        let categories = Arc::clone(&categories);
+
+        move || {
+            consent.with(|consent| {
+                categories
+                    .iter()
+                    .all(|category| consent.status(category) != ConsentStatus::Pending)
+            })
+        }
+    });
+
+    UseConsentReturn {
+        consent,
+        has_decided,
+        set_consent,
+        categories,
+        remove: Arc::new(remove),
+    }
+}
+
+/// Options for [`use_consent_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseConsentOptions {
+    /// Key the decisions are stored under. Defaults to `"leptos-use-consent"`.
+    #[builder(into)]
+    storage_key: String,
+
+    /// Storage type, can be `Local` or `Session` or custom. Defaults to `Local`.
+    storage: StorageType,
+
+    /// Listen to changes to the decisions from somewhere else, like other tabs.
+    /// Defaults to `true`.
+    listen_to_storage_changes: bool,
+}
+
+impl Default for UseConsentOptions {
+    fn default() -> Self {
+        Self {
+            storage_key: "leptos-use-consent".into(),
+            storage: StorageType::default(),
+            listen_to_storage_changes: true,
+        }
+    }
+}
+
+/// Return type of [`use_consent`].
+#[derive(Clone)]
+pub struct UseConsentReturn {
+    /// All decisions the user has made so far.
+    pub consent: Signal<ConsentState>,
+
+    /// Whether the user has granted or denied every category passed to [`use_consent`].
+    pub has_decided: Signal<bool>,
+
+    set_consent: WriteSignal<ConsentState>,
+    categories: Arc<Vec<String>>,
+    remove: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl UseConsentReturn {
+    /// Reactive status of a single category.
+    pub fn status(&self, category: impl Into<String>) -> Signal<ConsentStatus> {
+        let consent = self.consent;
+        let category = category.into();
+
+        Signal::derive(move || consent.with(|consent| consent.status(&category)))
+    }
+
+    /// Reactive flag that is `true` while the category is granted.
+    pub fn is_granted(&self, category: impl Into<String>) -> Signal<bool> {
+        let status = self.status(category);
+
+        Signal::derive(move || status.get() == ConsentStatus::Granted)
+    }
+
+    /// Grants consent for a single category.
+    pub fn grant(&self, category: impl Into<String>) {
+        let category = category.into();
+        self.set_consent
+            .update(|consent| consent.set(category, ConsentStatus::Granted));
+    }
+
+    /// Denies consent for a single category.
+    pub fn deny(&self, category: impl Into<String>) {
+        let category = category.into();
+        self.set_consent
+            .update(|consent| consent.set(category, ConsentStatus::Denied));
+    }
+
+    /// Grants consent for all categories passed to [`use_consent`].
+    pub fn grant_all(&self) {
+        self.set_all(ConsentStatus::Granted);
+    }
+
+    /// Denies consent for all categories passed to [`use_consent`].
+    pub fn deny_all(&self) {
+        self.set_all(ConsentStatus::Denied);
+    }
+
+    /// Forgets all decisions and removes them from storage.
+    pub fn reset(&self) {
+        (self.remove)();
+    }
+
+    /// Runs `init_fn` once as soon as `category` is granted. If it is granted already, `init_fn`
+    /// is run right away.
+    pub fn when_granted(&self, category: impl Into<String>, init_fn: impl FnOnce() + 'static) {
+        let is_granted = self.is_granted(category);
+        let mut init_fn = Some(init_fn);
+
+        let _ = Effect::watch(
+            move || is_granted.get(),
+            move |is_granted, _, _| {
+                if *is_granted {
+                    if let Some(init_fn) = init_fn.take() {
+                        init_fn();
+                    }
+                }
+            },
+            true,
+        );
+    }
+
+    fn set_all(&self, status: ConsentStatus) {
+        self.set_consent.update(|consent| {
+            for category in self.categories.iter() {
+                consent.set(category.clone(), status);
+            }
+        });
+    }
+}
+
+/// Decision of the user for a consent category.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ConsentStatus {
+    /// The user hasn't decided yet.
+    #[default]
+    Pending,
+    Granted,
+    Denied,
+}
+
+/// The decisions of the user per category. Categories without a decision are pending.
+///
+/// It's stored as a string like `"analytics:1,marketing:0"`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConsentState(BTreeMap<String, bool>);
+
+impl ConsentState {
+    /// Status of the given category.
+    pub fn status(&self, category: &str) -> ConsentStatus {
+        match self.0.get(category) {
+            Some(true) => ConsentStatus::Granted,
+            Some(false) => ConsentStatus::Denied,
+            None => ConsentStatus::Pending,
+        }
+    }
+
+    /// Sets the status of the given category. `ConsentStatus::Pending` removes the decision.
+    pub fn set(&mut self, category: impl Into<String>, status: ConsentStatus) {
+        let category = category.into();
+
+        match status {
+            ConsentStatus::Granted => self.0.insert(category, true),
+            ConsentStatus::Denied => self.0.insert(category, false),
+            ConsentStatus::Pending => self.0.remove(&category),
+        };
+    }
+
+    /// Iterates over all categories the user has decided on.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, ConsentStatus)> {
+        self.0
+            .keys()
+            .map(|category| (category.as_str(), self.status(category)))
+    }
+}
+
+impl Display for ConsentState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let entries = self
+            .0
+            .iter()
+            .map(|(category, granted)| format!("{category}:{}", u8::from(*granted)))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", entries.join(","))
+    }
+}
+
+impl FromStr for ConsentState {
+    type Err = ParseConsentStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once(':') {
+                Some((category, "1")) => Ok((category.to_string(), true)),
+                Some((category, "0")) => Ok((category.to_string(), false)),
+                _ => Err(ParseConsentStateError(entry.to_string())),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("invalid consent entry: {0}")]
+pub struct ParseConsentStateError(String);
@@ -0,0 +1,174 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use super::{use_indexed_db, UseIndexedDbError};
+use codee::{Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Persists a signal in IndexedDB.
+///
+/// This works like [`use_local_storage`](https://leptos-use.rs/storage/use_local_storage.html)
+/// but for data that is too large for it. Since IndexedDB is asynchronous, the signal starts with
+/// `default` and `is_ready` becomes `true` as soon as the stored value has been read.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::storage::{use_idb_signal, UseIdbSignalReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseIdbSignalReturn {
+///     value: notes,
+///     set_value: set_notes,
+///     is_ready,
+/// } = use_idb_signal::<Vec<String>, JsonSerdeCodec>("notes", vec![]);
+///
+/// view! {
+///     <Show when=move || is_ready.get() fallback=|| "Loading...">
+///         {move || notes.get().join(", ")}
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Values are stored in the object store `"key-value"` of the database `"leptos-use"` unless
+/// you specify something else with [`use_idb_signal_with_options`]. Changes that are made before
+/// `is_ready` is `true` are overwritten by the stored value.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signal just holds `default` and `is_ready` stays `false`.
+pub fn use_idb_signal<T, C>(key: impl Into<String>, default: T) -> UseIdbSignalReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str> + 'static,
+{
+    use_idb_signal_with_options::<T, C>(key, default, UseIdbSignalOptions::default())
+}
+
+/// Version of [`use_idb_signal`] that takes a `UseIdbSignalOptions`. See [`use_idb_signal`] for how to use.
+pub fn use_idb_signal_with_options<T, C>(
+    key: impl Into<String>,
+    default: T,
+    options: UseIdbSignalOptions<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>,
+) -> UseIdbSignalReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str> + 'static,
+{
+    let UseIdbSignalOptions {
+        db_name,
+        store_name,
+        on_error,
+    } = options;
+
+    let (value, set_value) = signal(default);
+    let (is_ready, set_is_ready) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let db = use_indexed_db(db_name, store_name);
+        let key = key.into();
+
+        leptos::task::spawn_local({
+            let key = key.clone();
+            let on_error = Arc::clone(&on_error);
+
+            async move {
+                match db.get::<T, C>(&key).await {
+                    Ok(Some(stored)) => {
+                        set_value.try_set(stored);
+                    }
+                    Ok(None) => {}
+                    Err(err) => on_error(err),
+                }
+
+                set_is_ready.try_set(true);
+            }
+        });
+
+        let _ = Effect::watch(
+            move || value.get(),
+            move |value, _, _| {
+                if !is_ready.get_untracked() {
+                    return;
+                }
+
+                let value = value.clone();
+                let key = key.clone();
+                let on_error = Arc::clone(&on_error);
+
+                leptos::task::spawn_local(async move {
+                    if let Err(err) = db.set::<T, C>(&key, &value).await {
+                        on_error(err);
+                    }
+                });
+            },
+            false,
+        );
+    }
+
+    UseIdbSignalReturn {
+        value: value.into(),
+        set_value,
+        is_ready: is_ready.into(),
+    }
+}
+
+/// Options for [`use_idb_signal_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseIdbSignalOptions<E, D> {
+    /// Name of the database. Defaults to `"leptos-use"`.
+    #[builder(into)]
+    db_name: String,
+
+    /// Name of the object store. Defaults to `"key-value"`.
+    #[builder(into)]
+    store_name: String,
+
+    /// Callback for when reading or writing the value fails.
+    #[builder(skip)]
+    on_error: Arc<dyn Fn(UseIndexedDbError<E, D>) + Send + Sync>,
+}
+
+impl<E, D> Default for UseIdbSignalOptions<E, D> {
+    fn default() -> Self {
+        Self {
+            db_name: "leptos-use".to_string(),
+            store_name: "key-value".to_string(),
+            on_error: Arc::new(|_| ()),
+        }
+    }
+}
+
+impl<E, D> UseIdbSignalOptions<E, D> {
+    /// Optional callback for when reading or writing the value fails.
+    pub fn on_error(
+        self,
+        on_error: impl Fn(UseIndexedDbError<E, D>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            on_error: Arc::new(on_error),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_idb_signal`].
+pub struct UseIdbSignalReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// The persisted value.
+    pub value: Signal<T>,
+
+    /// Sets the value and writes it to IndexedDB.
+    pub set_value: WriteSignal<T>,
+
+    /// `true` once the stored value has been read.
+    pub is_ready: Signal<bool>,
+}
@@ -0,0 +1,334 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use codee::{CodecError, Decoder, Encoder};
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use thiserror::Error;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbObjectStore, IdbRequest, IdbTransactionMode};
+
+/// Key-value store in [IndexedDB](https://developer.mozilla.org/en-US/docs/Web/API/IndexedDB_API).
+///
+/// Use this for data that is too large for [`use_local_storage`](https://leptos-use.rs/storage/use_local_storage.html).
+/// Values are (en)decoded to strings via the given codec just like with
+/// [`use_storage`](https://leptos-use.rs/storage/use_storage.html).
+///
+/// The database `db_name` is opened when it's first needed and the object store `store_name` is
+/// created if it doesn't exist yet.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::storage::use_indexed_db;
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Clone)]
+/// pub struct Document {
+///     title: String,
+///     content: String,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let db = use_indexed_db("my-app", "documents");
+///
+/// spawn_local(async move {
+///     let document = Document {
+///         title: "Hello".to_string(),
+///         content: "World".to_string(),
+///     };
+///
+///     db.set::<Document, JsonSerdeCodec>("doc-1", &document).await.ok();
+///
+///     let stored = db.get::<Document, JsonSerdeCodec>("doc-1").await; // Ok(Some(document))
+///     let keys = db.keys().await; // Ok(vec!["doc-1"])
+///
+///     db.remove("doc-1").await.ok();
+///     db.clear().await.ok();
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// To persist a single signal see [`fn@crate::storage::use_idb_signal`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server all operations return `Err(UseIndexedDbError::NotSupported)`.
+pub fn use_indexed_db(
+    db_name: impl Into<String>,
+    store_name: impl Into<String>,
+) -> UseIndexedDbReturn {
+    UseIndexedDbReturn {
+        db_name: StoredValue::new(db_name.into()),
+        store_name: StoredValue::new(store_name.into()),
+        db: StoredValue::new(None),
+    }
+}
+
+/// Return type of [`use_indexed_db`].
+#[derive(Clone, Copy)]
+pub struct UseIndexedDbReturn {
+    db_name: StoredValue<String>,
+    store_name: StoredValue<String>,
+    db: StoredValue<Option<SendWrapper<IdbDatabase>>>,
+}
+
+impl UseIndexedDbReturn {
+    /// Reads and decodes the value stored under `key`. Returns `None` if there is none.
+    pub async fn get<T, C>(
+        &self,
+        key: &str,
+    ) -> Result<Option<T>, UseIndexedDbError<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>>
+    where
+        C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    {
+        let store = self.object_store(IdbTransactionMode::Readonly).await?;
+        let request = store
+            .get(&JsValue::from_str(key))
+            .map_err(UseIndexedDbError::Request)?;
+        let value = request_future(&request)
+            .await
+            .map_err(UseIndexedDbError::Request)?;
+
+        if value.is_undefined() {
+            return Ok(None);
+        }
+
+        let encoded = value.as_string().ok_or(UseIndexedDbError::ValueNotString)?;
+
+        C::decode(&encoded)
+            .map(Some)
+            .map_err(|err| UseIndexedDbError::Codec(CodecError::Decode(err)))
+    }
+
+    /// Encodes `value` and stores it under `key`.
+    pub async fn set<T, C>(
+        &self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), UseIndexedDbError<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>>
+    where
+        C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    {
+        let encoded =
+            C::encode(value).map_err(|err| UseIndexedDbError::Codec(CodecError::Encode(err)))?;
+
+        let store = self.object_store(IdbTransactionMode::Readwrite).await?;
+        let request = store
+            .put_with_key(&JsValue::from_str(&encoded), &JsValue::from_str(key))
+            .map_err(UseIndexedDbError::Request)?;
+
+        request_future(&request)
+            .await
+            .map(|_| ())
+            .map_err(UseIndexedDbError::Request)
+    }
+
+    /// Removes the value stored under `key`.
+    pub async fn remove(&self, key: &str) -> Result<(), UseIndexedDbError> {
+        let store = self.object_store(IdbTransactionMode::Readwrite).await?;
+        let request = store
+            .delete(&JsValue::from_str(key))
+            .map_err(UseIndexedDbError::Request)?;
+
+        request_future(&request)
+            .await
+            .map(|_| ())
+            .map_err(UseIndexedDbError::Request)
+    }
+
+    /// Removes all values from the object store.
+    pub async fn clear(&self) -> Result<(), UseIndexedDbError> {
+        let store = self.object_store(IdbTransactionMode::Readwrite).await?;
+        let request = store.clear().map_err(UseIndexedDbError::Request)?;
+
+        request_future(&request)
+            .await
+            .map(|_| ())
+            .map_err(UseIndexedDbError::Request)
+    }
+
+    /// Returns all keys of the object store.
+    pub async fn keys(&self) -> Result<Vec<String>, UseIndexedDbError> {
+        let store = self.object_store(IdbTransactionMode::Readonly).await?;
+        let request = store.get_all_keys().map_err(UseIndexedDbError::Request)?;
+        let keys = request_future(&request)
+            .await
+            .map_err(UseIndexedDbError::Request)?;
+
+        Ok(keys
+            .unchecked_into::<js_sys::Array>()
+            .iter()
+            .filter_map(|key| key.as_string())
+            .collect())
+    }
+
+    async fn object_store<E, D>(
+        &self,
+        mode: IdbTransactionMode,
+    ) -> Result<IdbObjectStore, UseIndexedDbError<E, D>> {
+        let store_name = self.store_name.get_value();
+        let db = self.database().await?;
+
+        db.transaction_with_str_and_mode(&store_name, mode)
+            .and_then(|transaction| transaction.object_store(&store_name))
+            .map_err(UseIndexedDbError::Request)
+    }
+
+    async fn database<E, D>(&self) -> Result<IdbDatabase, UseIndexedDbError<E, D>> {
+        #[cfg(feature = "ssr")]
+        {
+            Err(UseIndexedDbError::NotSupported)
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            if let Some(db) = self.db.get_value() {
+                return Ok(db.take());
+            }
+
+            let db = open_database(&self.db_name.get_value(), &self.store_name.get_value()).await?;
+
+            // Close the connection if another connection wants to upgrade the database
+            // (e.g. to create another object store). It is reopened on the next operation.
+            let cached = self.db;
+            let on_version_change = Closure::<dyn FnMut()>::new({
+                let db = db.clone();
+                move || {
+                    db.close();
+                    cached.try_set_value(None);
+                }
+            });
+            db.set_onversionchange(Some(on_version_change.into_js_value().unchecked_ref()));
+
+            self.db.set_value(Some(SendWrapper::new(db.clone())));
+
+            Ok(db)
+        }
+    }
+}
+
+/// Opens the database and makes sure that the object store exists by upgrading the database
+/// if necessary.
+#[cfg(not(feature = "ssr"))]
+async fn open_database<E, D>(
+    db_name: &str,
+    store_name: &str,
+) -> Result<IdbDatabase, UseIndexedDbError<E, D>> {
+    let factory = window()
+        .indexed_db()
+        .map_err(|_| UseIndexedDbError::NotSupported)?
+        .ok_or(UseIndexedDbError::NotSupported)?;
+
+    let db = open_database_version(&factory, db_name, None, store_name)
+        .await
+        .map_err(UseIndexedDbError::Open)?;
+
+    if db.object_store_names().contains(store_name) {
+        return Ok(db);
+    }
+
+    let version = db.version() as u32 + 1;
+    db.close();
+
+    open_database_version(&factory, db_name, Some(version), store_name)
+        .await
+        .map_err(UseIndexedDbError::Open)
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn open_database_version(
+    factory: &web_sys::IdbFactory,
+    db_name: &str,
+    version: Option<u32>,
+    store_name: &str,
+) -> Result<IdbDatabase, JsValue> {
+    let request = match version {
+        Some(version) => factory.open_with_u32(db_name, version)?,
+        None => factory.open(db_name)?,
+    };
+
+    let on_upgrade_needed = Closure::<dyn FnMut()>::new({
+        let request = request.clone();
+        let store_name = store_name.to_string();
+
+        move || {
+            if let Ok(db) = request.result() {
+                let db = db.unchecked_into::<IdbDatabase>();
+
+                if !db.object_store_names().contains(&store_name) {
+                    let _ = db.create_object_store(&store_name);
+                }
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade_needed.as_ref().unchecked_ref()));
+
+    let result = request_future(&request).await;
+    request.set_onupgradeneeded(None);
+
+    Ok(result?.unchecked_into())
+}
+
+/// Waits for the request to succeed or fail and returns its result or error.
+async fn request_future(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let mut callbacks = None;
+    let promise = js_sys::Promise::new(&mut |resolve, reject| callbacks = Some((resolve, reject)));
+    let (resolve, reject) = callbacks.expect("Promise executor is called synchronously");
+
+    let on_success = Closure::once({
+        let request = request.clone();
+        move || {
+            let _ = resolve.call1(
+                &JsValue::UNDEFINED,
+                &request.result().unwrap_or(JsValue::UNDEFINED),
+            );
+        }
+    });
+
+    let on_error = Closure::once({
+        let request = request.clone();
+        move || {
+            let error = request
+                .error()
+                .ok()
+                .flatten()
+                .map(JsValue::from)
+                .unwrap_or(JsValue::UNDEFINED);
+
+            let _ = reject.call1(&JsValue::UNDEFINED, &error);
+        }
+    });
+
+    request.set_onsuccess(Some(on_success.as_ref().unchecked_ref()));
+    request.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+    let result = JsFuture::from(promise).await;
+
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+
+    result
+}
+
+/// Error type for [`use_indexed_db`] and [`fn@crate::storage::use_idb_signal`].
+#[derive(Error, Debug)]
+pub enum UseIndexedDbError<E = (), D = ()> {
+    #[error("IndexedDB is not supported")]
+    NotSupported,
+    #[error("failed to open database")]
+    Open(JsValue),
+    #[error("IndexedDB request failed")]
+    Request(JsValue),
+    #[error("stored value is not a string")]
+    ValueNotString,
+    #[error("failed to encode / decode value")]
+    Codec(CodecError<E, D>),
+}
@@ -16,6 +16,26 @@ use leptos::reactive::wrappers::read::Signal;
 /// ## Usage
 ///
 /// See [`use_storage`](https://leptos-use.rs/storage/use_storage.html) for more details on how to use.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::storage::{use_local_storage, use_local_storage_with_options, UseStorageOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// // defaults to `T::default()` if nothing is stored yet
+/// let (count, set_count, remove_count) = use_local_storage::<i32, FromToStringCodec>("count");
+///
+/// // use a custom default
+/// let (theme, set_theme, _) = use_local_storage_with_options::<String, FromToStringCodec>(
+///     "theme",
+///     UseStorageOptions::default().initial_value("light".to_string()),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
 pub fn use_local_storage<T, C>(
     key: impl Into<Signal<String>>,
 ) -> (Signal<T>, WriteSignal<T>, impl Fn() + Clone + Send + Sync)
@@ -81,6 +81,36 @@ const INTERNAL_STORAGE_EVENT: &str = "leptos-use-storage";
 /// }
 /// ```
 ///
+/// ### Merging defaults
+///
+/// When you add fields to a stored struct, values stored by an older version of your app can't
+/// be decoded anymore and the initial value is used instead. With the option `merge_defaults` the
+/// stored fields are merged into the initial value instead. This works with JSON based codecs.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::storage::{use_local_storage_with_options, UseStorageOptions};
+/// # use serde::{Deserialize, Serialize};
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// #[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+/// pub struct Settings {
+///     pub theme: String,
+///     // added in a later version
+///     pub font_size: u32,
+/// }
+///
+/// # #[component]
+/// # pub fn Demo() -> impl IntoView {
+/// // `{"theme":"dark"}` in storage is read as `Settings { theme: "dark", font_size: 0 }`
+/// let (settings, set_settings, _) = use_local_storage_with_options::<Settings, JsonSerdeCodec>(
+///     "settings",
+///     UseStorageOptions::default().merge_defaults(true),
+/// );
+/// #    view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server the returned signals will just read/manipulate the `initial_value` without persistence.
@@ -170,6 +200,7 @@ where
         initial_value,
         filter,
         delay_during_hydration,
+        merge_defaults,
     } = options;
 
     let (data, set_data) = initial_value.into_signal();
@@ -181,6 +212,7 @@ where
         let _ = listen_to_storage_changes;
         let _ = filter;
         let _ = delay_during_hydration;
+        let _ = merge_defaults;
         let _ = storage_type;
         let _ = key;
         let _ = INTERNAL_STORAGE_EVENT;
@@ -237,6 +269,7 @@ where
         let read_from_storage = {
             let storage = storage.to_owned();
             let on_error = on_error.to_owned();
+            let default = default.clone();
 
             move || {
                 storage
@@ -252,7 +285,14 @@ where
                     .as_ref()
                     .map(|encoded| {
                         // Decode item
-                        let result = C::decode(encoded)
+                        let result = C::decode(encoded).or_else(|e| {
+                            // Try again with the stored fields merged into the default value
+                            merge_defaults
+                                .then(|| merge_with_default::<T, C>(encoded, &default))
+                                .flatten()
+                                .ok_or(e)
+                        });
+                        let result = result
                             .map_err(|e| UseStorageError::ItemCodecError(CodecError::Decode(e)));
                         handle_error(&on_error, result)
                     })
@@ -457,6 +497,11 @@ where
     /// This ensures that during hydration the value is the initial value just like it is on the server
     /// which helps prevent hydration errors. Defaults to `false`.
     delay_during_hydration: bool,
+    /// If the stored value can't be decoded, e.g. because fields have been added to `T` since it
+    /// was stored, the stored JSON object is deeply merged into the encoded initial value and
+    /// decoded again. This only works with JSON based codecs like `JsonSerdeCodec`.
+    /// Defaults to `false`.
+    merge_defaults: bool,
 }
 
 /// Calls the on_error callback with the given error. Removes the error from the Result to avoid double error handling.
@@ -468,6 +513,54 @@ fn handle_error<T, E, D>(
     result.map_err(|err| (on_error)(err))
 }
 
+/// Merges the stored JSON `encoded` into the encoded `default` value and decodes the result.
+#[cfg(not(feature = "ssr"))]
+fn merge_with_default<T, C>(encoded: &str, default: &T) -> Option<T>
+where
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+{
+    let encoded_default = C::encode(default).ok()?;
+
+    let merged = merge_json(
+        &js_sys::JSON::parse(&encoded_default).ok()?,
+        &js_sys::JSON::parse(encoded).ok()?,
+    );
+
+    let merged = js_sys::JSON::stringify(&merged).ok()?.as_string()?;
+
+    C::decode(&merged).ok()
+}
+
+/// Deeply merges the JSON object `stored` into `default`. Anything that is not an object
+/// (including arrays) is taken from `stored` as is.
+#[cfg(not(feature = "ssr"))]
+fn merge_json(default: &JsValue, stored: &JsValue) -> JsValue {
+    use wasm_bindgen::JsCast;
+
+    let is_object = |value: &JsValue| value.is_object() && !js_sys::Array::is_array(value);
+
+    if !is_object(default) || !is_object(stored) {
+        return stored.clone();
+    }
+
+    let merged = js_sys::Object::new();
+    let _ = js_sys::Object::assign(&merged, default.unchecked_ref());
+
+    for key in js_sys::Object::keys(stored.unchecked_ref()).iter() {
+        let stored_value = js_sys::Reflect::get(stored, &key).unwrap_or_default();
+        let value = match js_sys::Reflect::get(default, &key) {
+            Ok(default_value) if !default_value.is_undefined() => {
+                merge_json(&default_value, &stored_value)
+            }
+            _ => stored_value,
+        };
+
+        let _ = js_sys::Reflect::set(&merged, &key, &value);
+    }
+
+    merged.into()
+}
+
 impl<T: Default, E, D> Default for UseStorageOptions<T, E, D>
 where
     T: Send + Sync + 'static,
@@ -479,6 +572,7 @@ where
             initial_value: MaybeRwSignal::default(),
             filter: FilterOptions::default(),
             delay_during_hydration: false,
+            merge_defaults: false,
         }
     }
 }
@@ -0,0 +1,326 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+/// Await a change or condition of a signal.
+///
+/// Lets async code wait for reactive state instead of polling it.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/until)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::until;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (is_ready, set_ready) = signal(false);
+///
+/// spawn_local(async move {
+///     until(is_ready).is(true).await;
+///
+///     log!("Ready!");
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// There are several ways to wait:
+///
+/// * `is(value)` and `is_not(value)` wait until the signal has (or doesn't have) the given value.
+/// * `matches(predicate)` waits until the predicate returns `true` for the value of the signal.
+/// * `changed()` waits for the next change of the signal.
+/// * `changed_times(n)` waits until the signal has changed `n` times.
+///
+/// `is`, `is_not` and `matches` resolve right away if the signal already fulfills the condition.
+/// All of them resolve to `Some` with the value of the signal at that moment.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::until;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (count, set_count) = signal(0);
+///
+/// spawn_local(async move {
+///     let count: i32 = until(count).matches(|count| *count > 10).await.unwrap();
+///
+///     log!("count is {count} now");
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Timeout
+///
+/// With a timeout in milliseconds the future resolves to `None` if the condition isn't fulfilled
+/// in time.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::until;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (is_ready, set_ready) = signal(false);
+/// #
+/// spawn_local(async move {
+///     if until(is_ready).timeout(5000.0).is(true).await.is_none() {
+///         log!("Not ready after 5 seconds");
+///     }
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The signal is only watched while the future is pending. Dropping the future stops watching.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signal isn't watched and there's no timeout. The future only resolves if the
+/// condition is already fulfilled when it's created.
+///
+/// ## See also
+///
+/// * [`fn@crate::whenever`]
+/// * [`fn@crate::watch_once`]
+pub fn until<T>(source: impl Into<Signal<T>>) -> Until<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    Until {
+        source: source.into(),
+        timeout: None,
+    }
+}
+
+/// Return type of [`until`]. Use one of its methods to get a future.
+pub struct Until<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    source: Signal<T>,
+    timeout: Option<f64>,
+}
+
+impl<T> Clone for Until<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Until<T> where T: Clone + Send + Sync + 'static {}
+
+impl<T> Until<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Resolves to `None` if the condition isn't fulfilled after `ms` milliseconds.
+    pub fn timeout(self, ms: f64) -> Self {
+        Self {
+            timeout: Some(ms),
+            ..self
+        }
+    }
+
+    /// Waits until the signal has the value `value`.
+    pub fn is(self, value: T) -> impl Future<Output = Option<T>>
+    where
+        T: PartialEq,
+    {
+        self.matches(move |v| *v == value)
+    }
+
+    /// Waits until the signal doesn't have the value `value`.
+    pub fn is_not(self, value: T) -> impl Future<Output = Option<T>>
+    where
+        T: PartialEq,
+    {
+        self.matches(move |v| *v != value)
+    }
+
+    /// Waits until `predicate` returns `true` for the value of the signal.
+    pub fn matches(
+        self,
+        predicate: impl Fn(&T) -> bool + 'static,
+    ) -> impl Future<Output = Option<T>> {
+        let current = self.source.get_untracked();
+
+        if predicate(&current) {
+            return UntilFuture::resolved(current);
+        }
+
+        self.wait(predicate)
+    }
+
+    /// Waits for the next change of the signal.
+    pub fn changed(self) -> impl Future<Output = Option<T>> {
+        self.changed_times(1)
+    }
+
+    /// Waits until the signal has changed `n` times.
+    pub fn changed_times(self, n: usize) -> impl Future<Output = Option<T>> {
+        if n == 0 {
+            return UntilFuture::resolved(self.source.get_untracked());
+        }
+
+        let changes = Cell::new(0_usize);
+
+        self.wait(move |_| {
+            changes.set(changes.get() + 1);
+            changes.get() >= n
+        })
+    }
+
+    /// Calls `check` on every change of the signal until it returns `true`.
+    fn wait(self, check: impl Fn(&T) -> bool + 'static) -> UntilFuture<T> {
+        let state = Rc::new(RefCell::new(UntilState {
+            result: None,
+            waker: None,
+            effect: None,
+            timeout: None,
+        }));
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let source = self.source;
+
+            let effect = Effect::watch(
+                move || source.get(),
+                {
+                    let state = Rc::clone(&state);
+
+                    move |value, _, _| {
+                        if check(value) {
+                            settle(&state, Some(value.clone()));
+                        }
+                    }
+                },
+                false,
+            );
+
+            let timeout = self.timeout.and_then(|ms| {
+                let state = Rc::clone(&state);
+
+                set_timeout_with_handle(
+                    move || settle(&state, None),
+                    std::time::Duration::from_millis(ms as u64),
+                )
+                .ok()
+            });
+
+            let mut state = state.borrow_mut();
+            state.effect = Some(effect);
+            state.timeout = timeout;
+        }
+
+        UntilFuture { state }
+    }
+}
+
+/// Shared between the future returned by [`Until`] and the watcher of the signal.
+struct UntilState<T> {
+    result: Option<Option<T>>,
+    waker: Option<Waker>,
+    effect: Option<Effect<LocalStorage>>,
+    timeout: Option<TimeoutHandle>,
+}
+
+/// Future returned by the methods of [`Until`].
+struct UntilFuture<T> {
+    state: Rc<RefCell<UntilState<T>>>,
+}
+
+impl<T> UntilFuture<T> {
+    fn resolved(value: T) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(UntilState {
+                result: Some(Some(value)),
+                waker: None,
+                effect: None,
+                timeout: None,
+            })),
+        }
+    }
+}
+
+impl<T> Future for UntilFuture<T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for UntilFuture<T> {
+    fn drop(&mut self) {
+        stop(&self.state);
+    }
+}
+
+/// Resolves the future with `result` and stops watching.
+fn settle<T>(state: &Rc<RefCell<UntilState<T>>>, result: Option<T>) {
+    {
+        let mut state = state.borrow_mut();
+
+        if state.result.is_some() || state.effect.is_none() && state.timeout.is_none() {
+            return;
+        }
+
+        state.result = Some(result);
+    }
+
+    stop(state);
+
+    if let Some(waker) = state.borrow_mut().waker.take() {
+        waker.wake();
+    }
+}
+
+/// Stops watching the signal and clears the timeout.
+fn stop<T>(state: &Rc<RefCell<UntilState<T>>>) {
+    let (effect, timeout) = {
+        let mut state = state.borrow_mut();
+        (state.effect.take(), state.timeout.take())
+    };
+
+    if let Some(effect) = effect {
+        effect.stop();
+    }
+
+    if let Some(timeout) = timeout {
+        timeout.clear();
+    }
+}
@@ -0,0 +1,489 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Web Animations API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Animations_API).
+///
+/// Animates `target` with [`Element.animate()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/animate)
+/// and keeps the state of the animation in signals. When the target element or the keyframes
+/// change the animation is re-created.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_animate)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_animate_with_options, Keyframe, UseAnimateOptions, UseAnimateReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let animate = use_animate_with_options(
+///     el,
+///     vec![
+///         Keyframe::new().set("transform", "rotate(0deg)"),
+///         Keyframe::new().set("transform", "rotate(360deg)"),
+///     ],
+///     UseAnimateOptions::default()
+///         .duration(2000.0)
+///         .iterations(f64::INFINITY),
+/// );
+/// let UseAnimateReturn { play_state, .. } = animate;
+///
+/// view! {
+///     <div node_ref=el>"Spinning"</div>
+///     <button on:click=move |_| animate.pause()>"Pause"</button>
+///     <button on:click=move |_| animate.play()>"Play"</button>
+///     <p>{move || format!("{:?}", play_state.get())}</p>
+/// }
+/// # }
+/// ```
+///
+/// Property names can be given in CSS (`background-color`) or JavaScript (`backgroundColor`)
+/// notation. A keyframe can also have an `offset` and an `easing`.
+///
+/// ### Current time and playback rate
+///
+/// `current_time` and `playback_rate` can be changed with `set_current_time` and
+/// `set_playback_rate`, for example to scrub through an animation with a slider.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_animate_with_options, Keyframe, UseAnimateOptions, UseAnimateReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let el = NodeRef::<Div>::new();
+/// let UseAnimateReturn { current_time, set_current_time, .. } = use_animate_with_options(
+///     el,
+///     vec![
+///         Keyframe::new().set("opacity", "0"),
+///         Keyframe::new().set("opacity", "1"),
+///     ],
+///     UseAnimateOptions::default().duration(1000.0).immediate(false),
+/// );
+///
+/// view! {
+///     <input
+///         type="range"
+///         min="0"
+///         max="1000"
+///         prop:value=move || current_time.get().unwrap_or_default()
+///         on:input=move |e| set_current_time.set(event_target_value(&e).parse().ok())
+///     />
+/// }
+/// # }
+/// ```
+///
+/// The animation is cancelled when the component is unmounted unless the option `commit_styles`
+/// is set, which keeps the current styles of the animation on the element.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is animated, `play_state` is `Idle` and the methods do nothing.
+pub fn use_animate<El, M>(
+    target: El,
+    keyframes: impl Into<Signal<Vec<Keyframe>>>,
+) -> UseAnimateReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_animate_with_options(target, keyframes, UseAnimateOptions::default())
+}
+
+/// Version of [`use_animate`] that takes a `UseAnimateOptions`. See [`use_animate`] for how to use.
+pub fn use_animate_with_options<El, M>(
+    target: El,
+    keyframes: impl Into<Signal<Vec<Keyframe>>>,
+    options: UseAnimateOptions,
+) -> UseAnimateReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let target = target.into_element_maybe_signal();
+    let keyframes = keyframes.into();
+
+    let (play_state, set_play_state) = signal(web_sys::AnimationPlayState::Idle);
+    let (pending, set_pending) = signal(false);
+    let (current_time, set_current_time) = signal(None::<f64>);
+    let (playback_rate, set_playback_rate) = signal(1.0);
+
+    let animation = StoredValue::new_local(None::<web_sys::Animation>);
+    let sync_fn = StoredValue::new_local(Box::new(|| ()) as Box<dyn Fn()>);
+    let sync = move || sync_fn.with_value(|sync| sync());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::utils::Pausable;
+        use crate::{js_fut, sendwrap_fn, use_raf_fn_with_options, UseRafFnOptions};
+        use wasm_bindgen::JsCast;
+
+        let immediate = options.immediate;
+        let commit_styles = options.commit_styles;
+        let animation_options = options.to_js();
+
+        // copies the state of the animation into the signals
+        let update_signals = move || {
+            let Some(animation) = animation.get_value() else {
+                return;
+            };
+
+            let new_play_state = animation.play_state();
+            if play_state.get_untracked() != new_play_state {
+                set_play_state.set(new_play_state);
+            }
+
+            if pending.get_untracked() != animation.pending() {
+                set_pending.set(animation.pending());
+            }
+
+            if current_time.get_untracked() != animation.current_time() {
+                set_current_time.set(animation.current_time());
+            }
+
+            if playback_rate.get_untracked() != animation.playback_rate() {
+                set_playback_rate.set(animation.playback_rate());
+            }
+        };
+
+        // the current time only changes while running so it's only polled then
+        let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+            move |_| update_signals(),
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        sync_fn.set_value(Box::new(move || {
+            update_signals();
+
+            if play_state.get_untracked() == web_sys::AnimationPlayState::Running {
+                resume();
+            } else {
+                pause();
+            }
+        }));
+
+        let _ = Effect::watch(
+            move || (target.get(), keyframes.get()),
+            move |(target, keyframes), _, _| {
+                if let Some(animation) = animation.get_value() {
+                    animation.cancel();
+                }
+
+                let new_animation = target.as_ref().map(|target| {
+                    let new_animation = target.animate_with_keyframe_animation_options(
+                        Some(&keyframes_to_js(keyframes)),
+                        &animation_options,
+                    );
+
+                    if !immediate {
+                        let _ = new_animation.pause();
+                    }
+
+                    // keeps the playback rate that has been set before
+                    new_animation.set_playback_rate(playback_rate.get_untracked());
+
+                    if let Ok(ready) = new_animation.ready() {
+                        leptos::task::spawn_local(async move {
+                            let _ = js_fut!(ready).await;
+                            sync();
+                        });
+                    }
+
+                    new_animation
+                });
+
+                animation.set_value(new_animation);
+                sync();
+            },
+            true,
+        );
+
+        let _ = Effect::watch(
+            move || current_time.get(),
+            move |current_time, _, _| {
+                if let Some(animation) = animation.get_value() {
+                    if animation.current_time() != *current_time {
+                        animation.set_current_time(*current_time);
+                        sync();
+                    }
+                }
+            },
+            false,
+        );
+
+        let _ = Effect::watch(
+            move || playback_rate.get(),
+            move |playback_rate, _, _| {
+                if let Some(animation) = animation.get_value() {
+                    if animation.playback_rate() != *playback_rate {
+                        animation.set_playback_rate(*playback_rate);
+                        sync();
+                    }
+                }
+            },
+            false,
+        );
+
+        on_cleanup(sendwrap_fn!(move || {
+            if let Some(animation) = animation.try_get_value().flatten() {
+                if commit_styles {
+                    // `commitStyles` isn't available in `web_sys`
+                    let _ = js_sys::Reflect::get(&animation, &"commitStyles".into())
+                        .and_then(|commit| commit.dyn_into::<js_sys::Function>())
+                        .and_then(|commit| commit.call0(&animation));
+                }
+                animation.cancel();
+            }
+        }));
+    }
+
+    UseAnimateReturn {
+        play_state: play_state.into(),
+        pending: pending.into(),
+        current_time: current_time.into(),
+        set_current_time,
+        playback_rate: playback_rate.into(),
+        set_playback_rate,
+        animation,
+        sync: sync_fn,
+    }
+}
+
+/// A keyframe of [`use_animate`].
+///
+/// See [Keyframe Formats](https://developer.mozilla.org/en-US/docs/Web/API/Web_Animations_API/Keyframe_Formats).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keyframe {
+    /// Position of the keyframe between `0.0` and `1.0`. Keyframes without an offset are spaced
+    /// evenly.
+    pub offset: Option<f64>,
+
+    /// Easing from this keyframe to the next one.
+    pub easing: Option<String>,
+
+    /// CSS properties and their values
+    pub properties: Vec<(String, String)>,
+}
+
+impl Keyframe {
+    /// Creates an empty keyframe.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the CSS property `property` to `value`.
+    pub fn set(mut self, property: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.push((property.into(), value.into()));
+        self
+    }
+
+    /// Sets the offset of the keyframe.
+    pub fn offset(self, offset: f64) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Sets the easing from this keyframe to the next one.
+    pub fn easing(self, easing: impl Into<String>) -> Self {
+        Self {
+            easing: Some(easing.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn keyframes_to_js(keyframes: &[Keyframe]) -> js_sys::Object {
+    keyframes
+        .iter()
+        .map(|keyframe| {
+            let obj = js_sys::Object::new();
+
+            for (property, value) in &keyframe.properties {
+                let _ = js_sys::Reflect::set(
+                    &obj,
+                    &camel_case(property).into(),
+                    &JsValue::from_str(value),
+                );
+            }
+            if let Some(offset) = keyframe.offset {
+                let _ = js_sys::Reflect::set(&obj, &"offset".into(), &offset.into());
+            }
+            if let Some(easing) = &keyframe.easing {
+                let _ = js_sys::Reflect::set(&obj, &"easing".into(), &easing.into());
+            }
+
+            JsValue::from(obj)
+        })
+        .collect::<js_sys::Array>()
+        .into()
+}
+
+/// Converts CSS property names like `background-color` to `backgroundColor`. Custom properties
+/// like `--my-color` are kept.
+fn camel_case(property: &str) -> String {
+    if property.starts_with("--") {
+        return property.to_string();
+    }
+
+    let mut result = String::with_capacity(property.len());
+    let mut upper = false;
+
+    for c in property.chars() {
+        if c == '-' {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Options for [`use_animate_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAnimateOptions {
+    /// Whether to start playing right away. Defaults to `true`.
+    immediate: bool,
+
+    /// Whether to keep the styles of the animation on the element when the component is
+    /// unmounted. Defaults to `false`.
+    commit_styles: bool,
+
+    /// Duration of one iteration in milliseconds. Defaults to `1000.0`.
+    duration: f64,
+
+    /// Delay before the animation starts in milliseconds. Defaults to `0.0`.
+    delay: f64,
+
+    /// Number of iterations. Use `f64::INFINITY` to repeat forever. Defaults to `1.0`.
+    iterations: f64,
+
+    /// Easing of the whole animation like `"ease-in-out"`. Defaults to `"linear"`.
+    #[builder(into)]
+    easing: String,
+
+    /// Direction of the animation. Defaults to `PlaybackDirection::Normal`.
+    direction: web_sys::PlaybackDirection,
+
+    /// How styles are applied before and after the animation. Defaults to `FillMode::Auto`.
+    fill: web_sys::FillMode,
+}
+
+impl Default for UseAnimateOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            commit_styles: false,
+            duration: 1000.0,
+            delay: 0.0,
+            iterations: 1.0,
+            easing: "linear".to_string(),
+            direction: web_sys::PlaybackDirection::Normal,
+            fill: web_sys::FillMode::Auto,
+        }
+    }
+}
+
+impl UseAnimateOptions {
+    #[cfg(not(feature = "ssr"))]
+    fn to_js(&self) -> web_sys::KeyframeAnimationOptions {
+        let options = web_sys::KeyframeAnimationOptions::new();
+        options.set_duration(&self.duration.into());
+        options.set_delay(self.delay);
+        options.set_iterations(self.iterations);
+        options.set_easing(&self.easing);
+        options.set_direction(self.direction);
+        options.set_fill(self.fill);
+        options
+    }
+}
+
+/// Return type of [`use_animate`].
+#[derive(Clone, Copy)]
+pub struct UseAnimateReturn {
+    /// State of the animation like `Running`, `Paused` or `Finished`.
+    pub play_state: Signal<web_sys::AnimationPlayState>,
+
+    /// Whether the animation is waiting for an asynchronous operation like starting to play.
+    pub pending: Signal<bool>,
+
+    /// Current time of the animation in milliseconds. `None` if the animation isn't active.
+    pub current_time: Signal<Option<f64>>,
+
+    /// Seeks the animation to a time in milliseconds.
+    pub set_current_time: WriteSignal<Option<f64>>,
+
+    /// Playback rate of the animation. `1.0` is normal speed, negative values play backwards.
+    pub playback_rate: Signal<f64>,
+
+    /// Changes the playback rate of the animation.
+    pub set_playback_rate: WriteSignal<f64>,
+
+    animation: StoredValue<Option<web_sys::Animation>, LocalStorage>,
+    sync: StoredValue<Box<dyn Fn()>, LocalStorage>,
+}
+
+impl UseAnimateReturn {
+    /// Starts or resumes the animation.
+    pub fn play(&self) {
+        self.call(|animation| {
+            let _ = animation.play();
+        });
+    }
+
+    /// Pauses the animation.
+    pub fn pause(&self) {
+        self.call(|animation| {
+            let _ = animation.pause();
+        });
+    }
+
+    /// Reverses the playback direction and plays the animation.
+    pub fn reverse(&self) {
+        self.call(|animation| {
+            let _ = animation.reverse();
+        });
+    }
+
+    /// Jumps to the end of the animation.
+    pub fn finish(&self) {
+        self.call(|animation| {
+            let _ = animation.finish();
+        });
+    }
+
+    /// Removes all effects of the animation and stops it.
+    pub fn cancel(&self) {
+        self.call(web_sys::Animation::cancel);
+    }
+
+    /// The underlying `Animation`. `None` if there is no target element.
+    pub fn animation(&self) -> Option<web_sys::Animation> {
+        self.animation.get_value()
+    }
+
+    fn call(&self, f: impl FnOnce(&web_sys::Animation)) {
+        if let Some(animation) = self.animation.get_value() {
+            f(&animation);
+            self.sync.with_value(|sync| sync());
+        }
+    }
+}
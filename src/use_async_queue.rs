@@ -0,0 +1,352 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Run async tasks one after another.
+///
+/// The tasks are run in order and the state and result of every task is kept in a signal. With
+/// the option `concurrency` several tasks run at the same time.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_async_queue)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_async_queue, AsyncQueueTask, UseAsyncQueueReturn};
+/// #
+/// # async fn upload(file: &str) -> Result<String, String> { Ok(file.to_string()) }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseAsyncQueueReturn {
+///     results,
+///     active_index,
+///     is_finished,
+///     ..
+/// } = use_async_queue(vec![
+///     AsyncQueueTask::new(|| upload("a.png")),
+///     AsyncQueueTask::new(|| upload("b.png")),
+///     AsyncQueueTask::new(|| upload("c.png")),
+/// ]);
+///
+/// view! {
+///     <Show when=move || !is_finished.get() fallback=|| "All done">
+///         "Uploading file " {move || active_index.get().map(|index| index + 1)}
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Every task is a function that returns a future with a `Result`. `results` contains the
+/// [`AsyncQueueTaskState`] of every task in the same order as the tasks. `active_index` is the
+/// index of the first task that hasn't settled yet and `None` when all tasks are done.
+///
+/// ### Failing Tasks
+///
+/// By default the remaining tasks are aborted as soon as one task fails. Set the option `interrupt`
+/// to `false` to keep running them. The queue can also be stopped by calling `abort`. Tasks that
+/// are running when the queue is aborted aren't cancelled but their results are dropped.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_async_queue_with_options, AsyncQueueTask, UseAsyncQueueOptions};
+/// #
+/// # async fn fetch_thumbnail(id: u32) -> Result<Vec<u8>, String> { Ok(vec![]) }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let thumbnails = use_async_queue_with_options(
+///     (1..=20)
+///         .map(|id| AsyncQueueTask::new(move || fetch_thumbnail(id)))
+///         .collect(),
+///     UseAsyncQueueOptions::default()
+///         .concurrency(4)
+///         .interrupt(false)
+///         .on_error(|err: &String| log!("Failed: {err}"))
+///         .on_finished(|| log!("Finished")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// `on_finished` is called once when all tasks have settled, also after the queue has been
+/// aborted.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the tasks aren't run. All of them stay `Pending` and `on_finished` isn't called.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_async_state`]
+pub fn use_async_queue<T, E>(
+    tasks: Vec<AsyncQueueTask<T, E>>,
+) -> UseAsyncQueueReturn<T, E, impl Fn() + Clone + Send + Sync>
+where
+    T: 'static,
+    E: 'static,
+{
+    use_async_queue_with_options(tasks, UseAsyncQueueOptions::default())
+}
+
+/// Version of [`use_async_queue`] that takes a `UseAsyncQueueOptions`. See [`use_async_queue`] for how to use.
+pub fn use_async_queue_with_options<T, E>(
+    tasks: Vec<AsyncQueueTask<T, E>>,
+    options: UseAsyncQueueOptions<E>,
+) -> UseAsyncQueueReturn<T, E, impl Fn() + Clone + Send + Sync>
+where
+    T: 'static,
+    E: 'static,
+{
+    let UseAsyncQueueOptions {
+        concurrency,
+        interrupt,
+        on_error,
+        on_finished,
+    } = options;
+
+    let (results, set_results) = signal_local(
+        (0..tasks.len())
+            .map(|_| AsyncQueueTaskState::Pending)
+            .collect::<Vec<_>>(),
+    );
+
+    let queue = Rc::new(QueueRunner {
+        tasks: RefCell::new(tasks.into_iter().enumerate().collect()),
+        running: Cell::new(0),
+        is_aborted: Cell::new(false),
+        is_finished: Cell::new(false),
+        set_results,
+        concurrency: concurrency.max(1),
+        interrupt,
+        on_error,
+        on_finished,
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        queue.run_next();
+    }
+
+    let active_index = Signal::derive(move || {
+        results.with(|results| {
+            results.iter().position(|state| {
+                matches!(
+                    state,
+                    AsyncQueueTaskState::Pending | AsyncQueueTaskState::Running
+                )
+            })
+        })
+    });
+
+    UseAsyncQueueReturn {
+        results: results.into(),
+        active_index,
+        is_finished: Signal::derive(move || active_index.get().is_none()),
+        abort: sendwrap_fn!(move || queue.abort()),
+    }
+}
+
+/// A task of [`use_async_queue`].
+#[allow(clippy::type_complexity)]
+pub struct AsyncQueueTask<T, E>(Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = Result<T, E>>>>>);
+
+impl<T, E> AsyncQueueTask<T, E> {
+    /// Creates a task from a function that returns a future.
+    pub fn new<F, Fut>(task: F) -> Self
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        Self(Box::new(move || Box::pin(task())))
+    }
+}
+
+/// State of a task of [`use_async_queue`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AsyncQueueTaskState<T, E> {
+    /// The task hasn't started yet.
+    Pending,
+
+    /// The task is running.
+    Running,
+
+    /// The task succeeded with this value.
+    Fulfilled(T),
+
+    /// The task failed with this error.
+    Rejected(E),
+
+    /// The task didn't run or its result was dropped because the queue was aborted.
+    Aborted,
+}
+
+/// Runs the tasks and keeps track of them.
+struct QueueRunner<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    tasks: RefCell<VecDeque<(usize, AsyncQueueTask<T, E>)>>,
+    running: Cell<usize>,
+    is_aborted: Cell<bool>,
+    is_finished: Cell<bool>,
+    set_results: WriteSignal<Vec<AsyncQueueTaskState<T, E>>, LocalStorage>,
+    concurrency: usize,
+    interrupt: bool,
+    on_error: Arc<dyn Fn(&E) + Send + Sync>,
+    on_finished: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl<T, E> QueueRunner<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    /// Starts tasks until `concurrency` tasks are running.
+    fn run_next(self: &Rc<Self>) {
+        while self.running.get() < self.concurrency && !self.is_aborted.get() {
+            let Some((index, AsyncQueueTask(task))) = self.tasks.borrow_mut().pop_front() else {
+                break;
+            };
+
+            self.running.set(self.running.get() + 1);
+            self.set_state(index, AsyncQueueTaskState::Running);
+
+            let future = task();
+            let runner = Rc::clone(self);
+
+            leptos::task::spawn_local(async move {
+                let result = future.await;
+                runner.settle(index, result);
+            });
+        }
+
+        if self.running.get() == 0
+            && self.tasks.borrow().is_empty()
+            && !self.is_finished.replace(true)
+        {
+            (self.on_finished)();
+        }
+    }
+
+    /// Stores the result of the task at `index` and continues with the next ones.
+    fn settle(self: &Rc<Self>, index: usize, result: Result<T, E>) {
+        self.running.set(self.running.get() - 1);
+
+        if self.is_aborted.get() {
+            self.set_state(index, AsyncQueueTaskState::Aborted);
+        } else {
+            match result {
+                Ok(value) => self.set_state(index, AsyncQueueTaskState::Fulfilled(value)),
+                Err(err) => {
+                    (self.on_error)(&err);
+                    self.set_state(index, AsyncQueueTaskState::Rejected(err));
+
+                    if self.interrupt {
+                        self.abort();
+                    }
+                }
+            }
+        }
+
+        self.run_next();
+    }
+
+    /// Aborts the tasks that haven't started yet. Running tasks are aborted when they settle.
+    fn abort(self: &Rc<Self>) {
+        if self.is_aborted.replace(true) {
+            return;
+        }
+
+        let aborted = self.tasks.borrow_mut().drain(..).collect::<Vec<_>>();
+        for (index, _) in aborted {
+            self.set_state(index, AsyncQueueTaskState::Aborted);
+        }
+
+        self.run_next();
+    }
+
+    fn set_state(&self, index: usize, state: AsyncQueueTaskState<T, E>) {
+        self.set_results
+            .try_update(|results| results[index] = state);
+    }
+}
+
+/// Options for [`use_async_queue_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAsyncQueueOptions<E>
+where
+    E: 'static,
+{
+    /// How many tasks run at the same time. Defaults to `1`.
+    concurrency: usize,
+
+    /// Whether the remaining tasks are aborted when a task fails. Defaults to `true`.
+    interrupt: bool,
+
+    /// Called with the error when a task fails.
+    #[builder(skip)]
+    on_error: Arc<dyn Fn(&E) + Send + Sync>,
+
+    /// Called when all tasks have settled.
+    on_finished: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl<E: 'static> UseAsyncQueueOptions<E> {
+    /// Called with the error when a task fails.
+    pub fn on_error<F>(self, handler: F) -> Self
+    where
+        F: Fn(&E) + Send + Sync + 'static,
+    {
+        Self {
+            on_error: Arc::new(handler),
+            ..self
+        }
+    }
+}
+
+impl<E: 'static> Default for UseAsyncQueueOptions<E> {
+    fn default() -> Self {
+        Self {
+            concurrency: 1,
+            interrupt: true,
+            on_error: Arc::new(|_| {}),
+            on_finished: Arc::new(|| {}),
+        }
+    }
+}
+
+/// Return type of [`use_async_queue`].
+pub struct UseAsyncQueueReturn<T, E, AbortFn>
+where
+    T: 'static,
+    E: 'static,
+    AbortFn: Fn() + Clone + Send + Sync,
+{
+    /// The states of the tasks in the same order as the tasks
+    pub results: Signal<Vec<AsyncQueueTaskState<T, E>>, LocalStorage>,
+
+    /// Index of the first task that hasn't settled yet. `None` when all tasks are done.
+    pub active_index: Signal<Option<usize>>,
+
+    /// Whether all tasks have settled
+    pub is_finished: Signal<bool>,
+
+    /// Aborts the tasks that haven't finished yet
+    pub abort: AbortFn,
+}
@@ -0,0 +1,297 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::cell::Cell;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Reactive state of an async function.
+///
+/// Runs the async function and keeps its result, its error and whether it's still running in
+/// signals. This works with any async work, not only HTTP requests.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_async_state)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_async_state, UseAsyncStateReturn};
+/// #
+/// # async fn compute_checksum() -> Result<u32, String> { Ok(42) }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseAsyncStateReturn {
+///     state,
+///     is_loading,
+///     error,
+///     ..
+/// } = use_async_state(|_: ()| compute_checksum(), 0);
+///
+/// view! {
+///     <Show when=move || !is_loading.get() fallback=|| "Computing...">
+///         <p>"Checksum: " {move || state.get()}</p>
+///     </Show>
+///     <p>{move || error.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// The async function returns a `Result`. `state` contains the initial value until the function
+/// succeeds for the first time and `is_ready` becomes `true` after that. An `Err` is put into
+/// `error`.
+///
+/// ### Executing Again
+///
+/// The function is run once right away with `A::default()` as its argument. Set the option
+/// `immediate` to `false` to only run it by calling `execute`. `execute` takes a delay in
+/// milliseconds and the argument that is passed to the function.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_async_state_with_options, UseAsyncStateOptions, UseAsyncStateReturn};
+/// #
+/// # async fn resize_image(width: u32) -> Result<String, ()> { Ok(format!("{width}px")) }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseAsyncStateReturn { state, execute, .. } = use_async_state_with_options(
+///     |width: u32| resize_image(width),
+///     String::new(),
+///     UseAsyncStateOptions::default()
+///         .immediate(false)
+///         .reset_on_execute(false)
+///         .on_error(|_: &()| leptos::logging::error!("Resizing failed")),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| execute(0.0, 800)>"Resize"</button>
+///     <p>{move || state.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// When `execute` is called before the previous run has finished, only the result of the latest
+/// run is used.
+///
+/// By default `state` is reset to the initial value on every execution. Set the option
+/// `reset_on_execute` to `false` to keep the previous value while loading.
+///
+/// ### Errors
+///
+/// Instead of throwing errors like in JavaScript, they are put into `error` and passed to the
+/// `on_error` callback. `on_success` is called with the value when the function succeeds.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the function isn't run. `state` always contains the initial value, `error` is
+/// always `None` and `is_ready` and `is_loading` are always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_lazy_component`]
+/// * [`fn@crate::use_memoize`]
+pub fn use_async_state<T, E, A, F, Fut>(
+    future_factory: F,
+    initial_state: T,
+) -> UseAsyncStateReturn<T, E, impl Fn(f64, A) + Clone + Send + Sync>
+where
+    T: Clone + 'static,
+    E: 'static,
+    A: Default + 'static,
+    F: Fn(A) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    use_async_state_with_options(
+        future_factory,
+        initial_state,
+        UseAsyncStateOptions::default(),
+    )
+}
+
+/// Version of [`use_async_state`] that takes a `UseAsyncStateOptions`. See [`use_async_state`] for how to use.
+pub fn use_async_state_with_options<T, E, A, F, Fut>(
+    future_factory: F,
+    initial_state: T,
+    options: UseAsyncStateOptions<T, E>,
+) -> UseAsyncStateReturn<T, E, impl Fn(f64, A) + Clone + Send + Sync>
+where
+    T: Clone + 'static,
+    E: 'static,
+    A: Default + 'static,
+    F: Fn(A) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let UseAsyncStateOptions {
+        immediate,
+        delay,
+        reset_on_execute,
+        on_error,
+        on_success,
+    } = options;
+
+    let (state, set_state) = signal_local(initial_state.clone());
+    let (error, set_error) = signal_local(None::<E>);
+    let (is_ready, set_ready) = signal(false);
+    let (is_loading, set_loading) = signal(false);
+
+    let future_factory = Rc::new(future_factory);
+
+    // only the result of the latest execution is used
+    let execution = Rc::new(Cell::new(0_u64));
+
+    let execute = sendwrap_fn!(move |delay: f64, args: A| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if reset_on_execute {
+                set_state.set(initial_state.clone());
+            }
+            set_error.set(None);
+            set_ready.set(false);
+            set_loading.set(true);
+
+            execution.set(execution.get() + 1);
+            let current_execution = execution.get();
+
+            let run = {
+                let future_factory = Rc::clone(&future_factory);
+                let execution = Rc::clone(&execution);
+                let on_error = Arc::clone(&on_error);
+                let on_success = Arc::clone(&on_success);
+
+                move || {
+                    let future = future_factory(args);
+
+                    leptos::task::spawn_local(async move {
+                        let result = future.await;
+
+                        if execution.get() != current_execution {
+                            return;
+                        }
+
+                        match result {
+                            Ok(value) => {
+                                on_success(&value);
+                                set_state.try_set(value);
+                                set_ready.try_set(true);
+                            }
+                            Err(err) => {
+                                on_error(&err);
+                                set_error.try_set(Some(err));
+                            }
+                        }
+
+                        set_loading.try_set(false);
+                    });
+                }
+            };
+
+            if delay > 0.0 {
+                set_timeout(run, std::time::Duration::from_millis(delay as u64));
+            } else {
+                run();
+            }
+        }
+    });
+
+    if immediate {
+        execute(delay, A::default());
+    }
+
+    UseAsyncStateReturn {
+        state: state.into(),
+        is_ready: is_ready.into(),
+        is_loading: is_loading.into(),
+        error: error.into(),
+        execute,
+    }
+}
+
+/// Options for [`use_async_state_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAsyncStateOptions<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    /// Whether the function is run right away with `A::default()` as argument. Defaults to `true`.
+    immediate: bool,
+
+    /// Delay in milliseconds of the first run if `immediate` is `true`. Defaults to `0`.
+    delay: f64,
+
+    /// Whether `state` is reset to the initial value on every execution. Defaults to `true`.
+    reset_on_execute: bool,
+
+    /// Called with the error when the function fails.
+    #[builder(skip)]
+    on_error: Arc<dyn Fn(&E) + Send + Sync>,
+
+    /// Called with the value when the function succeeds.
+    #[builder(skip)]
+    on_success: Arc<dyn Fn(&T) + Send + Sync>,
+}
+
+impl<T: 'static, E: 'static> UseAsyncStateOptions<T, E> {
+    /// Called with the error when the function fails.
+    pub fn on_error<F>(self, handler: F) -> Self
+    where
+        F: Fn(&E) + Send + Sync + 'static,
+    {
+        Self {
+            on_error: Arc::new(handler),
+            ..self
+        }
+    }
+
+    /// Called with the value when the function succeeds.
+    pub fn on_success<F>(self, handler: F) -> Self
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        Self {
+            on_success: Arc::new(handler),
+            ..self
+        }
+    }
+}
+
+impl<T: 'static, E: 'static> Default for UseAsyncStateOptions<T, E> {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            delay: 0.0,
+            reset_on_execute: true,
+            on_error: Arc::new(|_| {}),
+            on_success: Arc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_async_state`].
+pub struct UseAsyncStateReturn<T, E, ExecuteFn>
+where
+    T: 'static,
+    E: 'static,
+{
+    /// The value of the latest successful run or the initial value
+    pub state: Signal<T, LocalStorage>,
+
+    /// Whether the function has succeeded since the latest execution
+    pub is_ready: Signal<bool>,
+
+    /// Whether the function is currently running
+    pub is_loading: Signal<bool>,
+
+    /// The error of the latest run
+    pub error: Signal<Option<E>, LocalStorage>,
+
+    /// Runs the function with the given argument after a delay in milliseconds
+    pub execute: ExecuteFn,
+}
@@ -0,0 +1,277 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Reactive volume level of the audio in a media stream.
+///
+/// The audio is analyzed with an [`AnalyserNode`](https://developer.mozilla.org/en-US/docs/Web/API/AnalyserNode)
+/// on every animation frame. `level` is the smoothed loudness between `0.0` (silence) and `1.0`
+/// and `is_speaking` tells if the level is above a threshold, which is useful to show microphone
+/// activity in call UIs.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_audio_level)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{
+/// #     use_audio_level, use_user_media_with_options, UseAudioLevelReturn, UseUserMediaOptions,
+/// #     UseUserMediaReturn,
+/// # };
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, .. } = use_user_media_with_options(
+///     UseUserMediaOptions::default().audio(true).video(false),
+/// );
+///
+/// let UseAudioLevelReturn { level, is_speaking } =
+///     use_audio_level(Signal::derive_local(move || stream.get().and_then(Result::ok)));
+///
+/// view! {
+///     <div
+///         class:speaking=is_speaking
+///         style:width=move || format!("{}%", level.get() * 100.0)
+///     />
+/// }
+/// # }
+/// ```
+///
+/// The level is derived from the RMS of the signal in decibels and mapped from the range
+/// `min_decibels..max_decibels` to `0.0..1.0`. `is_speaking` becomes `true` as soon as the level
+/// reaches `threshold` and stays `true` until the level has been below it for `hangover`
+/// milliseconds so it doesn't flicker between words.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_audio_level_with_options, UseAudioLevelOptions, UseAudioLevelReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let stream = Signal::<Option<web_sys::MediaStream>, LocalStorage>::stored_local(None);
+/// let UseAudioLevelReturn { is_speaking, .. } = use_audio_level_with_options(
+///     stream,
+///     UseAudioLevelOptions::default().threshold(0.3).hangover(500.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The audio context is closed when the stream changes or the component is unmounted.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `level` is always `0.0` and `is_speaking` is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_user_media`]
+pub fn use_audio_level(
+    stream: impl Into<Signal<Option<web_sys::MediaStream>, LocalStorage>>,
+) -> UseAudioLevelReturn {
+    use_audio_level_with_options(stream, UseAudioLevelOptions::default())
+}
+
+/// Version of [`use_audio_level`] that takes a `UseAudioLevelOptions`. See [`use_audio_level`] for how to use.
+pub fn use_audio_level_with_options(
+    stream: impl Into<Signal<Option<web_sys::MediaStream>, LocalStorage>>,
+    options: UseAudioLevelOptions,
+) -> UseAudioLevelReturn {
+    let UseAudioLevelOptions {
+        smoothing,
+        threshold,
+        hangover,
+        min_decibels,
+        max_decibels,
+    } = options;
+
+    let stream = stream.into();
+
+    let (level, set_level) = signal(0.0);
+    let (is_speaking, set_speaking) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::utils::Pausable;
+        use crate::{sendwrap_fn, use_raf_fn_with_options, UseRafFnOptions};
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+
+        let analysis = Rc::new(RefCell::new(None::<AudioAnalysis>));
+        let last_loud = Rc::new(Cell::new(0.0_f64));
+
+        let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+            {
+                let analysis = Rc::clone(&analysis);
+
+                move |args| {
+                    let Some(rms) = analysis.borrow_mut().as_mut().map(AudioAnalysis::rms) else {
+                        return;
+                    };
+
+                    let decibels = 20.0 * rms.max(f64::MIN_POSITIVE).log10();
+                    let current =
+                        ((decibels - min_decibels) / (max_decibels - min_decibels)).clamp(0.0, 1.0);
+
+                    let smoothed = level.get_untracked() * smoothing + current * (1.0 - smoothing);
+                    set_level.set(smoothed);
+
+                    if smoothed >= threshold {
+                        last_loud.set(args.timestamp);
+
+                        if !is_speaking.get_untracked() {
+                            set_speaking.set(true);
+                        }
+                    } else if is_speaking.get_untracked()
+                        && args.timestamp - last_loud.get() > hangover
+                    {
+                        set_speaking.set(false);
+                    }
+                }
+            },
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        let cleanup = {
+            let analysis = Rc::clone(&analysis);
+
+            move || {
+                pause();
+
+                if let Some(analysis) = analysis.take() {
+                    analysis.close();
+                }
+            }
+        };
+
+        let _ = Effect::watch(
+            move || stream.get(),
+            {
+                let cleanup = cleanup.clone();
+
+                move |stream: &Option<web_sys::MediaStream>, _, _| {
+                    cleanup();
+                    set_level.set(0.0);
+                    set_speaking.set(false);
+
+                    let Some(stream) = stream else {
+                        return;
+                    };
+
+                    if stream.get_audio_tracks().length() == 0 {
+                        return;
+                    }
+
+                    if let Some(new_analysis) = AudioAnalysis::new(stream) {
+                        analysis.replace(Some(new_analysis));
+                        resume();
+                    }
+                }
+            },
+            true,
+        );
+
+        on_cleanup(sendwrap_fn!(move || cleanup()));
+    }
+
+    UseAudioLevelReturn {
+        level: level.into(),
+        is_speaking: is_speaking.into(),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+struct AudioAnalysis {
+    context: web_sys::AudioContext,
+    source: web_sys::MediaStreamAudioSourceNode,
+    analyser: web_sys::AnalyserNode,
+    samples: Vec<f32>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl AudioAnalysis {
+    fn new(stream: &web_sys::MediaStream) -> Option<Self> {
+        let context = web_sys::AudioContext::new().ok()?;
+        let source = context.create_media_stream_source(stream).ok()?;
+        let analyser = context.create_analyser().ok()?;
+
+        analyser.set_fft_size(2048);
+        source.connect_with_audio_node(&analyser).ok()?;
+
+        // contexts that are created without user interaction start suspended
+        let _ = context.resume();
+
+        Some(Self {
+            samples: vec![0.0; analyser.fft_size() as usize],
+            context,
+            source,
+            analyser,
+        })
+    }
+
+    /// Root mean square of the current samples.
+    fn rms(&mut self) -> f64 {
+        self.analyser.get_float_time_domain_data(&mut self.samples);
+
+        let sum = self
+            .samples
+            .iter()
+            .map(|sample| (*sample as f64).powi(2))
+            .sum::<f64>();
+
+        (sum / self.samples.len().max(1) as f64).sqrt()
+    }
+
+    fn close(self) {
+        let _ = self.source.disconnect();
+        let _ = self.context.close();
+    }
+}
+
+/// Options for [`use_audio_level_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAudioLevelOptions {
+    /// How much of the previous level is kept every frame, between `0.0` (no smoothing) and
+    /// `1.0`. Defaults to `0.8`.
+    smoothing: f64,
+
+    /// Level from which on `is_speaking` is `true`. Defaults to `0.2`.
+    threshold: f64,
+
+    /// Milliseconds that `is_speaking` stays `true` after the level has dropped below
+    /// `threshold`. Defaults to `300.0`.
+    hangover: f64,
+
+    /// Loudness in decibels that corresponds to a level of `0.0`. Defaults to `-70.0`.
+    min_decibels: f64,
+
+    /// Loudness in decibels that corresponds to a level of `1.0`. Defaults to `-10.0`.
+    max_decibels: f64,
+}
+
+impl Default for UseAudioLevelOptions {
+    fn default() -> Self {
+        Self {
+            smoothing: 0.8,
+            threshold: 0.2,
+            hangover: 300.0,
+            min_decibels: -70.0,
+            max_decibels: -10.0,
+        }
+    }
+}
+
+/// Return type of [`use_audio_level`].
+#[derive(Clone, Copy)]
+pub struct UseAudioLevelReturn {
+    /// Smoothed volume level between `0.0` and `1.0`.
+    pub level: Signal<f64>,
+
+    /// Whether the level is above the threshold, including the hangover time.
+    pub is_speaking: Signal<bool>,
+}
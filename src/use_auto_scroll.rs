@@ -0,0 +1,330 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{Direction, IntoElementMaybeSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Continuously scrolls the content of an element like a marquee or a news ticker.
+///
+/// `target` is a scroll container, usually with `overflow: hidden`, that is scrolled by `speed`
+/// pixels per second on every animation frame. When the end is reached it starts over.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_auto_scroll)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::use_auto_scroll;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let auto_scroll = use_auto_scroll(el, 50.0);
+///
+/// view! {
+///     <div node_ref=el style="overflow: hidden; white-space: nowrap">
+///         "Breaking news: ..."
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// Scrolling pauses while the pointer is over the element or an element inside of it has focus
+/// and it doesn't start at all if the user prefers reduced motion. It can also be paused and
+/// resumed with `pause` and `resume`. `is_running` tells if it's actually scrolling.
+///
+/// ### Seamless loop
+///
+/// By default the element jumps back to the start when the end is reached. For an endless ticker
+/// render the content twice and set the option `seamless`. Then the element jumps back by the
+/// size of the content as soon as the second copy is where the first one started, which isn't
+/// visible.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_auto_scroll_with_options, UseAutoScrollOptions};
+/// # use leptos_use::core::Direction;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let auto_scroll = use_auto_scroll_with_options(
+///     el,
+///     80.0,
+///     UseAutoScrollOptions::default()
+///         .seamless(true)
+///         .direction(Direction::Right),
+/// );
+///
+/// let items = || view! { <span>"Item 1"</span> <span>"Item 2"</span> <span>"Item 3"</span> };
+///
+/// view! {
+///     <div node_ref=el style="display: flex; overflow: hidden; white-space: nowrap">
+///         {items()}
+///         {items()}
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// `direction` is the direction in which the content moves. `Direction::Top` and
+/// `Direction::Bottom` scroll vertically.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is scrolled and `is_running` is always `false`.
+pub fn use_auto_scroll<El, M>(target: El, speed: impl Into<Signal<f64>>) -> UseAutoScrollReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_auto_scroll_with_options(target, speed, UseAutoScrollOptions::default())
+}
+
+/// Version of [`use_auto_scroll`] that takes a `UseAutoScrollOptions`. See [`use_auto_scroll`] for how to use.
+pub fn use_auto_scroll_with_options<El, M>(
+    target: El,
+    speed: impl Into<Signal<f64>>,
+    options: UseAutoScrollOptions,
+) -> UseAutoScrollReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseAutoScrollOptions {
+        direction,
+        seamless,
+        pause_on_hover,
+        pause_on_focus,
+        respect_reduced_motion,
+    } = options;
+
+    let target = target.into_element_maybe_signal();
+    let speed = speed.into();
+
+    let (is_active, set_active) = signal(true);
+
+    #[cfg(feature = "ssr")]
+    let is_running = Signal::stored(false);
+
+    #[cfg(not(feature = "ssr"))]
+    let is_running = {
+        use crate::utils::Pausable;
+        use crate::{
+            use_element_hover, use_event_listener, use_prefers_reduced_motion,
+            use_raf_fn_with_options, UseRafFnOptions,
+        };
+
+        let hovered = if pause_on_hover {
+            use_element_hover(target)
+        } else {
+            Signal::stored(false)
+        };
+
+        let (focused, set_focused) = signal(false);
+
+        if pause_on_focus {
+            let _ = use_event_listener(target, leptos::ev::focusin, move |_| set_focused.set(true));
+            let _ = use_event_listener(target, leptos::ev::focusout, move |event| {
+                // focus moves to another element inside the target
+                let stays_inside = target.get_untracked().is_some_and(|target| {
+                    event
+                        .related_target()
+                        .and_then(|related| {
+                            wasm_bindgen::JsCast::dyn_into::<web_sys::Node>(related).ok()
+                        })
+                        .is_some_and(|related| target.contains(Some(&related)))
+                });
+
+                if !stays_inside {
+                    set_focused.set(false);
+                }
+            });
+        }
+
+        let reduced_motion = if respect_reduced_motion {
+            use_prefers_reduced_motion()
+        } else {
+            Signal::stored(false)
+        };
+
+        let is_running = Signal::derive(move || {
+            is_active.get() && !hovered.get() && !focused.get() && !reduced_motion.get()
+        });
+
+        let position = StoredValue::new(None::<f64>);
+        let last_timestamp = StoredValue::new(None::<f64>);
+
+        let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+            move |args| {
+                let Some(el) = target.get_untracked() else {
+                    return;
+                };
+
+                let Some(previous_timestamp) = last_timestamp.get_value() else {
+                    last_timestamp.set_value(Some(args.timestamp));
+                    return;
+                };
+                last_timestamp.set_value(Some(args.timestamp));
+
+                let distance =
+                    speed.get_untracked() * (args.timestamp - previous_timestamp) / 1000.0;
+                let current = position
+                    .get_value()
+                    .unwrap_or_else(|| scroll_position(&el, direction));
+
+                let next = scroll_step(&el, current, distance, direction, seamless);
+                position.set_value(Some(next));
+
+                match direction {
+                    Direction::Left | Direction::Right => el.set_scroll_left(next as i32),
+                    Direction::Top | Direction::Bottom => el.set_scroll_top(next as i32),
+                }
+            },
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        let _ = Effect::watch(
+            move || is_running.get(),
+            move |is_running, _, _| {
+                if *is_running {
+                    // don't count the time while paused
+                    last_timestamp.set_value(None);
+                    resume();
+                } else {
+                    pause();
+                }
+            },
+            true,
+        );
+
+        is_running
+    };
+
+    UseAutoScrollReturn {
+        is_running,
+        is_active: is_active.into(),
+        set_active,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn scroll_position(el: &web_sys::Element, direction: Direction) -> f64 {
+    match direction {
+        Direction::Left | Direction::Right => el.scroll_left() as f64,
+        Direction::Top | Direction::Bottom => el.scroll_top() as f64,
+    }
+}
+
+/// Moves `position` by `distance` in `direction` and wraps around at the ends.
+#[cfg(not(feature = "ssr"))]
+fn scroll_step(
+    el: &web_sys::Element,
+    position: f64,
+    distance: f64,
+    direction: Direction,
+    seamless: bool,
+) -> f64 {
+    let (scroll_size, client_size) = match direction {
+        Direction::Left | Direction::Right => (el.scroll_width(), el.client_width()),
+        Direction::Top | Direction::Bottom => (el.scroll_height(), el.client_height()),
+    };
+
+    // with duplicated content the second half looks exactly like the first one
+    let end = if seamless {
+        scroll_size as f64 / 2.0
+    } else {
+        (scroll_size - client_size) as f64
+    };
+
+    if end <= 0.0 {
+        return 0.0;
+    }
+
+    match direction {
+        // the content moves to the start so the scroll position increases
+        Direction::Left | Direction::Top => {
+            let position = position + distance;
+
+            if position < end {
+                position
+            } else if seamless {
+                position - end
+            } else {
+                0.0
+            }
+        }
+        Direction::Right | Direction::Bottom => {
+            let position = position - distance;
+
+            if position > 0.0 {
+                position
+            } else if seamless {
+                position + end
+            } else {
+                end
+            }
+        }
+    }
+}
+
+/// Options for [`use_auto_scroll_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAutoScrollOptions {
+    /// Direction in which the content moves. Defaults to `Direction::Left`.
+    direction: Direction,
+
+    /// Set to `true` if the content is rendered twice to loop without a visible jump.
+    /// Defaults to `false`.
+    seamless: bool,
+
+    /// Whether to pause while the pointer is over the element. Defaults to `true`.
+    pause_on_hover: bool,
+
+    /// Whether to pause while an element inside of the element has focus. Defaults to `true`.
+    pause_on_focus: bool,
+
+    /// Whether to not scroll if the user prefers reduced motion. Defaults to `true`.
+    respect_reduced_motion: bool,
+}
+
+impl Default for UseAutoScrollOptions {
+    fn default() -> Self {
+        Self {
+            direction: Direction::Left,
+            seamless: false,
+            pause_on_hover: true,
+            pause_on_focus: true,
+            respect_reduced_motion: true,
+        }
+    }
+}
+
+/// Return type of [`use_auto_scroll`].
+#[derive(Clone, Copy)]
+pub struct UseAutoScrollReturn {
+    /// Whether the element is currently being scrolled.
+    pub is_running: Signal<bool>,
+
+    /// `false` after `pause` has been called until `resume` is called.
+    pub is_active: Signal<bool>,
+
+    set_active: WriteSignal<bool>,
+}
+
+impl UseAutoScrollReturn {
+    /// Pauses scrolling.
+    pub fn pause(&self) {
+        self.set_active.set(false);
+    }
+
+    /// Resumes scrolling after `pause`.
+    pub fn resume(&self) {
+        self.set_active.set(true);
+    }
+}
@@ -0,0 +1,234 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Plays a muted video only while it's visible.
+///
+/// The video starts playing when enough of it is in the viewport and is paused again when it's
+/// scrolled out of view. This saves battery and bandwidth compared to the `autoplay` attribute
+/// which keeps playing videos that nobody sees.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_autoplay_when_visible)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_autoplay_when_visible, UseAutoplayWhenVisibleReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = NodeRef::<Video>::new();
+///
+/// let UseAutoplayWhenVisibleReturn { is_playing, .. } = use_autoplay_when_visible(video);
+///
+/// view! {
+///     <video node_ref=video src="/videos/teaser.mp4" loop playsinline />
+/// }
+/// # }
+/// ```
+///
+/// The video is muted before it's played because browsers only allow autoplay of muted videos.
+/// Set the option `muted` to `false` to leave that to the video element.
+///
+/// By default the video is played when at least half of it is visible. Change that with the option
+/// `threshold` which is a ratio between `0.0` and `1.0`.
+///
+/// ### User Preferences
+///
+/// The video isn't started automatically if the user prefers reduced motion or reduced data usage.
+/// The latter is the case when the media query `(prefers-reduced-data: reduce)` matches or the
+/// browser's data saver is on. The options `respect_reduced_motion` and `respect_reduced_data` turn
+/// these checks off. `is_allowed` tells whether autoplay is currently allowed.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_autoplay_when_visible_with_options, UseAutoplayWhenVisibleOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let video = NodeRef::<Video>::new();
+/// let autoplay = use_autoplay_when_visible_with_options(
+///     video,
+///     UseAutoplayWhenVisibleOptions::default()
+///         .threshold(0.8)
+///         .respect_reduced_data(false),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Users can still start the video themselves if autoplay isn't allowed. It's paused when it's
+/// scrolled out of view in any case.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is played and `is_visible` and `is_playing` are always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_element_visibility`]
+/// * [`fn@crate::use_prefers_reduced_motion`]
+pub fn use_autoplay_when_visible<El, M>(video: El) -> UseAutoplayWhenVisibleReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_autoplay_when_visible_with_options(video, UseAutoplayWhenVisibleOptions::default())
+}
+
+/// Version of [`use_autoplay_when_visible`] that takes a `UseAutoplayWhenVisibleOptions`. See [`use_autoplay_when_visible`] for how to use.
+pub fn use_autoplay_when_visible_with_options<El, M>(
+    video: El,
+    options: UseAutoplayWhenVisibleOptions,
+) -> UseAutoplayWhenVisibleReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseAutoplayWhenVisibleOptions {
+        threshold,
+        muted,
+        respect_reduced_motion,
+        respect_reduced_data,
+    } = options;
+
+    let (is_visible, set_visible) = signal(false);
+    let (is_playing, set_playing) = signal(false);
+
+    let prefers_reduced_motion = crate::use_prefers_reduced_motion();
+    let prefers_reduced_data = crate::use_media_query("(prefers-reduced-data: reduce)");
+
+    let is_allowed = Signal::derive(move || {
+        !(respect_reduced_motion && prefers_reduced_motion.get()
+            || respect_reduced_data && (prefers_reduced_data.get() || is_data_saver_on()))
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_event_listener, use_intersection_observer_with_options,
+            UseIntersectionObserverOptions,
+        };
+        use leptos::ev::{pause, play};
+        use wasm_bindgen::JsCast;
+
+        let video = video.into_element_maybe_signal();
+
+        use_intersection_observer_with_options(
+            video,
+            move |entries, _| {
+                if let Some(entry) = entries.last() {
+                    set_visible
+                        .set(entry.is_intersecting() && entry.intersection_ratio() >= threshold);
+                }
+            },
+            UseIntersectionObserverOptions::default().thresholds(vec![0.0, threshold]),
+        );
+
+        let _ = use_event_listener(video, play, move |_| set_playing.set(true));
+        let _ = use_event_listener(video, pause, move |_| set_playing.set(false));
+
+        let _ = Effect::watch(
+            move || (video.get(), is_visible.get(), is_allowed.get()),
+            move |(video, is_visible, is_allowed), _, _| {
+                let Some(media) = video
+                    .as_ref()
+                    .and_then(|video| video.dyn_ref::<web_sys::HtmlMediaElement>())
+                else {
+                    return;
+                };
+
+                if *is_visible && *is_allowed {
+                    if media.paused() {
+                        if muted {
+                            media.set_muted(true);
+                        }
+
+                        if let Ok(promise) = media.play() {
+                            // playing can be rejected, for example when it's paused right away
+                            leptos::task::spawn_local(async move {
+                                let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+                            });
+                        }
+                    }
+                } else if !*is_visible && !media.paused() {
+                    let _ = media.pause();
+                }
+            },
+            true,
+        );
+    }
+
+    UseAutoplayWhenVisibleReturn {
+        is_visible: is_visible.into(),
+        is_playing: is_playing.into(),
+        is_allowed,
+    }
+}
+
+/// Whether the browser's data saver is on according to `navigator.connection.saveData`.
+fn is_data_saver_on() -> bool {
+    #[cfg(feature = "ssr")]
+    {
+        false
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        js_sys::Reflect::get(&window().navigator(), &"connection".into())
+            .ok()
+            .filter(|connection| connection.is_object())
+            .and_then(|connection| js_sys::Reflect::get(&connection, &"saveData".into()).ok())
+            .and_then(|save_data| save_data.as_bool())
+            .unwrap_or(false)
+    }
+}
+
+/// Options for [`use_autoplay_when_visible_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAutoplayWhenVisibleOptions {
+    /// Ratio of the video that has to be visible for it to play. Defaults to `0.5`.
+    threshold: f64,
+
+    /// Whether the video is muted before it's played. Defaults to `true`.
+    muted: bool,
+
+    /// Whether the video isn't played automatically if the user prefers reduced motion.
+    /// Defaults to `true`.
+    respect_reduced_motion: bool,
+
+    /// Whether the video isn't played automatically if the user prefers reduced data usage.
+    /// Defaults to `true`.
+    respect_reduced_data: bool,
+}
+
+impl Default for UseAutoplayWhenVisibleOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.5,
+            muted: true,
+            respect_reduced_motion: true,
+            respect_reduced_data: true,
+        }
+    }
+}
+
+/// Return type of [`use_autoplay_when_visible`].
+#[derive(Clone, Copy)]
+pub struct UseAutoplayWhenVisibleReturn {
+    /// Whether enough of the video is visible
+    pub is_visible: Signal<bool>,
+
+    /// Whether the video is playing
+    pub is_playing: Signal<bool>,
+
+    /// Whether the user's preferences allow autoplay
+    pub is_allowed: Signal<bool>,
+}
@@ -0,0 +1,148 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::use_event_listener;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::JsValue;
+
+/// Reactive browser location.
+///
+/// Tracks `window.location` and `window.history`. They are updated when the browser navigates
+/// back and forth or the hash changes (`popstate` and `hashchange` events). This is meant for apps
+/// that don't use a router but still need to know the URL.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_browser_location)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_browser_location, UseBrowserLocationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBrowserLocationReturn {
+///     href,
+///     pathname,
+///     search,
+///     hash,
+///     origin,
+///     state,
+///     length,
+///     ..
+/// } = use_browser_location();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Changing the URL with `history.pushState()` or `history.replaceState()` doesn't emit any event.
+/// Call `refresh` afterward to update the signals.
+///
+/// ## Server-Side Rendering
+///
+/// On the server all strings are empty, `state` is `None` and `length` is `0`.
+pub fn use_browser_location() -> UseBrowserLocationReturn {
+    #[cfg(feature = "ssr")]
+    let initial_location = BrowserLocation::default();
+
+    #[cfg(not(feature = "ssr"))]
+    let initial_location = BrowserLocation::read();
+
+    let (location, set_location) = signal(initial_location);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = use_event_listener(window(), leptos::ev::popstate, move |_| {
+            set_location.set(BrowserLocation::read())
+        });
+        let _ = use_event_listener(window(), leptos::ev::hashchange, move |_| {
+            set_location.set(BrowserLocation::read())
+        });
+    }
+
+    UseBrowserLocationReturn {
+        href: Signal::derive(move || location.with(|location| location.href.clone())),
+        pathname: Signal::derive(move || location.with(|location| location.pathname.clone())),
+        search: Signal::derive(move || location.with(|location| location.search.clone())),
+        hash: Signal::derive(move || location.with(|location| location.hash.clone())),
+        origin: Signal::derive(move || location.with(|location| location.origin.clone())),
+        state: Signal::derive(move || location.with(|location| location.state.clone())),
+        length: Signal::derive(move || location.with(|location| location.length)),
+        set_location,
+    }
+}
+
+#[derive(Clone, Default)]
+struct BrowserLocation {
+    href: String,
+    pathname: String,
+    search: String,
+    hash: String,
+    origin: String,
+    state: Option<SendWrapper<JsValue>>,
+    length: usize,
+}
+
+impl BrowserLocation {
+    #[cfg(not(feature = "ssr"))]
+    fn read() -> Self {
+        let location = window().location();
+        let history = window().history().ok();
+
+        Self {
+            href: location.href().unwrap_or_default(),
+            pathname: location.pathname().unwrap_or_default(),
+            search: location.search().unwrap_or_default(),
+            hash: location.hash().unwrap_or_default(),
+            origin: location.origin().unwrap_or_default(),
+            state: history
+                .as_ref()
+                .and_then(|history| history.state().ok())
+                .filter(|state| !state.is_null() && !state.is_undefined())
+                .map(SendWrapper::new),
+            length: history
+                .and_then(|history| history.length().ok())
+                .unwrap_or_default() as usize,
+        }
+    }
+}
+
+/// Return type of [`use_browser_location`].
+#[derive(Clone, Copy)]
+pub struct UseBrowserLocationReturn {
+    /// The whole URL
+    pub href: Signal<String>,
+
+    /// The path of the URL starting with `/`
+    pub pathname: Signal<String>,
+
+    /// The query string of the URL starting with `?` or empty
+    pub search: Signal<String>,
+
+    /// The fragment of the URL starting with `#` or empty
+    pub hash: Signal<String>,
+
+    /// The scheme, host and port of the URL
+    pub origin: Signal<String>,
+
+    /// The state of the current history entry or `None` if there is none
+    pub state: Signal<Option<SendWrapper<JsValue>>>,
+
+    /// The number of entries in the session history
+    pub length: Signal<usize>,
+
+    set_location: WriteSignal<BrowserLocation>,
+}
+
+impl UseBrowserLocationReturn {
+    /// Reads the location again. Call this after changing the URL with the History API.
+    pub fn refresh(&self) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            self.set_location.set(BrowserLocation::read());
+        }
+    }
+}
@@ -0,0 +1,339 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Headless carousel.
+///
+/// The slides are the children of `container` which is a horizontal scroll container with
+/// [CSS scroll snapping](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_scroll_snap).
+/// Because the browser does the scrolling, swiping with touch, trackpad or scroll wheel works out
+/// of the box. This tracks the current slide, scrolls to other slides and optionally advances
+/// the slides automatically.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_carousel)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_carousel, UseCarouselReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let container = NodeRef::<Div>::new();
+///
+/// let carousel = use_carousel(container);
+/// let UseCarouselReturn { index, count, .. } = carousel;
+///
+/// view! {
+///     <div
+///         node_ref=container
+///         style="display: flex; overflow-x: auto; scroll-snap-type: x mandatory"
+///     >
+///         <img src="1.jpg" style="flex: 0 0 100%; scroll-snap-align: start" />
+///         <img src="2.jpg" style="flex: 0 0 100%; scroll-snap-align: start" />
+///         <img src="3.jpg" style="flex: 0 0 100%; scroll-snap-align: start" />
+///     </div>
+///     <button on:click=move |_| carousel.prev()>"Previous"</button>
+///     {move || format!("{} / {}", index.get() + 1, count.get())}
+///     <button on:click=move |_| carousel.next()>"Next"</button>
+/// }
+/// # }
+/// ```
+///
+/// The slides should be aligned at the start (`scroll-snap-align: start`). `index` is the slide
+/// closest to the start of the container or the last slide if the container is scrolled to the
+/// end.
+///
+/// ### Autoplay
+///
+/// With the option `autoplay` the carousel advances every `autoplay` milliseconds. Autoplay
+/// pauses while the pointer is over the container or the page is hidden and can be paused and
+/// resumed with `pause` and `play`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_carousel_with_options, UseCarouselOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let container = NodeRef::<Div>::new();
+/// #
+/// let carousel = use_carousel_with_options(
+///     container,
+///     UseCarouselOptions::default().autoplay(5000_u64),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// By default `next` goes from the last slide to the first one and `prev` the other way around.
+/// Set `loop_around` to `false` to stop at the ends.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `count` and `index` are `0` and all methods do nothing.
+pub fn use_carousel<El, M>(container: El) -> UseCarouselReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_carousel_with_options(container, UseCarouselOptions::default())
+}
+
+/// Version of [`use_carousel`] that takes a `UseCarouselOptions`. See [`use_carousel`] for how to use.
+pub fn use_carousel_with_options<El, M>(
+    container: El,
+    options: UseCarouselOptions,
+) -> UseCarouselReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseCarouselOptions {
+        autoplay,
+        loop_around,
+        smooth,
+        pause_on_hover,
+        pause_when_hidden,
+    } = options;
+
+    let container = container.into_element_maybe_signal();
+
+    let (index, set_index) = signal(0_usize);
+    let (count, set_count) = signal(0_usize);
+    let (is_playing, set_playing) = signal(autoplay.is_some());
+
+    let carousel = UseCarouselReturn {
+        index: index.into(),
+        count: count.into(),
+        is_playing: is_playing.into(),
+        set_index,
+        set_playing,
+        container,
+        has_autoplay: autoplay.is_some(),
+        loop_around,
+        smooth,
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_document_visibility, use_element_hover, use_event_listener_with_options,
+            use_interval_fn, use_mutation_observer_with_options, use_resize_observer,
+            UseEventListenerOptions, UseMutationObserverOptions,
+        };
+
+        let update = move || {
+            let Some(container) = container.get_untracked() else {
+                return;
+            };
+
+            let offsets = slide_offsets(&container);
+            let new_index = current_index(&container, &offsets);
+
+            if count.get_untracked() != offsets.len() {
+                set_count.set(offsets.len());
+            }
+            if index.get_untracked() != new_index {
+                set_index.set(new_index);
+            }
+        };
+
+        let _ = use_event_listener_with_options(
+            container,
+            leptos::ev::scroll,
+            move |_| update(),
+            UseEventListenerOptions::default().passive(true),
+        );
+
+        use_resize_observer(container, move |_, _| update());
+
+        use_mutation_observer_with_options(
+            container,
+            move |_, _| update(),
+            UseMutationObserverOptions::default().child_list(true),
+        );
+
+        if let Some(autoplay) = autoplay {
+            let hovered = if pause_on_hover {
+                use_element_hover(container)
+            } else {
+                Signal::stored(false)
+            };
+
+            let visibility = use_document_visibility();
+
+            let _ = use_interval_fn(
+                move || {
+                    let hidden = pause_when_hidden
+                        && visibility.get_untracked() == web_sys::VisibilityState::Hidden;
+
+                    if is_playing.get_untracked() && !hovered.get_untracked() && !hidden {
+                        carousel.next();
+                    }
+                },
+                autoplay,
+            );
+        }
+    }
+
+    carousel
+}
+
+/// Positions of the slides relative to the scroll start of `container`.
+#[cfg(not(feature = "ssr"))]
+fn slide_offsets(container: &web_sys::Element) -> Vec<f64> {
+    let container_left = container.get_bounding_client_rect().left();
+    let scroll_left = container.scroll_left() as f64;
+    let children = container.children();
+
+    (0..children.length())
+        .filter_map(|index| children.item(index))
+        .map(|child| child.get_bounding_client_rect().left() - container_left + scroll_left)
+        .collect()
+}
+
+/// The slide closest to the start or the last slide if scrolled to the end.
+#[cfg(not(feature = "ssr"))]
+fn current_index(container: &web_sys::Element, offsets: &[f64]) -> usize {
+    let scroll_left = container.scroll_left() as f64;
+    let max_scroll_left = (container.scroll_width() - container.client_width()) as f64;
+
+    if !offsets.is_empty() && max_scroll_left > 0.0 && scroll_left >= max_scroll_left - 1.0 {
+        return offsets.len() - 1;
+    }
+
+    offsets
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            (*a - scroll_left)
+                .abs()
+                .total_cmp(&(*b - scroll_left).abs())
+        })
+        .map(|(index, _)| index)
+        .unwrap_or_default()
+}
+
+/// Options for [`use_carousel_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseCarouselOptions {
+    /// If set, the carousel advances to the next slide every that many milliseconds.
+    /// Defaults to `None`.
+    #[builder(into)]
+    autoplay: Option<u64>,
+
+    /// Whether `next` on the last slide goes to the first one and `prev` on the first slide
+    /// goes to the last one. Defaults to `true`.
+    loop_around: bool,
+
+    /// Whether to scroll smoothly to the slides. Defaults to `true`.
+    smooth: bool,
+
+    /// Whether autoplay pauses while the pointer is over the container. Defaults to `true`.
+    pause_on_hover: bool,
+
+    /// Whether autoplay pauses while the page is hidden. Defaults to `true`.
+    pause_when_hidden: bool,
+}
+
+impl Default for UseCarouselOptions {
+    fn default() -> Self {
+        Self {
+            autoplay: None,
+            loop_around: true,
+            smooth: true,
+            pause_on_hover: true,
+            pause_when_hidden: true,
+        }
+    }
+}
+
+/// Return type of [`use_carousel`].
+#[derive(Clone, Copy)]
+pub struct UseCarouselReturn {
+    /// Index of the current slide
+    pub index: Signal<usize>,
+
+    /// Number of slides
+    pub count: Signal<usize>,
+
+    /// Whether autoplay is active. Always `false` without the option `autoplay`.
+    pub is_playing: Signal<bool>,
+
+    set_index: WriteSignal<usize>,
+    set_playing: WriteSignal<bool>,
+    container: ElementMaybeSignal<web_sys::Element>,
+    has_autoplay: bool,
+    loop_around: bool,
+    smooth: bool,
+}
+
+impl UseCarouselReturn {
+    /// Scrolls to the slide at `index`. Does nothing if there is no such slide.
+    pub fn go_to(&self, index: usize) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(container) = self.container.get_untracked() else {
+                return;
+            };
+
+            let Some(offset) = slide_offsets(&container).get(index).copied() else {
+                return;
+            };
+
+            let options = web_sys::ScrollToOptions::new();
+            options.set_left(offset);
+            options.set_behavior(if self.smooth {
+                web_sys::ScrollBehavior::Smooth
+            } else {
+                web_sys::ScrollBehavior::Auto
+            });
+            container.scroll_to_with_scroll_to_options(&options);
+
+            self.set_index.set(index);
+        }
+    }
+
+    /// Scrolls to the next slide.
+    pub fn next(&self) {
+        let index = self.index.get_untracked();
+        let count = self.count.get_untracked();
+
+        if index + 1 < count {
+            self.go_to(index + 1);
+        } else if self.loop_around {
+            self.go_to(0);
+        }
+    }
+
+    /// Scrolls to the previous slide.
+    pub fn prev(&self) {
+        let index = self.index.get_untracked();
+        let count = self.count.get_untracked();
+
+        if index > 0 {
+            self.go_to(index - 1);
+        } else if self.loop_around && count > 0 {
+            self.go_to(count - 1);
+        }
+    }
+
+    /// Resumes autoplay. Does nothing without the option `autoplay`.
+    pub fn play(&self) {
+        if self.has_autoplay {
+            self.set_playing.set(true);
+        }
+    }
+
+    /// Pauses autoplay.
+    pub fn pause(&self) {
+        self.set_playing.set(false);
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{js, js_fut, sendwrap_fn, use_event_listener, use_supported, UseTimeoutFnReturn};
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{
+    js, js_fut, sendwrap_fn, use_event_listener, use_permission, use_supported, PermissionState,
+    UseTimeoutFnReturn,
+};
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::{copy, cut};
 use leptos::prelude::*;
@@ -24,7 +29,7 @@ use leptos::reactive::wrappers::read::Signal;
 /// #
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
-/// let UseClipboardReturn { is_supported, text, copied, copy } = use_clipboard();
+/// let UseClipboardReturn { is_supported, text, copied, copy, .. } = use_clipboard();
 ///
 /// view! {
 ///     <Show
@@ -44,6 +49,36 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// ### Fallback Strategies
+///
+/// Some browsers (especially in managed enterprise environments) block the async Clipboard API.
+/// When copying, the strategies in `strategies` are tried in order until one succeeds. The
+/// async API is skipped right away if the `clipboard-write` permission has been denied.
+/// The returned signal `strategy` tells you which one was used for the last successful copy.
+///
+/// `ClipboardStrategy::ExecCommand` only works while handling a user gesture like a click, so call
+/// `copy` directly in the event handler. The fallbacks are run synchronously unless the async API
+/// has been tried before and failed. In that case they run once its promise has been rejected.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_clipboard_with_options, ClipboardStrategy, UseClipboardOptions, UseClipboardReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseClipboardReturn { copy, strategy, .. } = use_clipboard_with_options(
+///     UseClipboardOptions::default().strategies(vec![
+///         ClipboardStrategy::AsyncApi,
+///         ClipboardStrategy::ExecCommand,
+///         // as a last resort show a prompt with the text selected so the user can copy it
+///         ClipboardStrategy::Prompt,
+///     ]),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `copy` is a sendwrapped function. It can
@@ -51,7 +86,7 @@ use leptos::reactive::wrappers::read::Signal;
 ///
 /// ## Server-Side Rendering
 ///
-/// On the server the returnd `text` signal will always be `None` and `copy` is a no-op.
+/// On the server the returnd `text` and `strategy` signals will always be `None` and `copy` is a no-op.
 pub fn use_clipboard() -> UseClipboardReturn<impl Fn(&str) + Clone + Send + Sync> {
     use_clipboard_with_options(UseClipboardOptions::default())
 }
@@ -63,6 +98,8 @@ pub fn use_clipboard_with_options(
     let UseClipboardOptions {
         copied_reset_delay,
         read,
+        strategies,
+        prompt_message,
     } = options;
 
     let is_supported = use_supported(|| {
@@ -70,8 +107,11 @@ pub fn use_clipboard_with_options(
             .navigator())
     });
 
+    let permission_write = use_permission("clipboard-write");
+
     let (text, set_text) = signal(None);
     let (copied, set_copied) = signal(false);
+    let (strategy, set_strategy) = signal(None::<ClipboardStrategy>);
 
     let UseTimeoutFnReturn { start, .. } = crate::use_timeout_fn::use_timeout_fn(
         move |_: ()| {
@@ -100,18 +140,59 @@ pub fn use_clipboard_with_options(
         let start = start.clone();
 
         sendwrap_fn!(move |value: &str| {
-            if is_supported.get() {
-                let start = start.clone();
+            #[cfg(not(feature = "ssr"))]
+            {
                 let value = value.to_owned();
 
-                leptos::task::spawn_local(async move {
-                    let clipboard = window().navigator().clipboard();
-                    if js_fut!(clipboard.write_text(&value)).await.is_ok() {
+                let on_success = {
+                    let start = start.clone();
+
+                    move |value: String, strategy: ClipboardStrategy| {
                         set_text.set(Some(value));
+                        set_strategy.set(Some(strategy));
                         set_copied.set(true);
                         start(());
                     }
-                });
+                };
+
+                // Everything up to the async API runs synchronously so that the fallbacks still
+                // have the user activation of the event that triggered the copy.
+                let mut strategies = strategies.iter().copied();
+
+                while let Some(current_strategy) = strategies.next() {
+                    if current_strategy == ClipboardStrategy::AsyncApi {
+                        if !is_supported.get_untracked()
+                            || permission_write.get_untracked() == PermissionState::Denied
+                        {
+                            continue;
+                        }
+
+                        let remaining_strategies = strategies.collect::<Vec<_>>();
+                        let prompt_message = prompt_message.clone();
+
+                        leptos::task::spawn_local(async move {
+                            let clipboard = window().navigator().clipboard();
+                            if js_fut!(clipboard.write_text(&value)).await.is_ok() {
+                                on_success(value, ClipboardStrategy::AsyncApi);
+                                return;
+                            }
+
+                            for current_strategy in remaining_strategies {
+                                if copy_synchronously(current_strategy, &value, &prompt_message) {
+                                    on_success(value, current_strategy);
+                                    return;
+                                }
+                            }
+                        });
+
+                        return;
+                    }
+
+                    if copy_synchronously(current_strategy, &value, &prompt_message) {
+                        on_success(value, current_strategy);
+                        return;
+                    }
+                }
             }
         })
     };
@@ -120,10 +201,61 @@ pub fn use_clipboard_with_options(
         is_supported,
         text: text.into(),
         copied: copied.into(),
+        strategy: strategy.into(),
         copy: do_copy,
     }
 }
 
+/// Copies `value` with one of the strategies that don't need to wait for a promise. Returns `true`
+/// on success. `ClipboardStrategy::AsyncApi` always fails here.
+fn copy_synchronously(strategy: ClipboardStrategy, value: &str, prompt_message: &str) -> bool {
+    match strategy {
+        ClipboardStrategy::AsyncApi => false,
+        ClipboardStrategy::ExecCommand => copy_with_exec_command(value),
+        ClipboardStrategy::Prompt => window()
+            .prompt_with_message_and_default(prompt_message, value)
+            .is_ok_and(|result| result.is_some()),
+    }
+}
+
+/// Copies `value` by selecting it in a temporary, invisible `<textarea>` and running the
+/// deprecated `document.execCommand("copy")`. Returns `true` on success.
+fn copy_with_exec_command(value: &str) -> bool {
+    use wasm_bindgen::JsCast;
+
+    let document = document();
+
+    let Some(body) = document.body() else {
+        return false;
+    };
+
+    let Ok(textarea) = document
+        .create_element("textarea")
+        .map(|el| el.unchecked_into::<web_sys::HtmlTextAreaElement>())
+    else {
+        return false;
+    };
+
+    textarea.set_value(value);
+    let _ = textarea.set_attribute("readonly", "");
+    let _ = textarea.set_attribute("style", "position: absolute; left: -9999px; opacity: 0;");
+
+    if body.append_child(&textarea).is_err() {
+        return false;
+    }
+
+    textarea.select();
+
+    let success = document
+        .unchecked_ref::<web_sys::HtmlDocument>()
+        .exec_command("copy")
+        .unwrap_or(false);
+
+    textarea.remove();
+
+    success
+}
+
 /// Options for [`use_clipboard_with_options`].
 #[derive(DefaultBuilder)]
 pub struct UseClipboardOptions {
@@ -136,6 +268,16 @@ pub struct UseClipboardOptions {
     /// After how many milliseconds after copying should the returned signal `copied` be set to `false`?
     /// Defaults to 1500.
     copied_reset_delay: f64,
+
+    /// The ways to write to the clipboard that are tried in order until one succeeds.
+    /// Defaults to `[ClipboardStrategy::AsyncApi, ClipboardStrategy::ExecCommand]`.
+    #[builder(into)]
+    strategies: Vec<ClipboardStrategy>,
+
+    /// The message of the prompt that is shown by `ClipboardStrategy::Prompt`.
+    /// Defaults to `"Copy to clipboard: Ctrl+C, Enter"`.
+    #[builder(into)]
+    prompt_message: String,
 }
 
 impl Default for UseClipboardOptions {
@@ -143,10 +285,27 @@ impl Default for UseClipboardOptions {
         Self {
             read: false,
             copied_reset_delay: 1500.0,
+            strategies: vec![ClipboardStrategy::AsyncApi, ClipboardStrategy::ExecCommand],
+            prompt_message: "Copy to clipboard: Ctrl+C, Enter".to_string(),
         }
     }
 }
 
+/// A way of writing to the clipboard used by [`fn@crate::use_clipboard`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardStrategy {
+    /// The async [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/writeText).
+    AsyncApi,
+
+    /// The deprecated [`document.execCommand("copy")`](https://developer.mozilla.org/en-US/docs/Web/API/Document/execCommand)
+    /// on a temporary, invisible `<textarea>`.
+    ExecCommand,
+
+    /// A [`window.prompt`](https://developer.mozilla.org/en-US/docs/Web/API/Window/prompt) that
+    /// shows the text already selected so the user can copy it manually.
+    Prompt,
+}
+
 /// Return type of [`use_clipboard`].
 pub struct UseClipboardReturn<CopyFn>
 where
@@ -161,6 +320,9 @@ where
     /// `true` for [`UseClipboardOptions::copied_reset_delay`] milliseconds after copying.
     pub copied: Signal<bool>,
 
+    /// The strategy that was used for the last successful copy.
+    pub strategy: Signal<Option<ClipboardStrategy>>,
+
     /// Copy the given text to the clipboard.
     pub copy: CopyFn,
 }
@@ -0,0 +1,432 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use crate::storage::use_local_storage;
+use codee::{Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// Resizable table columns.
+///
+/// Manages the widths of columns that are resized by dragging a handle in the column headers.
+/// Double-clicking a handle fits the column to its content. Optionally the widths are persisted
+/// in local storage.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_column_resize)
+///
+/// ## Usage
+///
+/// Register the header cells with `register` and call `start_resize` when a resize handle is
+/// pressed. Columns that haven't been resized have no width so they keep their natural width.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Th;
+/// # use leptos_use::use_column_resize;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let resize = use_column_resize();
+///
+/// let header = move |column: &'static str| {
+///     let th = NodeRef::<Th>::new();
+///     resize.register(column, th);
+///
+///     view! {
+///         <th
+///             node_ref=th
+///             style:width=move || {
+///                 resize.width(column).map(|width| format!("{width}px")).unwrap_or_default()
+///             }
+///         >
+///             {column}
+///             <span
+///                 class="resize-handle"
+///                 on:pointerdown=move |e| resize.start_resize(column, &e)
+///                 on:dblclick=move |_| resize.auto_fit(column)
+///             />
+///         </th>
+///     }
+/// };
+///
+/// view! {
+///     <table style="table-layout: fixed">
+///         <tr>{header("name")} {header("email")}</tr>
+///     </table>
+/// }
+/// # }
+/// ```
+///
+/// ### Constraints
+///
+/// Widths are kept between the options `min_width` and `max_width`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_column_resize_with_options, UseColumnResizeOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let resize = use_column_resize_with_options(
+///     UseColumnResizeOptions::default()
+///         .min_width(80.0)
+///         .max_width(600.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Auto-fit
+///
+/// `auto_fit` measures the text of the header cell with the font of the cell. If the header is a
+/// table cell, the text of all cells in its column is measured as well. The column is then set to
+/// the widest text plus the horizontal padding and border of the cell.
+///
+/// ### Persistence
+///
+/// If you provide a `storage_key` the widths are stored in local storage under that key and
+/// restored on the next visit. See [`fn@crate::storage::use_local_storage`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_column_resize_with_options, UseColumnResizeOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let resize = use_column_resize_with_options(
+///     UseColumnResizeOptions::default().storage_key("users-table-columns"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server no widths are read from storage and resizing does nothing.
+pub fn use_column_resize() -> UseColumnResizeReturn {
+    use_column_resize_with_options(UseColumnResizeOptions::default())
+}
+
+/// Version of [`use_column_resize`] that takes a `UseColumnResizeOptions`. See [`use_column_resize`] for how to use.
+pub fn use_column_resize_with_options(options: UseColumnResizeOptions) -> UseColumnResizeReturn {
+    let UseColumnResizeOptions {
+        min_width,
+        max_width,
+        storage_key,
+    } = options;
+
+    let (widths, set_widths) = match storage_key {
+        Some(storage_key) => {
+            let (widths, set_widths, _) =
+                use_local_storage::<HashMap<String, f64>, ColumnWidthsCodec>(storage_key);
+            (widths, set_widths)
+        }
+        None => {
+            let (widths, set_widths) = signal(HashMap::new());
+            (widths.into(), set_widths)
+        }
+    };
+
+    let (resizing, set_resizing) = signal(None::<String>);
+
+    let headers = StoredValue::new(Vec::<(String, ElementMaybeSignal<web_sys::Element>)>::new());
+
+    // the column being resized, the pointer position and the column width when it started
+    let drag = StoredValue::new(None::<(String, f64, f64)>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{pointermove, pointerup};
+
+        let _ = use_event_listener(window(), pointermove, move |event| {
+            let Some((column, start_x, start_width)) = drag.get_value() else {
+                return;
+            };
+
+            let width = clamp(
+                start_width + event.client_x() as f64 - start_x,
+                min_width,
+                max_width,
+            );
+
+            set_widths.update(|widths| {
+                widths.insert(column, width);
+            });
+        });
+
+        let _ = use_event_listener(window(), pointerup, move |_| {
+            if drag.get_value().is_some() {
+                drag.set_value(None);
+                set_resizing.set(None);
+            }
+        });
+    }
+
+    UseColumnResizeReturn {
+        widths,
+        resizing: resizing.into(),
+        set_widths,
+        set_resizing,
+        headers,
+        drag,
+        min_width,
+        max_width,
+    }
+}
+
+fn clamp(width: f64, min_width: f64, max_width: f64) -> f64 {
+    width.max(min_width).min(max_width)
+}
+
+/// Options for [`use_column_resize_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseColumnResizeOptions {
+    /// Minimum width of a column in pixels. Defaults to `40.0`.
+    min_width: f64,
+
+    /// Maximum width of a column in pixels. Defaults to `f64::INFINITY`.
+    max_width: f64,
+
+    /// If set, the widths are persisted in local storage under this key. Defaults to `None`.
+    #[builder(into)]
+    storage_key: Option<String>,
+}
+
+impl Default for UseColumnResizeOptions {
+    fn default() -> Self {
+        Self {
+            min_width: 40.0,
+            max_width: f64::INFINITY,
+            storage_key: None,
+        }
+    }
+}
+
+/// Return type of [`use_column_resize`].
+#[derive(Clone, Copy)]
+pub struct UseColumnResizeReturn {
+    /// The widths in pixels of the columns that have been resized.
+    pub widths: Signal<HashMap<String, f64>>,
+
+    /// The column that is currently being resized.
+    pub resizing: Signal<Option<String>>,
+
+    set_widths: WriteSignal<HashMap<String, f64>>,
+    set_resizing: WriteSignal<Option<String>>,
+    headers: StoredValue<Vec<(String, ElementMaybeSignal<web_sys::Element>)>>,
+    drag: StoredValue<Option<(String, f64, f64)>>,
+    min_width: f64,
+    max_width: f64,
+}
+
+impl UseColumnResizeReturn {
+    /// Registers the header cell of `column`. It is used to determine the initial width when
+    /// resizing starts and for `auto_fit`. It is unregistered automatically when the calling
+    /// component is cleaned up.
+    pub fn register<El, M>(&self, column: impl Into<String>, el: El)
+    where
+        El: IntoElementMaybeSignal<web_sys::Element, M>,
+    {
+        let column = column.into();
+        let el = el.into_element_maybe_signal();
+
+        self.headers
+            .update_value(|headers| headers.push((column.clone(), el)));
+
+        let headers = self.headers;
+
+        on_cleanup(move || {
+            headers.try_update_value(|headers| headers.retain(|(c, _)| *c != column));
+        });
+    }
+
+    /// Returns the width of `column` in pixels or `None` if it hasn't been resized. Reactive if
+    /// called inside a reactive context.
+    pub fn width(&self, column: &str) -> Option<f64> {
+        self.widths.with(|widths| widths.get(column).copied())
+    }
+
+    /// Sets the width of `column` in pixels. The width is clamped between `min_width` and
+    /// `max_width`.
+    pub fn set_width(&self, column: impl Into<String>, width: f64) {
+        let width = clamp(width, self.min_width, self.max_width);
+
+        self.set_widths.update(|widths| {
+            widths.insert(column.into(), width);
+        });
+    }
+
+    /// Starts resizing `column`. Call this on `pointerdown` of the column's resize handle.
+    pub fn start_resize(&self, column: impl Into<String>, event: &web_sys::PointerEvent) {
+        if event.button() != 0 {
+            return;
+        }
+
+        event.prevent_default();
+
+        let column = column.into();
+        let start_width = self
+            .width(&column)
+            .or_else(|| {
+                self.header(&column)
+                    .map(|header| header.get_bounding_client_rect().width())
+            })
+            .unwrap_or(self.min_width);
+
+        self.drag
+            .set_value(Some((column.clone(), event.client_x() as f64, start_width)));
+        self.set_resizing.set(Some(column));
+    }
+
+    /// Fits `column` to the width of its content. See [`use_column_resize`] for details.
+    pub fn auto_fit(&self, column: impl Into<String>) {
+        let column = column.into();
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            if let Some(width) = self
+                .header(&column)
+                .and_then(|header| measure_column(&header))
+            {
+                self.set_width(column, width.ceil());
+            }
+        }
+    }
+
+    /// Resets all columns to their natural widths.
+    pub fn reset(&self) {
+        self.set_widths.set(HashMap::new());
+    }
+
+    fn header(&self, column: &str) -> Option<web_sys::Element> {
+        self.headers.with_value(|headers| {
+            headers
+                .iter()
+                .find(|(c, _)| c == column)
+                .and_then(|(_, el)| el.get_untracked())
+        })
+    }
+}
+
+/// Measures the widest text of the header and, if it's a table cell, of all cells in its column.
+#[cfg(not(feature = "ssr"))]
+fn measure_column(header: &web_sys::Element) -> Option<f64> {
+    use wasm_bindgen::JsCast;
+
+    let context = document()
+        .create_element("canvas")
+        .ok()?
+        .unchecked_into::<web_sys::HtmlCanvasElement>()
+        .get_context("2d")
+        .ok()??
+        .unchecked_into::<web_sys::CanvasRenderingContext2d>();
+
+    let mut cells = vec![header.clone()];
+
+    if let Some(header_cell) = header.dyn_ref::<web_sys::HtmlTableCellElement>() {
+        let table = header
+            .closest("table")
+            .ok()
+            .flatten()
+            .and_then(|table| table.dyn_into::<web_sys::HtmlTableElement>().ok());
+
+        if let (Some(table), Ok(index)) = (table, u32::try_from(header_cell.cell_index())) {
+            let rows = table.rows();
+
+            for row in 0..rows.length() {
+                if let Some(cell) = rows.item(row).and_then(|row| {
+                    row.unchecked_into::<web_sys::HtmlTableRowElement>()
+                        .cells()
+                        .item(index)
+                }) {
+                    cells.push(cell);
+                }
+            }
+        }
+    }
+
+    cells
+        .iter()
+        .filter_map(|cell| measure_cell(&context, cell))
+        .reduce(f64::max)
+}
+
+/// Width of the text of `cell` plus its horizontal padding and border.
+#[cfg(not(feature = "ssr"))]
+fn measure_cell(
+    context: &web_sys::CanvasRenderingContext2d,
+    cell: &web_sys::Element,
+) -> Option<f64> {
+    let style = window().get_computed_style(cell).ok()??;
+
+    let property = |name: &str| style.get_property_value(name).unwrap_or_default();
+    let pixels = |name: &str| {
+        property(name)
+            .trim_end_matches("px")
+            .parse::<f64>()
+            .unwrap_or_default()
+    };
+
+    context.set_font(&format!(
+        "{} {} {} {}",
+        property("font-style"),
+        property("font-weight"),
+        property("font-size"),
+        property("font-family"),
+    ));
+
+    let text_width = cell
+        .text_content()
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| context.measure_text(line.trim()).ok())
+        .map(|metrics| metrics.width())
+        .fold(0.0, f64::max);
+
+    Some(
+        text_width
+            + pixels("padding-left")
+            + pixels("padding-right")
+            + pixels("border-left-width")
+            + pixels("border-right-width"),
+    )
+}
+
+/// Stores the column widths as lines of `<width> <column>`.
+struct ColumnWidthsCodec;
+
+impl Encoder<HashMap<String, f64>> for ColumnWidthsCodec {
+    type Error = ();
+    type Encoded = String;
+
+    fn encode(val: &HashMap<String, f64>) -> Result<Self::Encoded, Self::Error> {
+        Ok(val
+            .iter()
+            .map(|(column, width)| format!("{width} {column}"))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+impl Decoder<HashMap<String, f64>> for ColumnWidthsCodec {
+    type Error = ();
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<HashMap<String, f64>, Self::Error> {
+        val.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (width, column) = line.split_once(' ').ok_or(())?;
+                let width = width.parse::<f64>().map_err(|_| ())?;
+
+                Ok((column.to_string(), width))
+            })
+            .collect()
+    }
+}
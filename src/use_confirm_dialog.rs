@@ -0,0 +1,309 @@
+use leptos::prelude::*;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+/// State of a confirmation dialog that can be awaited.
+///
+/// It doesn't render anything. It only keeps track of whether the dialog is revealed and turns
+/// the answer of the user into the result of a future, so confirmation flows can be written as
+/// straight async code.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_confirm_dialog)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_confirm_dialog, ConfirmDialogResult, UseConfirmDialogReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let dialog = use_confirm_dialog::<String, (), ()>();
+/// let UseConfirmDialogReturn { is_revealed, data, .. } = dialog;
+///
+/// let delete = move |_| {
+///     spawn_local(async move {
+///         let result = dialog.reveal("Delete this file?".to_string()).await;
+///
+///         if let ConfirmDialogResult::Confirmed(()) = result {
+///             // delete the file
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=delete>"Delete"</button>
+///
+///     <Show when=move || is_revealed.get()>
+///         <div class="modal">
+///             <p>{move || data.get()}</p>
+///             <button on:click=move |_| dialog.confirm(())>"Yes"</button>
+///             <button on:click=move |_| dialog.cancel(())>"No"</button>
+///         </div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The three type parameters are the types of the data passed to `reveal`, `confirm` and
+/// `cancel` respectively.
+///
+/// Calling `reveal` while the dialog is already revealed doesn't reveal it again. The returned
+/// future resolves with the same result as the first one and its data is ignored. `confirm` and
+/// `cancel` do nothing while the dialog isn't revealed.
+///
+/// ### Hooks
+///
+/// Instead of awaiting the result you can also register callbacks.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_confirm_dialog;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let dialog = use_confirm_dialog::<(), String, ()>();
+///
+/// dialog.on_reveal(|_| leptos::logging::log!("revealed"));
+/// dialog.on_confirm(|name| leptos::logging::log!("confirmed with {name}"));
+/// dialog.on_cancel(|_| leptos::logging::log!("canceled"));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// Works the same on the server but usually nothing reveals the dialog there.
+pub fn use_confirm_dialog<RevealData, ConfirmData, CancelData>(
+) -> UseConfirmDialogReturn<RevealData, ConfirmData, CancelData>
+where
+    RevealData: Clone + Send + Sync + 'static,
+    ConfirmData: Clone + 'static,
+    CancelData: Clone + 'static,
+{
+    let (is_revealed, set_revealed) = signal(false);
+    let (data, set_data) = signal(None::<RevealData>);
+
+    UseConfirmDialogReturn {
+        is_revealed: is_revealed.into(),
+        data: data.into(),
+        set_revealed,
+        set_data,
+        pending: StoredValue::new_local(None),
+        reveal_hooks: StoredValue::new_local(vec![]),
+        confirm_hooks: StoredValue::new_local(vec![]),
+        cancel_hooks: StoredValue::new_local(vec![]),
+    }
+}
+
+/// Outcome of revealing the dialog of [`fn@crate::use_confirm_dialog`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfirmDialogResult<ConfirmData, CancelData> {
+    /// The dialog has been confirmed with the given data.
+    Confirmed(ConfirmData),
+
+    /// The dialog has been canceled with the given data.
+    Canceled(CancelData),
+}
+
+impl<ConfirmData, CancelData> ConfirmDialogResult<ConfirmData, CancelData> {
+    /// Whether the dialog has been confirmed.
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, Self::Confirmed(_))
+    }
+
+    /// Whether the dialog has been canceled.
+    pub fn is_canceled(&self) -> bool {
+        matches!(self, Self::Canceled(_))
+    }
+}
+
+type Hooks<T> = Vec<Rc<dyn Fn(&T)>>;
+
+/// Shared between the futures returned by `reveal` until the dialog is closed.
+struct RevealState<ConfirmData, CancelData> {
+    result: Option<ConfirmDialogResult<ConfirmData, CancelData>>,
+    wakers: Vec<Waker>,
+}
+
+type SharedRevealState<C, X> = Rc<RefCell<RevealState<C, X>>>;
+
+/// Future returned by `reveal`.
+struct RevealFuture<ConfirmData, CancelData> {
+    state: SharedRevealState<ConfirmData, CancelData>,
+}
+
+impl<ConfirmData, CancelData> Future for RevealFuture<ConfirmData, CancelData>
+where
+    ConfirmData: Clone,
+    CancelData: Clone,
+{
+    type Output = ConfirmDialogResult<ConfirmData, CancelData>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        match &state.result {
+            Some(result) => Poll::Ready(result.clone()),
+            None => {
+                if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                    state.wakers.push(cx.waker().clone());
+                }
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Return type of [`use_confirm_dialog`].
+pub struct UseConfirmDialogReturn<RevealData, ConfirmData, CancelData>
+where
+    RevealData: Clone + Send + Sync + 'static,
+    ConfirmData: Clone + 'static,
+    CancelData: Clone + 'static,
+{
+    /// Whether the dialog is currently revealed.
+    pub is_revealed: Signal<bool>,
+
+    /// The data passed to `reveal` while the dialog is revealed.
+    pub data: Signal<Option<RevealData>>,
+
+    set_revealed: WriteSignal<bool>,
+    set_data: WriteSignal<Option<RevealData>>,
+    pending: StoredValue<Option<SharedRevealState<ConfirmData, CancelData>>, LocalStorage>,
+    reveal_hooks: StoredValue<Hooks<RevealData>, LocalStorage>,
+    confirm_hooks: StoredValue<Hooks<ConfirmData>, LocalStorage>,
+    cancel_hooks: StoredValue<Hooks<CancelData>, LocalStorage>,
+}
+
+impl<RevealData, ConfirmData, CancelData> Clone
+    for UseConfirmDialogReturn<RevealData, ConfirmData, CancelData>
+where
+    RevealData: Clone + Send + Sync + 'static,
+    ConfirmData: Clone + 'static,
+    CancelData: Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<RevealData, ConfirmData, CancelData> Copy
+    for UseConfirmDialogReturn<RevealData, ConfirmData, CancelData>
+where
+    RevealData: Clone + Send + Sync + 'static,
+    ConfirmData: Clone + 'static,
+    CancelData: Clone + 'static,
+{
+}
+
+impl<RevealData, ConfirmData, CancelData>
+    UseConfirmDialogReturn<RevealData, ConfirmData, CancelData>
+where
+    RevealData: Clone + Send + Sync + 'static,
+    ConfirmData: Clone + 'static,
+    CancelData: Clone + 'static,
+{
+    /// Reveals the dialog. The returned future resolves when the dialog is confirmed or canceled.
+    pub fn reveal(
+        &self,
+        data: RevealData,
+    ) -> impl Future<Output = ConfirmDialogResult<ConfirmData, CancelData>> {
+        let state = match self.pending.get_value() {
+            Some(state) => state,
+            None => {
+                let state = Rc::new(RefCell::new(RevealState {
+                    result: None,
+                    wakers: vec![],
+                }));
+                self.pending.set_value(Some(Rc::clone(&state)));
+
+                for hook in self.reveal_hooks.get_value() {
+                    hook(&data);
+                }
+
+                self.set_data.set(Some(data));
+                self.set_revealed.set(true);
+
+                state
+            }
+        };
+
+        RevealFuture { state }
+    }
+
+    /// Confirms the revealed dialog with the given data.
+    pub fn confirm(&self, data: ConfirmData) {
+        if let Some(state) = self.close() {
+            for hook in self.confirm_hooks.get_value() {
+                hook(&data);
+            }
+
+            settle(&state, ConfirmDialogResult::Confirmed(data));
+        }
+    }
+
+    /// Cancels the revealed dialog with the given data.
+    pub fn cancel(&self, data: CancelData) {
+        if let Some(state) = self.close() {
+            for hook in self.cancel_hooks.get_value() {
+                hook(&data);
+            }
+
+            settle(&state, ConfirmDialogResult::Canceled(data));
+        }
+    }
+
+    /// Registers a callback that is called with the data of `reveal` when the dialog is revealed.
+    pub fn on_reveal(&self, hook: impl Fn(&RevealData) + 'static) {
+        self.reveal_hooks
+            .update_value(|hooks| hooks.push(Rc::new(hook)));
+    }
+
+    /// Registers a callback that is called with the data of `confirm` when the dialog is confirmed.
+    pub fn on_confirm(&self, hook: impl Fn(&ConfirmData) + 'static) {
+        self.confirm_hooks
+            .update_value(|hooks| hooks.push(Rc::new(hook)));
+    }
+
+    /// Registers a callback that is called with the data of `cancel` when the dialog is canceled.
+    pub fn on_cancel(&self, hook: impl Fn(&CancelData) + 'static) {
+        self.cancel_hooks
+            .update_value(|hooks| hooks.push(Rc::new(hook)));
+    }
+
+    /// Hides the dialog. Returns the state of the pending futures or `None` if the dialog wasn't
+    /// revealed.
+    fn close(&self) -> Option<SharedRevealState<ConfirmData, CancelData>> {
+        let state = self.pending.try_update_value(Option::take).flatten()?;
+
+        self.set_revealed.set(false);
+        self.set_data.set(None);
+
+        Some(state)
+    }
+}
+
+/// Resolves the pending futures of `reveal` with `result`.
+fn settle<ConfirmData, CancelData>(
+    state: &SharedRevealState<ConfirmData, CancelData>,
+    result: ConfirmDialogResult<ConfirmData, CancelData>,
+) {
+    let wakers = {
+        let mut state = state.borrow_mut();
+        state.result = Some(result);
+        std::mem::take(&mut state.wakers)
+    };
+
+    for waker in wakers {
+        waker.wake();
+    }
+}
@@ -0,0 +1,185 @@
+use crate::core::IntoElementMaybeSignal;
+use crate::{use_element_size_with_options, UseElementSizeOptions, UseElementSizeReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Headless right-click menus.
+///
+/// Intercepts the `contextmenu` event on `target` and exposes whether the menu is open and where
+/// it should be positioned. The position is the pointer position clamped so that the `menu`
+/// element stays fully inside the viewport. The menu is closed when clicking outside of it or
+/// pressing <kbd>Escape</kbd>.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_context_menu)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_context_menu, UseContextMenuReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let target = NodeRef::<Div>::new();
+/// let menu = NodeRef::<Div>::new();
+///
+/// let UseContextMenuReturn { is_open, x, y, close } = use_context_menu(target, menu);
+///
+/// view! {
+///     <div node_ref=target>"Right click me"</div>
+///
+///     <div
+///         node_ref=menu
+///         style:display=move || if is_open.get() { "block" } else { "none" }
+///         style:position="fixed"
+///         style:left=move || format!("{}px", x.get())
+///         style:top=move || format!("{}px", y.get())
+///     >
+///         <button on:click=move |_| close()>"Do something"</button>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the menu is never opened and `x` and `y` are always `0.0`.
+pub fn use_context_menu<El, M, MenuEl, MenuM>(
+    target: El,
+    menu: MenuEl,
+) -> UseContextMenuReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    MenuEl: IntoElementMaybeSignal<web_sys::Element, MenuM>,
+{
+    use_context_menu_with_options(target, menu, UseContextMenuOptions::default())
+}
+
+/// Version of [`use_context_menu`] that takes a `UseContextMenuOptions`. See [`use_context_menu`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_context_menu_with_options<El, M, MenuEl, MenuM>(
+    target: El,
+    menu: MenuEl,
+    options: UseContextMenuOptions,
+) -> UseContextMenuReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    MenuEl: IntoElementMaybeSignal<web_sys::Element, MenuM>,
+{
+    let UseContextMenuOptions {
+        close_on_click_outside,
+        close_on_escape,
+    } = options;
+
+    let (is_open, set_open) = signal(false);
+    let (pointer, set_pointer) = signal((0.0, 0.0));
+
+    let menu = menu.into_element_maybe_signal();
+
+    let UseElementSizeReturn { width, height } = use_element_size_with_options(
+        menu,
+        UseElementSizeOptions::default().box_(web_sys::ResizeObserverBoxOptions::BorderBox),
+    );
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{on_click_outside, use_event_listener};
+        use leptos::ev::{contextmenu, keydown};
+
+        let _ = use_event_listener(target, contextmenu, move |event| {
+            event.prevent_default();
+
+            set_pointer.set((event.client_x() as f64, event.client_y() as f64));
+            set_open.set(true);
+        });
+
+        if close_on_escape {
+            let _ = use_event_listener(window(), keydown, move |event| {
+                if event.key() == "Escape" && is_open.get_untracked() {
+                    set_open.set(false);
+                }
+            });
+        }
+
+        if close_on_click_outside {
+            let _ = on_click_outside(menu, move |_| {
+                if is_open.get_untracked() {
+                    set_open.set(false);
+                }
+            });
+        }
+    }
+
+    let x = Signal::derive(move || {
+        let (viewport_width, _) = viewport_size();
+        clamp_to_viewport(pointer.get().0, width.get(), viewport_width)
+    });
+
+    let y = Signal::derive(move || {
+        let (_, viewport_height) = viewport_size();
+        clamp_to_viewport(pointer.get().1, height.get(), viewport_height)
+    });
+
+    UseContextMenuReturn {
+        is_open: is_open.into(),
+        x,
+        y,
+        close: move || set_open.set(false),
+    }
+}
+
+/// Returns the largest position at which an element of `size` still fits into the viewport.
+fn clamp_to_viewport(position: f64, size: f64, viewport: f64) -> f64 {
+    position.min(viewport - size).max(0.0)
+}
+
+fn viewport_size() -> (f64, f64) {
+    #[cfg(not(feature = "ssr"))]
+    {
+        if let Some(root) = document().document_element() {
+            return (root.client_width() as f64, root.client_height() as f64);
+        }
+    }
+
+    (f64::INFINITY, f64::INFINITY)
+}
+
+/// Options for [`use_context_menu_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseContextMenuOptions {
+    /// Close the menu when clicking outside of it. Defaults to `true`.
+    close_on_click_outside: bool,
+
+    /// Close the menu when <kbd>Escape</kbd> is pressed. Defaults to `true`.
+    close_on_escape: bool,
+}
+
+impl Default for UseContextMenuOptions {
+    fn default() -> Self {
+        Self {
+            close_on_click_outside: true,
+            close_on_escape: true,
+        }
+    }
+}
+
+/// Return type of [`use_context_menu`].
+pub struct UseContextMenuReturn<CloseFn>
+where
+    CloseFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the menu is currently open.
+    pub is_open: Signal<bool>,
+
+    /// The horizontal position (relative to the viewport) at which to show the menu.
+    pub x: Signal<f64>,
+
+    /// The vertical position (relative to the viewport) at which to show the menu.
+    pub y: Signal<f64>,
+
+    /// Closes the menu.
+    pub close: CloseFn,
+}
@@ -16,8 +16,8 @@ use std::sync::Arc;
 /// SSR-friendly and reactive cookie access.
 ///
 /// You can use this function multiple times for the same cookie and their signals will synchronize
-/// (even across windows/tabs). But there is no way to listen to changes to `document.cookie` directly so in case
-/// something outside of this function changes the cookie, the signal will **not** be updated.
+/// (even across windows/tabs). By default, if something outside of this function changes the cookie, the signal
+/// will **not** be updated. See [External changes](#external-changes) below for how to change that.
 ///
 /// When the options `max_age` or `expire` is given then the returned signal will
 /// automatically turn to `None` after that time.
@@ -89,6 +89,32 @@ use std::sync::Arc;
 /// # }
 /// ```
 ///
+/// ## External changes
+///
+/// There is no way to listen to changes to `document.cookie` directly. If the cookie can be changed by other
+/// JavaScript code or by responses of the server set the option `watch_external_changes` to `true`.
+/// Then the `change` event of the [Cookie Store API](https://developer.mozilla.org/en-US/docs/Web/API/Cookie_Store_API)
+/// is used in browsers that support it. Other browsers check `document.cookie` for changes every
+/// `external_changes_poll_interval` milliseconds.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_cookie_with_options, UseCookieOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (session, set_session) = use_cookie_with_options::<String, FromToStringCodec>(
+///     "session",
+///     UseCookieOptions::default()
+///         .watch_external_changes(true)
+///         .external_changes_poll_interval(5000),
+/// );
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// This works equally well on the server or the client.
@@ -151,6 +177,7 @@ where
 }
 
 /// Version of [`use_cookie`] that takes [`UseCookieOptions`].
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
 pub fn use_cookie_with_options<T, C>(
     cookie_name: &str,
     options: UseCookieOptions<T, <C as Encoder<T>>::Error, <C as Decoder<T>>::Error>,
@@ -171,6 +198,8 @@ where
         ssr_set_cookie,
         default_value,
         readonly,
+        watch_external_changes,
+        external_changes_poll_interval,
         on_error,
     } = options;
 
@@ -287,6 +316,63 @@ where
             }
         });
 
+        if watch_external_changes {
+            use crate::{use_event_listener, use_interval_fn};
+            use wasm_bindgen::JsCast;
+
+            let reload_cookie = {
+                let cookie_name = cookie_name.to_owned();
+                let ssr_cookies_header_getter = Arc::clone(&ssr_cookies_header_getter);
+                let on_error = Arc::clone(&on_error);
+                let pause = pause.clone();
+                let resume = resume.clone();
+
+                move || {
+                    let Some(new_jar) =
+                        load_and_parse_cookie_jar(Arc::clone(&ssr_cookies_header_getter))
+                    else {
+                        return;
+                    };
+
+                    let value = new_jar.get(&cookie_name).map(|c| c.value().to_owned());
+
+                    if value
+                        == jar.with_value(|jar| jar.get(&cookie_name).map(|c| c.value().to_owned()))
+                    {
+                        return;
+                    }
+
+                    pause();
+
+                    jar.set_value(new_jar);
+
+                    match value {
+                        Some(value) => match C::decode(&value) {
+                            Ok(value) => set_cookie.set(Some(value)),
+                            Err(err) => on_error(CodecError::Decode(err)),
+                        },
+                        None => set_cookie.set(None),
+                    }
+
+                    resume();
+                }
+            };
+
+            let cookie_store = js_sys::Reflect::get(&window(), &"cookieStore".into())
+                .ok()
+                .and_then(|cookie_store| cookie_store.dyn_into::<web_sys::EventTarget>().ok());
+
+            if let Some(cookie_store) = cookie_store {
+                let _ = use_event_listener(
+                    cookie_store,
+                    leptos::ev::Custom::<leptos::ev::Event>::new("change"),
+                    move |_| reload_cookie(),
+                );
+            } else {
+                let _ = use_interval_fn(reload_cookie, external_changes_poll_interval);
+            }
+        }
+
         // listen to cookie changes from the broadcast channel
         Effect::new({
             let ssr_cookies_header_getter = Arc::clone(&ssr_cookies_header_getter);
@@ -483,6 +569,16 @@ pub struct UseCookieOptions<T, E, D> {
     /// Default: `false`
     readonly: bool,
 
+    /// If `true` the signal is updated when the cookie is changed outside of this function, e.g. by
+    /// other JavaScript code or by the server. See [`use_cookie`] for details.
+    /// Default: `false`
+    watch_external_changes: bool,
+
+    /// Interval in milliseconds in which `document.cookie` is checked for external changes in browsers that
+    /// don't support the Cookie Store API. Only used if `watch_external_changes` is `true`.
+    /// Default: `1000`
+    external_changes_poll_interval: u64,
+
     /// Getter function to return the string value of the cookie header.
     /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default implementation provided.
     ssr_cookies_header_getter: Arc<dyn Fn() -> Option<String> + Send + Sync>,
@@ -504,6 +600,8 @@ impl<T, E, D> Default for UseCookieOptions<T, E, D> {
             http_only: false,
             default_value: None,
             readonly: false,
+            watch_external_changes: false,
+            external_changes_poll_interval: 1000,
             secure: false,
             domain: None,
             path: None,
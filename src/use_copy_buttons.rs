@@ -0,0 +1,360 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Attribute that marks the buttons added by [`use_copy_buttons`].
+const BUTTON_ATTRIBUTE: &str = "data-copy-button";
+
+/// Adds copy-to-clipboard buttons to code blocks inside rendered content.
+///
+/// Every element inside `container` that matches `selector` gets a button that copies its text.
+/// This is handy for HTML that is rendered from markdown or comes from a CMS where you can't add
+/// the buttons to the source.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_copy_buttons)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Article;
+/// # use leptos_use::use_copy_buttons;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let rendered_markdown = "<pre><code>cargo add leptos-use</code></pre>";
+/// let article = NodeRef::<Article>::new();
+///
+/// use_copy_buttons(article, "pre");
+///
+/// view! {
+///     <article node_ref=article inner_html=rendered_markdown />
+/// }
+/// # }
+/// ```
+///
+/// The button is appended as the last child of the block and has the class `copy-button` so it
+/// can be styled, for example positioned in the top right corner of the block. The copied text is
+/// the text of the block without the button.
+///
+/// Blocks that are added to the container later get a button as well.
+///
+/// ### Copied State
+///
+/// After copying, the button shows "Copied!" for 1.5 seconds and has the attribute
+/// `data-copied`. `copied` contains the index of that block in the order of the document.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Article;
+/// # use leptos_use::{use_copy_buttons_with_options, UseCopyButtonsOptions, UseCopyButtonsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let article = NodeRef::<Article>::new();
+/// let UseCopyButtonsReturn { count, copied, .. } = use_copy_buttons_with_options(
+///     article,
+///     "pre, .snippet",
+///     UseCopyButtonsOptions::default()
+///         .button_class("btn btn-small")
+///         .button_text("📋")
+///         .copied_text("✓")
+///         .copied_reset_delay(3000.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The buttons are removed again when the component that called this is disposed.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no buttons are added. `count` is always `0` and `copied` is always `None`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_clipboard`]
+pub fn use_copy_buttons<El, M>(container: El, selector: impl Into<String>) -> UseCopyButtonsReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_copy_buttons_with_options(container, selector, UseCopyButtonsOptions::default())
+}
+
+/// Version of [`use_copy_buttons`] that takes a `UseCopyButtonsOptions`. See [`use_copy_buttons`] for how to use.
+pub fn use_copy_buttons_with_options<El, M>(
+    container: El,
+    selector: impl Into<String>,
+    options: UseCopyButtonsOptions,
+) -> UseCopyButtonsReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use crate::{use_clipboard_with_options, UseClipboardOptions, UseClipboardReturn};
+
+    let UseCopyButtonsOptions {
+        button_class,
+        button_text,
+        copied_text,
+        copied_reset_delay,
+    } = options;
+
+    let (count, set_count) = signal(0_usize);
+    let (copied_index, set_copied_index) = signal(None::<usize>);
+
+    let UseClipboardReturn {
+        is_supported,
+        copied,
+        copy,
+        ..
+    } = use_clipboard_with_options(
+        UseClipboardOptions::default().copied_reset_delay(copied_reset_delay),
+    );
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_event_listener, use_mutation_observer_with_options, UseMutationObserverOptions,
+        };
+        use leptos::ev::click;
+        use wasm_bindgen::JsCast;
+
+        let selector = selector.into();
+        let container = container.into_element_maybe_signal();
+
+        let blocks = StoredValue::new_local(Vec::<web_sys::Element>::new());
+
+        // finds the blocks and adds a button to the ones that don't have one yet
+        let scan = {
+            let button_text = button_text.clone();
+
+            move || {
+                let found = container
+                    .get_untracked()
+                    .and_then(|container| container.query_selector_all(&selector).ok())
+                    .map(|nodes| {
+                        (0..nodes.length())
+                            .filter_map(|i| nodes.item(i))
+                            .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                for block in &found {
+                    if find_button(block).is_none() {
+                        add_button(block, &button_class, &button_text);
+                    }
+                }
+
+                if count.get_untracked() != found.len() {
+                    set_count.set(found.len());
+                }
+
+                blocks.set_value(found);
+            }
+        };
+
+        let _ = Effect::watch(
+            move || container.get(),
+            {
+                let scan = scan.clone();
+                move |_, _, _| scan()
+            },
+            true,
+        );
+
+        // adding a button triggers the observer once more which then finds nothing to do
+        use_mutation_observer_with_options(
+            container,
+            move |_, _| scan(),
+            UseMutationObserverOptions::default()
+                .subtree(true)
+                .child_list(true),
+        );
+
+        let _ = use_event_listener(container, click, move |event| {
+            let Some(button) = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .and_then(|target| {
+                    target
+                        .closest(&format!("[{BUTTON_ATTRIBUTE}]"))
+                        .ok()
+                        .flatten()
+                })
+            else {
+                return;
+            };
+
+            let Some(block) = button.parent_element() else {
+                return;
+            };
+
+            let index = blocks.with_value(|blocks| {
+                blocks
+                    .iter()
+                    .position(|b| b.is_same_node(Some(block.as_ref())))
+            });
+
+            if let Some(index) = index {
+                event.prevent_default();
+                set_copied_index.set(Some(index));
+                copy(&text_without_buttons(&block));
+            }
+        });
+
+        let _ = Effect::watch(
+            move || (copied.get(), copied_index.get()),
+            move |(copied, copied_index), _, _| {
+                blocks.with_value(|blocks| {
+                    for (index, block) in blocks.iter().enumerate() {
+                        let Some(button) = find_button(block) else {
+                            continue;
+                        };
+
+                        let is_copied = *copied && *copied_index == Some(index);
+                        let text = if is_copied {
+                            &copied_text
+                        } else {
+                            &button_text
+                        };
+
+                        if button.text_content().as_deref() != Some(text.as_str()) {
+                            button.set_text_content(Some(text));
+                        }
+
+                        if is_copied {
+                            let _ = button.set_attribute("data-copied", "");
+                        } else {
+                            let _ = button.remove_attribute("data-copied");
+                        }
+                    }
+                });
+            },
+            false,
+        );
+
+        on_cleanup(move || {
+            // the stored value might be disposed already
+            let _ = blocks.try_with_value(|blocks| {
+                for block in blocks {
+                    if let Some(button) = find_button(block) {
+                        button.remove();
+                    }
+                }
+            });
+        });
+    }
+
+    UseCopyButtonsReturn {
+        is_supported,
+        count: count.into(),
+        copied: Signal::derive(move || {
+            if copied.get() {
+                copied_index.get()
+            } else {
+                None
+            }
+        }),
+    }
+}
+
+/// Returns the button that was added to `block`.
+#[cfg(not(feature = "ssr"))]
+fn find_button(block: &web_sys::Element) -> Option<web_sys::Element> {
+    block
+        .query_selector(&format!(":scope > [{BUTTON_ATTRIBUTE}]"))
+        .ok()
+        .flatten()
+}
+
+/// Appends a copy button to `block`.
+#[cfg(not(feature = "ssr"))]
+fn add_button(block: &web_sys::Element, class: &str, text: &str) {
+    let Ok(button) = document().create_element("button") else {
+        return;
+    };
+
+    let _ = button.set_attribute("type", "button");
+    let _ = button.set_attribute(BUTTON_ATTRIBUTE, "");
+    button.set_class_name(class);
+    button.set_text_content(Some(text));
+
+    let _ = block.append_child(&button);
+}
+
+/// The text of `block` without the text of the copy buttons inside of it.
+#[cfg(not(feature = "ssr"))]
+fn text_without_buttons(block: &web_sys::Element) -> String {
+    use wasm_bindgen::JsCast;
+
+    let Some(clone) = block
+        .clone_node_with_deep(true)
+        .ok()
+        .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+    else {
+        return block.text_content().unwrap_or_default();
+    };
+
+    if let Ok(buttons) = clone.query_selector_all(&format!("[{BUTTON_ATTRIBUTE}]")) {
+        for i in 0..buttons.length() {
+            if let Some(button) = buttons
+                .item(i)
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            {
+                button.remove();
+            }
+        }
+    }
+
+    clone.text_content().unwrap_or_default()
+}
+
+/// Options for [`use_copy_buttons_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseCopyButtonsOptions {
+    /// Class of the added buttons. Defaults to `"copy-button"`.
+    #[builder(into)]
+    button_class: String,
+
+    /// Text of the buttons. Defaults to `"Copy"`.
+    #[builder(into)]
+    button_text: String,
+
+    /// Text of a button after its block has been copied. Defaults to `"Copied!"`.
+    #[builder(into)]
+    copied_text: String,
+
+    /// How long a button shows `copied_text` in milliseconds. Defaults to `1500`.
+    copied_reset_delay: f64,
+}
+
+impl Default for UseCopyButtonsOptions {
+    fn default() -> Self {
+        Self {
+            button_class: "copy-button".to_string(),
+            button_text: "Copy".to_string(),
+            copied_text: "Copied!".to_string(),
+            copied_reset_delay: 1500.0,
+        }
+    }
+}
+
+/// Return type of [`use_copy_buttons`].
+#[derive(Clone, Copy)]
+pub struct UseCopyButtonsReturn {
+    /// Whether the Clipboard API is supported
+    pub is_supported: Signal<bool>,
+
+    /// The number of blocks with a copy button
+    pub count: Signal<usize>,
+
+    /// The index of the block that was just copied
+    pub copied: Signal<Option<usize>>,
+}
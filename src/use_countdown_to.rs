@@ -0,0 +1,234 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::now;
+use chrono::{DateTime, Utc};
+use default_struct_builder::DefaultBuilder;
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Reactive countdown to a point in time.
+///
+/// Unlike a timer that counts down a duration, the remaining time is always calculated from the
+/// current time of the clock. This keeps it correct after the browser has throttled or suspended
+/// the tab and after the computer has been sleeping. Useful for sale or launch timers.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_countdown_to)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_countdown_to, UseCountdownToReturn};
+/// # use chrono::{TimeZone, Utc};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let launch = Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+///
+/// let UseCountdownToReturn {
+///     days,
+///     hours,
+///     minutes,
+///     seconds,
+///     is_finished,
+///     ..
+/// } = use_countdown_to(launch);
+///
+/// view! {
+///     <Show when=move || !is_finished.get() fallback=|| "We have launched!">
+///         {days} "d " {hours} "h " {minutes} "m " {seconds} "s"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The target is an absolute point in time. Times in other time zones can be converted with
+/// `.to_utc()`. Days are always 24 hours long, so there's no jump when daylight saving time
+/// starts or ends before the target.
+///
+/// The values are rounded up to whole seconds, i.e. the countdown shows `0s` exactly when the
+/// target is reached. They are updated at the moment the displayed second changes. When the tab
+/// becomes visible again they are updated right away.
+///
+/// ### Finished
+///
+/// Use the option `on_finished` to do something when the target is reached. It isn't called if the
+/// target is already in the past when the countdown starts.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_countdown_to_with_options, UseCountdownToOptions};
+/// # use chrono::{TimeDelta, Utc};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (target, set_target) = signal(Utc::now() + TimeDelta::minutes(5));
+///
+/// let countdown = use_countdown_to_with_options(
+///     target,
+///     UseCountdownToOptions::default().on_finished(|| log!("Time is up!")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the remaining time is calculated once and never updates. `on_finished` is never
+/// called.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_now`]
+/// * [`fn@crate::use_timestamp`]
+pub fn use_countdown_to(target: impl Into<Signal<DateTime<Utc>>>) -> UseCountdownToReturn {
+    use_countdown_to_with_options(target, UseCountdownToOptions::default())
+}
+
+/// Version of [`use_countdown_to`] that takes a `UseCountdownToOptions`. See [`use_countdown_to`] for how to use.
+pub fn use_countdown_to_with_options(
+    target: impl Into<Signal<DateTime<Utc>>>,
+    options: UseCountdownToOptions,
+) -> UseCountdownToReturn {
+    let UseCountdownToOptions { on_finished } = options;
+
+    let target = target.into();
+
+    let (now, set_now) = signal(now());
+
+    let remaining = Memo::new(move |_| {
+        let remaining = target.get().timestamp_millis() as f64 - now.get();
+        remaining.max(0.0)
+    });
+
+    let total_seconds = Memo::new(move |_| (remaining.get() / 1000.0).ceil() as u64);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+
+        let timeout = StoredValue::new(None::<TimeoutHandle>);
+
+        let _ = Effect::watch(
+            move || target.get(),
+            move |_, _, _| tick(target, set_now, timeout),
+            true,
+        );
+
+        let _ = use_event_listener(document(), leptos::ev::visibilitychange, move |_| {
+            if !document().hidden() {
+                tick(target, set_now, timeout);
+            }
+        });
+
+        let _ = Effect::watch(
+            move || remaining.get() == 0.0,
+            move |is_finished, was_finished, _| {
+                if *is_finished && was_finished == Some(&false) {
+                    on_finished();
+                }
+            },
+            false,
+        );
+
+        on_cleanup(move || {
+            if let Some(timeout) = timeout.try_update_value(Option::take).flatten() {
+                timeout.clear();
+            }
+        });
+    }
+
+    UseCountdownToReturn {
+        remaining: remaining.into(),
+        days: Signal::derive(move || total_seconds.get() / 86_400),
+        hours: Signal::derive(move || total_seconds.get() / 3600 % 24),
+        minutes: Signal::derive(move || total_seconds.get() / 60 % 60),
+        seconds: Signal::derive(move || total_seconds.get() % 60),
+        total_seconds: total_seconds.into(),
+        is_finished: Signal::derive(move || remaining.get() == 0.0),
+    }
+}
+
+/// Updates the current time and schedules the next update for when the displayed second changes.
+#[cfg(not(feature = "ssr"))]
+fn tick(
+    target: Signal<DateTime<Utc>>,
+    set_now: WriteSignal<f64>,
+    timeout: StoredValue<Option<TimeoutHandle>>,
+) {
+    if let Some(timeout) = timeout.try_update_value(Option::take).flatten() {
+        timeout.clear();
+    }
+
+    let Some(target_time) = target.try_get_untracked() else {
+        return;
+    };
+
+    let now = now();
+    set_now.set(now);
+
+    let remaining = target_time.timestamp_millis() as f64 - now;
+    if remaining <= 0.0 {
+        return;
+    }
+
+    let delay = match remaining % 1000.0 {
+        delay if delay > 0.0 => delay.ceil(),
+        _ => 1000.0,
+    };
+
+    timeout.try_set_value(
+        set_timeout_with_handle(
+            move || tick(target, set_now, timeout),
+            Duration::from_millis(delay as u64),
+        )
+        .ok(),
+    );
+}
+
+/// Options for [`use_countdown_to_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseCountdownToOptions {
+    /// Called when the target is reached. Defaults to a noop.
+    on_finished: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl Default for UseCountdownToOptions {
+    fn default() -> Self {
+        Self {
+            on_finished: Arc::new(|| {}),
+        }
+    }
+}
+
+/// Return type of [`use_countdown_to`].
+#[derive(Clone, Copy)]
+pub struct UseCountdownToReturn {
+    /// Remaining time in milliseconds. `0.0` once the target is reached.
+    pub remaining: Signal<f64>,
+
+    /// Remaining time in whole seconds, rounded up.
+    pub total_seconds: Signal<u64>,
+
+    /// Whole days of the remaining time.
+    pub days: Signal<u64>,
+
+    /// Hours of the remaining time without the days (`0` to `23`).
+    pub hours: Signal<u64>,
+
+    /// Minutes of the remaining time without the hours (`0` to `59`).
+    pub minutes: Signal<u64>,
+
+    /// Seconds of the remaining time without the minutes (`0` to `59`).
+    pub seconds: Signal<u64>,
+
+    /// Whether the target has been reached.
+    pub is_finished: Signal<bool>,
+}
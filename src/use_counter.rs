@@ -0,0 +1,146 @@
+use crate::core::MaybeRwSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Basic counter with utility functions.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_counter)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_counter, UseCounterReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let counter = use_counter(0);
+/// let UseCounterReturn { count, .. } = counter;
+///
+/// counter.inc(1); // count is 1
+/// counter.dec(3); // count is -2
+/// counter.set(10); // count is 10
+/// counter.reset(); // count is 0 again
+///
+/// view! {
+///     <p>"Count: " {count}</p>
+///     <button on:click=move |_| counter.inc(1)>"+"</button>
+///     <button on:click=move |_| counter.dec(1)>"-"</button>
+/// }
+/// # }
+/// ```
+///
+/// `reset` sets the count to the initial value. If you pass a signal, that's its value when
+/// `use_counter` is called.
+///
+/// ### Min and Max
+///
+/// The count can be kept in a range with the options `min` and `max`. `inc`, `dec` and `set`
+/// clamp the new value to that range.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_counter_with_options, UseCounterOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let quantity = use_counter_with_options(1, UseCounterOptions::default().min(1).max(10));
+///
+/// quantity.dec(5); // count is 1
+/// quantity.set(42); // count is 10
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## See also
+///
+/// * [`fn@crate::use_toggle`]
+/// * [`fn@crate::use_cycle_list`]
+pub fn use_counter(initial_value: impl Into<MaybeRwSignal<i64>>) -> UseCounterReturn {
+    use_counter_with_options(initial_value, UseCounterOptions::default())
+}
+
+/// Version of [`use_counter`] that takes a `UseCounterOptions`. See [`use_counter`] for how to use.
+pub fn use_counter_with_options(
+    initial_value: impl Into<MaybeRwSignal<i64>>,
+    options: UseCounterOptions,
+) -> UseCounterReturn {
+    let UseCounterOptions { min, max } = options;
+
+    let (count, set_count) = initial_value.into().into_signal();
+
+    let counter = UseCounterReturn {
+        count,
+        set_count,
+        initial_value: count.get_untracked(),
+        min,
+        max,
+    };
+
+    counter.set(counter.initial_value);
+
+    counter
+}
+
+/// Options for [`use_counter_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseCounterOptions {
+    /// The smallest value of the count. Defaults to `None` (no limit).
+    #[builder(into)]
+    min: Option<i64>,
+
+    /// The largest value of the count. Defaults to `None` (no limit).
+    #[builder(into)]
+    max: Option<i64>,
+}
+
+/// Return type of [`use_counter`].
+#[derive(Clone, Copy)]
+pub struct UseCounterReturn {
+    /// The current count
+    pub count: Signal<i64>,
+
+    set_count: WriteSignal<i64>,
+    initial_value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+}
+
+impl UseCounterReturn {
+    /// Increases the count by `by`.
+    pub fn inc(&self, by: i64) {
+        self.set(self.get().saturating_add(by));
+    }
+
+    /// Decreases the count by `by`.
+    pub fn dec(&self, by: i64) {
+        self.set(self.get().saturating_sub(by));
+    }
+
+    /// Sets the count to `value`.
+    pub fn set(&self, value: i64) {
+        let value = self.clamp(value);
+
+        if self.get() != value {
+            self.set_count.set(value);
+        }
+    }
+
+    /// Returns the current count without tracking it.
+    pub fn get(&self) -> i64 {
+        self.count.get_untracked()
+    }
+
+    /// Sets the count to the initial value.
+    pub fn reset(&self) {
+        self.set(self.initial_value);
+    }
+
+    fn clamp(&self, value: i64) -> i64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+}
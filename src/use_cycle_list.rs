@@ -30,6 +30,33 @@ use leptos::prelude::*;
 /// # view! { }
 /// # }
 /// ```
+///
+/// ### Options
+///
+/// By default `next`, `prev` and `shift` wrap around at the ends of the list. Set the option
+/// `wrap` to `false` to stop at the first and last item instead.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// use leptos_use::{use_cycle_list_with_options, UseCycleListOptions, UseCycleListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseCycleListReturn { state, prev, .. } = use_cycle_list_with_options(
+///     vec!["Dog", "Cat", "Lizard"],
+///     UseCycleListOptions::default().wrap(false),
+/// );
+///
+/// prev();
+///
+/// log!("{}", state.get()); // still "Dog"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Use the option `initial_value` to start with another item than the first one.
 pub fn use_cycle_list<T, L>(
     list: L,
 ) -> UseCycleListReturn<
@@ -46,6 +73,7 @@ where
     use_cycle_list_with_options(list, UseCycleListOptions::default())
 }
 
+/// Version of [`use_cycle_list`] that takes a `UseCycleListOptions`. See [`use_cycle_list`] for how to use.
 pub fn use_cycle_list_with_options<T, L>(
     list: L,
     options: UseCycleListOptions<T>,
@@ -64,6 +92,7 @@ where
         initial_value,
         fallback_index,
         get_position,
+        wrap,
     } = options;
 
     let list = list.into();
@@ -96,6 +125,10 @@ where
     let set = move |i: usize| {
         let length = list.read().len();
 
+        if length == 0 {
+            return state.get_untracked();
+        }
+
         let index = i % length;
         let value = list.read()[index].clone();
 
@@ -111,8 +144,16 @@ where
     let shift = move |delta: i64| {
         let length = list.read().len() as i64;
 
+        if length == 0 {
+            return state.get_untracked();
+        }
+
         let i = index.get_untracked() as i64 + delta;
-        let index = (i % length) + length;
+        let index = if wrap {
+            i.rem_euclid(length)
+        } else {
+            i.clamp(0, length - 1)
+        };
 
         set(index as usize)
     };
@@ -156,6 +197,10 @@ where
     /// Custom function to get the index of the current value. Defaults to `Iterator::position()`
     #[builder(keep_type)]
     get_position: fn(&T, &Vec<T>) -> Option<usize>,
+
+    /// Whether `next`, `prev` and `shift` wrap around at the ends of the list. If `false` they
+    /// stop at the first and last item. Defaults to `true`.
+    wrap: bool,
 }
 
 impl<T> Default for UseCycleListOptions<T>
@@ -167,6 +212,7 @@ where
             initial_value: None,
             fallback_index: 0,
             get_position: |value: &T, list: &Vec<T>| list.iter().position(|v| v == value),
+            wrap: true,
         }
     }
 }
@@ -188,10 +234,10 @@ where
     pub index: Signal<usize>,
     /// Set current index of current value in list
     pub set_index: SetFn,
-    /// Go to next value (cyclic)
+    /// Go to next value (cyclic unless the option `wrap` is `false`)
     pub next: NextFn,
-    /// Go to previous value (cyclic)
+    /// Go to previous value (cyclic unless the option `wrap` is `false`)
     pub prev: PrevFn,
-    /// Move by the specified amount from the current value (cyclic)
+    /// Move by the specified amount from the current value (cyclic unless the option `wrap` is `false`)
     pub shift: ShiftFn,
 }
@@ -1,5 +1,9 @@
+use crate::sendwrap_fn;
 pub use crate::utils::DebounceOptions;
-use crate::utils::{create_filter_wrapper, create_filter_wrapper_with_arg, debounce_filter};
+use crate::utils::{
+    create_filter_wrapper, create_filter_wrapper_with_arg, debounce_filter,
+    debounce_filter_with_cancel,
+};
 use leptos::prelude::Signal;
 use std::sync::{Arc, Mutex};
 
@@ -34,7 +38,8 @@ use std::sync::{Arc, Mutex};
 ///
 /// Please note that if the current component is cleaned up before the throttled callback is called, the throttled callback will not be called.
 ///
-/// You can also pass options to [`use_debounce_fn_with_options`] with a maximum wait time, similar to
+/// You can also pass options to [`use_debounce_fn_with_options`] with a maximum wait time and
+/// whether to invoke on the leading and/or trailing edge, similar to
 /// [lodash debounce](https://lodash.com/docs/#debounce).
 ///
 /// ```
@@ -51,7 +56,8 @@ use std::sync::{Arc, Mutex};
 ///     },
 ///     1000.0,
 ///     DebounceOptions::default()
-///         .max_wait(Some(5000.0)),
+///         .max_wait(Some(5000.0))
+///         .leading(true),
 /// );
 ///
 /// window_event_listener(resize, move |_| { debounced_fn(); });
@@ -59,6 +65,39 @@ use std::sync::{Arc, Mutex};
 /// # }
 /// ```
 ///
+/// With `leading(true)` the function is called immediately on the first call after a pause.
+/// Further calls within `ms` are debounced as usual and trigger another call on the trailing
+/// edge unless `trailing` is set to `false`.
+///
+/// ### Cancel
+///
+/// [`use_debounce_fn_with_cancel`] and [`use_debounce_fn_with_arg_and_cancel`] additionally
+/// return a `cancel` function. It drops the pending call together with its arguments so the
+/// function isn't invoked on the trailing edge. When the current component is cleaned up the
+/// pending call is dropped as well.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_debounce_fn_with_arg_and_cancel, UseDebounceFnReturn};
+/// # use leptos_use::utils::DebounceOptions;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDebounceFnReturn { run: save, cancel } = use_debounce_fn_with_arg_and_cancel(
+///     |text: String| {
+///         // save the draft
+///     },
+///     1000.0,
+///     DebounceOptions::default(),
+/// );
+///
+/// view! {
+///     <textarea on:input=move |e| { save(event_target_value(&e)); } />
+///     <button on:click=move |_| cancel()>"Discard"</button>
+/// }
+/// # }
+/// ```
+///
 /// Currently there is no way to use a function with a return value. Please open an issue if you need this.
 ///
 /// If you want to throttle a function that takes an argument there are also the versions
@@ -128,3 +167,61 @@ where
 {
     create_filter_wrapper_with_arg(Arc::new(debounce_filter(ms, options)), func)
 }
+
+/// Version of [`use_debounce_fn_with_options`] that also returns a function to cancel the pending
+/// call. See the docs for [`use_debounce_fn`] for how to use.
+pub fn use_debounce_fn_with_cancel<F, R>(
+    func: F,
+    ms: impl Into<Signal<f64>> + 'static,
+    options: DebounceOptions,
+) -> UseDebounceFnReturn<
+    impl Fn() -> Arc<Mutex<Option<R>>> + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    F: Fn() -> R + Clone + 'static,
+    R: 'static,
+{
+    let (filter, cancel) = debounce_filter_with_cancel(ms, options);
+
+    UseDebounceFnReturn {
+        run: create_filter_wrapper(Arc::new(filter), func),
+        cancel: sendwrap_fn!(move || cancel()),
+    }
+}
+
+/// Version of [`use_debounce_fn_with_arg_and_options`] that also returns a function to cancel the
+/// pending call. See the docs for [`use_debounce_fn`] for how to use.
+pub fn use_debounce_fn_with_arg_and_cancel<F, Arg, R>(
+    func: F,
+    ms: impl Into<Signal<f64>> + 'static,
+    options: DebounceOptions,
+) -> UseDebounceFnReturn<
+    impl Fn(Arg) -> Arc<Mutex<Option<R>>> + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    F: Fn(Arg) -> R + Clone + 'static,
+    Arg: Clone + 'static,
+    R: 'static,
+{
+    let (filter, cancel) = debounce_filter_with_cancel(ms, options);
+
+    UseDebounceFnReturn {
+        run: create_filter_wrapper_with_arg(Arc::new(filter), func),
+        cancel: sendwrap_fn!(move || cancel()),
+    }
+}
+
+/// Return type of [`use_debounce_fn_with_cancel`] and [`use_debounce_fn_with_arg_and_cancel`].
+pub struct UseDebounceFnReturn<RunFn, CancelFn>
+where
+    RunFn: Clone + Send + Sync,
+    CancelFn: Fn() + Clone + Send + Sync,
+{
+    /// The debounced function
+    pub run: RunFn,
+
+    /// Drops the pending call (if any) so it won't be invoked
+    pub cancel: CancelFn,
+}
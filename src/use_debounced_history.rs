@@ -0,0 +1,115 @@
+use crate::core::UseRwSignal;
+use crate::utils::DebounceOptions;
+use crate::{use_history_with_options, UseHistoryOptions, UseHistoryReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// A debounced version of [`fn@crate::use_history`].
+///
+/// Rapid consecutive changes are recorded as a single snapshot once the signal hasn't changed for
+/// `ms` milliseconds. This way typing creates one undo step per pause and not one per keystroke.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_debounced_history)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_debounced_history;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let text = RwSignal::new(String::new());
+///
+/// let history = use_debounced_history(text, 1000.0);
+///
+/// view! {
+///     <textarea prop:value=text on:input=move |e| text.set(event_target_value(&e)) />
+///     <button disabled=move || !history.can_undo.get() on:click=move |_| history.undo()>
+///         "Undo"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// This is only shorthand for `use_history_with_options(source, UseHistoryOptions::default().debounce(ms))`.
+/// It returns the same [`UseHistoryReturn`] with `undo`, `redo`, `commit` and so on.
+///
+/// A change that is still waiting to be recorded is dropped when `undo`, `redo`, `commit` or
+/// `reset` is called in the meantime. It's also dropped if the current component is cleaned up
+/// before it's recorded.
+///
+/// There's also [`use_debounced_history_with_options`] where you can specify the capacity and the
+/// maximum time a change waits to be recorded.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_debounced_history_with_options, UseDebouncedHistoryOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let text = RwSignal::new(String::new());
+/// #
+/// let history = use_debounced_history_with_options(
+///     text,
+///     1000.0,
+///     UseDebouncedHistoryOptions::default()
+///         .capacity(50_usize)
+///         .max_wait(Some(5000.0)),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server changes aren't recorded automatically, just like with [`fn@crate::use_history`].
+///
+/// ## See also
+///
+/// * [`fn@crate::use_history`]
+/// * [`fn@crate::watch_debounced`]
+pub fn use_debounced_history<T>(
+    source: impl Into<UseRwSignal<T>>,
+    ms: impl Into<Signal<f64>>,
+) -> UseHistoryReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    use_debounced_history_with_options(source, ms, UseDebouncedHistoryOptions::default())
+}
+
+/// Version of [`use_debounced_history`] that takes a `UseDebouncedHistoryOptions`. See [`use_debounced_history`] for how to use.
+pub fn use_debounced_history_with_options<T>(
+    source: impl Into<UseRwSignal<T>>,
+    ms: impl Into<Signal<f64>>,
+    options: UseDebouncedHistoryOptions,
+) -> UseHistoryReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let UseDebouncedHistoryOptions { capacity, max_wait } = options;
+
+    use_history_with_options(
+        source,
+        UseHistoryOptions::default()
+            .capacity::<usize>(capacity)
+            .debounce_with_options(ms, DebounceOptions::default().max_wait(max_wait)),
+    )
+}
+
+/// Options for [`use_debounced_history_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseDebouncedHistoryOptions {
+    /// Maximum number of snapshots in the undo stack. Defaults to `None` (no limit).
+    #[builder(into)]
+    capacity: Option<usize>,
+
+    /// The maximum time a change waits to be recorded in milliseconds.
+    /// Same as [`DebounceOptions::max_wait`]
+    #[builder(into)]
+    max_wait: Signal<Option<f64>>,
+}
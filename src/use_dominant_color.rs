@@ -0,0 +1,297 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive dominant color, average color and palette of an image.
+///
+/// Whenever `url` changes, the image is loaded, scaled down and its pixels are sampled on an
+/// [`OffscreenCanvas`](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas). This
+/// happens in a [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API)
+/// that is generated from an inline script, so it doesn't block the UI. Useful for backgrounds
+/// that adapt to an image.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_dominant_color)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_dominant_color, UseDominantColorReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (url, set_url) = signal(Some("/cover.jpg".to_string()));
+///
+/// let UseDominantColorReturn { color, palette, .. } = use_dominant_color(url);
+///
+/// view! {
+///     <div style:background-color=move || color.get().unwrap_or_default()>
+///         <img src=move || url.get() />
+///     </div>
+///     <For each=move || palette.get() key=|color| color.clone() let:color>
+///         <span style:background-color=color>" "</span>
+///     </For>
+/// }
+/// # }
+/// ```
+///
+/// All colors are CSS color strings like `"rgb(12, 34, 56)"`. `color` is the most common color,
+/// `average` the mean of all pixels and `palette` the most common colors in descending order.
+/// Transparent pixels are ignored.
+///
+/// The image is fetched by the worker. Images from other origins have to be served with
+/// [CORS](https://developer.mozilla.org/en-US/docs/Web/HTTP/CORS) headers. Object URLs of
+/// files selected by the user work as well.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is created. `color`, `average` and `error` are always `None`,
+/// `palette` is always empty and `is_loading` is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_image_transform`]
+pub fn use_dominant_color(url: impl Into<Signal<Option<String>>>) -> UseDominantColorReturn {
+    use_dominant_color_with_options(url, UseDominantColorOptions::default())
+}
+
+/// Version of [`use_dominant_color`] that takes a `UseDominantColorOptions`. See [`use_dominant_color`] for how to use.
+pub fn use_dominant_color_with_options(
+    url: impl Into<Signal<Option<String>>>,
+    options: UseDominantColorOptions,
+) -> UseDominantColorReturn {
+    let UseDominantColorOptions {
+        palette_size,
+        sample_size,
+    } = options;
+
+    let url = url.into();
+
+    let (average, set_average) = signal(None::<String>);
+    let (palette, set_palette) = signal(Vec::<String>::new());
+    let (is_loading, set_loading) = signal(false);
+    let (error, set_error) = signal_local(None::<UseDominantColorError>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use crate::utils::create_inline_worker;
+
+        let run_id = StoredValue::new(0_u64);
+
+        let worker = match create_inline_worker(WORKER_SCRIPT) {
+            Ok((worker, worker_url)) => {
+                let _ = use_event_listener(worker.clone(), leptos::ev::message, move |event| {
+                    let data = event.data();
+                    let get = |key: &str| js_sys::Reflect::get(&data, &key.into()).ok();
+
+                    // results of previous images are outdated
+                    if get("id").and_then(|id| id.as_f64()) != Some(run_id.get_value() as f64) {
+                        return;
+                    }
+
+                    set_loading.set(false);
+
+                    if let Some(error) = get("error").and_then(|error| error.as_string()) {
+                        set_error.set(Some(UseDominantColorError::Sample(error)));
+                        return;
+                    }
+
+                    set_average.set(get("average").and_then(|color| css_color(&color)));
+                    set_palette.set(
+                        get("palette")
+                            .map(|palette| {
+                                js_sys::Array::from(&palette)
+                                    .iter()
+                                    .filter_map(|color| css_color(&color))
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    );
+                });
+
+                let _ = use_event_listener(worker.clone(), leptos::ev::error, move |event| {
+                    set_loading.set(false);
+                    set_error.set(Some(UseDominantColorError::Event(event.into())));
+                });
+
+                Some((worker, worker_url))
+            }
+            Err(err) => {
+                set_error.set(Some(UseDominantColorError::Create(err)));
+                None
+            }
+        };
+        let worker = StoredValue::new_local(worker);
+
+        let _ = Effect::watch(
+            move || url.get(),
+            move |url, _, _| {
+                let id = run_id.get_value() + 1;
+                run_id.set_value(id);
+
+                set_average.set(None);
+                set_palette.set(vec![]);
+                set_error.set(None);
+                set_loading.set(false);
+
+                let (Some(url), Some((worker, _))) = (url.clone(), worker.get_value()) else {
+                    return;
+                };
+
+                let message = js_sys::Object::new();
+                let set = |key: &str, value: JsValue| {
+                    let _ = js_sys::Reflect::set(&message, &key.into(), &value);
+                };
+
+                set("id", JsValue::from_f64(id as f64));
+                set("url", url.into());
+                set("sampleSize", sample_size.into());
+                set("paletteSize", (palette_size as u32).into());
+
+                match worker.post_message(&message) {
+                    Ok(()) => set_loading.set(true),
+                    Err(err) => set_error.set(Some(UseDominantColorError::PostMessage(err))),
+                }
+            },
+            true,
+        );
+
+        on_cleanup(move || {
+            if let Some((worker, worker_url)) = worker.try_update_value(Option::take).flatten() {
+                worker.terminate();
+                let _ = web_sys::Url::revoke_object_url(&worker_url);
+            }
+        });
+    }
+
+    UseDominantColorReturn {
+        color: Signal::derive(move || palette.with(|palette| palette.first().cloned())),
+        average: average.into(),
+        palette: palette.into(),
+        is_loading: is_loading.into(),
+        error: error.into(),
+    }
+}
+
+/// Source of the worker that samples the images. Pixels are grouped into buckets of similar
+/// colors (4 bits per channel). The palette consists of the averages of the largest buckets.
+const WORKER_SCRIPT: &str = r#"self.onmessage = async ({ data: { id, url, sampleSize, paletteSize } }) => {
+  try {
+    const response = await fetch(url);
+    const bitmap = await createImageBitmap(await response.blob());
+    const scale = Math.min(1, sampleSize / bitmap.width, sampleSize / bitmap.height);
+    const width = Math.max(1, Math.round(bitmap.width * scale));
+    const height = Math.max(1, Math.round(bitmap.height * scale));
+
+    const context = new OffscreenCanvas(width, height).getContext("2d");
+    context.drawImage(bitmap, 0, 0, width, height);
+    bitmap.close();
+    const { data } = context.getImageData(0, 0, width, height);
+
+    const total = [0, 0, 0, 0];
+    const buckets = new Map();
+
+    for (let i = 0; i < data.length; i += 4) {
+      if (data[i + 3] < 128) continue;
+
+      const [r, g, b] = [data[i], data[i + 1], data[i + 2]];
+      const key = ((r >> 4) << 8) | ((g >> 4) << 4) | (b >> 4);
+      const bucket = buckets.get(key) ?? [0, 0, 0, 0];
+
+      for (const sum of [total, bucket]) {
+        sum[0] += r;
+        sum[1] += g;
+        sum[2] += b;
+        sum[3] += 1;
+      }
+
+      buckets.set(key, bucket);
+    }
+
+    const toColor = ([r, g, b, count]) => [r, g, b].map((sum) => Math.round(sum / count));
+
+    const palette = [...buckets.values()]
+      .sort((a, b) => b[3] - a[3])
+      .slice(0, paletteSize)
+      .map(toColor);
+
+    self.postMessage({ id, average: total[3] > 0 ? toColor(total) : null, palette });
+  } catch (error) {
+    self.postMessage({ id, error: String(error) });
+  }
+};
+"#;
+
+/// Turns an array `[r, g, b]` into a CSS color string.
+#[cfg(not(feature = "ssr"))]
+fn css_color(color: &JsValue) -> Option<String> {
+    if !color.is_array() {
+        return None;
+    }
+
+    let color = js_sys::Array::from(color);
+    let channel = |i| color.get(i).as_f64().map(|channel| channel as u8);
+
+    Some(format!(
+        "rgb({}, {}, {})",
+        channel(0)?,
+        channel(1)?,
+        channel(2)?
+    ))
+}
+
+/// Options for [`use_dominant_color_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseDominantColorOptions {
+    /// Maximum number of colors in the palette. Defaults to `5`.
+    palette_size: usize,
+
+    /// Before sampling, the image is scaled down to fit into a square of this many pixels. Larger
+    /// values are more precise but slower. Defaults to `64`.
+    sample_size: u32,
+}
+
+impl Default for UseDominantColorOptions {
+    fn default() -> Self {
+        Self {
+            palette_size: 5,
+            sample_size: 64,
+        }
+    }
+}
+
+/// Return type of [`use_dominant_color`].
+pub struct UseDominantColorReturn {
+    /// The most common color of the current image.
+    pub color: Signal<Option<String>>,
+
+    /// The average color of the current image.
+    pub average: Signal<Option<String>>,
+
+    /// The most common colors of the current image in descending order.
+    pub palette: Signal<Vec<String>>,
+
+    /// Whether the current image is being loaded and sampled.
+    pub is_loading: Signal<bool>,
+
+    /// Latest error.
+    pub error: Signal<Option<UseDominantColorError>, LocalStorage>,
+}
+
+#[derive(Debug, Error)]
+pub enum UseDominantColorError {
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("worker error event")]
+    Event(web_sys::Event),
+    #[error("failed to sample the image: {0}")]
+    Sample(String),
+}
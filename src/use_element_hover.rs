@@ -1,5 +1,8 @@
 use crate::core::IntoElementMaybeSignal;
-use crate::{use_event_listener_with_options, UseEventListenerOptions};
+use crate::utils::{
+    create_filter_wrapper_with_arg, DebounceOptions, FilterOptions, ThrottleOptions,
+};
+use crate::{filter_builder_methods, use_event_listener_with_options, UseEventListenerOptions};
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::{mouseenter, mouseleave};
 use leptos::leptos_dom::helpers::TimeoutHandle;
@@ -30,6 +33,28 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// The event handlers can be debounced, throttled or paused with the same filters as
+/// [`fn@crate::watch_with_options`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Button;
+/// # use leptos_use::{use_element_hover_with_options, UseElementHoverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Button>::new();
+/// let (tooltips_enabled, set_tooltips_enabled) = signal(true);
+///
+/// let is_hovered = use_element_hover_with_options(
+///     el,
+///     UseElementHoverOptions::default().pausable(tooltips_enabled),
+/// );
+/// #
+/// # view! { <button node_ref=el></button> }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `false`.
@@ -53,6 +78,7 @@ where
     let UseElementHoverOptions {
         delay_enter,
         delay_leave,
+        event_filter,
     } = options;
 
     let (is_hovered, set_hovered) = signal(false);
@@ -84,15 +110,32 @@ where
         }
     };
 
+    let toggle = create_filter_wrapper_with_arg(event_filter.filter_fn(), toggle);
+
     let listener_options = UseEventListenerOptions::default().passive(true);
 
     let el = el.into_element_maybe_signal();
 
-    let _ =
-        use_event_listener_with_options(el, mouseenter, move |_| toggle(true), listener_options);
+    let _ = use_event_listener_with_options(
+        el,
+        mouseenter,
+        {
+            let toggle = toggle.clone();
+            move |_| {
+                toggle(true);
+            }
+        },
+        listener_options,
+    );
 
-    let _ =
-        use_event_listener_with_options(el, mouseleave, move |_| toggle(false), listener_options);
+    let _ = use_event_listener_with_options(
+        el,
+        mouseleave,
+        move |_| {
+            toggle(false);
+        },
+        listener_options,
+    );
 
     is_hovered.into()
 }
@@ -105,4 +148,14 @@ pub struct UseElementHoverOptions {
 
     /// The time in ms after the mouse has left the element before the signal is changed to `false`. Defaults to `0`.
     delay_leave: u64,
+
+    /// Allows to debounce, throttle or pause the event handlers. Defaults to no filter.
+    event_filter: FilterOptions,
+}
+
+impl UseElementHoverOptions {
+    filter_builder_methods!(
+        /// the event handlers
+        event_filter
+    );
 }
@@ -2,10 +2,12 @@ use crate::core::IntoElementMaybeSignal;
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::EventDescriptor;
+use leptos::prelude::*;
+use std::ops::Deref;
+use std::sync::Arc;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
     use crate::{watch_with_options, WatchOptions, sendwrap_fn};
-    use leptos::prelude::*;
     use std::cell::RefCell;
     use std::rc::Rc;
     use wasm_bindgen::closure::Closure;
@@ -64,7 +66,7 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
-/// You can also call the returned to unregister the listener.
+/// You can also call the returned value to unregister the listener.
 ///
 /// ```
 /// # use leptos::prelude::*;
@@ -85,19 +87,53 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ### Pause and Resume
+///
+/// The returned [`UseEventListenerReturn`] can also temporarily remove the listener with `pause`
+/// and add it again with `resume`. The handler isn't recreated for that. `is_active` tells whether
+/// the listener is currently attached.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::ev::keydown;
+/// # use leptos::logging::log;
+/// # use web_sys::KeyboardEvent;
+/// # use leptos_use::use_event_listener;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let shortcuts = use_event_listener(document().body(), keydown, |evt: KeyboardEvent| {
+///     log!("{}", &evt.key());
+/// });
+///
+/// let listening = shortcuts.is_active;
+///
+/// view! {
+///     <input
+///         on:focus={
+///             let shortcuts = shortcuts.clone();
+///             move |_| shortcuts.pause()
+///         }
+///         on:blur=move |_| shortcuts.resume()
+///     />
+///     <p>{move || if listening.get() { "Shortcuts enabled" } else { "Shortcuts disabled" }}</p>
+/// }
+/// # }
+/// ```
+///
+/// While paused, the listener still follows changes of the target and is attached to the new
+/// target once it's resumed. A listener with the option `once` that has already fired stays
+/// removed, `resume` doesn't do anything in that case.
+///
 /// ## SendWrapped Return
 ///
-/// The returned closure is a sendwrapped function. It can
+/// The functions of the return value are sendwrapped. They can
 /// only be called from the same thread that called `use_event_listener`.
 ///
 /// ## Server-Side Rendering
 ///
 /// On the server this amounts to a noop.
-pub fn use_event_listener<Ev, El, M, F>(
-    target: El,
-    event: Ev,
-    handler: F,
-) -> impl Fn() + Clone + Send + Sync
+pub fn use_event_listener<Ev, El, M, F>(target: El, event: Ev, handler: F) -> UseEventListenerReturn
 where
     Ev: EventDescriptor + 'static,
     El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
@@ -114,7 +150,7 @@ pub fn use_event_listener_with_options<Ev, El, M, F>(
     event: Ev,
     mut handler: F,
     options: UseEventListenerOptions,
-) -> impl Fn() + Clone + Send + Sync
+) -> UseEventListenerReturn
 where
     Ev: EventDescriptor + 'static,
     El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
@@ -122,22 +158,43 @@ where
 {
     #[cfg(feature = "ssr")]
     {
-        || {}
+        UseEventListenerReturn {
+            is_active: Signal::derive(|| false),
+            stop: Arc::new(|| {}),
+            pause: Arc::new(|| {}),
+            resume: Arc::new(|| {}),
+        }
     }
 
     #[cfg(not(feature = "ssr"))]
     {
         use send_wrapper::SendWrapper;
-        let event_name = event.name();
-        let closure_js = Closure::wrap(Box::new(move |e| {
-            #[cfg(debug_assertions)]
-            let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+        use std::cell::Cell;
+
+        let (is_active, set_active) = signal(false);
+
+        // with `once` the browser removes the listener after it has fired
+        let has_fired = Rc::new(Cell::new(false));
 
-            handler(e);
+        let closure_js = Closure::wrap(Box::new({
+            let has_fired = Rc::clone(&has_fired);
+
+            move |e| {
+                #[cfg(debug_assertions)]
+                let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                if options.once {
+                    has_fired.set(true);
+                    set_active.try_set(false);
+                }
+
+                handler(e);
+            }
         }) as Box<dyn FnMut(_)>)
         .into_js_value();
 
-        let cleanup_fn = {
+        let detach = {
+            let event_name = event.name();
             let closure_js = closure_js.clone();
             let options = options.as_add_event_listener_options();
 
@@ -150,24 +207,42 @@ where
             }
         };
 
-        let event_name = event.name();
+        let attach = {
+            let event_name = event.name();
+
+            move |element: &web_sys::EventTarget| {
+                let options = options.as_add_event_listener_options();
+
+                _ = element.add_event_listener_with_callback_and_add_event_listener_options(
+                    &event_name,
+                    closure_js.as_ref().unchecked_ref(),
+                    &options,
+                );
+            }
+        };
 
         let signal = target.into_element_maybe_signal();
 
         let prev_element = Rc::new(RefCell::new(None::<web_sys::EventTarget>));
+        let is_paused = Rc::new(Cell::new(false));
 
         let cleanup_prev_element = {
             let prev_element = prev_element.clone();
+            let detach = detach.clone();
 
             move || {
                 if let Some(element) = prev_element.take() {
-                    cleanup_fn(&element);
+                    detach(&element);
                 }
+                set_active.try_set(false);
             }
         };
 
         let stop_watch = {
             let cleanup_prev_element = cleanup_prev_element.clone();
+            let prev_element = prev_element.clone();
+            let is_paused = Rc::clone(&is_paused);
+            let attach = attach.clone();
 
             watch_with_options(
                 move || signal.get(),
@@ -176,14 +251,10 @@ where
                     prev_element.replace(element.clone());
 
                     if let Some(element) = element {
-                        let options = options.as_add_event_listener_options();
-
-                        _ = element
-                            .add_event_listener_with_callback_and_add_event_listener_options(
-                                &event_name,
-                                closure_js.as_ref().unchecked_ref(),
-                                &options,
-                            );
+                        if !is_paused.get() {
+                            attach(element);
+                            set_active.set(true);
+                        }
                     }
                 },
                 WatchOptions::default().immediate(true),
@@ -195,13 +266,84 @@ where
             cleanup_prev_element();
         });
 
+        let pause = {
+            let prev_element = prev_element.clone();
+            let is_paused = Rc::clone(&is_paused);
+
+            sendwrap_fn!(move || {
+                is_paused.set(true);
+
+                if let Some(element) = prev_element.borrow().as_ref() {
+                    detach(element);
+                }
+                set_active.try_set(false);
+            })
+        };
+
+        let resume = sendwrap_fn!(move || {
+            if has_fired.get() {
+                return;
+            }
+
+            is_paused.set(false);
+
+            if let Some(element) = prev_element.borrow().as_ref() {
+                attach(element);
+                set_active.try_set(true);
+            }
+        });
+
         on_cleanup({
             let stop = SendWrapper::new(stop.clone());
             #[allow(clippy::redundant_closure)]
             move || stop()
         });
 
-        stop
+        UseEventListenerReturn {
+            is_active: is_active.into(),
+            stop: Arc::new(stop),
+            pause: Arc::new(pause),
+            resume: Arc::new(resume),
+        }
+    }
+}
+
+/// Return type of [`use_event_listener`].
+///
+/// It can be called like a function to remove the listener for good, just like
+/// [`UseEventListenerReturn::stop`].
+#[derive(Clone)]
+pub struct UseEventListenerReturn {
+    /// Whether the listener is currently attached to an element and not paused or stopped
+    pub is_active: Signal<bool>,
+
+    stop: Arc<dyn Fn() + Send + Sync>,
+    pause: Arc<dyn Fn() + Send + Sync>,
+    resume: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl UseEventListenerReturn {
+    /// Removes the listener and stops reacting to changes of the target.
+    pub fn stop(&self) {
+        (self.stop)();
+    }
+
+    /// Removes the listener temporarily. The listener keeps following changes of the target.
+    pub fn pause(&self) {
+        (self.pause)();
+    }
+
+    /// Adds the listener again after it has been paused.
+    pub fn resume(&self) {
+        (self.resume)();
+    }
+}
+
+impl Deref for UseEventListenerReturn {
+    type Target = dyn Fn() + Send + Sync;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.stop
     }
 }
 
@@ -1,12 +1,11 @@
-use crate::core::ConnectionReadyState;
-use crate::{js, sendwrap_fn, use_event_listener, ReconnectLimit};
+use crate::core::{BackoffPolicy, BackoffStrategy, ConnectionReadyState, ReconnectLimit};
+use crate::{js, sendwrap_fn, use_event_listener};
 use codee::Decoder;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use std::marker::PhantomData;
 use std::sync::atomic::{AtomicBool, AtomicU32};
 use std::sync::Arc;
-use std::time::Duration;
 use thiserror::Error;
 
 /// Reactive [EventSource](https://developer.mozilla.org/en-US/docs/Web/API/EventSource)
@@ -80,7 +79,8 @@ use thiserror::Error;
 /// Reconnect on errors automatically (enabled by default).
 ///
 /// You can control the number of reconnection attempts by setting `reconnect_limit` and the
-/// interval between them by setting `reconnect_interval`.
+/// interval between them by setting `reconnect_interval`. For more control, like exponentially
+/// growing delays with jitter, pass a [`BackoffPolicy`] to `reconnect_policy`.
 ///
 /// ```
 /// # use leptos::prelude::*;
@@ -102,6 +102,29 @@ use thiserror::Error;
 /// # }
 /// ```
 ///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_event_source_with_options, UseEventSourceReturn, UseEventSourceOptions, BackoffPolicy, ReconnectLimit};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseEventSourceReturn {
+///     ready_state, data, error, close, ..
+/// } = use_event_source_with_options::<bool, FromToStringCodec>(
+///     "https://event-source-url",
+///     UseEventSourceOptions::default().reconnect_policy(
+///         BackoffPolicy::exponential(1000)     // wait 1s, 2s, 4s, ...
+///             .max_delay(60_000_u64)           // but never longer than a minute
+///             .jitter(0.1)
+///             .max_attempts(ReconnectLimit::Infinite)
+///     )
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 ///
 /// ## SendWrapped Return
 ///
@@ -146,8 +169,7 @@ where
     C::Error: Send + Sync,
 {
     let UseEventSourceOptions {
-        reconnect_limit,
-        reconnect_interval,
+        reconnect_policy,
         on_failed,
         immediate,
         named_events,
@@ -211,9 +233,16 @@ where
 
             set_event_source.set(Some(es.clone()));
 
-            let on_open = Closure::wrap(Box::new(move |_: web_sys::Event| {
-                set_ready_state.set(ConnectionReadyState::Open);
-                set_error.set(None);
+            let on_open = Closure::wrap(Box::new({
+                let retried = Arc::clone(&retried);
+
+                move |_: web_sys::Event| {
+                    set_ready_state.set(ConnectionReadyState::Open);
+                    set_error.set(None);
+
+                    // a successful connection starts the reconnection attempts from scratch
+                    retried.store(0, std::sync::atomic::Ordering::Relaxed);
+                }
             }) as Box<dyn FnMut(web_sys::Event)>);
             es.set_onopen(Some(on_open.as_ref().unchecked_ref()));
             on_open.forget();
@@ -225,28 +254,31 @@ where
                 let es = es.clone();
 
                 move |e: web_sys::Event| {
-                    set_ready_state.set(ConnectionReadyState::Closed);
+                    // readyState 0 means the browser is already trying to reconnect by itself
+                    set_ready_state.set(if es.ready_state() == 0 {
+                        ConnectionReadyState::Connecting
+                    } else {
+                        ConnectionReadyState::Closed
+                    });
                     set_error.set(Some(UseEventSourceError::Event(e)));
 
                     // only reconnect if EventSource isn't reconnecting by itself
                     // this is the case when the connection is closed (readyState is 2)
                     if es.ready_state() == 2
                         && !explicitly_closed.load(std::sync::atomic::Ordering::Relaxed)
-                        && matches!(reconnect_limit, ReconnectLimit::Limited(_))
                     {
                         es.close();
 
-                        let retried_value =
-                            retried.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+                        let attempt = retried.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
-                        if reconnect_limit.is_exceeded_by(retried_value as u64) {
+                        if let Some(delay) = reconnect_policy.delay(attempt as u64) {
                             set_timeout(
                                 move || {
                                     if let Some(init) = init.get_value() {
                                         init();
                                     }
                                 },
-                                Duration::from_millis(reconnect_interval),
+                                delay,
                             );
                         } else {
                             #[cfg(debug_assertions)]
@@ -328,12 +360,10 @@ pub struct UseEventSourceOptions<T>
 where
     T: 'static,
 {
-    /// Retry times. Defaults to `ReconnectLimit::Limited(3)`. Use `ReconnectLimit::Infinite` for
-    /// infinite retries.
-    reconnect_limit: ReconnectLimit,
-
-    /// Retry interval in ms. Defaults to 3000.
-    reconnect_interval: u64,
+    /// Delay between and maximum number of reconnection attempts.
+    /// Defaults to 3 attempts with a fixed interval of 3000ms. See [`BackoffPolicy`].
+    #[builder(into)]
+    reconnect_policy: BackoffPolicy,
 
     /// On maximum retry times reached.
     on_failed: Arc<dyn Fn() + Send + Sync>,
@@ -356,8 +386,7 @@ where
 impl<T> Default for UseEventSourceOptions<T> {
     fn default() -> Self {
         Self {
-            reconnect_limit: ReconnectLimit::default(),
-            reconnect_interval: 3000,
+            reconnect_policy: BackoffPolicy::default(),
             on_failed: Arc::new(|| {}),
             immediate: true,
             named_events: vec![],
@@ -367,6 +396,28 @@ impl<T> Default for UseEventSourceOptions<T> {
     }
 }
 
+impl<T> UseEventSourceOptions<T> {
+    /// Retry times. Defaults to `ReconnectLimit::Limited(3)`. Use `ReconnectLimit::Infinite` for
+    /// infinite retries. Shorthand for setting the maximum attempts of the `reconnect_policy`.
+    pub fn reconnect_limit(self, limit: ReconnectLimit) -> Self {
+        Self {
+            reconnect_policy: self.reconnect_policy.max_attempts(limit),
+            ..self
+        }
+    }
+
+    /// Retry interval in ms. Defaults to 3000. Shorthand for setting a fixed delay as the
+    /// strategy of the `reconnect_policy`.
+    pub fn reconnect_interval(self, interval: u64) -> Self {
+        Self {
+            reconnect_policy: self
+                .reconnect_policy
+                .strategy(BackoffStrategy::Fixed(interval)),
+            ..self
+        }
+    }
+}
+
 /// Return type of [`use_event_source`].
 pub struct UseEventSourceReturn<T, Err, OpenFn, CloseFn>
 where
@@ -0,0 +1,286 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Attribute that marks the links to other origins.
+const EXTERNAL_ATTRIBUTE: &str = "data-external";
+
+/// Hardens links to other sites inside an element.
+///
+/// Every link inside `container` that points to another origin gets `rel="noopener noreferrer"`
+/// and `target="_blank"`. Links that are added or changed later are handled as well. This is
+/// useful for user generated or markdown rendered content where you don't control the HTML.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_external_links)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Article;
+/// # use leptos_use::use_external_links;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let rendered_markdown = r#"<a href="https://leptos.dev">Leptos</a>"#;
+/// let article = NodeRef::<Article>::new();
+///
+/// use_external_links(article);
+///
+/// view! {
+///     <article node_ref=article inner_html=rendered_markdown />
+/// }
+/// # }
+/// ```
+///
+/// The tokens of `rel` are added to the ones the link already has. External links also get the
+/// attribute `data-external` so they can be styled, for example with an icon.
+///
+/// Only `http` and `https` links are considered. Links like `mailto:` or `#section` are left alone.
+///
+/// ### Confirm Before Leaving
+///
+/// `on_leave` is called with the URL when an external link is clicked. Return `false` to stay on
+/// the page.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Article;
+/// # use leptos_use::{use_external_links_with_options, UseExternalLinksOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let article = NodeRef::<Article>::new();
+/// use_external_links_with_options(
+///     article,
+///     UseExternalLinksOptions::default()
+///         .target::<String>(None)
+///         .on_leave(|url: String| {
+///             window()
+///                 .confirm_with_message(&format!("You're about to leave for {url}"))
+///                 .unwrap_or(false)
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// With `target` set to `None` the `target` attribute of the links isn't changed.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the links aren't changed and `count` is always `0`. Because of that, links that
+/// are rendered on the server only get their attributes after hydration.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_copy_buttons`]
+pub fn use_external_links<El, M>(
+    container: El,
+) -> UseExternalLinksReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_external_links_with_options(container, UseExternalLinksOptions::default())
+}
+
+/// Version of [`use_external_links`] that takes a `UseExternalLinksOptions`. See [`use_external_links`] for how to use.
+pub fn use_external_links_with_options<El, M>(
+    container: El,
+    options: UseExternalLinksOptions,
+) -> UseExternalLinksReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let (count, set_count) = signal(0_usize);
+
+    let refresh;
+
+    #[cfg(feature = "ssr")]
+    {
+        refresh = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_event_listener, use_mutation_observer_with_options, UseMutationObserverOptions,
+        };
+        use leptos::ev::click;
+        use wasm_bindgen::JsCast;
+
+        let UseExternalLinksOptions {
+            rel,
+            target,
+            on_leave,
+        } = options;
+
+        let container = container.into_element_maybe_signal();
+
+        refresh = move || {
+            let Some(links) = container
+                .get_untracked()
+                .and_then(|container| container.query_selector_all("a[href]").ok())
+            else {
+                return;
+            };
+
+            let origin = window().location().origin().unwrap_or_default();
+            let mut new_count = 0;
+
+            for i in 0..links.length() {
+                let Some(link) = links
+                    .item(i)
+                    .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+                else {
+                    continue;
+                };
+
+                if external_url(&link, &origin).is_some() {
+                    harden(&link, &rel, target.as_deref());
+                    new_count += 1;
+                } else if link.has_attribute(EXTERNAL_ATTRIBUTE) {
+                    // the href has been changed to an internal one
+                    let _ = link.remove_attribute(EXTERNAL_ATTRIBUTE);
+                }
+            }
+
+            if count.get_untracked() != new_count {
+                set_count.set(new_count);
+            }
+        };
+
+        let _ = Effect::watch(
+            move || container.get(),
+            {
+                let refresh = refresh.clone();
+                move |_, _, _| refresh()
+            },
+            true,
+        );
+
+        // only changes of `href` are observed so setting `rel` and `target` doesn't trigger this
+        use_mutation_observer_with_options(
+            container,
+            {
+                let refresh = refresh.clone();
+                move |_, _| refresh()
+            },
+            UseMutationObserverOptions::default()
+                .subtree(true)
+                .child_list(true)
+                .attributes(true)
+                .attribute_filter(vec!["href".to_string()]),
+        );
+
+        let _ = use_event_listener(container, click, move |event| {
+            let Some(link) = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .and_then(|target| target.closest("a[href]").ok().flatten())
+            else {
+                return;
+            };
+
+            let origin = window().location().origin().unwrap_or_default();
+
+            if let Some(url) = external_url(&link, &origin) {
+                if !on_leave(url) {
+                    event.prevent_default();
+                }
+            }
+        });
+    }
+
+    UseExternalLinksReturn {
+        count: count.into(),
+        refresh,
+    }
+}
+
+/// Returns the absolute URL of `link` if it's an `http(s)` link to another origin than `origin`.
+#[cfg(not(feature = "ssr"))]
+fn external_url(link: &web_sys::Element, origin: &str) -> Option<String> {
+    let href = link.get_attribute("href")?;
+    let base = document().base_uri().ok().flatten().unwrap_or_default();
+    let url = web_sys::Url::new_with_base(&href, &base).ok()?;
+
+    let is_http = matches!(url.protocol().as_str(), "http:" | "https:");
+
+    (is_http && url.origin() != origin).then(|| url.href())
+}
+
+/// Adds the `rel` tokens, sets `target` and marks `link` as external.
+#[cfg(not(feature = "ssr"))]
+fn harden(link: &web_sys::Element, rel: &str, target: Option<&str>) {
+    let current_rel = link.get_attribute("rel").unwrap_or_default();
+
+    let mut tokens = current_rel.split_whitespace().collect::<Vec<_>>();
+    for token in rel.split_whitespace() {
+        if !tokens.iter().any(|t| t.eq_ignore_ascii_case(token)) {
+            tokens.push(token);
+        }
+    }
+
+    let new_rel = tokens.join(" ");
+    if !new_rel.is_empty() && new_rel != current_rel {
+        let _ = link.set_attribute("rel", &new_rel);
+    }
+
+    if let Some(target) = target {
+        if link.get_attribute("target").as_deref() != Some(target) {
+            let _ = link.set_attribute("target", target);
+        }
+    }
+
+    if !link.has_attribute(EXTERNAL_ATTRIBUTE) {
+        let _ = link.set_attribute(EXTERNAL_ATTRIBUTE, "");
+    }
+}
+
+/// Options for [`use_external_links_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseExternalLinksOptions {
+    /// Tokens that are added to the `rel` attribute of external links.
+    /// Defaults to `"noopener noreferrer"`.
+    #[builder(into)]
+    rel: String,
+
+    /// Value of the `target` attribute of external links. `None` leaves it as it is.
+    /// Defaults to `Some("_blank")`.
+    #[builder(into)]
+    target: Option<String>,
+
+    /// Called with the URL when an external link is clicked. Return `false` to prevent the
+    /// navigation. Defaults to always allowing it.
+    on_leave: Arc<dyn Fn(String) -> bool + Send + Sync>,
+}
+
+impl Default for UseExternalLinksOptions {
+    fn default() -> Self {
+        Self {
+            rel: "noopener noreferrer".to_string(),
+            target: Some("_blank".to_string()),
+            on_leave: Arc::new(|_| true),
+        }
+    }
+}
+
+/// Return type of [`use_external_links`].
+pub struct UseExternalLinksReturn<RefreshFn>
+where
+    RefreshFn: Fn() + Clone + Send + Sync,
+{
+    /// The number of external links inside the container
+    pub count: Signal<usize>,
+
+    /// Finds and hardens the links again
+    pub refresh: RefreshFn,
+}
@@ -0,0 +1,845 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::BackoffPolicy;
+use crate::{js_fut, sendwrap_fn, FetchPriority, FetchQueue};
+use codee::Decoder;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Fetch API](https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API).
+///
+/// Provides the state of the request as signals and lets you abort and re-execute it.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_fetch)
+///
+/// ## Usage
+///
+/// By default the request is sent immediately and the response body is read as text.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fetch, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchReturn {
+///     data,
+///     is_fetching,
+///     error,
+///     ..
+/// } = use_fetch("https://httpbin.org/get");
+///
+/// view! {
+///     <Show when=move || !is_fetching.get() fallback=|| "Loading...">
+///         <pre>{move || data.get()}</pre>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ### Request
+///
+/// Method, headers and body are configured with the options.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fetch_with_options, FetchMethod, UseFetchOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let fetch = use_fetch_with_options(
+///     "https://httpbin.org/post",
+///     UseFetchOptions::default()
+///         .method(FetchMethod::Post)
+///         .header("Content-Type", "application/json")
+///         .body(r#"{ "name": "leptos" }"#),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Response
+///
+/// How the response body is read is chosen with the options `.text()` (default), `.json()`,
+/// `.blob()` and `.array_buffer()`. This changes the type of `data`. `.json()` takes a codec to
+/// decode the body with.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::Deserialize;
+/// #
+/// #[derive(Deserialize, Clone)]
+/// struct Todo {
+///     title: String,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchReturn { data, .. } = use_fetch_with_options(
+///     "https://jsonplaceholder.typicode.com/todos/1",
+///     UseFetchOptions::default().json::<Todo, JsonSerdeCodec>(),
+/// );
+///
+/// view! {
+///     <p>{move || data.get().map(|todo| todo.title)}</p>
+/// }
+/// # }
+/// ```
+///
+/// ### Executing and Aborting
+///
+/// With `immediate(false)` the request is only sent when calling `execute`. With `refetch(true)`
+/// it is sent again whenever the url changes. Calling `execute` while a request is running
+/// aborts the running one. `abort` cancels the running request and sets `aborted` to `true`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (id, set_id) = signal(1);
+///
+/// let UseFetchReturn { data, execute, abort, .. } = use_fetch_with_options(
+///     Signal::derive(move || format!("https://jsonplaceholder.typicode.com/todos/{}", id.get())),
+///     UseFetchOptions::default().refetch(true),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| set_id.update(|id| *id += 1)>"Next"</button>
+///     <button on:click=move |_| execute()>"Reload"</button>
+///     <button on:click=move |_| abort()>"Abort"</button>
+/// }
+/// # }
+/// ```
+///
+/// ### Interceptors, Timeout and Retry
+///
+/// `before_fetch` can modify the request before it is sent, e.g. to add an auth header.
+/// `after_fetch` is called with every successful response and `on_fetch_error` when a request
+/// has finally failed, e.g. to refresh an expired token and `execute` again.
+///
+/// With `timeout` a request is aborted if it takes too long. With `retry` requests that failed
+/// because of transient errors (network errors, timeouts and the status codes `408`, `429` and
+/// `5xx`) are sent again according to the given [`BackoffPolicy`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions};
+/// # use leptos_use::core::BackoffPolicy;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (token, set_token) = signal("secret".to_string());
+///
+/// let fetch = use_fetch_with_options(
+///     "https://httpbin.org/bearer",
+///     UseFetchOptions::default()
+///         .before_fetch(move |ctx| {
+///             ctx.set_header("Authorization", format!("Bearer {}", token.get_untracked()));
+///         })
+///         .on_fetch_error(|ctx| {
+///             if ctx.status_code == Some(401) {
+///                 // refresh the token
+///             }
+///         })
+///         .timeout(5000_u64)
+///         .retry(BackoffPolicy::exponential(500)),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Request Queue
+///
+/// With `priority` the request waits in the [`FetchQueue`] of [`fn@crate::use_priority_fetch`]
+/// until there is a free slot. This limits how many requests are in flight at the same time and
+/// starts the most important ones first. `visible` treats the request as `FetchPriority::High`
+/// while it's `true`, e.g. when passing the signal returned by
+/// [`fn@crate::use_element_visibility`]. While waiting `is_fetching` is already `true`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_visibility, use_fetch_with_options, FetchPriority, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let is_visible = use_element_visibility(el);
+///
+/// let UseFetchReturn { data, .. } = use_fetch_with_options(
+///     "https://jsonplaceholder.typicode.com/comments",
+///     UseFetchOptions::default()
+///         .priority(FetchPriority::Low)
+///         .visible(is_visible),
+/// );
+///
+/// view! {
+///     <div node_ref=el>{move || data.get()}</div>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `execute` and `abort` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_fetch`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no request is sent. `data` stays `None` and `execute` and `abort` do nothing.
+pub fn use_fetch(
+    url: impl Into<Signal<String>>,
+) -> UseFetchReturn<TextResponse, impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+{
+    use_fetch_with_options(url, UseFetchOptions::default())
+}
+
+/// Version of [`use_fetch`] that takes a `UseFetchOptions`. See [`use_fetch`] for how to use.
+pub fn use_fetch_with_options<R>(
+    url: impl Into<Signal<String>>,
+    options: UseFetchOptions<R>,
+) -> UseFetchReturn<R, impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    R: FetchResponseType,
+{
+    let UseFetchOptions {
+        method,
+        headers,
+        body,
+        immediate,
+        refetch,
+        timeout,
+        retry,
+        priority,
+        visible,
+        before_fetch,
+        after_fetch,
+        on_fetch_error,
+        ..
+    } = options;
+
+    let url = url.into();
+
+    let (data, set_data) = signal(None::<R::Data>);
+    let (status_code, set_status_code) = signal(None::<u16>);
+    let (response, set_response) = signal_local(None::<web_sys::Response>);
+    let (is_fetching, set_fetching) = signal(false);
+    let (is_finished, set_finished) = signal(false);
+    let (aborted, set_aborted) = signal(false);
+    let (error, set_error) = signal_local(None::<UseFetchError<R::Error>>);
+
+    let controller = StoredValue::new_local(None::<web_sys::AbortController>);
+    let timed_out = StoredValue::new(false);
+
+    // incremented on every execution so that results of outdated requests are ignored
+    let request_id = StoredValue::new(0_u64);
+
+    let queue = StoredValue::new(
+        priority.map(|_| use_context::<FetchQueue>().unwrap_or_else(FetchQueue::shared)),
+    );
+    let queued_id = StoredValue::new(None::<u64>);
+
+    let abort_request = move || {
+        if is_fetching.try_get_untracked().unwrap_or_default() {
+            if let Some(id) = queued_id.try_update_value(Option::take).flatten() {
+                if let Some(queue) = queue.try_get_value().flatten() {
+                    queue.cancel(id);
+                }
+            }
+
+            if let Some(controller) = controller.try_update_value(Option::take).flatten() {
+                controller.abort();
+            }
+
+            set_aborted.set(true);
+            set_fetching.set(false);
+        }
+    };
+
+    let abort = sendwrap_fn!(move || abort_request());
+
+    let execute = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            use leptos::leptos_dom::helpers::set_timeout_with_handle;
+            use std::rc::Rc;
+            use std::time::Duration;
+
+            abort_request();
+
+            request_id.update_value(|id| *id += 1);
+            let id = request_id.get_value();
+
+            let mut context = BeforeFetchContext {
+                url: url.get_untracked(),
+                method,
+                headers: headers.clone(),
+                body: body.map(|body| body.get_untracked()),
+                cancel: false,
+            };
+
+            before_fetch(&mut context);
+
+            if context.cancel {
+                return;
+            }
+
+            set_fetching.set(true);
+            set_finished.set(false);
+            set_aborted.set(false);
+            set_error.set(None);
+            set_status_code.set(None);
+
+            let after_fetch = Arc::clone(&after_fetch);
+            let on_fetch_error = Arc::clone(&on_fetch_error);
+
+            let request = async move {
+                let is_current = move || request_id.try_get_value() == Some(id);
+                let mut attempt = 0;
+
+                let result = loop {
+                    let init = context.to_request_init();
+
+                    if let Ok(new_controller) = web_sys::AbortController::new() {
+                        init.set_signal(Some(&new_controller.signal()));
+                        controller.set_value(Some(new_controller));
+                    }
+
+                    timed_out.set_value(false);
+
+                    let timeout_handle = timeout.and_then(|timeout| {
+                        set_timeout_with_handle(
+                            move || {
+                                if is_current() {
+                                    timed_out.set_value(true);
+
+                                    if let Some(controller) = controller.get_value() {
+                                        controller.abort();
+                                    }
+                                }
+                            },
+                            Duration::from_millis(timeout),
+                        )
+                        .ok()
+                    });
+
+                    let result = async {
+                        let response =
+                            js_fut!(window().fetch_with_str_and_init(&context.url, &init))
+                                .await
+                                .map_err(UseFetchError::Fetch)?
+                                .unchecked_into::<web_sys::Response>();
+
+                        if is_current() {
+                            set_status_code.set(Some(response.status()));
+
+                            // a clone so that the body can still be read from the exposed response
+                            let response = response.clone().map_err(UseFetchError::Fetch)?;
+                            set_response.set(Some(response));
+                        }
+
+                        if !response.ok() {
+                            return Err(UseFetchError::Status(response.status()));
+                        }
+
+                        after_fetch(&response);
+
+                        R::read(response).await
+                    }
+                    .await;
+
+                    if let Some(timeout_handle) = timeout_handle {
+                        timeout_handle.clear();
+                    }
+
+                    if !is_current() || aborted.get_untracked() {
+                        return;
+                    }
+
+                    let result = result.map_err(|err| {
+                        if timed_out.get_value() {
+                            UseFetchError::Timeout
+                        } else {
+                            err
+                        }
+                    });
+
+                    let retry_delay = match &result {
+                        Err(err) if err.is_transient() => {
+                            retry.and_then(|retry| retry.delay(attempt))
+                        }
+                        _ => None,
+                    };
+
+                    let Some(delay) = retry_delay else {
+                        break result;
+                    };
+                    attempt += 1;
+
+                    controller.set_value(None);
+                    gloo_timers::future::sleep(delay).await;
+
+                    if !is_current() || aborted.get_untracked() {
+                        return;
+                    }
+                };
+
+                match result {
+                    Ok(value) => set_data.set(Some(value)),
+                    Err(err) => {
+                        on_fetch_error(FetchErrorContext {
+                            status_code: status_code.get_untracked(),
+                            response: response.get_untracked(),
+                            message: err.to_string(),
+                        });
+                        set_error.set(Some(err));
+                    }
+                }
+
+                controller.set_value(None);
+                set_fetching.set(false);
+                set_finished.set(true);
+            };
+
+            match (queue.get_value(), priority) {
+                (Some(queue), Some(priority)) => {
+                    let queued = queue.enqueue(
+                        Rc::new(move || {
+                            if visible.is_some_and(|visible| visible.get_untracked()) {
+                                FetchPriority::High
+                            } else {
+                                priority
+                            }
+                        }),
+                        move || Box::pin(request),
+                    );
+
+                    // cancelling a request that has already been started does nothing
+                    queued_id.set_value(Some(queued));
+                }
+                _ => leptos::task::spawn_local(request),
+            }
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        if refetch {
+            let execute = execute.clone();
+
+            let _ = Effect::watch(move || url.get(), move |_, _, _| execute(), immediate);
+        } else if immediate {
+            execute();
+        }
+
+        on_cleanup(abort_request);
+    }
+
+    UseFetchReturn {
+        data: data.into(),
+        status_code: status_code.into(),
+        response: response.into(),
+        is_fetching: is_fetching.into(),
+        is_finished: is_finished.into(),
+        aborted: aborted.into(),
+        error: error.into(),
+        execute,
+        abort,
+    }
+}
+
+/// HTTP method of the request sent by [`fn@crate::use_fetch`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FetchMethod {
+    #[default]
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+}
+
+impl FetchMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+            Self::Head => "HEAD",
+            Self::Options => "OPTIONS",
+        }
+    }
+}
+
+/// Options for [`use_fetch_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseFetchOptions<R>
+where
+    R: FetchResponseType,
+{
+    /// The HTTP method of the request. Defaults to `FetchMethod::Get`.
+    method: FetchMethod,
+
+    /// The headers sent with the request. Use `header` to add a single one.
+    /// Defaults to no headers.
+    headers: Vec<(String, String)>,
+
+    /// The body of the request. Set it with `body`. Defaults to `None`.
+    #[builder(skip)]
+    body: Option<Signal<String>>,
+
+    /// If `true` the request is sent immediately. Otherwise you have to call `execute`.
+    /// Defaults to `true`.
+    immediate: bool,
+
+    /// If `true` the request is sent again whenever the url changes. Defaults to `false`.
+    refetch: bool,
+
+    /// Timeout in milliseconds after which a request is aborted and fails with
+    /// `UseFetchError::Timeout`. Defaults to `None` which means no timeout.
+    #[builder(into)]
+    timeout: Option<u64>,
+
+    /// If set, requests that fail because of a network error, a timeout or a status code of
+    /// `408`, `429` or `5xx` are retried according to this policy. Defaults to `None`.
+    #[builder(into)]
+    retry: Option<BackoffPolicy>,
+
+    /// If set, the request waits in the [`FetchQueue`] for a free slot and is started according
+    /// to this priority. Defaults to `None` which sends the request right away.
+    #[builder(into)]
+    priority: Option<FetchPriority>,
+
+    /// While this signal is `true` a queued request is treated as `FetchPriority::High`. Usually
+    /// this is the return value of [`fn@crate::use_element_visibility`]. Only used together with
+    /// `priority`. Defaults to `None`.
+    #[builder(into)]
+    visible: Option<Signal<bool>>,
+
+    /// Called before every execution. It can modify the request, e.g. to add an auth header, or
+    /// cancel it by setting `cancel` to `true`. Retries use the same modified request.
+    before_fetch: Arc<dyn Fn(&mut BeforeFetchContext) + Send + Sync>,
+
+    /// Called with every successful response before its body is read.
+    after_fetch: Arc<dyn Fn(&web_sys::Response) + Send + Sync>,
+
+    /// Called when a request has finally failed (after all retries). Not called when the
+    /// request has been aborted.
+    on_fetch_error: Arc<dyn Fn(FetchErrorContext) + Send + Sync>,
+
+    #[builder(skip)]
+    _marker: PhantomData<R>,
+}
+
+impl Default for UseFetchOptions<TextResponse> {
+    fn default() -> Self {
+        Self {
+            method: FetchMethod::Get,
+            headers: vec![],
+            body: None,
+            immediate: true,
+            refetch: false,
+            timeout: None,
+            retry: None,
+            priority: None,
+            visible: None,
+            before_fetch: Arc::new(|_| {}),
+            after_fetch: Arc::new(|_| {}),
+            on_fetch_error: Arc::new(|_| {}),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R> UseFetchOptions<R>
+where
+    R: FetchResponseType,
+{
+    /// Adds a header to the request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of the request. If it is a signal, the current value is sent on every
+    /// execution.
+    pub fn body(self, body: impl Into<Signal<String>>) -> Self {
+        Self {
+            body: Some(body.into()),
+            ..self
+        }
+    }
+
+    /// Reads the response body as text.
+    pub fn text(self) -> UseFetchOptions<TextResponse> {
+        self.response_type()
+    }
+
+    /// Reads the response body as text and decodes it with the codec `C`
+    /// (e.g. `JsonSerdeCodec`).
+    pub fn json<T, C>(self) -> UseFetchOptions<JsonResponse<T, C>>
+    where
+        T: Send + Sync + 'static,
+        C: Decoder<T, Encoded = str> + 'static,
+    {
+        self.response_type()
+    }
+
+    /// Reads the response body as a `Blob`.
+    pub fn blob(self) -> UseFetchOptions<BlobResponse> {
+        self.response_type()
+    }
+
+    /// Reads the response body as bytes.
+    pub fn array_buffer(self) -> UseFetchOptions<ArrayBufferResponse> {
+        self.response_type()
+    }
+
+    fn response_type<NewR: FetchResponseType>(self) -> UseFetchOptions<NewR> {
+        UseFetchOptions {
+            method: self.method,
+            headers: self.headers,
+            body: self.body,
+            immediate: self.immediate,
+            refetch: self.refetch,
+            timeout: self.timeout,
+            retry: self.retry,
+            priority: self.priority,
+            visible: self.visible,
+            before_fetch: self.before_fetch,
+            after_fetch: self.after_fetch,
+            on_fetch_error: self.on_fetch_error,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// The request that is about to be sent by [`fn@crate::use_fetch`]. Passed to the
+/// `before_fetch` option.
+#[derive(Clone, Debug)]
+pub struct BeforeFetchContext {
+    /// The url of the request.
+    pub url: String,
+
+    /// The HTTP method of the request.
+    pub method: FetchMethod,
+
+    /// The headers of the request.
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the request.
+    pub body: Option<String>,
+
+    /// Set this to `true` to not send the request.
+    pub cancel: bool,
+}
+
+impl BeforeFetchContext {
+    /// Sets or replaces the header `name`.
+    pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+
+        self.headers
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+        self.headers.push((name, value.into()));
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn to_request_init(&self) -> web_sys::RequestInit {
+        let init = web_sys::RequestInit::new();
+        init.set_method(self.method.as_str());
+
+        if let Ok(headers) = web_sys::Headers::new() {
+            for (name, value) in &self.headers {
+                let _ = headers.append(name, value);
+            }
+            init.set_headers(&headers);
+        }
+
+        if let Some(body) = &self.body {
+            init.set_body(&JsValue::from_str(body));
+        }
+
+        init
+    }
+}
+
+/// Information about a failed request of [`fn@crate::use_fetch`]. Passed to the
+/// `on_fetch_error` option.
+#[derive(Clone, Debug)]
+pub struct FetchErrorContext {
+    /// The HTTP status code if a response has been received.
+    pub status_code: Option<u16>,
+
+    /// The response if one has been received.
+    pub response: Option<web_sys::Response>,
+
+    /// Description of the error.
+    pub message: String,
+}
+
+/// Determines how [`fn@crate::use_fetch`] reads the body of the response.
+pub trait FetchResponseType: 'static {
+    /// The type of the data read from the body.
+    type Data: Send + Sync + 'static;
+
+    /// The error that can occur when decoding the body.
+    type Error: 'static;
+
+    /// Reads the body of `response`.
+    fn read(
+        response: web_sys::Response,
+    ) -> impl Future<Output = Result<Self::Data, UseFetchError<Self::Error>>>;
+}
+
+/// Reads the response body as `String`. See [`UseFetchOptions::text`].
+pub struct TextResponse;
+
+impl FetchResponseType for TextResponse {
+    type Data = String;
+    type Error = ();
+
+    async fn read(response: web_sys::Response) -> Result<String, UseFetchError<()>> {
+        read_text(response).await
+    }
+}
+
+/// Decodes the response body with the codec `C`. See [`UseFetchOptions::json`].
+pub struct JsonResponse<T, C>(PhantomData<fn() -> (T, C)>);
+
+impl<T, C> FetchResponseType for JsonResponse<T, C>
+where
+    T: Send + Sync + 'static,
+    C: Decoder<T, Encoded = str> + 'static,
+{
+    type Data = T;
+    type Error = C::Error;
+
+    async fn read(response: web_sys::Response) -> Result<T, UseFetchError<C::Error>> {
+        let text = read_text(response).await?;
+        C::decode(&text).map_err(UseFetchError::Decode)
+    }
+}
+
+/// Reads the response body as `Blob`. See [`UseFetchOptions::blob`].
+pub struct BlobResponse;
+
+impl FetchResponseType for BlobResponse {
+    type Data = SendWrapper<web_sys::Blob>;
+    type Error = ();
+
+    async fn read(
+        response: web_sys::Response,
+    ) -> Result<SendWrapper<web_sys::Blob>, UseFetchError<()>> {
+        let blob = js_fut!(response.blob().map_err(UseFetchError::Read)?)
+            .await
+            .map_err(UseFetchError::Read)?;
+
+        Ok(SendWrapper::new(blob.unchecked_into()))
+    }
+}
+
+/// Reads the response body as bytes. See [`UseFetchOptions::array_buffer`].
+pub struct ArrayBufferResponse;
+
+impl FetchResponseType for ArrayBufferResponse {
+    type Data = Vec<u8>;
+    type Error = ();
+
+    async fn read(response: web_sys::Response) -> Result<Vec<u8>, UseFetchError<()>> {
+        let buffer = js_fut!(response.array_buffer().map_err(UseFetchError::Read)?)
+            .await
+            .map_err(UseFetchError::Read)?;
+
+        Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+}
+
+async fn read_text<E>(response: web_sys::Response) -> Result<String, UseFetchError<E>> {
+    js_fut!(response.text().map_err(UseFetchError::Read)?)
+        .await
+        .map_err(UseFetchError::Read)?
+        .as_string()
+        .ok_or(UseFetchError::Read(JsValue::NULL))
+}
+
+/// Return type of [`use_fetch`].
+pub struct UseFetchReturn<R, ExecuteFn, AbortFn>
+where
+    R: FetchResponseType,
+    ExecuteFn: Fn() + Clone + Send + Sync,
+    AbortFn: Fn() + Clone + Send + Sync,
+{
+    /// The data read from the body of the latest successful response.
+    pub data: Signal<Option<R::Data>>,
+
+    /// The HTTP status code of the latest response.
+    pub status_code: Signal<Option<u16>>,
+
+    /// The latest response.
+    pub response: Signal<Option<web_sys::Response>, LocalStorage>,
+
+    /// `true` while a request is running.
+    pub is_fetching: Signal<bool>,
+
+    /// `true` when the latest request has finished (successfully or not).
+    pub is_finished: Signal<bool>,
+
+    /// `true` if the latest request has been aborted.
+    pub aborted: Signal<bool>,
+
+    /// The error of the latest request.
+    pub error: Signal<Option<UseFetchError<R::Error>>, LocalStorage>,
+
+    /// Sends the request (again). A running request is aborted.
+    pub execute: ExecuteFn,
+
+    /// Aborts the running request.
+    pub abort: AbortFn,
+}
+
+#[derive(Debug, Error)]
+pub enum UseFetchError<E> {
+    #[error("failed to fetch")]
+    Fetch(JsValue),
+    #[error("response has status code {0}")]
+    Status(u16),
+    #[error("failed to read response body")]
+    Read(JsValue),
+    #[error("failed to decode response body")]
+    Decode(E),
+    #[error("request timed out")]
+    Timeout,
+}
+
+impl<E> UseFetchError<E> {
+    /// Returns `true` for errors that might go away when retrying the request.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Fetch(_) | Self::Timeout => true,
+            Self::Status(status) => *status == 408 || *status == 429 || *status >= 500,
+            Self::Read(_) | Self::Decode(_) => false,
+        }
+    }
+}
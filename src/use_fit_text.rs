@@ -0,0 +1,267 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Scales the font size of an element so that its text fits its width.
+///
+/// Searches for the largest font size between `min_font_size` and `max_font_size` at which the
+/// text neither overflows horizontally nor wraps onto more than `max_lines` lines and sets it as
+/// the inline `font-size` of `target`. This is recomputed whenever the width of `target` or its
+/// text changes.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_fit_text)
+///
+/// ## Usage
+///
+/// `target` has to be a block element so that its width is determined by its container.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::H1;
+/// # use leptos_use::{use_fit_text, UseFitTextReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<H1>::new();
+///
+/// let UseFitTextReturn { font_size, .. } = use_fit_text(el);
+///
+/// view! {
+///     <h1 node_ref=el style="white-space: nowrap">"Hello, World!"</h1>
+/// }
+/// # }
+/// ```
+///
+/// ### Options
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Span;
+/// # use leptos_use::{use_fit_text_with_options, UseFitTextOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Span>::new();
+///
+/// let fit_text = use_fit_text_with_options(
+///     el,
+///     UseFitTextOptions::default()
+///         .min_font_size(12.0)
+///         .max_font_size(48.0)
+///         .max_lines(2),
+/// );
+///
+/// view! {
+///     <span node_ref=el style="display: block">"A longer text that may take two lines"</span>
+/// }
+/// # }
+/// ```
+///
+/// If the text can't fit even at `min_font_size`, `min_font_size` is used.
+///
+/// Changes of the font itself (e.g. when a web font has finished loading) aren't detected. Call
+/// `fit` to recompute the font size manually.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no font size is set and `font_size` is always `None`.
+pub fn use_fit_text<El, M>(target: El) -> UseFitTextReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_fit_text_with_options(target, UseFitTextOptions::default())
+}
+
+/// Version of [`use_fit_text`] that takes a `UseFitTextOptions`. See [`use_fit_text`] for how to use.
+pub fn use_fit_text_with_options<El, M>(target: El, options: UseFitTextOptions) -> UseFitTextReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseFitTextOptions {
+        min_font_size,
+        max_font_size,
+        max_lines,
+    } = options;
+
+    let target = target.into_element_maybe_signal();
+
+    let (font_size, set_font_size) = signal(None::<f64>);
+
+    let fit_text = UseFitTextReturn {
+        font_size: font_size.into(),
+        set_font_size,
+        target,
+        min_font_size,
+        max_font_size,
+        max_lines,
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_mutation_observer_with_options, use_resize_observer, UseMutationObserverOptions,
+        };
+
+        // changing the font size changes the height of the element so only react to the width
+        let last_width = StoredValue::new(None::<f64>);
+
+        use_resize_observer(target, move |entries, _| {
+            let Some(entry) = entries.first() else {
+                return;
+            };
+
+            let width = entry.content_rect().width();
+
+            if last_width.get_value() != Some(width) {
+                last_width.set_value(Some(width));
+                fit_text.fit();
+            }
+        });
+
+        use_mutation_observer_with_options(
+            target,
+            move |_, _| fit_text.fit(),
+            UseMutationObserverOptions::default()
+                .subtree(true)
+                .child_list(true)
+                .character_data(true),
+        );
+    }
+
+    fit_text
+}
+
+/// Options for [`use_fit_text_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseFitTextOptions {
+    /// Smallest font size in pixels. Defaults to `8.0`.
+    min_font_size: f64,
+
+    /// Largest font size in pixels. Defaults to `256.0`.
+    max_font_size: f64,
+
+    /// Maximum number of lines the text may take. Defaults to `1`.
+    max_lines: usize,
+}
+
+impl Default for UseFitTextOptions {
+    fn default() -> Self {
+        Self {
+            min_font_size: 8.0,
+            max_font_size: 256.0,
+            max_lines: 1,
+        }
+    }
+}
+
+/// Return type of [`use_fit_text`].
+#[derive(Clone, Copy)]
+pub struct UseFitTextReturn {
+    /// The font size in pixels that has been set on the target. `None` until the target is
+    /// mounted.
+    pub font_size: Signal<Option<f64>>,
+
+    set_font_size: WriteSignal<Option<f64>>,
+    target: ElementMaybeSignal<web_sys::Element>,
+    min_font_size: f64,
+    max_font_size: f64,
+    max_lines: usize,
+}
+
+impl UseFitTextReturn {
+    /// Recomputes the font size.
+    pub fn fit(&self) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            let Some(el) = self
+                .target
+                .get_untracked()
+                .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+            else {
+                return;
+            };
+
+            let font_size =
+                fit_font_size(&el, self.min_font_size, self.max_font_size, self.max_lines);
+
+            if self.font_size.get_untracked() != Some(font_size) {
+                self.set_font_size.set(Some(font_size));
+            }
+        }
+    }
+}
+
+/// Binary search for the largest font size at which the text of `el` fits.
+#[cfg(not(feature = "ssr"))]
+fn fit_font_size(
+    el: &web_sys::HtmlElement,
+    min_font_size: f64,
+    max_font_size: f64,
+    max_lines: usize,
+) -> f64 {
+    let style = el.style();
+    let set_font_size = |font_size: f64| {
+        let _ = style.set_property("font-size", &format!("{font_size}px"));
+    };
+
+    let fits = |font_size: f64| {
+        set_font_size(font_size);
+        text_fits(el, font_size, max_lines)
+    };
+
+    if fits(max_font_size) {
+        return max_font_size;
+    }
+
+    let mut low = min_font_size;
+    let mut high = max_font_size;
+
+    while high - low > 0.5 {
+        let middle = (low + high) / 2.0;
+
+        if fits(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    let font_size = low.floor().max(min_font_size);
+    set_font_size(font_size);
+
+    font_size
+}
+
+#[cfg(not(feature = "ssr"))]
+fn text_fits(el: &web_sys::HtmlElement, font_size: f64, max_lines: usize) -> bool {
+    if el.scroll_width() > el.client_width() {
+        return false;
+    }
+
+    let Some(style) = window().get_computed_style(el).ok().flatten() else {
+        return true;
+    };
+
+    let pixels = |name: &str| {
+        style
+            .get_property_value(name)
+            .unwrap_or_default()
+            .trim_end_matches("px")
+            .parse::<f64>()
+            .ok()
+    };
+
+    // `normal` is about 1.2 times the font size for most fonts
+    let line_height = pixels("line-height").unwrap_or(font_size * 1.2);
+    let content_height = el.scroll_height() as f64
+        - pixels("padding-top").unwrap_or_default()
+        - pixels("padding-bottom").unwrap_or_default();
+
+    (content_height / line_height).round() as usize <= max_lines.max(1)
+}
@@ -0,0 +1,285 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use sha2::Digest;
+use std::fmt::Display;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive hash of a (large) file.
+///
+/// Whenever `file` changes, its hash is computed with the given algorithm. The file is read and
+/// hashed chunk by chunk, so it's never held in memory as a whole and the UI stays responsive in
+/// between the chunks.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_hash_file)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_hash_file, HashAlgorithm, UseHashFileReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (file, set_file) = signal_local(None::<web_sys::File>);
+///
+/// let UseHashFileReturn {
+///     hash,
+///     progress,
+///     abort,
+///     ..
+/// } = use_hash_file(file, HashAlgorithm::Sha256);
+///
+/// view! {
+///     <progress max="1" value=move || progress.get().to_string() />
+///     <p>"SHA-256: " {move || hash.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// The hash is a lowercase hex string. A new file aborts hashing the previous one. You can also
+/// abort it with `abort`.
+///
+/// The [Web Crypto API](https://developer.mozilla.org/en-US/docs/Web/API/SubtleCrypto/digest)
+/// can't hash incrementally and doesn't support MD5, so the hashing is done in Rust.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is hashed. `hash` and `error` are always `None`, `progress` is always
+/// `0.0` and `is_hashing` is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_stream_pipeline`]
+pub fn use_hash_file(
+    file: impl Into<Signal<Option<web_sys::File>, LocalStorage>>,
+    algorithm: HashAlgorithm,
+) -> UseHashFileReturn<impl Fn() + Clone + Send + Sync> {
+    use_hash_file_with_options(file, algorithm, UseHashFileOptions::default())
+}
+
+/// Version of [`use_hash_file`] that takes a `UseHashFileOptions`. See [`use_hash_file`] for how to use.
+pub fn use_hash_file_with_options(
+    file: impl Into<Signal<Option<web_sys::File>, LocalStorage>>,
+    algorithm: HashAlgorithm,
+    options: UseHashFileOptions,
+) -> UseHashFileReturn<impl Fn() + Clone + Send + Sync> {
+    let UseHashFileOptions { chunk_size } = options;
+
+    let file = file.into();
+
+    let (hash, set_hash) = signal(None::<String>);
+    let (progress, set_progress) = signal(0.0);
+    let (is_hashing, set_hashing) = signal(false);
+    let (error, set_error) = signal_local(None::<UseHashFileError>);
+
+    let run_id = StoredValue::new(0_u64);
+
+    let abort = sendwrap_fn!(move || {
+        // makes the running loop stop before the next chunk
+        run_id.try_update_value(|id| *id += 1);
+        set_hashing.set(false);
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use leptos::task::spawn_local;
+
+        let _ = Effect::watch(
+            move || file.get(),
+            {
+                let abort = abort.clone();
+
+                move |file: &Option<web_sys::File>, _, _| {
+                    abort();
+
+                    set_hash.set(None);
+                    set_progress.set(0.0);
+                    set_error.set(None);
+
+                    let Some(file) = file.clone() else {
+                        return;
+                    };
+
+                    let id = run_id.get_value();
+                    let is_current = move || run_id.try_get_value() == Some(id);
+
+                    set_hashing.set(true);
+
+                    spawn_local(async move {
+                        let result = hash_file(&file, algorithm, chunk_size, |progress| {
+                            if is_current() {
+                                set_progress.set(progress);
+                                true
+                            } else {
+                                false
+                            }
+                        })
+                        .await;
+
+                        if !is_current() {
+                            return;
+                        }
+
+                        match result {
+                            Ok(Some(hash)) => set_hash.set(Some(hash)),
+                            Ok(None) => {}
+                            Err(err) => set_error.set(Some(UseHashFileError::Read(err))),
+                        }
+
+                        set_hashing.set(false);
+                    });
+                }
+            },
+            true,
+        );
+    }
+
+    on_cleanup({
+        let abort = abort.clone();
+
+        move || abort()
+    });
+
+    UseHashFileReturn {
+        hash: hash.into(),
+        progress: progress.into(),
+        is_hashing: is_hashing.into(),
+        error: error.into(),
+        abort,
+    }
+}
+
+/// Hashes `file` chunk by chunk. After every chunk `on_progress` is called with the progress. If
+/// it returns `false` hashing stops and `None` is returned.
+#[cfg(not(feature = "ssr"))]
+async fn hash_file(
+    file: &web_sys::File,
+    algorithm: HashAlgorithm,
+    chunk_size: u32,
+    on_progress: impl Fn(f64) -> bool,
+) -> Result<Option<String>, JsValue> {
+    use crate::js_fut;
+
+    let mut hasher = Hasher::new(algorithm);
+
+    let size = file.size();
+    let mut offset = 0.0;
+
+    while offset < size {
+        let end = (offset + chunk_size as f64).min(size);
+
+        let buffer = js_fut!(file.slice_with_f64_and_f64(offset, end)?.array_buffer()).await?;
+        hasher.update(&js_sys::Uint8Array::new(&buffer).to_vec());
+
+        offset = end;
+
+        if !on_progress(offset / size) {
+            return Ok(None);
+        }
+    }
+
+    on_progress(1.0);
+
+    Ok(Some(hasher.finalize_hex()))
+}
+
+/// Hash algorithm of [`fn@crate::use_hash_file`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    Sha512,
+    Md5,
+}
+
+impl Display for HashAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sha256 => write!(f, "SHA-256"),
+            Self::Sha512 => write!(f, "SHA-512"),
+            Self::Md5 => write!(f, "MD5"),
+        }
+    }
+}
+
+enum Hasher {
+    Sha256(sha2::Sha256),
+    Sha512(sha2::Sha512),
+    Md5(md5::Md5),
+}
+
+impl Hasher {
+    fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+            HashAlgorithm::Sha512 => Self::Sha512(sha2::Sha512::new()),
+            HashAlgorithm::Md5 => Self::Md5(md5::Md5::new()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Sha256(hasher) => hasher.update(data),
+            Self::Sha512(hasher) => hasher.update(data),
+            Self::Md5(hasher) => hasher.update(data),
+        }
+    }
+
+    fn finalize_hex(self) -> String {
+        let digest = match self {
+            Self::Sha256(hasher) => hasher.finalize().to_vec(),
+            Self::Sha512(hasher) => hasher.finalize().to_vec(),
+            Self::Md5(hasher) => hasher.finalize().to_vec(),
+        };
+
+        digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+/// Options for [`use_hash_file_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseHashFileOptions {
+    /// Number of bytes that are read and hashed at once. Defaults to 2 MiB.
+    chunk_size: u32,
+}
+
+impl Default for UseHashFileOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: 2 * 1024 * 1024,
+        }
+    }
+}
+
+/// Return type of [`use_hash_file`].
+pub struct UseHashFileReturn<AbortFn>
+where
+    AbortFn: Fn() + Clone + Send + Sync,
+{
+    /// Lowercase hex string of the hash of the current file once it's complete.
+    pub hash: Signal<Option<String>>,
+
+    /// Share of the current file that has been hashed from `0.0` to `1.0`.
+    pub progress: Signal<f64>,
+
+    /// Whether a file is currently being hashed.
+    pub is_hashing: Signal<bool>,
+
+    /// Latest error.
+    pub error: Signal<Option<UseHashFileError>, LocalStorage>,
+
+    /// Stops hashing the current file.
+    pub abort: AbortFn,
+}
+
+#[derive(Debug, Error)]
+pub enum UseHashFileError {
+    #[error("failed to read the file")]
+    Read(JsValue),
+}
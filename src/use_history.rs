@@ -0,0 +1,337 @@
+use crate::core::{now, UseRwSignal};
+use crate::filter_builder_methods;
+use crate::utils::{
+    create_filter_wrapper_with_arg, DebounceOptions, FilterOptions, ThrottleOptions,
+};
+use crate::watch_ignorable::{ignore_updates, watch_ignoring};
+use crate::WatchOptions;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Undo and redo changes of a signal.
+///
+/// Records snapshots of the value of a signal together with a timestamp. `undo` and `redo` write
+/// the snapshots back to the signal.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_history)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_history;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let text = RwSignal::new(String::new());
+///
+/// let history = use_history(text);
+///
+/// text.set("Hello".to_string());
+/// // after the change has been recorded
+/// history.undo(); // text is "" again
+/// history.redo(); // text is "Hello" again
+///
+/// view! {
+///     <textarea prop:value=text on:input=move |e| text.set(event_target_value(&e)) />
+///     <button disabled=move || !history.can_undo.get() on:click=move |_| history.undo()>
+///         "Undo"
+///     </button>
+///     <button disabled=move || !history.can_redo.get() on:click=move |_| history.redo()>
+///         "Redo"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// The source can be an `RwSignal` or a tuple of a signal and its `WriteSignal`.
+///
+/// Every change of the signal is recorded as a snapshot, which is a clone of the value. Changes are
+/// recorded asynchronously like effects run, so several changes in the same event handler are
+/// recorded as one. Making a change after undoing clears the redo stack.
+///
+/// `history` contains all snapshots with the newest one first. That is the current snapshot
+/// `last` followed by `undo_stack`. `redo_stack` contains the snapshots that were undone with the
+/// most recently undone one first.
+///
+/// `clear` removes all snapshots except the current one.
+///
+/// ### Manual Commits
+///
+/// With the option `manual` changes aren't recorded automatically. Call `commit` to record the
+/// current value. `reset` sets the signal back to the last recorded snapshot.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_history_with_options, UseHistoryOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (document, set_document) = signal(vec!["First line".to_string()]);
+///
+/// let history = use_history_with_options(
+///     (document, set_document),
+///     UseHistoryOptions::default().manual(true).capacity(100_usize),
+/// );
+///
+/// set_document.update(|lines| lines.push("Second line".to_string()));
+/// history.commit();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The option `filter` lets you debounce or throttle the automatic recording. See
+/// [`fn@crate::use_debounced_history`] and [`fn@crate::use_throttled_history`] for shorthands.
+///
+/// The option `capacity` limits the number of snapshots in `undo_stack`. The oldest ones are
+/// removed first.
+///
+/// ## Server-Side Rendering
+///
+/// On the server changes aren't recorded automatically. Manual commits, undo and redo work.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_history_state`]
+/// * [`fn@crate::watch_ignorable`]
+pub fn use_history<T>(source: impl Into<UseRwSignal<T>>) -> UseHistoryReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    use_history_with_options(source, UseHistoryOptions::default())
+}
+
+/// Version of [`use_history`] that takes a `UseHistoryOptions`. See [`use_history`] for how to use.
+pub fn use_history_with_options<T>(
+    source: impl Into<UseRwSignal<T>>,
+    options: UseHistoryOptions,
+) -> UseHistoryReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let UseHistoryOptions {
+        capacity,
+        manual,
+        filter,
+    } = options;
+
+    let source = source.into();
+
+    let (last, set_last) = signal(UseHistoryRecord::new(source.get_untracked()));
+
+    // oldest first
+    let (undo_stack, set_undo_stack) = signal(Vec::<UseHistoryRecord<T>>::new());
+    let (redo_stack, set_redo_stack) = signal(Vec::<UseHistoryRecord<T>>::new());
+
+    let history = UseHistoryReturn {
+        history: Signal::derive(move || {
+            let mut history = undo_stack.get();
+            history.push(last.get());
+            history.reverse();
+            history
+        }),
+        last: last.into(),
+        undo_stack: Signal::derive(move || undo_stack.get().into_iter().rev().collect()),
+        redo_stack: Signal::derive(move || redo_stack.get().into_iter().rev().collect()),
+        can_undo: Signal::derive(move || undo_stack.with(|stack| !stack.is_empty())),
+        can_redo: Signal::derive(move || redo_stack.with(|stack| !stack.is_empty())),
+        source,
+        set_last,
+        set_undo_stack,
+        set_redo_stack,
+        capacity,
+        ignore_next_change: StoredValue::new(false),
+        generation: StoredValue::new(0),
+    };
+
+    if !manual {
+        // a delayed commit is dropped if the history has changed in the meantime
+        let filtered_commit =
+            create_filter_wrapper_with_arg(filter.filter_fn(), move |generation: u64| {
+                if history.generation.get_value() == generation {
+                    history.commit();
+                }
+            });
+
+        let _ = watch_ignoring(
+            history.ignore_next_change,
+            move || source.with(|_| ()),
+            move |_, _, _| filtered_commit(history.generation.get_value()),
+            WatchOptions::default(),
+        );
+    }
+
+    history
+}
+
+/// A snapshot recorded by [`use_history`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseHistoryRecord<T> {
+    /// The value of the signal
+    pub snapshot: T,
+
+    /// When the snapshot was recorded in milliseconds since the Unix epoch
+    pub timestamp: f64,
+}
+
+impl<T> UseHistoryRecord<T> {
+    fn new(snapshot: T) -> Self {
+        Self {
+            snapshot,
+            timestamp: now(),
+        }
+    }
+}
+
+/// Options for [`use_history_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseHistoryOptions {
+    /// Maximum number of snapshots in the undo stack. Defaults to `None` (no limit).
+    #[builder(into)]
+    capacity: Option<usize>,
+
+    /// If `true` changes are only recorded by calling `commit`. Defaults to `false`.
+    manual: bool,
+
+    /// Allows to debounce or throttle the automatic recording of changes. Defaults to no filter.
+    filter: FilterOptions,
+}
+
+impl UseHistoryOptions {
+    filter_builder_methods!(
+        /// the recording of changes
+        filter
+    );
+}
+
+/// Return type of [`use_history`].
+pub struct UseHistoryReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// All snapshots with the newest one first
+    pub history: Signal<Vec<UseHistoryRecord<T>>>,
+
+    /// The current snapshot
+    pub last: Signal<UseHistoryRecord<T>>,
+
+    /// The snapshots that can be undone with the newest one first
+    pub undo_stack: Signal<Vec<UseHistoryRecord<T>>>,
+
+    /// The snapshots that can be redone with the most recently undone one first
+    pub redo_stack: Signal<Vec<UseHistoryRecord<T>>>,
+
+    /// Whether there's something to undo
+    pub can_undo: Signal<bool>,
+
+    /// Whether there's something to redo
+    pub can_redo: Signal<bool>,
+
+    source: UseRwSignal<T>,
+    set_last: WriteSignal<UseHistoryRecord<T>>,
+    set_undo_stack: WriteSignal<Vec<UseHistoryRecord<T>>>,
+    set_redo_stack: WriteSignal<Vec<UseHistoryRecord<T>>>,
+    capacity: Option<usize>,
+    ignore_next_change: StoredValue<bool>,
+    generation: StoredValue<u64>,
+}
+
+impl<T> Clone for UseHistoryReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseHistoryReturn<T> where T: Send + Sync + 'static {}
+
+impl<T> UseHistoryReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Records the current value of the signal. Clears the redo stack.
+    pub fn commit(&self) {
+        let record = UseHistoryRecord::new(self.source.get_untracked());
+        let previous = self.last.get_untracked();
+
+        self.set_last.set(record);
+        self.push_undo(previous);
+        self.set_redo_stack.update(Vec::clear);
+        self.bump_generation();
+    }
+
+    /// Sets the signal to the previous snapshot.
+    pub fn undo(&self) {
+        let Some(record) = self.set_undo_stack.try_update(Vec::pop).flatten() else {
+            return;
+        };
+
+        let current = self.last.get_untracked();
+        self.set_redo_stack.update(|stack| stack.push(current));
+
+        self.restore(record);
+    }
+
+    /// Sets the signal to the snapshot that was undone most recently.
+    pub fn redo(&self) {
+        let Some(record) = self.set_redo_stack.try_update(Vec::pop).flatten() else {
+            return;
+        };
+
+        let current = self.last.get_untracked();
+        self.push_undo(current);
+
+        self.restore(record);
+    }
+
+    /// Sets the signal back to the current snapshot, discarding changes that haven't been
+    /// recorded.
+    pub fn reset(&self) {
+        let snapshot = self.last.get_untracked().snapshot;
+        self.ignore_updates(|| self.source.set(snapshot));
+        self.bump_generation();
+    }
+
+    /// Removes all snapshots except the current one.
+    pub fn clear(&self) {
+        self.set_undo_stack.update(Vec::clear);
+        self.set_redo_stack.update(Vec::clear);
+    }
+
+    fn push_undo(&self, record: UseHistoryRecord<T>) {
+        let capacity = self.capacity;
+
+        self.set_undo_stack.update(|stack| {
+            stack.push(record);
+
+            if let Some(capacity) = capacity {
+                let excess = stack.len().saturating_sub(capacity);
+                stack.drain(..excess);
+            }
+        });
+    }
+
+    /// Makes `record` the current snapshot and writes it to the signal.
+    fn restore(&self, record: UseHistoryRecord<T>) {
+        let snapshot = record.snapshot.clone();
+
+        self.set_last.set(record);
+        self.ignore_updates(|| self.source.set(snapshot));
+        self.bump_generation();
+    }
+
+    fn bump_generation(&self) {
+        self.generation.update_value(|generation| *generation += 1);
+    }
+
+    /// Runs `updater` without recording the changes it makes.
+    fn ignore_updates(&self, updater: impl FnOnce()) {
+        ignore_updates(self.ignore_next_change, updater);
+    }
+}
@@ -0,0 +1,250 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::HistoryWriteMode;
+use crate::use_event_listener;
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Signal that is stored in [`history.state`](https://developer.mozilla.org/en-US/docs/Web/API/History/state).
+///
+/// The value is stored under `key` in the state of the current history entry. Every change
+/// replaces the state of the current entry and when the browser navigates back or forward the
+/// value of that entry is restored. This makes UI state like scroll positions, open panels or
+/// form inputs survive back/forward navigation without putting them into the URL.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_history_state)
+///
+/// ## Usage
+///
+/// Values are (en)decoded to strings via the given codec.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_history_state;
+/// # use codee::string::{FromToStringCodec, JsonSerdeCodec};
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Clone, Default)]
+/// pub struct Filters {
+///     query: String,
+///     show_archived: bool,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (filters, set_filters) = use_history_state::<Filters, JsonSerdeCodec>("filters");
+///
+/// let (tab, set_tab) = use_history_state::<u32, FromToStringCodec>("tab");
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// If the history entry has no value for `key` the signal starts with `T::default()`. Use
+/// `UseHistoryStateOptions::initial_value` to start with something else.
+///
+/// ### Push
+///
+/// To create a new history entry for every change, so that the back button goes back to the
+/// previous value, use `HistoryWriteMode::Push`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_history_state_with_options, UseHistoryStateOptions};
+/// # use leptos_use::core::HistoryWriteMode;
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (step, set_step) = use_history_state_with_options::<u32, FromToStringCodec>(
+///     "wizard-step",
+///     UseHistoryStateOptions::default()
+///         .initial_value(1)
+///         .write_mode(HistoryWriteMode::Push),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Other values in `history.state` are kept so several calls with different keys can be used
+/// side by side. Please note that a router that replaces `history.state` on navigation will
+/// remove the values.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signal just holds the initial value.
+pub fn use_history_state<T, C>(key: impl Into<String>) -> (Signal<T>, WriteSignal<T>)
+where
+    T: Default + Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+{
+    use_history_state_with_options::<T, C>(key, UseHistoryStateOptions::default())
+}
+
+/// Version of [`use_history_state`] that takes a `UseHistoryStateOptions`. See [`use_history_state`] for how to use.
+pub fn use_history_state_with_options<T, C>(
+    key: impl Into<String>,
+    options: UseHistoryStateOptions<T, <C as Encoder<T>>::Error, <C as Decoder<T>>::Error>,
+) -> (Signal<T>, WriteSignal<T>)
+where
+    T: Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+{
+    let UseHistoryStateOptions {
+        initial_value,
+        write_mode,
+        on_error,
+    } = options;
+
+    let key = key.into();
+
+    #[cfg(feature = "ssr")]
+    let (value, set_value) = signal(initial_value);
+
+    #[cfg(not(feature = "ssr"))]
+    let (value, set_value) = {
+        let read = {
+            let key = key.clone();
+            let on_error = Arc::clone(&on_error);
+
+            move || -> Option<T> {
+                let encoded = read_encoded(&key)?;
+
+                C::decode(&encoded)
+                    .map_err(|err| on_error(UseHistoryStateError::Codec(CodecError::Decode(err))))
+                    .ok()
+            }
+        };
+
+        let (value, set_value) = signal(read().unwrap_or(initial_value));
+
+        let _ = use_event_listener(window(), leptos::ev::popstate, move |_| {
+            if let Some(stored) = read() {
+                set_value.set(stored);
+            }
+        });
+
+        let _ = Effect::watch(
+            move || value.track(),
+            move |_, _, _| {
+                let encoded = match value.with_untracked(C::encode) {
+                    Ok(encoded) => encoded,
+                    Err(err) => {
+                        on_error(UseHistoryStateError::Codec(CodecError::Encode(err)));
+                        return;
+                    }
+                };
+
+                // the value was just restored from this history entry
+                if read_encoded(&key).as_deref() == Some(encoded.as_str()) {
+                    return;
+                }
+
+                if let Err(err) = write_encoded(&key, &encoded, write_mode) {
+                    on_error(UseHistoryStateError::History(err));
+                }
+            },
+            false,
+        );
+
+        (value, set_value)
+    };
+
+    (value.into(), set_value)
+}
+
+#[cfg(not(feature = "ssr"))]
+fn read_encoded(key: &str) -> Option<String> {
+    let state = window().history().ok()?.state().ok()?;
+
+    if !state.is_object() {
+        return None;
+    }
+
+    js_sys::Reflect::get(&state, &JsValue::from_str(key))
+        .ok()?
+        .as_string()
+}
+
+/// Writes `encoded` under `key` into a copy of the current state.
+#[cfg(not(feature = "ssr"))]
+fn write_encoded(key: &str, encoded: &str, write_mode: HistoryWriteMode) -> Result<(), JsValue> {
+    let history = window().history()?;
+    let current_state = history.state()?;
+
+    let state = js_sys::Object::new();
+    if current_state.is_object() {
+        js_sys::Object::assign(&state, current_state.unchecked_ref());
+    }
+    js_sys::Reflect::set(&state, &JsValue::from_str(key), &JsValue::from_str(encoded))?;
+
+    match write_mode {
+        HistoryWriteMode::Replace => history.replace_state(&state, ""),
+        HistoryWriteMode::Push => history.push_state(&state, ""),
+    }
+}
+
+/// Options for [`use_history_state_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseHistoryStateOptions<T, E, D> {
+    /// Value to use if the history entry has no value for the key. Defaults to `T::default()`.
+    #[builder(skip)]
+    initial_value: T,
+
+    /// How changes are written to the browser history. Defaults to `HistoryWriteMode::Replace`.
+    write_mode: HistoryWriteMode,
+
+    /// Callback for when the value can't be (en)decoded or written.
+    #[builder(skip)]
+    on_error: Arc<dyn Fn(UseHistoryStateError<E, D>) + Send + Sync>,
+}
+
+impl<T: Default, E, D> Default for UseHistoryStateOptions<T, E, D> {
+    fn default() -> Self {
+        Self {
+            initial_value: T::default(),
+            write_mode: HistoryWriteMode::default(),
+            on_error: Arc::new(|_| ()),
+        }
+    }
+}
+
+impl<T, E, D> UseHistoryStateOptions<T, E, D> {
+    /// Value to use if the history entry has no value for the key.
+    pub fn initial_value(self, initial_value: T) -> Self {
+        Self {
+            initial_value,
+            ..self
+        }
+    }
+
+    /// Optional callback for when the value can't be (en)decoded or written.
+    pub fn on_error(
+        self,
+        on_error: impl Fn(UseHistoryStateError<E, D>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            on_error: Arc::new(on_error),
+            ..self
+        }
+    }
+}
+
+/// Error type for [`use_history_state_with_options`].
+#[derive(Error, Debug)]
+pub enum UseHistoryStateError<E, D> {
+    #[error("failed to access history")]
+    History(JsValue),
+    #[error("failed to encode / decode value")]
+    Codec(CodecError<E, D>),
+}
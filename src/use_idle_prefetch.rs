@@ -0,0 +1,314 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Run prefetch and warmup tasks while the user is idle.
+///
+/// The tasks are run one after another in browser idle periods using
+/// [`requestIdleCallback`](https://developer.mozilla.org/en-US/docs/Web/API/Window/requestIdleCallback)
+/// but only while the user is inactive and the browser is online. As soon as the user interacts
+/// with the page again, the remaining tasks are put on hold until the user is idle again.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_idle_prefetch)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_idle_prefetch, IdlePrefetchTask, UseIdlePrefetchReturn};
+/// #
+/// # fn prefetch(url: &str) {}
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseIdlePrefetchReturn { pending, .. } = use_idle_prefetch(vec![
+///     IdlePrefetchTask::new(|| prefetch("/settings")),
+///     IdlePrefetchTask::new(|| prefetch("/profile")),
+/// ]);
+///
+/// view! { <p>{move || pending.get()} " pages left to prefetch"</p> }
+/// # }
+/// ```
+///
+/// A task is a closure that is called once. It should start its work quickly, like spawning a
+/// fetch, because it runs on the main thread. Within one idle period as many tasks are run as fit
+/// into the time the browser has left. Tasks that have already been started aren't cancelled when
+/// the user becomes active again.
+///
+/// More tasks can be added later by calling `enqueue`. `pending` is the number of tasks that
+/// haven't been run yet and `is_active` tells whether tasks are currently allowed to run.
+///
+/// ### Options
+///
+/// The user is considered idle after `idle_timeout` milliseconds without activity, by default two
+/// seconds. Set the option `require_online` to `false` to also run the tasks while offline.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_idle_prefetch_with_options, IdlePrefetchTask, UseIdlePrefetchOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let prefetch = use_idle_prefetch_with_options(
+///     vec![IdlePrefetchTask::new(|| { /* warm up a cache */ })],
+///     UseIdlePrefetchOptions::default()
+///         .idle_timeout(500)
+///         .require_online(false),
+/// );
+///
+/// (prefetch.enqueue)(IdlePrefetchTask::new(|| { /* compile a template */ }));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// In browsers without `requestIdleCallback` one task is run per `setTimeout` instead.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `enqueue` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_idle_prefetch`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no tasks are run. `pending` is always the number of initial tasks and `is_active`
+/// is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_idle`]
+/// * [`fn@crate::use_async_queue`]
+pub fn use_idle_prefetch(
+    tasks: Vec<IdlePrefetchTask>,
+) -> UseIdlePrefetchReturn<impl Fn(IdlePrefetchTask) + Clone + Send + Sync> {
+    use_idle_prefetch_with_options(tasks, UseIdlePrefetchOptions::default())
+}
+
+/// Version of [`use_idle_prefetch`] that takes a `UseIdlePrefetchOptions`. See [`use_idle_prefetch`] for how to use.
+pub fn use_idle_prefetch_with_options(
+    tasks: Vec<IdlePrefetchTask>,
+    options: UseIdlePrefetchOptions,
+) -> UseIdlePrefetchReturn<impl Fn(IdlePrefetchTask) + Clone + Send + Sync> {
+    let UseIdlePrefetchOptions {
+        idle_timeout,
+        require_online,
+    } = options;
+
+    let (pending, set_pending) = signal(tasks.len());
+
+    let is_active;
+    let enqueue;
+
+    #[cfg(feature = "ssr")]
+    {
+        is_active = Signal::derive(|| false);
+        enqueue = |_: IdlePrefetchTask| {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_event_listener, use_idle, use_window, UseIdleReturn};
+        use leptos::ev::{offline, online};
+
+        let UseIdleReturn { idle, .. } = use_idle(idle_timeout);
+
+        let (is_online, set_online) = signal(window().navigator().on_line());
+        let _ = use_event_listener(use_window(), online, move |_| set_online.set(true));
+        let _ = use_event_listener(use_window(), offline, move |_| set_online.set(false));
+
+        is_active = Signal::derive(move || idle.get() && (!require_online || is_online.get()));
+
+        let prefetcher = Rc::new(IdlePrefetcher {
+            tasks: RefCell::new(tasks.into()),
+            scheduled: Cell::new(None),
+            set_pending,
+            is_active,
+        });
+
+        let _ = Effect::watch(
+            move || is_active.get(),
+            {
+                let prefetcher = Rc::clone(&prefetcher);
+
+                move |is_active, _, _| {
+                    if *is_active {
+                        prefetcher.schedule();
+                    } else {
+                        prefetcher.cancel();
+                    }
+                }
+            },
+            true,
+        );
+
+        on_cleanup({
+            let prefetcher = Rc::clone(&prefetcher);
+
+            sendwrap_fn!(move || prefetcher.cancel())
+        });
+
+        enqueue = sendwrap_fn!(move |task: IdlePrefetchTask| {
+            prefetcher.tasks.borrow_mut().push_back(task);
+            prefetcher.update_pending();
+            prefetcher.schedule();
+        });
+    }
+
+    UseIdlePrefetchReturn {
+        pending: pending.into(),
+        is_active,
+        enqueue,
+    }
+}
+
+/// A task of [`use_idle_prefetch`].
+pub struct IdlePrefetchTask(Box<dyn FnOnce()>);
+
+impl IdlePrefetchTask {
+    /// Creates a task from a closure that is called once when the user is idle.
+    pub fn new(task: impl FnOnce() + 'static) -> Self {
+        Self(Box::new(task))
+    }
+}
+
+/// Handle of the callback that runs the next tasks.
+#[derive(Clone, Copy)]
+enum ScheduledCallback {
+    Idle(u32),
+    Timeout(i32),
+}
+
+/// Runs the tasks in idle periods.
+struct IdlePrefetcher {
+    tasks: RefCell<VecDeque<IdlePrefetchTask>>,
+    scheduled: Cell<Option<ScheduledCallback>>,
+    set_pending: WriteSignal<usize>,
+    is_active: Signal<bool>,
+}
+
+impl IdlePrefetcher {
+    /// Requests an idle callback if there are tasks left and none is requested yet.
+    fn schedule(self: &Rc<Self>) {
+        use wasm_bindgen::prelude::*;
+        use wasm_bindgen::JsCast;
+
+        if self.scheduled.get().is_some()
+            || self.tasks.borrow().is_empty()
+            || !self.is_active.get_untracked()
+        {
+            return;
+        }
+
+        // the prefetcher must not be kept alive by callbacks that are cancelled and never called
+        let prefetcher = Rc::downgrade(self);
+
+        let window = window();
+
+        let scheduled = if js_sys::Reflect::has(&window, &"requestIdleCallback".into())
+            .unwrap_or(false)
+        {
+            let callback = Closure::once_into_js(move |deadline: web_sys::IdleDeadline| {
+                if let Some(prefetcher) = prefetcher.upgrade() {
+                    prefetcher.run(Some(deadline));
+                }
+            });
+
+            window
+                .request_idle_callback(callback.unchecked_ref())
+                .ok()
+                .map(ScheduledCallback::Idle)
+        } else {
+            let callback = Closure::once_into_js(move || {
+                if let Some(prefetcher) = prefetcher.upgrade() {
+                    prefetcher.run(None);
+                }
+            });
+
+            window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(callback.unchecked_ref(), 1)
+                .ok()
+                .map(ScheduledCallback::Timeout)
+        };
+
+        self.scheduled.set(scheduled);
+    }
+
+    /// Cancels the requested idle callback.
+    fn cancel(&self) {
+        match self.scheduled.take() {
+            Some(ScheduledCallback::Idle(handle)) => window().cancel_idle_callback(handle),
+            Some(ScheduledCallback::Timeout(handle)) => window().clear_timeout_with_handle(handle),
+            None => {}
+        }
+    }
+
+    /// Runs tasks until the idle period is over and requests the next idle callback.
+    fn run(self: &Rc<Self>, deadline: Option<web_sys::IdleDeadline>) {
+        self.scheduled.set(None);
+
+        while self.is_active.get_untracked() {
+            let Some(IdlePrefetchTask(task)) = self.tasks.borrow_mut().pop_front() else {
+                break;
+            };
+
+            self.update_pending();
+            task();
+
+            if deadline
+                .as_ref()
+                .is_none_or(|deadline| deadline.time_remaining() <= 0.0)
+            {
+                break;
+            }
+        }
+
+        self.schedule();
+    }
+
+    fn update_pending(&self) {
+        self.set_pending.try_set(self.tasks.borrow().len());
+    }
+}
+
+/// Options for [`use_idle_prefetch_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseIdlePrefetchOptions {
+    /// Milliseconds without user activity after which the user is considered idle.
+    /// Defaults to `2000`.
+    idle_timeout: u64,
+
+    /// Whether tasks are only run while the browser is online. Defaults to `true`.
+    require_online: bool,
+}
+
+impl Default for UseIdlePrefetchOptions {
+    fn default() -> Self {
+        Self {
+            idle_timeout: 2000,
+            require_online: true,
+        }
+    }
+}
+
+/// Return type of [`use_idle_prefetch`].
+pub struct UseIdlePrefetchReturn<EnqueueFn>
+where
+    EnqueueFn: Fn(IdlePrefetchTask) + Clone + Send + Sync,
+{
+    /// Number of tasks that haven't been run yet
+    pub pending: Signal<usize>,
+
+    /// Whether the user is idle and the browser is online so tasks are run
+    pub is_active: Signal<bool>,
+
+    /// Adds a task to the end of the queue
+    pub enqueue: EnqueueFn,
+}
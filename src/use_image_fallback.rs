@@ -0,0 +1,224 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Attribute that contains the original `src` of images that failed to load.
+const FALLBACK_ATTRIBUTE: &str = "data-fallback-for";
+
+/// Replaces images that fail to load with a fallback.
+///
+/// `target` can be a single image or an element that contains images. When an image fails to
+/// load, its `src` is replaced with `fallback_src`. Images that are added later are handled as
+/// well because the error events are listened to on `target`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_image_fallback)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::use_image_fallback;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let gallery = NodeRef::<Div>::new();
+///
+/// let failed = use_image_fallback(gallery, "/images/placeholder.png");
+///
+/// view! {
+///     <div node_ref=gallery>
+///         <img src="https://example.com/missing.png" alt="Missing" />
+///         <img src="https://example.com/also-missing.png" alt="Also missing" />
+///     </div>
+///     <p>{failed} " images failed to load"</p>
+/// }
+/// # }
+/// ```
+///
+/// The returned signal contains the number of images that failed to load. Images that had
+/// already failed before `target` was available are found and replaced as well.
+///
+/// A replaced image keeps its original source in the attribute `data-fallback-for` so it can be
+/// styled differently. Its `srcset` is removed because it would take precedence over `src`.
+///
+/// ### Placeholder
+///
+/// If the fallback fails to load as well, the image can be replaced by a placeholder. Set the
+/// option `placeholder_class` to replace it with a `span` with that class that contains the `alt`
+/// text of the image.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_image_fallback_with_options, UseImageFallbackOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let gallery = NodeRef::<Div>::new();
+/// let failed = use_image_fallback_with_options(
+///     gallery,
+///     "/images/placeholder.png",
+///     UseImageFallbackOptions::default().placeholder_class("image-placeholder"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// With an empty `fallback_src` failed images are replaced by the placeholder right away.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no images are replaced and the returned signal always contains `0`.
+pub fn use_image_fallback<El, M>(target: El, fallback_src: impl Into<String>) -> Signal<usize>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_image_fallback_with_options(target, fallback_src, UseImageFallbackOptions::default())
+}
+
+/// Version of [`use_image_fallback`] that takes a `UseImageFallbackOptions`. See [`use_image_fallback`] for how to use.
+pub fn use_image_fallback_with_options<El, M>(
+    target: El,
+    fallback_src: impl Into<String>,
+    options: UseImageFallbackOptions,
+) -> Signal<usize>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let (failed, set_failed) = signal(0_usize);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_event_listener_with_options, UseEventListenerOptions};
+        use leptos::ev::error;
+        use wasm_bindgen::JsCast;
+
+        let UseImageFallbackOptions { placeholder_class } = options;
+
+        let fallback_src = fallback_src.into();
+        let target = target.into_element_maybe_signal();
+
+        let handle_error = move |img: &web_sys::HtmlImageElement| {
+            let replace = |img: &web_sys::HtmlImageElement| {
+                if let Some(class) = &placeholder_class {
+                    replace_with_placeholder(img, class);
+                }
+            };
+
+            if img.has_attribute(FALLBACK_ATTRIBUTE) {
+                // the fallback failed as well
+                replace(img);
+                return;
+            }
+
+            let _ = img.set_attribute(FALLBACK_ATTRIBUTE, &img.src());
+            set_failed.update(|failed| *failed += 1);
+
+            if fallback_src.is_empty() {
+                replace(img);
+            } else {
+                let _ = img.remove_attribute("srcset");
+                img.set_src(&fallback_src);
+            }
+        };
+
+        // error events don't bubble so they're caught in the capture phase
+        let _ = use_event_listener_with_options(
+            target,
+            error,
+            {
+                let handle_error = handle_error.clone();
+
+                move |event| {
+                    if let Some(img) = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<web_sys::HtmlImageElement>().ok())
+                    {
+                        handle_error(&img);
+                    }
+                }
+            },
+            UseEventListenerOptions::default().capture(true),
+        );
+
+        // images that failed before the listener was added
+        let _ = Effect::watch(
+            move || target.get(),
+            move |target, _, _| {
+                let Some(target) = target else {
+                    return;
+                };
+
+                for img in images(target) {
+                    let has_failed = img.complete()
+                        && img.natural_width() == 0
+                        && !img.get_attribute("src").unwrap_or_default().is_empty();
+
+                    if has_failed && !img.has_attribute(FALLBACK_ATTRIBUTE) {
+                        handle_error(&img);
+                    }
+                }
+            },
+            true,
+        );
+    }
+
+    failed.into()
+}
+
+/// `target` itself if it's an image and all images inside of it.
+#[cfg(not(feature = "ssr"))]
+fn images(target: &web_sys::Element) -> Vec<web_sys::HtmlImageElement> {
+    use wasm_bindgen::JsCast;
+
+    let mut images = vec![];
+
+    if let Some(img) = target.dyn_ref::<web_sys::HtmlImageElement>() {
+        images.push(img.clone());
+    }
+
+    if let Ok(nodes) = target.query_selector_all("img") {
+        images.extend(
+            (0..nodes.length())
+                .filter_map(|i| nodes.item(i))
+                .filter_map(|node| node.dyn_into::<web_sys::HtmlImageElement>().ok()),
+        );
+    }
+
+    images
+}
+
+/// Replaces `img` with a `span` with the class `class` that contains the `alt` text of the image.
+#[cfg(not(feature = "ssr"))]
+fn replace_with_placeholder(img: &web_sys::HtmlImageElement, class: &str) {
+    let Ok(placeholder) = document().create_element("span") else {
+        return;
+    };
+
+    placeholder.set_class_name(class);
+    let _ = placeholder.set_attribute("role", "img");
+
+    let alt = img.alt();
+    if !alt.is_empty() {
+        let _ = placeholder.set_attribute("aria-label", &alt);
+        placeholder.set_text_content(Some(&alt));
+    }
+
+    let _ = img.replace_with_with_node_1(&placeholder);
+}
+
+/// Options for [`use_image_fallback_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseImageFallbackOptions {
+    /// If set, images whose fallback fails to load as well are replaced by a `span` with this
+    /// class. Defaults to `None`.
+    #[builder(into)]
+    placeholder_class: Option<String>,
+}
@@ -0,0 +1,679 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use leptos::prelude::*;
+use std::fmt::Display;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive metadata of an image file.
+///
+/// Whenever `file` changes, the dimensions, the EXIF orientation and the capture date are parsed
+/// from the headers of the image. JPEG, PNG and HEIF (HEIC / AVIF) files are supported. Only the
+/// beginning of the file is read, not the whole image.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_image_metadata)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_image_metadata, UseImageMetadataReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (file, set_file) = signal_local(None::<web_sys::File>);
+///
+/// let UseImageMetadataReturn { metadata, .. } = use_image_metadata(file);
+///
+/// let transform = move || {
+///     metadata
+///         .get()
+///         .map(|metadata| metadata.orientation.css_transform())
+///         .unwrap_or_default()
+/// };
+///
+/// view! {
+///     <canvas style:transform=transform />
+///     <p>"Taken on " {move || metadata.get().and_then(|metadata| metadata.capture_date)}</p>
+/// }
+/// # }
+/// ```
+///
+/// `width` and `height` are the dimensions as stored in the file, before the orientation is
+/// applied. Use [`ImageMetadata::oriented_size`] to get the dimensions as the image should be
+/// displayed.
+///
+/// Browsers apply the EXIF orientation to `<img>` elements by default (see the CSS property
+/// [`image-orientation`](https://developer.mozilla.org/en-US/docs/Web/CSS/image-orientation)).
+/// [`ImageOrientation::css_transform`] is useful for everything else, like drawing the image on
+/// a canvas.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is parsed. `metadata` and `error` are always `None` and `is_loading` is
+/// always `false`.
+pub fn use_image_metadata(
+    file: impl Into<Signal<Option<web_sys::File>, LocalStorage>>,
+) -> UseImageMetadataReturn {
+    let file = file.into();
+
+    let (metadata, set_metadata) = signal(None::<ImageMetadata>);
+    let (is_loading, set_loading) = signal(false);
+    let (error, set_error) = signal_local(None::<UseImageMetadataError>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use leptos::task::spawn_local;
+
+        let run_id = StoredValue::new(0_u64);
+
+        let _ = Effect::watch(
+            move || file.get(),
+            move |file, _, _| {
+                let id = run_id.get_value() + 1;
+                run_id.set_value(id);
+
+                set_metadata.set(None);
+                set_error.set(None);
+                set_loading.set(false);
+
+                let Some(file) = file.clone() else {
+                    return;
+                };
+
+                set_loading.set(true);
+
+                spawn_local(async move {
+                    let result = read_metadata(&file).await;
+
+                    // results of previous files are outdated
+                    if run_id.try_get_value() != Some(id) {
+                        return;
+                    }
+
+                    match result {
+                        Ok(metadata) => set_metadata.set(Some(metadata)),
+                        Err(err) => set_error.set(Some(err)),
+                    }
+
+                    set_loading.set(false);
+                });
+            },
+            true,
+        );
+    }
+
+    UseImageMetadataReturn {
+        metadata: metadata.into(),
+        is_loading: is_loading.into(),
+        error: error.into(),
+    }
+}
+
+/// Number of bytes at the beginning of the file that are searched for metadata.
+const HEADER_SIZE: f64 = 256.0 * 1024.0;
+
+#[cfg(not(feature = "ssr"))]
+async fn read_metadata(file: &web_sys::File) -> Result<ImageMetadata, UseImageMetadataError> {
+    let header = read_range(file, 0.0, HEADER_SIZE)
+        .await
+        .map_err(UseImageMetadataError::Read)?;
+
+    if header.starts_with(&[0xFF, 0xD8]) {
+        Ok(parse_jpeg(&header))
+    } else if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Ok(parse_png(&header))
+    } else if header.get(4..8) == Some(b"ftyp".as_slice()) {
+        let (mut metadata, exif_location) = parse_heif(&header);
+
+        // the EXIF data of HEIF files can be anywhere in the file
+        if let Some((offset, length)) = exif_location {
+            let exif = read_range(file, offset as f64, offset.saturating_add(length) as f64)
+                .await
+                .map_err(UseImageMetadataError::Read)?;
+
+            let tiff = read_u32(&exif, 0, false)
+                .and_then(|tiff_offset| (tiff_offset as usize).checked_add(4))
+                .and_then(|start| exif.get(start..));
+
+            if let Some(tiff) = tiff {
+                parse_exif(tiff, &mut metadata);
+            }
+        }
+
+        Ok(metadata)
+    } else {
+        Err(UseImageMetadataError::UnsupportedFormat)
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn read_range(file: &web_sys::File, start: f64, end: f64) -> Result<Vec<u8>, JsValue> {
+    use crate::js_fut;
+
+    let buffer = js_fut!(file.slice_with_f64_and_f64(start, end)?.array_buffer()).await?;
+
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+fn parse_jpeg(data: &[u8]) -> ImageMetadata {
+    let mut metadata = ImageMetadata::new(ImageMetadataFormat::Jpeg);
+
+    let mut offset = 2;
+
+    while let (Some(0xFF), Some(&marker)) = (data.get(offset), data.get(offset + 1)) {
+        match marker {
+            // fill byte
+            0xFF => {
+                offset += 1;
+                continue;
+            }
+            // markers without a segment
+            0x01 | 0xD0..=0xD8 => {
+                offset += 2;
+                continue;
+            }
+            // start of scan or end of image. The image data follows.
+            0xD9 | 0xDA => break,
+            _ => {}
+        }
+
+        let Some(length) = read_u16(data, offset + 2, false) else {
+            break;
+        };
+        let segment = data.get(offset + 4..offset + 2 + length as usize);
+
+        match (marker, segment) {
+            (0xE1, Some(segment)) if segment.starts_with(b"Exif\0\0") => {
+                parse_exif(&segment[6..], &mut metadata);
+            }
+            // start of frame except for DHT, JPG and DAC which share the range
+            (0xC0..=0xCF, Some(segment)) if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                metadata.height = read_u16(segment, 1, false).map(u32::from);
+                metadata.width = read_u16(segment, 3, false).map(u32::from);
+            }
+            _ => {}
+        }
+
+        offset += 2 + length as usize;
+    }
+
+    metadata
+}
+
+fn parse_png(data: &[u8]) -> ImageMetadata {
+    let mut metadata = ImageMetadata::new(ImageMetadataFormat::Png);
+
+    let mut offset = 8;
+
+    while let Some(length) = read_u32(data, offset, false) {
+        let chunk_type = data.get(offset + 4..offset + 8);
+        let chunk = (offset + 8)
+            .checked_add(length as usize)
+            .and_then(|end| data.get(offset + 8..end));
+        let Some(chunk) = chunk else {
+            break;
+        };
+
+        match chunk_type {
+            Some(b"IHDR") => {
+                metadata.width = read_u32(chunk, 0, false);
+                metadata.height = read_u32(chunk, 4, false);
+            }
+            Some(b"eXIf") => parse_exif(chunk, &mut metadata),
+            Some(b"IEND") => break,
+            _ => {}
+        }
+
+        // chunk header and CRC
+        offset += 12 + length as usize;
+    }
+
+    metadata
+}
+
+/// Parses the boxes of a HEIF file. Returns the metadata and the offset and length of the EXIF
+/// item (if any).
+fn parse_heif(data: &[u8]) -> (ImageMetadata, Option<(u64, u64)>) {
+    let mut metadata = ImageMetadata::new(ImageMetadataFormat::Heif);
+    let mut exif_location = None;
+
+    let Some((_, meta)) = IsoBoxes(data).find(|(box_type, _)| *box_type == b"meta") else {
+        return (metadata, None);
+    };
+
+    // skip version and flags of the full box
+    let meta = meta.get(4..).unwrap_or_default();
+
+    let mut exif_item_id = None;
+    let mut locations = vec![];
+
+    for (box_type, content) in IsoBoxes(meta) {
+        match box_type {
+            b"iinf" => exif_item_id = parse_iinf(content),
+            b"iloc" => locations = parse_iloc(content).unwrap_or_default(),
+            b"iprp" => {
+                let properties = IsoBoxes(content)
+                    .filter(|(box_type, _)| *box_type == b"ipco")
+                    .flat_map(|(_, ipco)| IsoBoxes(ipco));
+
+                // The dimensions of the primary image. Thumbnails and grid tiles are smaller.
+                for (box_type, ispe) in properties {
+                    if box_type != b"ispe" {
+                        continue;
+                    }
+
+                    if let (Some(width), Some(height)) =
+                        (read_u32(ispe, 4, false), read_u32(ispe, 8, false))
+                    {
+                        let area = |width: Option<u32>, height: Option<u32>| {
+                            width.unwrap_or(0) as u64 * height.unwrap_or(0) as u64
+                        };
+
+                        if area(Some(width), Some(height)) > area(metadata.width, metadata.height) {
+                            metadata.width = Some(width);
+                            metadata.height = Some(height);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(exif_item_id) = exif_item_id {
+        exif_location = locations
+            .into_iter()
+            .find(|(item_id, _, _)| *item_id == exif_item_id)
+            .map(|(_, offset, length)| (offset, length));
+    }
+
+    (metadata, exif_location)
+}
+
+/// Returns the id of the EXIF item of an `iinf` box.
+fn parse_iinf(data: &[u8]) -> Option<u32> {
+    let version = *data.first()?;
+    let entries = data.get(if version == 0 { 6 } else { 8 }..)?;
+
+    IsoBoxes(entries)
+        .filter(|(box_type, _)| *box_type == b"infe")
+        .find_map(|(_, infe)| {
+            let version = *infe.first()?;
+
+            let (item_id, item_type_offset) = match version {
+                2 => (read_u16(infe, 4, false)? as u32, 8),
+                3 => (read_u32(infe, 4, false)?, 10),
+                _ => return None,
+            };
+
+            (infe.get(item_type_offset..item_type_offset + 4)? == b"Exif").then_some(item_id)
+        })
+}
+
+/// Returns the id, offset and length of the first extent of every item of an `iloc` box.
+fn parse_iloc(data: &[u8]) -> Option<Vec<(u32, u64, u64)>> {
+    let version = *data.first()?;
+
+    let sizes = *data.get(4)?;
+    let (offset_size, length_size) = ((sizes >> 4) as usize, (sizes & 0xF) as usize);
+
+    let sizes = *data.get(5)?;
+    let base_offset_size = (sizes >> 4) as usize;
+    let index_size = if version > 0 {
+        (sizes & 0xF) as usize
+    } else {
+        0
+    };
+
+    let mut offset = 6;
+
+    let read_sized = |offset: &mut usize, size: usize| {
+        let value = match size {
+            0 => Some(0),
+            2 => read_u16(data, *offset, false).map(u64::from),
+            4 => read_u32(data, *offset, false).map(u64::from),
+            8 => read_u64(data, *offset),
+            _ => None,
+        };
+        *offset += size;
+        value
+    };
+
+    // item ids and counts are 16 bit before version 2
+    let id_size = if version < 2 { 2 } else { 4 };
+
+    let item_count = read_sized(&mut offset, id_size)?;
+
+    let mut items = vec![];
+
+    for _ in 0..item_count {
+        let item_id = read_sized(&mut offset, id_size)? as u32;
+
+        let construction_method = if version > 0 {
+            read_sized(&mut offset, 2)? & 0xF
+        } else {
+            0
+        };
+
+        // data reference index
+        offset += 2;
+
+        let base_offset = read_sized(&mut offset, base_offset_size)?;
+
+        let extent_count = read_sized(&mut offset, 2)?;
+
+        let mut first_extent = None;
+
+        for _ in 0..extent_count {
+            offset += index_size;
+            let extent_offset = read_sized(&mut offset, offset_size)?;
+            let extent_length = read_sized(&mut offset, length_size)?;
+
+            first_extent.get_or_insert((extent_offset, extent_length));
+        }
+
+        // only items that are stored in the file itself are supported
+        if let (0, Some((extent_offset, extent_length))) = (construction_method, first_extent) {
+            items.push((
+                item_id,
+                base_offset.saturating_add(extent_offset),
+                extent_length,
+            ));
+        }
+    }
+
+    Some(items)
+}
+
+/// Iterator over the boxes of an ISO base media file (like HEIF) as tuples of type and content.
+struct IsoBoxes<'a>(&'a [u8]);
+
+impl<'a> Iterator for IsoBoxes<'a> {
+    type Item = (&'a [u8], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.0;
+
+        let box_type = data.get(4..8)?;
+
+        let (header_size, size) = match read_u32(data, 0, false)? {
+            // extends to the end
+            0 => (8, data.len()),
+            // 64 bit size
+            1 => (16, read_u64(data, 8)? as usize),
+            size => (8, size as usize),
+        };
+
+        let content = data.get(header_size..size)?;
+        self.0 = &data[size..];
+
+        Some((box_type, content))
+    }
+}
+
+/// Parses the orientation and capture date from the TIFF structure of EXIF data.
+fn parse_exif(tiff: &[u8], metadata: &mut ImageMetadata) {
+    let little_endian = match tiff.get(0..2) {
+        Some(b"II") => true,
+        Some(b"MM") => false,
+        _ => return,
+    };
+
+    let entries = |ifd_offset: u32| {
+        let ifd_offset = ifd_offset as usize;
+        let count = read_u16(tiff, ifd_offset, little_endian).unwrap_or(0) as usize;
+
+        (0..count).filter_map(move |i| {
+            let entry = ifd_offset.saturating_add(2 + i * 12);
+            Some((read_u16(tiff, entry, little_endian)?, entry))
+        })
+    };
+
+    let Some(ifd0) = read_u32(tiff, 4, little_endian) else {
+        return;
+    };
+
+    let mut date_time = None;
+    let mut exif_ifd = None;
+
+    for (tag, entry) in entries(ifd0) {
+        match tag {
+            0x0112 => {
+                if let Some(orientation) = read_u16(tiff, entry + 8, little_endian)
+                    .and_then(|value| ImageOrientation::try_from(value).ok())
+                {
+                    metadata.orientation = orientation;
+                }
+            }
+            0x0132 => date_time = read_ascii(tiff, entry, little_endian),
+            0x8769 => exif_ifd = read_u32(tiff, entry + 8, little_endian),
+            _ => {}
+        }
+    }
+
+    let date_time_original = exif_ifd.and_then(|exif_ifd| {
+        entries(exif_ifd)
+            .find(|(tag, _)| *tag == 0x9003)
+            .and_then(|(_, entry)| read_ascii(tiff, entry, little_endian))
+    });
+
+    metadata.capture_date = date_time_original.or(date_time).map(|date| {
+        // "YYYY:MM:DD HH:MM:SS" -> "YYYY-MM-DDTHH:MM:SS"
+        date.char_indices()
+            .map(|(i, c)| match (i, c) {
+                (4 | 7, ':') => '-',
+                (10, ' ') => 'T',
+                _ => c,
+            })
+            .collect()
+    });
+}
+
+/// Reads the ASCII value of the IFD entry at `entry`.
+fn read_ascii(tiff: &[u8], entry: usize, little_endian: bool) -> Option<String> {
+    let count = read_u32(tiff, entry + 4, little_endian)? as usize;
+
+    // values of up to 4 bytes are stored in the entry itself
+    let offset = if count <= 4 {
+        entry + 8
+    } else {
+        read_u32(tiff, entry + 8, little_endian)? as usize
+    };
+
+    let value = tiff.get(offset..offset.checked_add(count)?)?;
+    let value = value.split(|byte| *byte == 0).next()?;
+
+    let value = std::str::from_utf8(value).ok()?.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn read_u16(data: &[u8], offset: usize, little_endian: bool) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?.try_into().ok()?;
+
+    Some(if little_endian {
+        u16::from_le_bytes(bytes)
+    } else {
+        u16::from_be_bytes(bytes)
+    })
+}
+
+fn read_u32(data: &[u8], offset: usize, little_endian: bool) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+
+    Some(if little_endian {
+        u32::from_le_bytes(bytes)
+    } else {
+        u32::from_be_bytes(bytes)
+    })
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(
+        data.get(offset..offset.checked_add(8)?)?.try_into().ok()?,
+    ))
+}
+
+/// Metadata of an image returned by [`fn@crate::use_image_metadata`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageMetadata {
+    /// Format of the image file.
+    pub format: ImageMetadataFormat,
+
+    /// Width in pixels as stored in the file. `None` if it couldn't be found.
+    pub width: Option<u32>,
+
+    /// Height in pixels as stored in the file. `None` if it couldn't be found.
+    pub height: Option<u32>,
+
+    /// EXIF orientation. [`ImageOrientation::TopLeft`] if the image doesn't specify one.
+    pub orientation: ImageOrientation,
+
+    /// The date and time when the image was taken as `YYYY-MM-DDTHH:MM:SS` in the (unknown)
+    /// local time of the camera.
+    pub capture_date: Option<String>,
+}
+
+impl ImageMetadata {
+    fn new(format: ImageMetadataFormat) -> Self {
+        Self {
+            format,
+            width: None,
+            height: None,
+            orientation: ImageOrientation::default(),
+            capture_date: None,
+        }
+    }
+
+    /// Width and height after applying the orientation.
+    pub fn oriented_size(&self) -> Option<(u32, u32)> {
+        let (width, height) = (self.width?, self.height?);
+
+        if self.orientation.swaps_dimensions() {
+            Some((height, width))
+        } else {
+            Some((width, height))
+        }
+    }
+}
+
+/// Format of the file read by [`fn@crate::use_image_metadata`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImageMetadataFormat {
+    Jpeg,
+    Png,
+    /// HEIC, AVIF and other HEIF based formats.
+    Heif,
+}
+
+impl Display for ImageMetadataFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Jpeg => write!(f, "JPEG"),
+            Self::Png => write!(f, "PNG"),
+            Self::Heif => write!(f, "HEIF"),
+        }
+    }
+}
+
+/// EXIF orientation of an image. The names describe where the first row and the first column
+/// of the stored image are supposed to be displayed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ImageOrientation {
+    /// No transformation needed.
+    #[default]
+    TopLeft = 1,
+    /// Mirrored horizontally.
+    TopRight = 2,
+    /// Rotated by 180°.
+    BottomRight = 3,
+    /// Mirrored vertically.
+    BottomLeft = 4,
+    /// Mirrored horizontally and rotated by 90° clockwise.
+    LeftTop = 5,
+    /// Rotated by 90° clockwise.
+    RightTop = 6,
+    /// Mirrored horizontally and rotated by 270° clockwise.
+    RightBottom = 7,
+    /// Rotated by 270° clockwise.
+    LeftBottom = 8,
+}
+
+impl ImageOrientation {
+    /// Clockwise rotation in degrees that has to be applied to display the image correctly.
+    /// Mirroring (see [`Self::is_mirrored`]) has to be applied before the rotation.
+    pub fn rotation(&self) -> u16 {
+        match self {
+            Self::TopLeft | Self::TopRight => 0,
+            Self::BottomRight | Self::BottomLeft => 180,
+            Self::LeftTop | Self::RightTop => 90,
+            Self::RightBottom | Self::LeftBottom => 270,
+        }
+    }
+
+    /// Whether the image has to be mirrored horizontally to be displayed correctly.
+    pub fn is_mirrored(&self) -> bool {
+        matches!(
+            self,
+            Self::TopRight | Self::BottomLeft | Self::LeftTop | Self::RightBottom
+        )
+    }
+
+    /// Whether width and height are swapped when displaying the image.
+    pub fn swaps_dimensions(&self) -> bool {
+        !self.rotation().is_multiple_of(180)
+    }
+
+    /// Value of the CSS property `transform` that displays the image correctly. Empty for
+    /// [`Self::TopLeft`].
+    pub fn css_transform(&self) -> String {
+        let rotation = self.rotation();
+
+        match (rotation, self.is_mirrored()) {
+            (0, false) => String::new(),
+            (0, true) => "scaleX(-1)".to_string(),
+            (_, false) => format!("rotate({rotation}deg)"),
+            (_, true) => format!("rotate({rotation}deg) scaleX(-1)"),
+        }
+    }
+}
+
+impl TryFrom<u16> for ImageOrientation {
+    type Error = ();
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::TopLeft),
+            2 => Ok(Self::TopRight),
+            3 => Ok(Self::BottomRight),
+            4 => Ok(Self::BottomLeft),
+            5 => Ok(Self::LeftTop),
+            6 => Ok(Self::RightTop),
+            7 => Ok(Self::RightBottom),
+            8 => Ok(Self::LeftBottom),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Return type of [`use_image_metadata`].
+pub struct UseImageMetadataReturn {
+    /// Metadata of the current file once it has been parsed.
+    pub metadata: Signal<Option<ImageMetadata>>,
+
+    /// Whether the current file is being read.
+    pub is_loading: Signal<bool>,
+
+    /// Latest error.
+    pub error: Signal<Option<UseImageMetadataError>, LocalStorage>,
+}
+
+#[derive(Debug, Error)]
+pub enum UseImageMetadataError {
+    #[error("failed to read the file")]
+    Read(JsValue),
+    #[error("the file is not a JPEG, PNG or HEIF image")]
+    UnsupportedFormat,
+}
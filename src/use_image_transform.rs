@@ -0,0 +1,305 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::fmt::Display;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Resize and (re)compress an image on the client, for example to create a thumbnail before
+/// uploading it.
+///
+/// Whenever `file` changes, the image is decoded with
+/// [`createImageBitmap`](https://developer.mozilla.org/en-US/docs/Web/API/Window/createImageBitmap),
+/// scaled down on an [`OffscreenCanvas`](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas)
+/// and encoded again. All of this happens in a
+/// [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API) so large images
+/// don't block the UI. The worker is generated from an inline script, you don't have to provide one.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_image_transform)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_image_transform_with_options, ImageFormat, UseImageTransformOptions, UseImageTransformReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (file, set_file) = signal_local(None::<web_sys::File>);
+///
+/// let UseImageTransformReturn {
+///     blob,
+///     width,
+///     height,
+///     ..
+/// } = use_image_transform_with_options(
+///     file,
+///     UseImageTransformOptions::default()
+///         .max_width(320_u32)
+///         .max_height(320_u32)
+///         .format(ImageFormat::Webp)
+///         .quality(0.8),
+/// );
+///
+/// view! {
+///     <p>"Thumbnail: " {move || width.get()} "x" {move || height.get()}</p>
+///     <p>"Size: " {move || blob.get().map(|blob| blob.size())} " bytes"</p>
+/// }
+/// # }
+/// ```
+///
+/// The image is scaled down proportionally to fit into `max_width` and `max_height`. It's never
+/// scaled up. Without any of them the image keeps its size and is only re-encoded.
+///
+/// `quality` is a number between `0.0` and `1.0` and is only used by the lossy formats
+/// [`ImageFormat::Jpeg`] and [`ImageFormat::Webp`]. Browsers that can't encode the requested
+/// format fall back to PNG. Check the type of the resulting `Blob` if that matters to you.
+///
+/// A new file discards the result of the previous one. Setting `file` to `None` clears the result.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is created. `blob` and `error` are always `None`, `width` and `height`
+/// are always `0` and `is_transforming` is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_web_worker_fn`]
+pub fn use_image_transform(
+    file: impl Into<Signal<Option<web_sys::File>, LocalStorage>>,
+) -> UseImageTransformReturn {
+    use_image_transform_with_options(file, UseImageTransformOptions::default())
+}
+
+/// Version of [`use_image_transform`] that takes a `UseImageTransformOptions`. See [`use_image_transform`] for how to use.
+pub fn use_image_transform_with_options(
+    file: impl Into<Signal<Option<web_sys::File>, LocalStorage>>,
+    options: UseImageTransformOptions,
+) -> UseImageTransformReturn {
+    let UseImageTransformOptions {
+        max_width,
+        max_height,
+        format,
+        quality,
+    } = options;
+
+    let file = file.into();
+
+    let (blob, set_blob) = signal_local(None::<web_sys::Blob>);
+    let (width, set_width) = signal(0_u32);
+    let (height, set_height) = signal(0_u32);
+    let (is_transforming, set_transforming) = signal(false);
+    let (error, set_error) = signal_local(None::<UseImageTransformError>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use crate::utils::create_inline_worker;
+        use wasm_bindgen::JsCast;
+
+        let run_id = StoredValue::new(0_u64);
+
+        let worker = match create_inline_worker(WORKER_SCRIPT) {
+            Ok((worker, url)) => {
+                let _ = use_event_listener(worker.clone(), leptos::ev::message, move |event| {
+                    let data = event.data();
+                    let get = |key: &str| js_sys::Reflect::get(&data, &key.into()).ok();
+
+                    // results of previous files are outdated
+                    if get("id").and_then(|id| id.as_f64()) != Some(run_id.get_value() as f64) {
+                        return;
+                    }
+
+                    set_transforming.set(false);
+
+                    match get("error").and_then(|error| error.as_string()) {
+                        Some(error) => {
+                            set_error.set(Some(UseImageTransformError::Transform(error)))
+                        }
+                        None => {
+                            let size = |key| get(key).and_then(|size| size.as_f64()).unwrap_or(0.0);
+
+                            set_width.set(size("width") as u32);
+                            set_height.set(size("height") as u32);
+                            set_blob.set(get("blob").map(|blob| blob.unchecked_into()));
+                        }
+                    }
+                });
+
+                let _ = use_event_listener(worker.clone(), leptos::ev::error, move |event| {
+                    set_transforming.set(false);
+                    set_error.set(Some(UseImageTransformError::Event(event.into())));
+                });
+
+                Some((worker, url))
+            }
+            Err(err) => {
+                set_error.set(Some(UseImageTransformError::Create(err)));
+                None
+            }
+        };
+        let worker = StoredValue::new_local(worker);
+
+        let _ = Effect::watch(
+            move || file.get(),
+            move |file, _, _| {
+                let id = run_id.get_value() + 1;
+                run_id.set_value(id);
+
+                set_blob.set(None);
+                set_width.set(0);
+                set_height.set(0);
+                set_error.set(None);
+                set_transforming.set(false);
+
+                let (Some(file), Some((worker, _))) = (file.clone(), worker.get_value()) else {
+                    return;
+                };
+
+                let message = js_sys::Object::new();
+                let set = |key: &str, value: JsValue| {
+                    let _ = js_sys::Reflect::set(&message, &key.into(), &value);
+                };
+
+                set("id", JsValue::from_f64(id as f64));
+                set("file", file.into());
+                set(
+                    "maxWidth",
+                    max_width.map_or(f64::INFINITY, f64::from).into(),
+                );
+                set(
+                    "maxHeight",
+                    max_height.map_or(f64::INFINITY, f64::from).into(),
+                );
+                set("type", format.mime_type().into());
+                set(
+                    "quality",
+                    quality.map_or(JsValue::UNDEFINED, JsValue::from_f64),
+                );
+
+                match worker.post_message(&message) {
+                    Ok(()) => set_transforming.set(true),
+                    Err(err) => set_error.set(Some(UseImageTransformError::PostMessage(err))),
+                }
+            },
+            true,
+        );
+
+        on_cleanup(move || {
+            if let Some((worker, url)) = worker.try_update_value(Option::take).flatten() {
+                worker.terminate();
+                let _ = web_sys::Url::revoke_object_url(&url);
+            }
+        });
+    }
+
+    UseImageTransformReturn {
+        blob: blob.into(),
+        width: width.into(),
+        height: height.into(),
+        is_transforming: is_transforming.into(),
+        error: error.into(),
+    }
+}
+
+/// Source of the worker that decodes, scales and encodes the images it receives.
+const WORKER_SCRIPT: &str = r#"self.onmessage = async ({ data: { id, file, maxWidth, maxHeight, type, quality } }) => {
+  try {
+    const bitmap = await createImageBitmap(file);
+    const scale = Math.min(1, maxWidth / bitmap.width, maxHeight / bitmap.height);
+    const width = Math.max(1, Math.round(bitmap.width * scale));
+    const height = Math.max(1, Math.round(bitmap.height * scale));
+
+    const canvas = new OffscreenCanvas(width, height);
+    const context = canvas.getContext("2d");
+    context.imageSmoothingQuality = "high";
+    context.drawImage(bitmap, 0, 0, width, height);
+    bitmap.close();
+
+    const blob = await canvas.convertToBlob({ type, quality });
+    self.postMessage({ id, blob, width, height });
+  } catch (error) {
+    self.postMessage({ id, error: String(error) });
+  }
+};
+"#;
+
+/// Image format of the result of [`fn@crate::use_image_transform`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// Lossless. Ignores `quality`.
+    #[default]
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ImageFormat {
+    /// MIME type of the format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+        }
+    }
+}
+
+impl Display for ImageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mime_type())
+    }
+}
+
+/// Options for [`use_image_transform_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseImageTransformOptions {
+    /// Maximum width of the resulting image in pixels. Defaults to `None` which means no limit.
+    #[builder(into)]
+    max_width: Option<u32>,
+
+    /// Maximum height of the resulting image in pixels. Defaults to `None` which means no limit.
+    #[builder(into)]
+    max_height: Option<u32>,
+
+    /// Format of the resulting image. Defaults to [`ImageFormat::Png`].
+    format: ImageFormat,
+
+    /// Quality of the lossy formats between `0.0` and `1.0`. Defaults to `None` which means the
+    /// browser's default.
+    #[builder(into)]
+    quality: Option<f64>,
+}
+
+/// Return type of [`use_image_transform`].
+pub struct UseImageTransformReturn {
+    /// The resulting image once the current file has been transformed.
+    pub blob: Signal<Option<web_sys::Blob>, LocalStorage>,
+
+    /// Width of the resulting image in pixels. `0` while there is none.
+    pub width: Signal<u32>,
+
+    /// Height of the resulting image in pixels. `0` while there is none.
+    pub height: Signal<u32>,
+
+    /// Whether the current file is being transformed.
+    pub is_transforming: Signal<bool>,
+
+    /// Latest error.
+    pub error: Signal<Option<UseImageTransformError>, LocalStorage>,
+}
+
+#[derive(Debug, Error)]
+pub enum UseImageTransformError {
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("worker error event")]
+    Event(web_sys::Event),
+    #[error("failed to transform the image: {0}")]
+    Transform(String),
+}
@@ -30,10 +30,30 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// ### Reactive interval
+///
+/// The interval can also be a signal. When it changes the timer is restarted with the new
+/// interval while the counter keeps its value.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_interval, UseIntervalReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (interval, set_interval) = signal(1000_u64);
+///
+/// let UseIntervalReturn { counter, .. } = use_interval(interval);
+///
+/// set_interval.set(500); // from now on the counter increases twice per second
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause`, `resume` and `reset` are sendwrapped functions. They can
-/// only be called from the same thread that called `use_intersection_observer`.
+/// only be called from the same thread that called `use_interval`.
 ///
 /// ## Server-Side Rendering
 ///
@@ -75,7 +95,7 @@ where
 
     let cb = move || {
         update();
-        callback(counter.get());
+        callback(counter.get_untracked());
     };
 
     let Pausable {
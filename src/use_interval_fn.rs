@@ -35,6 +35,33 @@ use std::time::Duration;
 /// # }
 /// ```
 ///
+/// The interval can also be a signal. When it changes while the timer is active the timer is
+/// restarted with the new interval. An interval of `0` doesn't start the timer.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_interval_fn_with_options, UseIntervalFnOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (interval, set_interval) = signal(1000_u64);
+///
+/// let pausable = use_interval_fn_with_options(
+///     || {
+///         // do something
+///     },
+///     interval,
+///     UseIntervalFnOptions::default()
+///         .immediate(false)
+///         .immediate_callback(true),
+/// );
+///
+/// // starts the timer and calls the callback right away
+/// (pausable.resume)();
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause` and `resume` are sendwrapped functions. They can
@@ -98,7 +125,7 @@ where
     let resume = sendwrap_fn!(move || {
         #[cfg(not(feature = "ssr"))]
         {
-            let interval_value = interval.get();
+            let interval_value = interval.get_untracked();
             if interval_value == 0 {
                 return;
             }
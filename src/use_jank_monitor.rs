@@ -0,0 +1,284 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{sendwrap_fn, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Reactive main thread load based on long tasks.
+///
+/// Observes [long tasks](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceLongTaskTiming),
+/// which are tasks that block the main thread for more than 50 milliseconds, with a
+/// [`PerformanceObserver`](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver).
+/// From these the total blocking time over a rolling time window is computed. This lets your app
+/// degrade gracefully under load, for example by pausing animations while the main thread is busy.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_jank_monitor)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_jank_monitor, UseJankMonitorReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseJankMonitorReturn {
+///     is_busy,
+///     total_blocking_time,
+///     ..
+/// } = use_jank_monitor();
+///
+/// view! {
+///     <div class="spinner" class:paused=is_busy />
+///     <p>"Blocked for " {total_blocking_time} "ms"</p>
+/// }
+/// # }
+/// ```
+///
+/// The blocking time of a long task is the part of its duration above 50 milliseconds.
+/// `total_blocking_time` is the sum of the blocking times of all long tasks that ended within the
+/// time window and `long_task_count` is the number of these tasks. Tasks that are older than the
+/// window are dropped even if no new long tasks occur.
+///
+/// `is_busy` is `true` while the total blocking time is at least `busy_threshold` milliseconds.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_jank_monitor_with_options, UseJankMonitorOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let jank = use_jank_monitor_with_options(
+///     UseJankMonitorOptions::default()
+///         .time_window(10_000.0)
+///         .busy_threshold(500.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Long tasks are only reported by Chromium based browsers. In other browsers `is_supported` is
+/// `false` and the main thread is never considered busy.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_busy` are always `false` and the other signals are always
+/// zero.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_idle_prefetch`]
+/// * [`fn@crate::use_raf_fn`]
+pub fn use_jank_monitor() -> UseJankMonitorReturn {
+    use_jank_monitor_with_options(UseJankMonitorOptions::default())
+}
+
+/// Version of [`use_jank_monitor`] that takes a `UseJankMonitorOptions`. See [`use_jank_monitor`] for how to use.
+pub fn use_jank_monitor_with_options(options: UseJankMonitorOptions) -> UseJankMonitorReturn {
+    let UseJankMonitorOptions {
+        time_window,
+        busy_threshold,
+    } = options;
+
+    let (total_blocking_time, set_total_blocking_time) = signal(0.0);
+    let (long_task_count, set_long_task_count) = signal(0_usize);
+
+    let is_supported = use_supported(|| {
+        js_sys::Reflect::get(&window(), &"PerformanceObserver".into())
+            .and_then(|observer| js_sys::Reflect::get(&observer, &"supportedEntryTypes".into()))
+            .ok()
+            .filter(js_sys::Array::is_array)
+            .is_some_and(|entry_types| {
+                js_sys::Array::from(&entry_types).includes(&"longtask".into(), 0)
+            })
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use wasm_bindgen::prelude::*;
+        use wasm_bindgen::JsCast;
+
+        let long_tasks = Rc::new(LongTasks {
+            tasks: RefCell::default(),
+            expire_timer: Cell::new(None),
+            set_total_blocking_time,
+            set_long_task_count,
+            time_window,
+        });
+
+        let observer = if is_supported.get_untracked() {
+            let closure_js = Closure::<dyn FnMut(web_sys::PerformanceObserverEntryList)>::new({
+                let long_tasks = Rc::clone(&long_tasks);
+
+                move |list: web_sys::PerformanceObserverEntryList| {
+                    #[cfg(debug_assertions)]
+                    let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                    long_tasks.add(
+                        list.get_entries()
+                            .iter()
+                            .map(|entry| entry.unchecked_into::<web_sys::PerformanceEntry>()),
+                    );
+                }
+            })
+            .into_js_value();
+
+            web_sys::PerformanceObserver::new(closure_js.unchecked_ref())
+                .ok()
+                .inspect(|observer| {
+                    observer.observe(&web_sys::PerformanceObserverInit::new(&js_sys::Array::of1(
+                        &"longtask".into(),
+                    )));
+                })
+        } else {
+            None
+        };
+
+        on_cleanup(sendwrap_fn!(move || {
+            if let Some(observer) = &observer {
+                observer.disconnect();
+            }
+
+            long_tasks.clear_timer();
+        }));
+    }
+
+    UseJankMonitorReturn {
+        is_supported,
+        is_busy: Signal::derive(move || {
+            long_task_count.get() > 0 && total_blocking_time.get() >= busy_threshold
+        }),
+        total_blocking_time: total_blocking_time.into(),
+        long_task_count: long_task_count.into(),
+    }
+}
+
+/// Tasks that take longer than this many milliseconds are long tasks.
+const LONG_TASK_THRESHOLD: f64 = 50.0;
+
+/// The long tasks within the time window of [`use_jank_monitor`].
+struct LongTasks {
+    /// End time and blocking time of every long task, oldest first
+    tasks: RefCell<VecDeque<(f64, f64)>>,
+    expire_timer: Cell<Option<TimeoutHandle>>,
+    set_total_blocking_time: WriteSignal<f64>,
+    set_long_task_count: WriteSignal<usize>,
+    time_window: f64,
+}
+
+impl LongTasks {
+    fn add(self: &Rc<Self>, entries: impl Iterator<Item = web_sys::PerformanceEntry>) {
+        let mut tasks = self.tasks.borrow_mut();
+
+        tasks.extend(entries.map(|entry| {
+            let duration = entry.duration();
+
+            (
+                entry.start_time() + duration,
+                (duration - LONG_TASK_THRESHOLD).max(0.0),
+            )
+        }));
+        tasks
+            .make_contiguous()
+            .sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        drop(tasks);
+
+        self.refresh();
+    }
+
+    /// Drops the tasks that have left the time window and updates the signals.
+    fn refresh(self: &Rc<Self>) {
+        let now = performance_now();
+        let time_window = self.time_window;
+
+        let mut tasks = self.tasks.borrow_mut();
+        while tasks
+            .front()
+            .is_some_and(|(end, _)| end + time_window <= now)
+        {
+            tasks.pop_front();
+        }
+
+        self.set_total_blocking_time
+            .try_set(tasks.iter().map(|(_, blocking_time)| blocking_time).sum());
+        self.set_long_task_count.try_set(tasks.len());
+
+        self.clear_timer();
+
+        // refresh again when the oldest task leaves the time window
+        if let Some((end, _)) = tasks.front() {
+            let long_tasks = Rc::downgrade(self);
+
+            self.expire_timer.set(
+                set_timeout_with_handle(
+                    move || {
+                        if let Some(long_tasks) = long_tasks.upgrade() {
+                            long_tasks.refresh();
+                        }
+                    },
+                    Duration::from_millis((end + time_window - now).max(0.0).ceil() as u64),
+                )
+                .ok(),
+            );
+        }
+    }
+
+    fn clear_timer(&self) {
+        if let Some(timer) = self.expire_timer.take() {
+            timer.clear();
+        }
+    }
+}
+
+fn performance_now() -> f64 {
+    window()
+        .performance()
+        .map(|performance| performance.now())
+        .unwrap_or_default()
+}
+
+/// Options for [`use_jank_monitor_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseJankMonitorOptions {
+    /// Length of the rolling time window in milliseconds. Defaults to `5000`.
+    time_window: f64,
+
+    /// Total blocking time in milliseconds within the time window from which on the main thread
+    /// is considered busy. Defaults to `200`.
+    busy_threshold: f64,
+}
+
+impl Default for UseJankMonitorOptions {
+    fn default() -> Self {
+        Self {
+            time_window: 5000.0,
+            busy_threshold: 200.0,
+        }
+    }
+}
+
+/// Return type of [`use_jank_monitor`].
+#[derive(Clone, Copy)]
+pub struct UseJankMonitorReturn {
+    /// Whether the browser reports long tasks
+    pub is_supported: Signal<bool>,
+
+    /// Whether the total blocking time is at least `busy_threshold`
+    pub is_busy: Signal<bool>,
+
+    /// Sum of the blocking times of the long tasks within the time window in milliseconds
+    pub total_blocking_time: Signal<f64>,
+
+    /// Number of long tasks within the time window
+    pub long_task_count: Signal<usize>,
+}
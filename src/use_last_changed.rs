@@ -0,0 +1,106 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::now;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Records the timestamp of the last change of a signal.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_last_changed)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_last_changed;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (text, set_text) = signal(String::new());
+///
+/// let last_changed = use_last_changed::<String>(text);
+///
+/// view! {
+///     <textarea on:input=move |e| set_text.set(event_target_value(&e)) />
+///     <Show when=move || last_changed.get().is_some()>
+///         <p>"Last edited " {move || last_changed.get()} " ms after the epoch"</p>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The timestamp is in milliseconds since the Unix epoch like `Date.now()`. It's `None` until the
+/// signal changes for the first time. To start with a timestamp, for example the time the data was
+/// last saved, use the option `initial_value`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_last_changed_with_options, UseLastChangedOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (text, set_text) = signal(String::new());
+/// # let saved_at = 1_700_000_000_000.0;
+/// let last_changed = use_last_changed_with_options::<String>(
+///     text,
+///     UseLastChangedOptions::default().initial_value(saved_at),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Together with [`fn@crate::use_timestamp`] it's easy to display something like "edited 5 seconds
+/// ago".
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signal isn't watched and this always returns the initial value.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_timestamp`]
+/// * [`fn@crate::watch_with_options`]
+pub fn use_last_changed<T>(source: impl Into<Signal<T>>) -> Signal<Option<f64>>
+where
+    T: Send + Sync + 'static,
+{
+    use_last_changed_with_options(source, UseLastChangedOptions::default())
+}
+
+/// Version of [`use_last_changed`] that takes a `UseLastChangedOptions`. See [`use_last_changed`] for how to use.
+pub fn use_last_changed_with_options<T>(
+    source: impl Into<Signal<T>>,
+    options: UseLastChangedOptions,
+) -> Signal<Option<f64>>
+where
+    T: Send + Sync + 'static,
+{
+    let UseLastChangedOptions { initial_value } = options;
+
+    let source = source.into();
+
+    let (last_changed, set_last_changed) = signal(initial_value);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = Effect::watch(
+            move || source.track(),
+            move |_, _, _| set_last_changed.set(Some(now())),
+            false,
+        );
+    }
+
+    last_changed.into()
+}
+
+/// Options for [`use_last_changed_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseLastChangedOptions {
+    /// Timestamp in milliseconds that is returned until the signal changes. Defaults to `None`.
+    #[builder(into)]
+    initial_value: Option<f64>,
+}
@@ -0,0 +1,208 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::future::Future;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+
+/// Load something heavy only once it's needed.
+///
+/// The async `loader` is run the first time `load` is called or the option `when` becomes
+/// `true`, for example when an element becomes visible. Its result is kept, so it's only loaded
+/// once. This helps to keep large integrations like chart or editor libraries out of the initial
+/// load of big apps.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_lazy_component)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{dynamic_import, use_element_visibility, use_lazy_component_with_options, UseLazyComponentOptions, UseLazyComponentReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let is_visible = use_element_visibility(el);
+///
+/// let UseLazyComponentReturn {
+///     data: chart_module,
+///     is_loaded,
+///     ..
+/// } = use_lazy_component_with_options(
+///     || dynamic_import("https://esm.sh/chart.js"),
+///     UseLazyComponentOptions::default().when(is_visible),
+/// );
+///
+/// view! {
+///     <div node_ref=el>
+///         <Show when=move || is_loaded.get() fallback=|| "Loading chart...">
+///             "Chart loaded"
+///         </Show>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// The loader can be any future that returns a `Result`. [`dynamic_import`] is a small helper
+/// that imports an ES module with JavaScript's
+/// [`import()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/import)
+/// and resolves to the module namespace object.
+///
+/// ### Manual Loading
+///
+/// Without the option `when`, call `load` yourself. Calling it while loading or after loading
+/// succeeded does nothing. After an error it tries again.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_lazy_component, UseLazyComponentReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseLazyComponentReturn { data, load, .. } = use_lazy_component(|| async {
+///     // run an expensive initialization here
+///     Ok::<_, ()>(42)
+/// });
+///
+/// view! {
+///     <button on:click=move |_| load()>"Open editor"</button>
+///     <p>{move || data.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is loaded. `data` and `error` are always `None` and `is_loaded` and
+/// `is_loading` are always `false`.
+pub fn use_lazy_component<T, E, F, Fut>(
+    loader: F,
+) -> UseLazyComponentReturn<T, E, impl Fn() + Clone + Send + Sync>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    use_lazy_component_with_options(loader, UseLazyComponentOptions::default())
+}
+
+/// Version of [`use_lazy_component`] that takes a `UseLazyComponentOptions`. See [`use_lazy_component`] for how to use.
+pub fn use_lazy_component_with_options<T, E, F, Fut>(
+    loader: F,
+    options: UseLazyComponentOptions,
+) -> UseLazyComponentReturn<T, E, impl Fn() + Clone + Send + Sync>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let UseLazyComponentOptions { when } = options;
+
+    let (data, set_data) = signal_local(None::<T>);
+    let (error, set_error) = signal_local(None::<E>);
+    let (is_loading, set_loading) = signal(false);
+
+    let loader = Rc::new(loader);
+
+    let load = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if is_loading.get_untracked() || data.with_untracked(Option::is_some) {
+                return;
+            }
+
+            set_loading.set(true);
+            set_error.set(None);
+
+            let loading = loader();
+
+            leptos::task::spawn_local(async move {
+                match loading.await {
+                    Ok(value) => {
+                        set_data.try_set(Some(value));
+                    }
+                    Err(err) => {
+                        set_error.try_set(Some(err));
+                    }
+                }
+
+                set_loading.try_set(false);
+            });
+        }
+    });
+
+    let _ = Effect::watch(
+        move || when.get(),
+        {
+            let load = load.clone();
+
+            move |when, _, _| {
+                if *when {
+                    load();
+                }
+            }
+        },
+        true,
+    );
+
+    UseLazyComponentReturn {
+        is_loaded: Signal::derive(move || data.with(Option::is_some)),
+        data: data.into(),
+        is_loading: is_loading.into(),
+        error: error.into(),
+        load,
+    }
+}
+
+/// Imports the ES module at `specifier` with JavaScript's `import()` and resolves to the module
+/// namespace object. Relative specifiers are resolved relative to the current page.
+///
+/// This relies on creating a function from a string so it doesn't work with a
+/// [Content Security Policy](https://developer.mozilla.org/en-US/docs/Web/HTTP/CSP) that
+/// disallows `unsafe-eval`.
+pub async fn dynamic_import(specifier: &str) -> Result<JsValue, JsValue> {
+    let import = js_sys::Function::new_with_args("specifier", "return import(specifier)");
+    let promise = import.call1(&JsValue::NULL, &JsValue::from_str(specifier))?;
+
+    wasm_bindgen_futures::JsFuture::from(js_sys::Promise::from(promise)).await
+}
+
+/// Options for [`use_lazy_component_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseLazyComponentOptions {
+    /// As soon as this is `true` the loader is run. Defaults to `false` which means you have to
+    /// call `load` yourself.
+    #[builder(into)]
+    when: Signal<bool>,
+}
+
+/// Return type of [`use_lazy_component`].
+pub struct UseLazyComponentReturn<T, E, LoadFn>
+where
+    T: 'static,
+    E: 'static,
+    LoadFn: Fn() + Clone + Send + Sync,
+{
+    /// The value the loader resolved with.
+    pub data: Signal<Option<T>, LocalStorage>,
+
+    /// Whether the loader has resolved successfully.
+    pub is_loaded: Signal<bool>,
+
+    /// Whether the loader is currently running.
+    pub is_loading: Signal<bool>,
+
+    /// Error of the latest run of the loader.
+    pub error: Signal<Option<E>, LocalStorage>,
+
+    /// Runs the loader unless it's running already or has succeeded.
+    pub load: LoadFn,
+}
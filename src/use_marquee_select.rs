@@ -0,0 +1,295 @@
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Select elements by dragging a selection rectangle ("marquee") over them like in a file manager.
+///
+/// Dragging with the primary pointer button inside `container` creates a rectangle. Every element
+/// that has been registered with `register` and intersects the rectangle becomes part of the
+/// `selected` set.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_marquee_select)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_marquee_select, UseMarqueeSelectReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let container = NodeRef::<Div>::new();
+///
+/// let marquee = use_marquee_select::<usize, _, _>(container);
+/// let UseMarqueeSelectReturn { rect, selected, .. } = marquee;
+///
+/// view! {
+///     <div node_ref=container style="position: relative; user-select: none;">
+///         {(0..20).map(|i| {
+///             let item = NodeRef::<Div>::new();
+///             marquee.register(i, item);
+///
+///             view! {
+///                 <div node_ref=item class:selected=move || selected.with(|s| s.contains(&i))>
+///                     {format!("File {i}")}
+///                 </div>
+///             }
+///         }).collect_view()}
+///
+///         <Show when=move || rect.get().is_some()>
+///             <div style:position="absolute"
+///                 style:left=move || format!("{}px", rect.get().unwrap_or_default().left)
+///                 style:top=move || format!("{}px", rect.get().unwrap_or_default().top)
+///                 style:width=move || format!("{}px", rect.get().unwrap_or_default().width)
+///                 style:height=move || format!("{}px", rect.get().unwrap_or_default().height)
+///             />
+///         </Show>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// The `rect` is relative to the padding box of the `container` (including its scroll offset),
+/// so it can be rendered directly as an absolutely positioned child of a `position: relative`
+/// container.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `rect` is always `None` and `selected` always empty.
+pub fn use_marquee_select<K, El, M>(container: El) -> UseMarqueeSelectReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_marquee_select_with_options(container, UseMarqueeSelectOptions::default())
+}
+
+/// Version of [`use_marquee_select`] that takes a `UseMarqueeSelectOptions`. See [`use_marquee_select`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_marquee_select_with_options<K, El, M>(
+    container: El,
+    options: UseMarqueeSelectOptions,
+) -> UseMarqueeSelectReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseMarqueeSelectOptions { threshold, contain } = options;
+
+    let (rect, set_rect) = signal(None::<MarqueeRect>);
+    let (selected, set_selected) = signal(HashSet::<K>::new());
+    let items = StoredValue::new(Vec::<(K, ElementMaybeSignal<web_sys::Element>)>::new());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{pointerdown, pointermove, pointerup};
+
+        let container = container.into_element_maybe_signal();
+
+        // the point where the drag started in container coordinates
+        let start = StoredValue::new(None::<(f64, f64)>);
+
+        let to_container_coords = move |event: &web_sys::PointerEvent| {
+            container.get_untracked().map(|container| {
+                let bounds = container.get_bounding_client_rect();
+
+                (
+                    event.client_x() as f64 - bounds.left() - container.client_left() as f64
+                        + container.scroll_left() as f64,
+                    event.client_y() as f64 - bounds.top() - container.client_top() as f64
+                        + container.scroll_top() as f64,
+                )
+            })
+        };
+
+        let update_selected = move |marquee: MarqueeRect| {
+            let Some(container) = container.get_untracked() else {
+                return;
+            };
+
+            let bounds = container.get_bounding_client_rect();
+
+            // the marquee in viewport coordinates to compare it to the items
+            let offset_x =
+                bounds.left() + container.client_left() as f64 - container.scroll_left() as f64;
+            let offset_y =
+                bounds.top() + container.client_top() as f64 - container.scroll_top() as f64;
+            let left = marquee.left + offset_x;
+            let top = marquee.top + offset_y;
+            let right = left + marquee.width;
+            let bottom = top + marquee.height;
+
+            let new_selected = items.with_value(|items| {
+                items
+                    .iter()
+                    .filter(|(_, el)| {
+                        el.get_untracked().is_some_and(|el| {
+                            let item = el.get_bounding_client_rect();
+
+                            if contain {
+                                item.left() >= left
+                                    && item.right() <= right
+                                    && item.top() >= top
+                                    && item.bottom() <= bottom
+                            } else {
+                                item.left() < right
+                                    && item.right() > left
+                                    && item.top() < bottom
+                                    && item.bottom() > top
+                            }
+                        })
+                    })
+                    .map(|(key, _)| key.clone())
+                    .collect::<HashSet<_>>()
+            });
+
+            if selected.with_untracked(|selected| *selected != new_selected) {
+                set_selected.set(new_selected);
+            }
+        };
+
+        let _ = use_event_listener(container, pointerdown, move |event| {
+            if event.button() != 0 {
+                return;
+            }
+
+            start.set_value(to_container_coords(&event));
+        });
+
+        let _ = use_event_listener(window(), pointermove, move |event| {
+            let Some((start_x, start_y)) = start.get_value() else {
+                return;
+            };
+            let Some((x, y)) = to_container_coords(&event) else {
+                return;
+            };
+
+            if rect.get_untracked().is_none() && (x - start_x).hypot(y - start_y) < threshold {
+                return;
+            }
+
+            let marquee = MarqueeRect {
+                left: start_x.min(x),
+                top: start_y.min(y),
+                width: (x - start_x).abs(),
+                height: (y - start_y).abs(),
+            };
+
+            set_rect.set(Some(marquee));
+            update_selected(marquee);
+        });
+
+        let _ = use_event_listener(window(), pointerup, move |_| {
+            start.set_value(None);
+
+            if rect.get_untracked().is_some() {
+                set_rect.set(None);
+            }
+        });
+    }
+
+    UseMarqueeSelectReturn {
+        rect: rect.into(),
+        is_selecting: Signal::derive(move || rect.with(Option::is_some)),
+        selected: selected.into(),
+        items,
+        set_selected,
+    }
+}
+
+/// Options for [`use_marquee_select_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMarqueeSelectOptions {
+    /// How many pixels the pointer has to move after pressing it before the selection starts.
+    /// This avoids turning simple clicks into selections. Defaults to `5.0`.
+    threshold: f64,
+
+    /// If `true` elements have to be fully contained in the rectangle to be selected.
+    /// If `false` it is sufficient if they intersect with it. Defaults to `false`.
+    contain: bool,
+}
+
+impl Default for UseMarqueeSelectOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 5.0,
+            contain: false,
+        }
+    }
+}
+
+/// Selection rectangle of [`fn@crate::use_marquee_select`] relative to the container.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MarqueeRect {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Return type of [`use_marquee_select`].
+pub struct UseMarqueeSelectReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// The current selection rectangle. `None` while not dragging.
+    pub rect: Signal<Option<MarqueeRect>>,
+
+    /// `true` while the user is dragging a selection rectangle.
+    pub is_selecting: Signal<bool>,
+
+    /// The keys of the registered elements that are selected.
+    pub selected: Signal<HashSet<K>>,
+
+    items: StoredValue<Vec<(K, ElementMaybeSignal<web_sys::Element>)>>,
+    set_selected: WriteSignal<HashSet<K>>,
+}
+
+impl<K> Clone for UseMarqueeSelectReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for UseMarqueeSelectReturn<K> where K: Eq + Hash + Clone + Send + Sync + 'static {}
+
+impl<K> UseMarqueeSelectReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Registers an element that can be selected under the given `key`. It is unregistered
+    /// automatically when the calling component is cleaned up.
+    pub fn register<El, M>(&self, key: K, el: El)
+    where
+        El: IntoElementMaybeSignal<web_sys::Element, M>,
+    {
+        let el = el.into_element_maybe_signal();
+
+        self.items
+            .update_value(|items| items.push((key.clone(), el)));
+
+        let items = self.items;
+        let set_selected = self.set_selected;
+
+        on_cleanup(move || {
+            items.try_update_value(|items| items.retain(|(k, _)| *k != key));
+            set_selected.try_update(|selected| {
+                selected.remove(&key);
+            });
+        });
+    }
+
+    /// Deselects all elements.
+    pub fn clear(&self) {
+        self.set_selected.set(HashSet::new());
+    }
+}
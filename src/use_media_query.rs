@@ -1,6 +1,6 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
-use crate::use_event_listener;
+use crate::{use_event_listener, UseEventListenerReturn};
 use cfg_if::cfg_if;
 use leptos::ev::change;
 use leptos::prelude::*;
@@ -76,11 +76,11 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
 
                     let listener = Rc::clone(&*listener.borrow());
 
-                    remove_listener.replace(Some(Box::new(use_event_listener(
+                    remove_listener.replace(Some(use_event_listener(
                         media_query.clone(),
                         change,
                         move |e| listener(e),
-                    ))));
+                    )));
                 } else {
                     set_matches.set(false);
                 }
@@ -104,4 +104,4 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
     matches.into()
 }
 
-type RemoveListener = Rc<RefCell<Option<Box<dyn Fn()>>>>;
+type RemoveListener = Rc<RefCell<Option<UseEventListenerReturn>>>;
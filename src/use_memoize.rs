@@ -0,0 +1,257 @@
+use crate::core::now;
+use default_struct_builder::DefaultBuilder;
+use futures_util::future::{FutureExt, LocalBoxFuture, Shared};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Cache the results of an async function by its argument.
+///
+/// Repeated lookups with the same key don't call the function again but get the cached result.
+/// Useful for example to fetch the details of a row in a table only once.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_memoize)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_memoize;
+/// #
+/// # async fn fetch_user_name(id: u32) -> String { format!("User {id}") }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let user_names = use_memoize(|id: u32| async move { fetch_user_name(id).await });
+///
+/// let (user_id, set_user_id) = signal(1);
+/// let user_name = LocalResource::new(move || user_names.load(user_id.get()));
+///
+/// view! {
+///     <button on:click=move |_| set_user_id.update(|id| *id += 1)>"Next user"</button>
+///     <p>{move || user_name.get().map(|name| name.take())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `load(key)` returns the cached result or calls the function with `key` and caches its result.
+/// Loading a key that is currently loading doesn't call the function a second time but waits for
+/// the running call.
+///
+/// Remove an entry with `delete(key)` so it's loaded again next time, or all entries with
+/// `clear()`. The result is cached whatever it is, so if the function returns a `Result` you might
+/// want to delete the key after an error to try again. `size` contains the number of cached
+/// entries.
+///
+/// ### Size and Expiration
+///
+/// With the option `max_size` the least recently used entries are removed when the cache grows
+/// larger than that. With the option `ttl` entries are loaded again when they are older than that
+/// many milliseconds.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_memoize_with_options, UseMemoizeOptions};
+/// #
+/// # async fn fetch_price(symbol: String) -> f64 { 42.0 }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let prices = use_memoize_with_options(
+///     |symbol: String| async move { fetch_price(symbol).await },
+///     UseMemoizeOptions::default().max_size(100_usize).ttl(60_000.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// This works on the server as well. Keep in mind that the cache only lives as long as the reactive
+/// owner that created it, so it's not shared between requests.
+pub fn use_memoize<K, T, F, Fut>(resolver: F) -> UseMemoizeReturn<K, T>
+where
+    K: Hash + Eq + Clone + 'static,
+    T: Clone + 'static,
+    F: Fn(K) -> Fut + 'static,
+    Fut: Future<Output = T> + 'static,
+{
+    use_memoize_with_options(resolver, UseMemoizeOptions::default())
+}
+
+/// Version of [`use_memoize`] that takes a `UseMemoizeOptions`. See [`use_memoize`] for how to use.
+pub fn use_memoize_with_options<K, T, F, Fut>(
+    resolver: F,
+    options: UseMemoizeOptions,
+) -> UseMemoizeReturn<K, T>
+where
+    K: Hash + Eq + Clone + 'static,
+    T: Clone + 'static,
+    F: Fn(K) -> Fut + 'static,
+    Fut: Future<Output = T> + 'static,
+{
+    let UseMemoizeOptions { max_size, ttl } = options;
+
+    let (size, set_size) = signal(0_usize);
+
+    let resolver: Resolver<K, T> = Rc::new(move |key| resolver(key).boxed_local());
+
+    UseMemoizeReturn {
+        size: size.into(),
+        set_size,
+        cache: StoredValue::new_local(MemoizeCache {
+            entries: HashMap::new(),
+            last_used: 0,
+        }),
+        resolver: StoredValue::new_local(resolver),
+        max_size,
+        ttl,
+    }
+}
+
+type Resolver<K, T> = Rc<dyn Fn(K) -> LocalBoxFuture<'static, T>>;
+
+struct MemoizeCache<K, T> {
+    entries: HashMap<K, CacheEntry<T>>,
+
+    /// Incremented on every access to find the least recently used entry
+    last_used: u64,
+}
+
+struct CacheEntry<T> {
+    value: Shared<LocalBoxFuture<'static, T>>,
+    created_at: f64,
+    last_used: u64,
+}
+
+/// Options for [`use_memoize_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseMemoizeOptions {
+    /// Maximum number of cached entries. When the cache grows larger, the least recently used
+    /// entries are removed. Defaults to `None` (no limit).
+    #[builder(into)]
+    max_size: Option<usize>,
+
+    /// Time in milliseconds after which an entry is loaded again. Defaults to `None` (never).
+    #[builder(into)]
+    ttl: Option<f64>,
+}
+
+/// Return type of [`use_memoize`].
+pub struct UseMemoizeReturn<K, T>
+where
+    K: 'static,
+    T: 'static,
+{
+    /// The number of cached entries
+    pub size: Signal<usize>,
+
+    set_size: WriteSignal<usize>,
+    cache: StoredValue<MemoizeCache<K, T>, LocalStorage>,
+    resolver: StoredValue<Resolver<K, T>, LocalStorage>,
+    max_size: Option<usize>,
+    ttl: Option<f64>,
+}
+
+impl<K: 'static, T: 'static> Clone for UseMemoizeReturn<K, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: 'static, T: 'static> Copy for UseMemoizeReturn<K, T> {}
+
+impl<K, T> UseMemoizeReturn<K, T>
+where
+    K: Hash + Eq + Clone + 'static,
+    T: Clone + 'static,
+{
+    /// Returns the cached result for `key` or calls the function and caches its result.
+    pub fn load(&self, key: K) -> impl Future<Output = T> {
+        let ttl = self.ttl;
+        let max_size = self.max_size;
+        let resolver = self.resolver.get_value();
+        let fallback_key = key.clone();
+
+        let value = self.cache.try_update_value(|cache| {
+            cache.last_used += 1;
+            let last_used = cache.last_used;
+
+            let is_expired =
+                |entry: &CacheEntry<T>| ttl.is_some_and(|ttl| now() - entry.created_at > ttl);
+
+            if let Some(entry) = cache
+                .entries
+                .get_mut(&key)
+                .filter(|entry| !is_expired(entry))
+            {
+                entry.last_used = last_used;
+                return entry.value.clone();
+            }
+
+            let value = resolver(key.clone()).shared();
+
+            cache.entries.insert(
+                key,
+                CacheEntry {
+                    value: value.clone(),
+                    created_at: now(),
+                    last_used,
+                },
+            );
+
+            if let Some(max_size) = max_size {
+                while cache.entries.len() > max_size.max(1) {
+                    let Some(oldest) = cache
+                        .entries
+                        .iter()
+                        .min_by_key(|(_, entry)| entry.last_used)
+                        .map(|(key, _)| key.clone())
+                    else {
+                        break;
+                    };
+
+                    cache.entries.remove(&oldest);
+                }
+            }
+
+            value
+        });
+
+        self.update_size();
+
+        // the cache has been disposed together with its owner
+        value.unwrap_or_else(|| resolver(fallback_key).shared())
+    }
+
+    /// Removes the cached result for `key` so it's loaded again the next time.
+    pub fn delete(&self, key: &K) {
+        self.cache
+            .try_update_value(|cache| cache.entries.remove(key));
+
+        self.update_size();
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        self.cache.try_update_value(|cache| cache.entries.clear());
+
+        self.update_size();
+    }
+
+    fn update_size(&self) {
+        let Some(size) = self.cache.try_with_value(|cache| cache.entries.len()) else {
+            return;
+        };
+
+        if self.size.try_get_untracked() != Some(size) {
+            self.set_size.try_set(size);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{sendwrap_fn, use_event_listener};
+use codee::{CodecError, Decoder, Encoder};
+use leptos::prelude::*;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [MessageChannel](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel).
+///
+/// Creates a channel with two connected ports. Messages posted to one port are received by the
+/// other one. Both ports are closed automatically when the component is cleaned up.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_message_channel)
+///
+/// ## Usage
+///
+/// Values are (en)decoded via the given codec. You can use any of the string codecs or a
+/// binary codec wrapped in `Base64`.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_message_channel, UseMessageChannelReturn};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMessageChannelReturn {
+///     message2,
+///     post1,
+///     ..
+/// } = use_message_channel::<String, FromToStringCodec>();
+///
+/// post1(&"Hello".to_string()); // `message2` is `Some("Hello")` soon after
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Transferring a Port
+///
+/// Usually one of the ports is transferred to a worker or an iframe to get a private connection
+/// to it. After a port has been transferred, the messages sent to it aren't received here anymore
+/// but by the new owner.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_message_channel, UseMessageChannelReturn};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let worker = web_sys::Worker::new("worker.js").unwrap();
+/// let UseMessageChannelReturn {
+///     port2,
+///     message1,
+///     post1,
+///     ..
+/// } = use_message_channel::<String, FromToStringCodec>();
+///
+/// if let Some(port2) = port2.get_untracked() {
+///     let _ = worker.post_message_with_transfer(&port2, &js_sys::Array::of1(&port2));
+/// }
+///
+/// // talk to the worker through port 1
+/// post1(&"Hello worker".to_string());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The other side receives the encoded strings and has to reply with strings that the codec can
+/// decode.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `post1`, `post2` and `close` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_message_channel`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no channel is created. `port1`, `port2`, `message1`, `message2` and `error` are
+/// always `None` and `post1`, `post2` and `close` do nothing.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_broadcast_channel`]
+/// * [`fn@crate::use_rpc_channel`]
+pub fn use_message_channel<T, C>(
+) -> UseMessageChannelReturn<T, C, impl Fn(&T) + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    T: Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    <C as Encoder<T>>::Error: Send + Sync,
+    <C as Decoder<T>>::Error: Send + Sync,
+{
+    let (port1, set_port1) = signal_local(None::<web_sys::MessagePort>);
+    let (port2, set_port2) = signal_local(None::<web_sys::MessagePort>);
+    let (message1, set_message1) = signal(None::<T>);
+    let (message2, set_message2) = signal(None::<T>);
+    let (is_closed, set_closed) = signal(false);
+    let (error, set_error) = signal_local(None::<ErrorType<T, C>>);
+
+    let close = sendwrap_fn!(move || {
+        for port in [port1.get_untracked(), port2.get_untracked()]
+            .into_iter()
+            .flatten()
+        {
+            port.close();
+        }
+        set_closed.set(true);
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        match web_sys::MessageChannel::new() {
+            Ok(channel) => {
+                listen::<T, C>(channel.port1(), set_message1, set_error);
+                listen::<T, C>(channel.port2(), set_message2, set_error);
+
+                set_port1.set(Some(channel.port1()));
+                set_port2.set(Some(channel.port2()));
+            }
+            Err(err) => {
+                set_error.set(Some(UseMessageChannelError::Create(err)));
+            }
+        }
+    }
+
+    on_cleanup({
+        let close = close.clone();
+
+        move || close()
+    });
+
+    UseMessageChannelReturn {
+        port1: port1.into(),
+        port2: port2.into(),
+        message1: message1.into(),
+        message2: message2.into(),
+        post1: post_fn::<T, C>(port1, set_error),
+        post2: post_fn::<T, C>(port2, set_error),
+        is_closed: is_closed.into(),
+        error: error.into(),
+        close,
+    }
+}
+
+/// Decodes the messages that arrive at `port` into `set_message`.
+#[cfg(not(feature = "ssr"))]
+fn listen<T, C>(
+    port: web_sys::MessagePort,
+    set_message: WriteSignal<Option<T>>,
+    set_error: WriteSignal<Option<ErrorType<T, C>>, LocalStorage>,
+) where
+    T: Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+{
+    let _ = use_event_listener(port.clone(), leptos::ev::message, move |event| {
+        if let Some(data) = event.data().as_string() {
+            match C::decode(&data) {
+                Ok(msg) => set_message.set(Some(msg)),
+                Err(err) => {
+                    set_error.set(Some(UseMessageChannelError::Codec(CodecError::Decode(err))))
+                }
+            }
+        } else {
+            set_error.set(Some(UseMessageChannelError::ValueNotString));
+        }
+    });
+
+    let _ = use_event_listener(port.clone(), leptos::ev::messageerror, move |event| {
+        set_error.set(Some(UseMessageChannelError::MessageEvent(event)));
+    });
+
+    // listening via `addEventListener` doesn't start the port implicitly
+    port.start();
+}
+
+/// Creates the function that encodes and posts a message through `port`.
+fn post_fn<T, C>(
+    port: ReadSignal<Option<web_sys::MessagePort>, LocalStorage>,
+    set_error: WriteSignal<Option<ErrorType<T, C>>, LocalStorage>,
+) -> impl Fn(&T) + Clone + Send + Sync
+where
+    T: Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+{
+    sendwrap_fn!(move |value: &T| {
+        if let Some(port) = port.get_untracked() {
+            match C::encode(value) {
+                Ok(msg) => {
+                    port.post_message(&msg.into())
+                        .map_err(|err| {
+                            set_error.set(Some(UseMessageChannelError::PostMessage(err)))
+                        })
+                        .ok();
+                }
+                Err(err) => {
+                    set_error.set(Some(UseMessageChannelError::Codec(CodecError::Encode(err))));
+                }
+            }
+        }
+    })
+}
+
+/// Return type of [`use_message_channel`].
+pub struct UseMessageChannelReturn<T, C, PostFn, CloseFn>
+where
+    T: Send + Sync + 'static,
+    C: Encoder<T> + Decoder<T>,
+    PostFn: Fn(&T) + Clone + Send + Sync,
+    CloseFn: Fn() + Clone + Send + Sync,
+{
+    /// The first port of the channel. Can be transferred to a worker or an iframe.
+    pub port1: Signal<Option<web_sys::MessagePort>, LocalStorage>,
+
+    /// The second port of the channel. Can be transferred to a worker or an iframe.
+    pub port2: Signal<Option<web_sys::MessagePort>, LocalStorage>,
+
+    /// Latest message received by port 1.
+    pub message1: Signal<Option<T>>,
+
+    /// Latest message received by port 2.
+    pub message2: Signal<Option<T>>,
+
+    /// Sends a message through port 1. It's received by port 2.
+    pub post1: PostFn,
+
+    /// Sends a message through port 2. It's received by port 1.
+    pub post2: PostFn,
+
+    /// Whether the ports have been closed.
+    pub is_closed: Signal<bool>,
+
+    /// Latest error.
+    pub error: Signal<Option<ErrorType<T, C>>, LocalStorage>,
+
+    /// Closes both ports.
+    pub close: CloseFn,
+}
+
+type ErrorType<T, C> = UseMessageChannelError<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>;
+
+#[derive(Debug, Error)]
+pub enum UseMessageChannelError<E, D> {
+    #[error("failed to create message channel")]
+    Create(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("port message error")]
+    MessageEvent(web_sys::MessageEvent),
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("received value is not a string")]
+    ValueNotString,
+}
@@ -0,0 +1,368 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Minimap of a scroll container drawn onto a canvas.
+///
+/// The whole content of the scroll container is scaled down to the size of the canvas. Elements
+/// are drawn as blocks together with a rectangle for the part that is currently visible. Clicking
+/// or dragging on the canvas scrolls the container to that position.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_minimap)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{Canvas, Div};
+/// # use leptos_use::use_minimap;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let content = NodeRef::<Div>::new();
+/// let minimap = NodeRef::<Canvas>::new();
+///
+/// use_minimap(content, minimap);
+///
+/// view! {
+///     <div class="flex">
+///         <div node_ref=content class="h-[400px] overflow-auto">...</div>
+///         <canvas node_ref=minimap class="w-[80px] h-[400px]" />
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// The canvas is resized to its displayed size automatically, so give it a size with CSS. Its
+/// width and height are mapped to the scroll width and height of the content. So if the canvas
+/// has the same height as the container, the whole minimap is visible at once.
+///
+/// The minimap is redrawn when the content is scrolled, resized or changed and when the canvas is
+/// resized. Changes that can't be observed like a style change of a class can be drawn by calling
+/// `redraw`.
+///
+/// ### Blocks
+///
+/// By default every direct child of the content is drawn as a block. Use the option
+/// `block_selector` to draw other elements, for example the lines of a code view. The selector is
+/// run on the content element.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{Canvas, Div};
+/// # use leptos_use::{use_minimap_with_options, UseMinimapOptions, UseMinimapReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let content = NodeRef::<Div>::new();
+/// # let minimap = NodeRef::<Canvas>::new();
+/// #
+/// let UseMinimapReturn { is_dragging, redraw } = use_minimap_with_options(
+///     content,
+///     minimap,
+///     UseMinimapOptions::default()
+///         .block_selector("p, h1, h2, h3, pre, img")
+///         .block_color("#888")
+///         .viewport_color("rgba(0, 128, 255, 0.2)"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this does nothing. `is_dragging` is always `false` and `redraw` does nothing.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_scroll`]
+/// * [`fn@crate::use_scroll_sync`]
+pub fn use_minimap<ContentEl, ContentM, CanvasEl, CanvasM>(
+    content: ContentEl,
+    canvas: CanvasEl,
+) -> UseMinimapReturn<impl Fn() + Clone + Send + Sync>
+where
+    ContentEl: IntoElementMaybeSignal<web_sys::Element, ContentM>,
+    CanvasEl: IntoElementMaybeSignal<web_sys::Element, CanvasM>,
+{
+    use_minimap_with_options(content, canvas, UseMinimapOptions::default())
+}
+
+/// Version of [`use_minimap`] that takes a `UseMinimapOptions`. See [`use_minimap`] for how to use.
+pub fn use_minimap_with_options<ContentEl, ContentM, CanvasEl, CanvasM>(
+    content: ContentEl,
+    canvas: CanvasEl,
+    options: UseMinimapOptions,
+) -> UseMinimapReturn<impl Fn() + Clone + Send + Sync>
+where
+    ContentEl: IntoElementMaybeSignal<web_sys::Element, ContentM>,
+    CanvasEl: IntoElementMaybeSignal<web_sys::Element, CanvasM>,
+{
+    let (is_dragging, set_dragging) = signal(false);
+
+    let redraw;
+
+    #[cfg(feature = "ssr")]
+    {
+        redraw = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_event_listener, use_mutation_observer_with_options, use_resize_observer,
+            UseMutationObserverOptions,
+        };
+        use leptos::ev::{pointerdown, pointermove, pointerup, scroll};
+
+        let UseMinimapOptions {
+            block_selector,
+            block_color,
+            viewport_color,
+        } = options;
+
+        let content = content.into_element_maybe_signal();
+        let canvas = canvas.into_element_maybe_signal();
+
+        redraw = move || {
+            if let (Some(content), Some(canvas)) = (content.get_untracked(), canvas.get_untracked())
+            {
+                draw(
+                    &content,
+                    &canvas,
+                    &block_selector,
+                    &block_color,
+                    &viewport_color,
+                );
+            }
+        };
+
+        let _ = Effect::watch(
+            move || (content.get(), canvas.get()),
+            {
+                let redraw = redraw.clone();
+                move |_, _, _| redraw()
+            },
+            true,
+        );
+
+        let _ = use_event_listener(content, scroll, {
+            let redraw = redraw.clone();
+            move |_| redraw()
+        });
+
+        use_resize_observer(content, {
+            let redraw = redraw.clone();
+            move |_, _| redraw()
+        });
+
+        use_resize_observer(canvas, {
+            let redraw = redraw.clone();
+            move |_, _| redraw()
+        });
+
+        use_mutation_observer_with_options(
+            content,
+            {
+                let redraw = redraw.clone();
+                move |_, _| redraw()
+            },
+            UseMutationObserverOptions::default()
+                .subtree(true)
+                .child_list(true)
+                .character_data(true),
+        );
+
+        // scrolls the content so that the viewport is centered at the pointer
+        let scroll_to_pointer = move |event: &web_sys::PointerEvent| {
+            let (Some(content), Some(canvas)) = (content.get_untracked(), canvas.get_untracked())
+            else {
+                return;
+            };
+
+            let bounds = canvas.get_bounding_client_rect();
+            if bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+                return;
+            }
+
+            let x = (event.client_x() as f64 - bounds.left()) / bounds.width();
+            let y = (event.client_y() as f64 - bounds.top()) / bounds.height();
+
+            let left = x * content.scroll_width() as f64 - content.client_width() as f64 / 2.0;
+            let top = y * content.scroll_height() as f64 - content.client_height() as f64 / 2.0;
+
+            content.set_scroll_left(left.round() as i32);
+            content.set_scroll_top(top.round() as i32);
+        };
+
+        let _ = use_event_listener(canvas, pointerdown, move |event| {
+            if event.button() != 0 {
+                return;
+            }
+
+            event.prevent_default();
+            set_dragging.set(true);
+            scroll_to_pointer(&event);
+        });
+
+        let _ = use_event_listener(window(), pointermove, move |event| {
+            if is_dragging.get_untracked() {
+                scroll_to_pointer(&event);
+            }
+        });
+
+        let _ = use_event_listener(window(), pointerup, move |_| {
+            if is_dragging.get_untracked() {
+                set_dragging.set(false);
+            }
+        });
+    }
+
+    UseMinimapReturn {
+        is_dragging: is_dragging.into(),
+        redraw,
+    }
+}
+
+/// Draws the blocks of `content` and the visible part onto `canvas`.
+#[cfg(not(feature = "ssr"))]
+fn draw(
+    content: &web_sys::Element,
+    canvas: &web_sys::Element,
+    block_selector: &str,
+    block_color: &str,
+    viewport_color: &str,
+) {
+    use wasm_bindgen::JsCast;
+
+    let Some((context, width, height)) = prepare_canvas(canvas) else {
+        return;
+    };
+
+    let scroll_width = content.scroll_width() as f64;
+    let scroll_height = content.scroll_height() as f64;
+    if scroll_width <= 0.0 || scroll_height <= 0.0 {
+        return;
+    }
+
+    let scale_x = width / scroll_width;
+    let scale_y = height / scroll_height;
+
+    // the origin of the scrolled content in viewport coordinates
+    let bounds = content.get_bounding_client_rect();
+    let offset_x = bounds.left() + content.client_left() as f64 - content.scroll_left() as f64;
+    let offset_y = bounds.top() + content.client_top() as f64 - content.scroll_top() as f64;
+
+    context.set_fill_style_str(block_color);
+
+    if let Ok(blocks) = content.query_selector_all(block_selector) {
+        for i in 0..blocks.length() {
+            let Some(block) = blocks
+                .item(i)
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            else {
+                continue;
+            };
+
+            let rect = block.get_bounding_client_rect();
+
+            context.fill_rect(
+                (rect.left() - offset_x) * scale_x,
+                (rect.top() - offset_y) * scale_y,
+                rect.width() * scale_x,
+                // keep small blocks visible
+                (rect.height() * scale_y).max(1.0),
+            );
+        }
+    }
+
+    context.set_fill_style_str(viewport_color);
+    context.fill_rect(
+        content.scroll_left() as f64 * scale_x,
+        content.scroll_top() as f64 * scale_y,
+        content.client_width() as f64 * scale_x,
+        content.client_height() as f64 * scale_y,
+    );
+}
+
+/// Matches the size of the canvas to its displayed size and returns a cleared 2d context that is
+/// scaled to CSS pixels together with the width and height in CSS pixels.
+#[cfg(not(feature = "ssr"))]
+fn prepare_canvas(el: &web_sys::Element) -> Option<(web_sys::CanvasRenderingContext2d, f64, f64)> {
+    use wasm_bindgen::JsCast;
+
+    let canvas = el.dyn_ref::<web_sys::HtmlCanvasElement>()?;
+
+    let pixel_ratio = window().device_pixel_ratio();
+    let width = (canvas.client_width() as f64 * pixel_ratio).round() as u32;
+    let height = (canvas.client_height() as f64 * pixel_ratio).round() as u32;
+
+    // setting the size clears the canvas even if it didn't change
+    if canvas.width() != width {
+        canvas.set_width(width);
+    }
+    if canvas.height() != height {
+        canvas.set_height(height);
+    }
+
+    let context = canvas
+        .get_context("2d")
+        .ok()??
+        .unchecked_into::<web_sys::CanvasRenderingContext2d>();
+
+    context
+        .set_transform(pixel_ratio, 0.0, 0.0, pixel_ratio, 0.0, 0.0)
+        .ok()?;
+    context.clear_rect(0.0, 0.0, width as f64, height as f64);
+
+    Some((
+        context,
+        canvas.client_width() as f64,
+        canvas.client_height() as f64,
+    ))
+}
+
+/// Options for [`use_minimap_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMinimapOptions {
+    /// CSS selector of the elements inside the content that are drawn as blocks.
+    /// Defaults to `":scope > *"` which are the direct children of the content.
+    #[builder(into)]
+    block_selector: String,
+
+    /// CSS color of the blocks. Defaults to `"rgba(128, 128, 128, 0.5)"`.
+    #[builder(into)]
+    block_color: String,
+
+    /// CSS color of the rectangle of the visible part. Defaults to `"rgba(128, 128, 128, 0.2)"`.
+    #[builder(into)]
+    viewport_color: String,
+}
+
+impl Default for UseMinimapOptions {
+    fn default() -> Self {
+        Self {
+            block_selector: ":scope > *".to_string(),
+            block_color: "rgba(128, 128, 128, 0.5)".to_string(),
+            viewport_color: "rgba(128, 128, 128, 0.2)".to_string(),
+        }
+    }
+}
+
+/// Return type of [`use_minimap`].
+pub struct UseMinimapReturn<RedrawFn>
+where
+    RedrawFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the user is dragging on the minimap
+    pub is_dragging: Signal<bool>,
+
+    /// Draws the minimap again
+    pub redraw: RedrawFn,
+}
@@ -1,6 +1,8 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
 
 use crate::core::{IntoElementMaybeSignal, Position};
+use crate::filter_builder_methods;
+use crate::utils::{DebounceOptions, FilterOptions, ThrottleOptions};
 use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions, UseWindow};
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::{dragover, mousemove, touchend, touchmove, touchstart};
@@ -48,6 +50,26 @@ use wasm_bindgen::{JsCast, JsValue};
 /// # }
 /// ```
 ///
+/// ## Throttling
+///
+/// To update the position less often you can throttle or debounce the event handlers. This uses
+/// the same filters as [`fn@crate::use_throttle_fn`] and [`fn@crate::use_debounce_fn`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_mouse_with_options, UseMouseOptions, UseMouseReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMouseReturn {
+///     x, y, ..
+/// } = use_mouse_with_options(
+///     UseMouseOptions::default().throttle(100.0)
+/// );
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Custom Extractor
 ///
 /// It's also possible to provide a custom extractor to get the position from the events.
@@ -151,10 +173,32 @@ where
         set_y.set(initial_value.y);
     };
 
-    // TODO : event filters?
-
     #[cfg(not(feature = "ssr"))]
     {
+        use crate::utils::create_filter_wrapper_with_arg;
+
+        // mouse and touch events share the same filter
+        let filter = options.event_filter.filter_fn();
+
+        let mouse_handler = {
+            let filtered = create_filter_wrapper_with_arg(filter.clone(), mouse_handler);
+            move |event: web_sys::MouseEvent| {
+                filtered(event);
+            }
+        };
+        let drag_handler = {
+            let filtered = create_filter_wrapper_with_arg(filter.clone(), drag_handler);
+            move |event: web_sys::DragEvent| {
+                filtered(event);
+            }
+        };
+        let touch_handler = {
+            let filtered = create_filter_wrapper_with_arg(filter, touch_handler);
+            move |event: web_sys::TouchEvent| {
+                filtered(event);
+            }
+        };
+
         let target = options.target.into_element_maybe_signal();
         let event_listener_options = UseEventListenerOptions::default().passive(true);
 
@@ -222,6 +266,9 @@ where
     /// Initial values. Defaults to `{x: 0.0, y: 0.0}`.
     initial_value: Position,
 
+    /// Allows to debounce, throttle or pause the event handlers. Defaults to no filter.
+    event_filter: FilterOptions,
+
     #[builder(skip)]
     _marker: PhantomData<M>,
 }
@@ -237,11 +284,23 @@ where
             touch: true,
             reset_on_touch_ends: false,
             initial_value: Position { x: 0.0, y: 0.0 },
+            event_filter: FilterOptions::default(),
             _marker: PhantomData,
         }
     }
 }
 
+impl<El, M, Ex> UseMouseOptions<El, M, Ex>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    Ex: UseMouseEventExtractor + Clone,
+{
+    filter_builder_methods!(
+        /// the mouse and touch event handlers
+        event_filter
+    );
+}
+
 /// Defines how to get the coordinates from the event.
 #[derive(Clone)]
 pub enum UseMouseCoordType<E: UseMouseEventExtractor + Clone> {
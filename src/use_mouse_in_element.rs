@@ -1,4 +1,6 @@
 use crate::core::{IntoElementMaybeSignal, Position};
+use crate::filter_builder_methods;
+use crate::utils::{DebounceOptions, FilterOptions, ThrottleOptions};
 use crate::{
     use_mouse_with_options, use_window, UseMouseCoordType, UseMouseEventExtractor, UseMouseOptions,
     UseMouseReturn, UseMouseSourceType, UseWindow,
@@ -69,6 +71,7 @@ where
         reset_on_touch_ends,
         initial_value,
         handle_outside,
+        event_filter,
         ..
     } = options;
 
@@ -80,7 +83,8 @@ where
             .target(use_mouse_target)
             .touch(touch)
             .reset_on_touch_ends(reset_on_touch_ends)
-            .initial_value(initial_value),
+            .initial_value(initial_value)
+            .event_filter(event_filter),
     );
 
     let (element_x, set_element_x) = signal(0.0);
@@ -202,6 +206,10 @@ where
     /// Defaults to `true`.
     handle_outside: bool,
 
+    /// Allows to debounce, throttle or pause the mouse and touch event handlers.
+    /// Defaults to no filter.
+    event_filter: FilterOptions,
+
     #[builder(skip)]
     _marker: PhantomData<M>,
 }
@@ -218,11 +226,23 @@ where
             reset_on_touch_ends: false,
             initial_value: Position { x: 0.0, y: 0.0 },
             handle_outside: true,
+            event_filter: FilterOptions::default(),
             _marker: PhantomData,
         }
     }
 }
 
+impl<El, M, Ex> UseMouseInElementOptions<El, M, Ex>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    Ex: UseMouseEventExtractor + Clone,
+{
+    filter_builder_methods!(
+        /// the mouse and touch event handlers
+        event_filter
+    );
+}
+
 /// Return type of [`use_mouse_in_element`].
 pub struct UseMouseInElementReturn<F>
 where
@@ -0,0 +1,164 @@
+use crate::{
+    use_timestamp_with_controls_and_options, TimestampInterval, UseTimestampOptions,
+    UseTimestampReturn,
+};
+use chrono::{DateTime, Local, TimeZone};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Reactive current date and time.
+///
+/// Useful for clocks or labels like "updated 5 minutes ago" that have to update by themselves.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_now)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_now;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let now = use_now();
+///
+/// view! { {move || now.get().format("%H:%M:%S").to_string()} }
+/// # }
+/// ```
+///
+/// The date is a [`chrono::DateTime`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html)
+/// in the local time zone. To get a `js_sys::Date` use
+/// `js_sys::Date::new(&JsValue::from_f64(now.timestamp_millis() as f64))`.
+///
+/// ### Interval
+///
+/// By default the date is updated on every animation frame. For a clock that only shows seconds
+/// it's enough to update it every second.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_now_with_options, UseNowOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let now = use_now_with_options(UseNowOptions::default().interval(1000));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// With controls:
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_now_with_controls, UseNowReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseNowReturn {
+///     now,
+///     is_active,
+///     pause,
+///     resume,
+/// } = use_now_with_controls();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `pause` and `resume` of the `..._with_controls` versions are
+/// sendwrapped functions. They can only be called from the same thread that called
+/// `use_now_with_controls`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a signal with the time of the call that never updates.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_timestamp`]
+pub fn use_now() -> Signal<DateTime<Local>> {
+    use_now_with_controls().now
+}
+
+/// Version of [`use_now`] that takes a `UseNowOptions`. See [`use_now`] for how to use.
+pub fn use_now_with_options(options: UseNowOptions) -> Signal<DateTime<Local>> {
+    use_now_with_controls_and_options(options).now
+}
+
+/// Version of [`use_now`] that returns controls. See [`use_now`] for how to use.
+pub fn use_now_with_controls() -> UseNowReturn {
+    use_now_with_controls_and_options(UseNowOptions::default())
+}
+
+/// Version of [`use_now`] that takes a `UseNowOptions` and returns controls. See [`use_now`] for how to use.
+pub fn use_now_with_controls_and_options(options: UseNowOptions) -> UseNowReturn {
+    let UseNowOptions {
+        immediate,
+        interval,
+    } = options;
+
+    let UseTimestampReturn {
+        timestamp,
+        is_active,
+        pause,
+        resume,
+    } = use_timestamp_with_controls_and_options(
+        UseTimestampOptions::default()
+            .immediate(immediate)
+            .interval(interval),
+    );
+
+    UseNowReturn {
+        now: Signal::derive(move || {
+            Local
+                .timestamp_millis_opt(timestamp.get() as i64)
+                .single()
+                .unwrap_or_else(Local::now)
+        }),
+        is_active,
+        pause,
+        resume,
+    }
+}
+
+/// Options for [`use_now_with_controls_and_options`].
+#[derive(DefaultBuilder)]
+pub struct UseNowOptions {
+    /// Whether to update the date immediately. Defaults to `true`.
+    immediate: bool,
+
+    /// Update interval in milliseconds or `RequestAnimationFrame`. Defaults to `RequestAnimationFrame`.
+    #[builder(into)]
+    interval: TimestampInterval,
+}
+
+impl Default for UseNowOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            interval: TimestampInterval::RequestAnimationFrame,
+        }
+    }
+}
+
+/// Return type of [`use_now_with_controls`].
+pub struct UseNowReturn {
+    /// The current date and time
+    pub now: Signal<DateTime<Local>>,
+
+    /// A Signal that indicates whether the date updating is active. `false` when paused.
+    pub is_active: Signal<bool>,
+
+    /// Temporarily pause the date from updating
+    pub pause: Arc<dyn Fn()>,
+
+    /// Resume the date updating
+    pub resume: Arc<dyn Fn()>,
+}
@@ -0,0 +1,347 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::collections::HashSet;
+
+/// Reactive outline of the headings inside an element.
+///
+/// Extracts the headings `h1` to `h6` of `container` into a nested structure. The outline is
+/// updated whenever the content changes. Useful to build a table of contents.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_outline)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Article;
+/// # use leptos_use::{use_outline, OutlineItem, UseOutlineReturn};
+/// #
+/// fn table_of_contents(items: Vec<OutlineItem>) -> AnyView {
+///     view! {
+///         <ul>
+///             {items
+///                 .into_iter()
+///                 .map(|item| {
+///                     view! {
+///                         <li>
+///                             <a href=format!("#{}", item.id)>{item.text}</a>
+///                             {(!item.children.is_empty()).then(|| table_of_contents(item.children))}
+///                         </li>
+///                     }
+///                 })
+///                 .collect_view()}
+///         </ul>
+///     }
+///     .into_any()
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let article = NodeRef::<Article>::new();
+///
+/// let UseOutlineReturn { outline, .. } = use_outline(article);
+///
+/// view! {
+///     <nav>{move || table_of_contents(outline.get())}</nav>
+///     <article node_ref=article>
+///         <h1>"Guide"</h1>
+///         <h2>"Installation"</h2>
+///         <h2>"Usage"</h2>
+///         <h3>"Options"</h3>
+///     </article>
+/// }
+/// # }
+/// ```
+///
+/// A heading becomes a child of the closest heading before it with a lower level. Skipped levels
+/// are fine, so an `h4` directly after an `h2` is a child of the `h2`.
+///
+/// Headings without an `id` get one that is derived from their text, like `getting-started` for
+/// "Getting Started", so they can be linked to. Set the option `assign_ids` to `false` to disable
+/// this. In that case `id` is empty for those headings.
+///
+/// The outline is updated when elements or text inside the container change. To update it after
+/// other changes call `refresh`.
+///
+/// ### Active Heading
+///
+/// `ids` contains the ids of all headings in the order of the document. Together with
+/// [`fn@crate::use_intersection_observer`] or [`fn@crate::use_element_visibility`] it's easy to
+/// highlight the section that is currently visible.
+///
+/// ### Options
+///
+/// Only some heading levels can be selected with the option `selector`. Elements that aren't
+/// `h1` to `h6` use their `aria-level` attribute as level.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Article;
+/// # use leptos_use::{use_outline_with_options, UseOutlineOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let article = NodeRef::<Article>::new();
+/// let outline = use_outline_with_options(
+///     article,
+///     UseOutlineOptions::default().selector("h2, h3"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the outline is always empty.
+pub fn use_outline<El, M>(container: El) -> UseOutlineReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_outline_with_options(container, UseOutlineOptions::default())
+}
+
+/// Version of [`use_outline`] that takes a `UseOutlineOptions`. See [`use_outline`] for how to use.
+pub fn use_outline_with_options<El, M>(
+    container: El,
+    options: UseOutlineOptions,
+) -> UseOutlineReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let (outline, set_outline) = signal(Vec::<OutlineItem>::new());
+
+    let refresh;
+
+    #[cfg(feature = "ssr")]
+    {
+        refresh = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_mutation_observer_with_options, UseMutationObserverOptions};
+
+        let UseOutlineOptions {
+            selector,
+            assign_ids,
+        } = options;
+
+        let container = container.into_element_maybe_signal();
+
+        refresh = move || {
+            let new_outline = container
+                .get_untracked()
+                .map(|container| extract_outline(&container, &selector, assign_ids))
+                .unwrap_or_default();
+
+            if outline.with_untracked(|outline| *outline != new_outline) {
+                set_outline.set(new_outline);
+            }
+        };
+
+        let _ = Effect::watch(
+            move || container.get(),
+            {
+                let refresh = refresh.clone();
+                move |_, _, _| refresh()
+            },
+            true,
+        );
+
+        // ids are assigned as attributes so they don't trigger the observer
+        use_mutation_observer_with_options(
+            container,
+            {
+                let refresh = refresh.clone();
+                move |_, _| refresh()
+            },
+            UseMutationObserverOptions::default()
+                .subtree(true)
+                .child_list(true)
+                .character_data(true),
+        );
+    }
+
+    UseOutlineReturn {
+        outline: outline.into(),
+        ids: Signal::derive(move || {
+            let mut ids = vec![];
+            outline.with(|outline| collect_ids(outline, &mut ids));
+            ids
+        }),
+        refresh,
+    }
+}
+
+/// Returns the nested headings that match `selector` inside `container`.
+#[cfg(not(feature = "ssr"))]
+fn extract_outline(
+    container: &web_sys::Element,
+    selector: &str,
+    assign_ids: bool,
+) -> Vec<OutlineItem> {
+    use wasm_bindgen::JsCast;
+
+    let Ok(nodes) = container.query_selector_all(selector) else {
+        return vec![];
+    };
+
+    let mut outline = vec![];
+    let mut used_ids = HashSet::new();
+
+    for i in 0..nodes.length() {
+        let Some(heading) = nodes
+            .item(i)
+            .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+        else {
+            continue;
+        };
+
+        let text = heading
+            .text_content()
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut id = heading.id();
+        if id.is_empty() && assign_ids {
+            id = unique_id(&slugify(&text), &used_ids);
+            heading.set_id(&id);
+        }
+        used_ids.insert(id.clone());
+
+        insert(
+            &mut outline,
+            OutlineItem {
+                id,
+                text,
+                level: heading_level(&heading),
+                children: vec![],
+            },
+        );
+    }
+
+    outline
+}
+
+/// Adds `item` as a child of the last item with a lower level or at the top.
+fn insert(items: &mut Vec<OutlineItem>, item: OutlineItem) {
+    match items.last_mut() {
+        Some(last) if last.level < item.level => insert(&mut last.children, item),
+        _ => items.push(item),
+    }
+}
+
+fn collect_ids(items: &[OutlineItem], ids: &mut Vec<String>) {
+    for item in items {
+        ids.push(item.id.clone());
+        collect_ids(&item.children, ids);
+    }
+}
+
+/// Level of `h1` to `h6` or the `aria-level` of other elements.
+#[cfg(not(feature = "ssr"))]
+fn heading_level(heading: &web_sys::Element) -> u8 {
+    let tag_name = heading.tag_name().to_lowercase();
+
+    tag_name
+        .strip_prefix('h')
+        .and_then(|level| level.parse::<u8>().ok())
+        .filter(|level| (1..=6).contains(level))
+        .or_else(|| {
+            heading
+                .get_attribute("aria-level")
+                .and_then(|level| level.parse().ok())
+        })
+        .unwrap_or(1)
+}
+
+/// Turns "Getting Started!" into "getting-started".
+fn slugify(text: &str) -> String {
+    let slug = text
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if slug.is_empty() {
+        "heading".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Appends `-1`, `-2`, ... to `slug` until it isn't used yet in the outline or the document.
+#[cfg(not(feature = "ssr"))]
+fn unique_id(slug: &str, used_ids: &HashSet<String>) -> String {
+    let is_free = |id: &str| !used_ids.contains(id) && document().get_element_by_id(id).is_none();
+
+    if is_free(slug) {
+        return slug.to_string();
+    }
+
+    (1..)
+        .map(|n| format!("{slug}-{n}"))
+        .find(|id| is_free(id))
+        .expect("there is always a free id")
+}
+
+/// A heading in the outline of [`use_outline`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutlineItem {
+    /// The `id` attribute of the heading. Empty if it has none and `assign_ids` is `false`.
+    pub id: String,
+
+    /// The text of the heading with whitespace collapsed
+    pub text: String,
+
+    /// `1` for `h1` up to `6` for `h6`
+    pub level: u8,
+
+    /// The headings below this one with a higher level
+    pub children: Vec<OutlineItem>,
+}
+
+/// Options for [`use_outline_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseOutlineOptions {
+    /// CSS selector of the headings. Defaults to `"h1, h2, h3, h4, h5, h6"`.
+    #[builder(into)]
+    selector: String,
+
+    /// Whether headings without an `id` get one derived from their text. Defaults to `true`.
+    assign_ids: bool,
+}
+
+impl Default for UseOutlineOptions {
+    fn default() -> Self {
+        Self {
+            selector: "h1, h2, h3, h4, h5, h6".to_string(),
+            assign_ids: true,
+        }
+    }
+}
+
+/// Return type of [`use_outline`].
+pub struct UseOutlineReturn<RefreshFn>
+where
+    RefreshFn: Fn() + Clone + Send + Sync,
+{
+    /// The nested headings
+    pub outline: Signal<Vec<OutlineItem>>,
+
+    /// The ids of all headings in the order of the document
+    pub ids: Signal<Vec<String>>,
+
+    /// Reads the headings again
+    pub refresh: RefreshFn,
+}
@@ -0,0 +1,387 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{IntoElementMaybeSignal, Position};
+use crate::{sendwrap_fn, use_transform_with_options, UseTransformOptions, UseTransformReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Pan and zoom the `content` inside of `container`, e.g. for maps or diagrams.
+///
+/// Dragging the container pans, the mouse wheel and pinch gestures zoom around the pointer. The
+/// resulting transform is applied to `content` via [`fn@crate::use_transform`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_pan_zoom)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_pan_zoom, UsePanZoomReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let container = NodeRef::<Div>::new();
+/// let content = NodeRef::<Div>::new();
+///
+/// let UsePanZoomReturn {
+///     zoom,
+///     fit_to_view,
+///     reset,
+///     ..
+/// } = use_pan_zoom(container, content);
+///
+/// view! {
+///     <div node_ref=container style="overflow: hidden; touch-action: none; height: 400px">
+///         <div node_ref=content>"Content to pan and zoom"</div>
+///     </div>
+///
+///     <p>{move || format!("{:.0}%", zoom.get() * 100.0)}</p>
+///     <button on:click=move |_| fit_to_view()>"Fit"</button>
+///     <button on:click=move |_| reset()>"Reset"</button>
+/// }
+/// # }
+/// ```
+///
+/// The `transform-origin` of `content` is set to the top left corner. The container should have
+/// `touch-action: none` so that the browser doesn't handle touch gestures itself.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `fit_to_view` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_pan_zoom`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `zoom` is always `1.0` and `offset` is always `(0, 0)`. No event listeners
+/// are added and `fit_to_view` does nothing.
+pub fn use_pan_zoom<ContainerEl, ContainerM, ContentEl, ContentM>(
+    container: ContainerEl,
+    content: ContentEl,
+) -> UsePanZoomReturn<
+    impl Fn(f64, Position) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    ContainerEl: IntoElementMaybeSignal<web_sys::Element, ContainerM>,
+    ContentEl: IntoElementMaybeSignal<web_sys::Element, ContentM>,
+{
+    use_pan_zoom_with_options(container, content, UsePanZoomOptions::default())
+}
+
+/// Version of [`use_pan_zoom`] that takes a `UsePanZoomOptions`. See [`use_pan_zoom`] for how to use.
+pub fn use_pan_zoom_with_options<ContainerEl, ContainerM, ContentEl, ContentM>(
+    container: ContainerEl,
+    content: ContentEl,
+    options: UsePanZoomOptions,
+) -> UsePanZoomReturn<
+    impl Fn(f64, Position) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    ContainerEl: IntoElementMaybeSignal<web_sys::Element, ContainerM>,
+    ContentEl: IntoElementMaybeSignal<web_sys::Element, ContentM>,
+{
+    let UsePanZoomOptions {
+        min_zoom,
+        max_zoom,
+        wheel_speed,
+        pan,
+        zoom_on_wheel,
+        pinch,
+    } = options;
+
+    let container = container.into_element_maybe_signal();
+    let content = content.into_element_maybe_signal();
+
+    let UseTransformReturn {
+        translate_x,
+        set_translate_x,
+        translate_y,
+        set_translate_y,
+        scale: zoom,
+        set_scale: set_zoom,
+        ..
+    } = use_transform_with_options(
+        content,
+        UseTransformOptions::default().parse_existing(false),
+    );
+
+    let offset = Signal::derive(move || Position {
+        x: translate_x.get(),
+        y: translate_y.get(),
+    });
+
+    let set_offset = move |offset: Position| {
+        set_translate_x.set(offset.x);
+        set_translate_y.set(offset.y);
+    };
+
+    // zooms so that the content under `point` (in container coordinates) stays under it
+    let zoom_to = move |new_zoom: f64, point: Position| {
+        let old_zoom = zoom.get_untracked();
+        let new_zoom = new_zoom.clamp(min_zoom, max_zoom);
+        let offset = offset.get_untracked();
+
+        set_offset(Position {
+            x: point.x - (point.x - offset.x) / old_zoom * new_zoom,
+            y: point.y - (point.y - offset.y) / old_zoom * new_zoom,
+        });
+        set_zoom.set(new_zoom);
+    };
+
+    let (is_panning, set_panning) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_event_listener, use_event_listener_with_options, UseEventListenerOptions};
+        use leptos::ev::{pointercancel, pointerdown, pointermove, pointerup, wheel};
+
+        Effect::new(move |_| {
+            if let Some(content) = content.get() {
+                let _ = content
+                    .unchecked_into::<web_sys::HtmlElement>()
+                    .style()
+                    .set_property("transform-origin", "0 0");
+            }
+        });
+
+        let to_container_coords = move |client_x: f64, client_y: f64| {
+            container
+                .get_untracked()
+                .map(|container| {
+                    let bounds = container.get_bounding_client_rect();
+
+                    Position {
+                        x: client_x - bounds.left() - container.client_left() as f64,
+                        y: client_y - bounds.top() - container.client_top() as f64,
+                    }
+                })
+                .unwrap_or_default()
+        };
+
+        if zoom_on_wheel {
+            let _ = use_event_listener_with_options(
+                container,
+                wheel,
+                move |event| {
+                    event.prevent_default();
+
+                    let delta = match event.delta_mode() {
+                        web_sys::WheelEvent::DOM_DELTA_LINE => event.delta_y() * 16.0,
+                        web_sys::WheelEvent::DOM_DELTA_PAGE => event.delta_y() * 800.0,
+                        _ => event.delta_y(),
+                    };
+
+                    zoom_to(
+                        zoom.get_untracked() * (-delta * wheel_speed).exp(),
+                        to_container_coords(event.client_x() as f64, event.client_y() as f64),
+                    );
+                },
+                UseEventListenerOptions::default().passive(false),
+            );
+        }
+
+        // active pointers by id with their last position in container coordinates
+        let pointers = StoredValue::new(Vec::<(i32, Position)>::new());
+
+        let _ = use_event_listener(container, pointerdown, move |event| {
+            if event.button() != 0 {
+                return;
+            }
+
+            let position = to_container_coords(event.client_x() as f64, event.client_y() as f64);
+            pointers.update_value(|pointers| pointers.push((event.pointer_id(), position)));
+            set_panning.set(true);
+        });
+
+        let _ = use_event_listener(window(), pointermove, move |event| {
+            let id = event.pointer_id();
+            let position = to_container_coords(event.client_x() as f64, event.client_y() as f64);
+
+            let Some((before, after)) = pointers
+                .try_update_value(|pointers| {
+                    let before = pointers.iter().map(|(_, p)| *p).collect::<Vec<_>>();
+                    let (_, pointer) = pointers
+                        .iter_mut()
+                        .find(|(pointer_id, _)| *pointer_id == id)?;
+                    *pointer = position;
+                    let after = pointers.iter().map(|(_, p)| *p).collect::<Vec<_>>();
+
+                    Some((before, after))
+                })
+                .flatten()
+            else {
+                return;
+            };
+
+            match (before.as_slice(), after.as_slice()) {
+                ([old], [new]) if pan => {
+                    let offset = offset.get_untracked();
+                    set_offset(Position {
+                        x: offset.x + new.x - old.x,
+                        y: offset.y + new.y - old.y,
+                    });
+                }
+                ([old_a, old_b, ..], [new_a, new_b, ..]) if pinch => {
+                    let old_mid = midpoint(*old_a, *old_b);
+                    let new_mid = midpoint(*new_a, *new_b);
+                    let old_distance = distance(*old_a, *old_b);
+
+                    if old_distance > 0.0 {
+                        let ratio = distance(*new_a, *new_b) / old_distance;
+                        zoom_to(zoom.get_untracked() * ratio, old_mid);
+                    }
+
+                    let offset = offset.get_untracked();
+                    set_offset(Position {
+                        x: offset.x + new_mid.x - old_mid.x,
+                        y: offset.y + new_mid.y - old_mid.y,
+                    });
+                }
+                _ => {}
+            }
+        });
+
+        let on_pointer_end = move |event: web_sys::PointerEvent| {
+            let id = event.pointer_id();
+
+            pointers.update_value(|pointers| pointers.retain(|(pointer_id, _)| *pointer_id != id));
+
+            if pointers.with_value(Vec::is_empty) && is_panning.get_untracked() {
+                set_panning.set(false);
+            }
+        };
+
+        let _ = use_event_listener(window(), pointerup, on_pointer_end);
+        let _ = use_event_listener(window(), pointercancel, on_pointer_end);
+    }
+
+    let fit_to_view = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let (Some(container), Some(content)) =
+                (container.get_untracked(), content.get_untracked())
+            else {
+                return;
+            };
+
+            let content = content.unchecked_into::<web_sys::HtmlElement>();
+            let (width, height) = (
+                content.offset_width() as f64,
+                content.offset_height() as f64,
+            );
+            let (container_width, container_height) = (
+                container.client_width() as f64,
+                container.client_height() as f64,
+            );
+
+            if width <= 0.0 || height <= 0.0 {
+                return;
+            }
+
+            let new_zoom = (container_width / width)
+                .min(container_height / height)
+                .clamp(min_zoom, max_zoom);
+
+            set_zoom.set(new_zoom);
+            set_offset(Position {
+                x: (container_width - width * new_zoom) / 2.0,
+                y: (container_height - height * new_zoom) / 2.0,
+            });
+        }
+    });
+
+    let reset = move || {
+        set_zoom.set(1.0);
+        set_offset(Position::default());
+    };
+
+    UsePanZoomReturn {
+        zoom,
+        offset,
+        is_panning: is_panning.into(),
+        zoom_to_point: move |zoom: f64, point: Position| zoom_to(zoom, point),
+        fit_to_view,
+        reset,
+    }
+}
+
+fn midpoint(a: Position, b: Position) -> Position {
+    Position {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    }
+}
+
+fn distance(a: Position, b: Position) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Options for [`use_pan_zoom_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePanZoomOptions {
+    /// The minimum zoom factor. Defaults to `0.1`.
+    min_zoom: f64,
+
+    /// The maximum zoom factor. Defaults to `10.0`.
+    max_zoom: f64,
+
+    /// How fast the mouse wheel zooms. The zoom is multiplied by `exp(-delta_y * wheel_speed)`
+    /// for every wheel event. Defaults to `0.002`.
+    wheel_speed: f64,
+
+    /// Pan by dragging the container. Defaults to `true`.
+    pan: bool,
+
+    /// Zoom with the mouse wheel (and trackpad pinch). Defaults to `true`.
+    zoom_on_wheel: bool,
+
+    /// Zoom with two finger pinch gestures. Defaults to `true`.
+    pinch: bool,
+}
+
+impl Default for UsePanZoomOptions {
+    fn default() -> Self {
+        Self {
+            min_zoom: 0.1,
+            max_zoom: 10.0,
+            wheel_speed: 0.002,
+            pan: true,
+            zoom_on_wheel: true,
+            pinch: true,
+        }
+    }
+}
+
+/// Return type of [`use_pan_zoom`].
+pub struct UsePanZoomReturn<ZoomToPointFn, FitToViewFn, ResetFn>
+where
+    ZoomToPointFn: Fn(f64, Position) + Clone + Send + Sync,
+    FitToViewFn: Fn() + Clone + Send + Sync,
+    ResetFn: Fn() + Clone + Send + Sync,
+{
+    /// The current zoom factor.
+    pub zoom: Signal<f64>,
+
+    /// The offset of the content's top left corner relative to the container in pixels.
+    pub offset: Signal<Position>,
+
+    /// `true` while the user is dragging or pinching.
+    pub is_panning: Signal<bool>,
+
+    /// Zooms to the given factor while keeping the content under the given point (relative to
+    /// the container) in place.
+    pub zoom_to_point: ZoomToPointFn,
+
+    /// Zooms and pans so that the whole content fits into the container and is centered.
+    pub fit_to_view: FitToViewFn,
+
+    /// Resets zoom to `1.0` and the offset to `(0, 0)`.
+    pub reset: ResetFn,
+}
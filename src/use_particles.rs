@@ -0,0 +1,502 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Duration of a frame at 60 fps in milliseconds. Particle speeds are given per such frame.
+const FRAME_DURATION: f64 = 1000.0 / 60.0;
+
+/// Lightweight particle system for confetti and similar celebratory effects.
+///
+/// Particles are drawn onto `canvas` on every animation frame as long as there are any. The canvas
+/// is resized to its displayed size automatically, so it's usually positioned over the content
+/// with CSS and `pointer-events: none`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_particles)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Canvas;
+/// # use leptos_use::{use_particles, ParticleBurst};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let particles = use_particles(canvas);
+///
+/// view! {
+///     <canvas
+///         node_ref=canvas
+///         style="position: fixed; inset: 0; width: 100%; height: 100%; pointer-events: none"
+///     />
+///     <button on:click=move |_| particles.fire(ParticleBurst::default())>"Celebrate"</button>
+/// }
+/// # }
+/// ```
+///
+/// ### Presets
+///
+/// `ParticleBurst::default()` shoots confetti upwards from the bottom center. There are also
+/// `ParticleBurst::from_left()`, `ParticleBurst::from_right()` and `ParticleBurst::explosion()`.
+/// All of them can be customized with the builder methods.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Canvas;
+/// # use leptos_use::{use_particles, ParticleBurst};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let canvas = NodeRef::<Canvas>::new();
+/// let particles = use_particles(canvas);
+///
+/// let celebrate = move || {
+///     particles.fire(ParticleBurst::from_left().particle_count(80));
+///     particles.fire(ParticleBurst::from_right().particle_count(80));
+///     particles.fire(
+///         ParticleBurst::explosion()
+///             .origin((0.5, 0.3))
+///             .colors(vec!["gold".to_string(), "white".to_string()]),
+///     );
+/// };
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// If the user prefers reduced motion `fire` does nothing. Set the option
+/// `respect_reduced_motion` to `false` to change that.
+///
+/// The animation stops when the last particle has disappeared and is cancelled when the
+/// component is unmounted.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `fire` does nothing.
+pub fn use_particles<El, M>(canvas: El) -> UseParticlesReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_particles_with_options(canvas, UseParticlesOptions::default())
+}
+
+/// Version of [`use_particles`] that takes a `UseParticlesOptions`. See [`use_particles`] for how to use.
+pub fn use_particles_with_options<El, M>(
+    canvas: El,
+    options: UseParticlesOptions,
+) -> UseParticlesReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseParticlesOptions {
+        max_particles,
+        respect_reduced_motion,
+    } = options;
+
+    let canvas = canvas.into_element_maybe_signal();
+
+    let (is_active, set_active) = signal(false);
+    let particles = StoredValue::new(Vec::<Particle>::new());
+
+    #[cfg(feature = "ssr")]
+    let reduced_motion = Signal::stored(false);
+
+    #[cfg(not(feature = "ssr"))]
+    let reduced_motion = {
+        use crate::utils::Pausable;
+        use crate::{use_prefers_reduced_motion, use_raf_fn_with_options, UseRafFnOptions};
+
+        let last_timestamp = StoredValue::new(None::<f64>);
+
+        let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+            move |args| {
+                // the first frame after resuming has no sensible time difference
+                let elapsed = last_timestamp
+                    .get_value()
+                    .map(|last_timestamp| args.timestamp - last_timestamp)
+                    .unwrap_or(FRAME_DURATION);
+                last_timestamp.set_value(Some(args.timestamp));
+
+                let Some((context, height)) =
+                    canvas.get_untracked().and_then(|el| prepare_canvas(&el))
+                else {
+                    return;
+                };
+
+                let frames = elapsed / FRAME_DURATION;
+
+                particles.update_value(|particles| {
+                    particles.retain_mut(|particle| particle.step(frames, height));
+
+                    for particle in particles.iter() {
+                        particle.draw(&context);
+                    }
+                });
+
+                if particles.with_value(Vec::is_empty) {
+                    set_active.set(false);
+                }
+            },
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        let _ = Effect::watch(
+            move || is_active.get(),
+            move |is_active, _, _| {
+                if *is_active {
+                    last_timestamp.set_value(None);
+                    resume();
+                } else {
+                    pause();
+                }
+            },
+            false,
+        );
+
+        if respect_reduced_motion {
+            use_prefers_reduced_motion()
+        } else {
+            Signal::stored(false)
+        }
+    };
+
+    UseParticlesReturn {
+        is_active: is_active.into(),
+        set_active,
+        canvas,
+        particles,
+        max_particles,
+        reduced_motion,
+    }
+}
+
+/// Matches the size of the canvas to its displayed size and returns a cleared 2d context that is
+/// scaled to CSS pixels together with the height in CSS pixels.
+#[cfg(not(feature = "ssr"))]
+fn prepare_canvas(el: &web_sys::Element) -> Option<(web_sys::CanvasRenderingContext2d, f64)> {
+    use wasm_bindgen::JsCast;
+
+    let canvas = el.dyn_ref::<web_sys::HtmlCanvasElement>()?;
+
+    let pixel_ratio = window().device_pixel_ratio();
+    let width = (canvas.client_width() as f64 * pixel_ratio).round() as u32;
+    let height = (canvas.client_height() as f64 * pixel_ratio).round() as u32;
+
+    // setting the size clears the canvas even if it didn't change
+    if canvas.width() != width {
+        canvas.set_width(width);
+    }
+    if canvas.height() != height {
+        canvas.set_height(height);
+    }
+
+    let context = canvas
+        .get_context("2d")
+        .ok()??
+        .unchecked_into::<web_sys::CanvasRenderingContext2d>();
+
+    context
+        .set_transform(pixel_ratio, 0.0, 0.0, pixel_ratio, 0.0, 0.0)
+        .ok()?;
+    context.clear_rect(0.0, 0.0, width as f64, height as f64);
+
+    Some((context, canvas.client_height() as f64))
+}
+
+#[inline(always)]
+fn random() -> f64 {
+    #[cfg(not(feature = "ssr"))]
+    {
+        js_sys::Math::random()
+    }
+
+    #[cfg(feature = "ssr")]
+    {
+        0.5
+    }
+}
+
+/// A single piece of confetti.
+#[derive(Clone, Debug)]
+struct Particle {
+    x: f64,
+    y: f64,
+    /// Direction of movement in radians with `0` pointing right and `PI / 2` pointing up
+    angle: f64,
+    velocity: f64,
+    decay: f64,
+    gravity: f64,
+    drift: f64,
+    size: f64,
+    color: String,
+    /// Rotation of the particle in radians
+    tilt: f64,
+    /// Phase of the flip around the particle's own axis
+    wobble: f64,
+    wobble_speed: f64,
+    age: f64,
+    lifetime: f64,
+}
+
+impl Particle {
+    fn new(burst: &ParticleBurst, width: f64, height: f64) -> Self {
+        let ParticleBurst {
+            origin: (origin_x, origin_y),
+            angle,
+            spread,
+            start_velocity,
+            decay,
+            gravity,
+            drift,
+            size,
+            lifetime,
+            colors,
+            ..
+        } = burst;
+
+        let color = if colors.is_empty() {
+            "#f00".to_string()
+        } else {
+            colors[((random() * colors.len() as f64) as usize).min(colors.len() - 1)].clone()
+        };
+
+        Self {
+            x: origin_x * width,
+            y: origin_y * height,
+            angle: (angle + (random() - 0.5) * spread).to_radians(),
+            velocity: start_velocity * (0.5 + random() * 0.5),
+            decay: *decay,
+            gravity: *gravity,
+            drift: *drift,
+            size: size * (0.75 + random() * 0.5),
+            color,
+            tilt: random() * std::f64::consts::PI,
+            wobble: random() * 10.0,
+            wobble_speed: 0.05 + random() * 0.1,
+            age: 0.0,
+            lifetime: *lifetime as f64 * (0.8 + random() * 0.4),
+        }
+    }
+
+    /// Advances the particle by `frames` (fractional) frames. Returns `false` if it has
+    /// disappeared.
+    fn step(&mut self, frames: f64, height: f64) -> bool {
+        self.x += (self.angle.cos() * self.velocity + self.drift) * frames;
+        self.y += (self.gravity * 3.0 - self.angle.sin() * self.velocity) * frames;
+        self.velocity *= self.decay.powf(frames);
+        self.tilt += 0.1 * frames;
+        self.wobble += self.wobble_speed * frames;
+        self.age += frames;
+
+        self.age < self.lifetime && self.y < height + self.size * 2.0
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn draw(&self, context: &web_sys::CanvasRenderingContext2d) {
+        context.save();
+
+        context.set_global_alpha((1.0 - self.age / self.lifetime).clamp(0.0, 1.0));
+        context.set_fill_style_str(&self.color);
+
+        let _ = context.translate(self.x, self.y);
+        let _ = context.rotate(self.tilt);
+        let _ = context.scale(1.0, self.wobble.cos());
+        context.fill_rect(
+            -self.size / 2.0,
+            -self.size / 3.0,
+            self.size,
+            self.size / 1.5,
+        );
+
+        context.restore();
+    }
+}
+
+/// Options for [`use_particles_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseParticlesOptions {
+    /// Maximum number of particles on the canvas at the same time. When more are fired the
+    /// oldest ones are removed. Defaults to `1000`.
+    max_particles: usize,
+
+    /// Whether `fire` does nothing if the user prefers reduced motion. Defaults to `true`.
+    respect_reduced_motion: bool,
+}
+
+impl Default for UseParticlesOptions {
+    fn default() -> Self {
+        Self {
+            max_particles: 1000,
+            respect_reduced_motion: true,
+        }
+    }
+}
+
+/// Describes a burst of particles for [`UseParticlesReturn::fire`].
+///
+/// Speeds and lifetimes are given per frame at 60 fps. On screens with other refresh rates the
+/// animation runs at the same speed.
+#[derive(DefaultBuilder, Clone, Debug)]
+pub struct ParticleBurst {
+    /// Number of particles. Defaults to `100`.
+    particle_count: usize,
+
+    /// Where the particles start, relative to the size of the canvas. `(0.0, 0.0)` is the top left
+    /// corner and `(1.0, 1.0)` the bottom right one. Defaults to `(0.5, 1.0)`.
+    origin: (f64, f64),
+
+    /// Direction in degrees. `0` is right, `90` is up. Defaults to `90`.
+    angle: f64,
+
+    /// How far the directions of the particles differ from `angle` in degrees. Defaults to `60`.
+    spread: f64,
+
+    /// Maximum initial speed in pixels per frame. Defaults to `45`.
+    start_velocity: f64,
+
+    /// Factor by which the speed is multiplied every frame. Defaults to `0.9`.
+    decay: f64,
+
+    /// How fast the particles fall. `0` means no falling. Defaults to `1`.
+    gravity: f64,
+
+    /// Horizontal movement in pixels per frame, like wind. Defaults to `0`.
+    drift: f64,
+
+    /// Size of a particle in pixels. Defaults to `10`.
+    size: f64,
+
+    /// How many frames a particle lives on average. Defaults to `200`.
+    lifetime: u32,
+
+    /// CSS colors that are picked randomly for each particle. Defaults to a colorful mix.
+    colors: Vec<String>,
+}
+
+impl Default for ParticleBurst {
+    fn default() -> Self {
+        Self {
+            particle_count: 100,
+            origin: (0.5, 1.0),
+            angle: 90.0,
+            spread: 60.0,
+            start_velocity: 45.0,
+            decay: 0.9,
+            gravity: 1.0,
+            drift: 0.0,
+            size: 10.0,
+            lifetime: 200,
+            colors: [
+                "#26ccff", "#a25afd", "#ff5e7e", "#88ff5a", "#fcff42", "#ffa62d", "#ff36ff",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        }
+    }
+}
+
+impl ParticleBurst {
+    /// Confetti shot from the bottom left corner towards the center.
+    pub fn from_left() -> Self {
+        Self {
+            origin: (0.0, 1.0),
+            angle: 60.0,
+            spread: 55.0,
+            ..Default::default()
+        }
+    }
+
+    /// Confetti shot from the bottom right corner towards the center.
+    pub fn from_right() -> Self {
+        Self {
+            origin: (1.0, 1.0),
+            angle: 120.0,
+            spread: 55.0,
+            ..Default::default()
+        }
+    }
+
+    /// Particles flying from the center in all directions like a firework.
+    pub fn explosion() -> Self {
+        Self {
+            particle_count: 150,
+            origin: (0.5, 0.5),
+            spread: 360.0,
+            start_velocity: 30.0,
+            decay: 0.92,
+            gravity: 0.6,
+            lifetime: 150,
+            ..Default::default()
+        }
+    }
+}
+
+/// Return type of [`use_particles`].
+#[derive(Clone, Copy)]
+pub struct UseParticlesReturn {
+    /// Whether particles are currently animated.
+    pub is_active: Signal<bool>,
+
+    set_active: WriteSignal<bool>,
+    canvas: ElementMaybeSignal<web_sys::Element>,
+    particles: StoredValue<Vec<Particle>>,
+    max_particles: usize,
+    reduced_motion: Signal<bool>,
+}
+
+impl UseParticlesReturn {
+    /// Adds a burst of particles to the canvas and starts the animation if necessary.
+    pub fn fire(&self, burst: ParticleBurst) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if self.reduced_motion.get_untracked() {
+                return;
+            }
+
+            let Some(canvas) = self.canvas.get_untracked() else {
+                return;
+            };
+
+            let width = canvas.client_width() as f64;
+            let height = canvas.client_height() as f64;
+            let max_particles = self.max_particles;
+
+            self.particles.update_value(|particles| {
+                particles.extend(
+                    (0..burst.particle_count).map(|_| Particle::new(&burst, width, height)),
+                );
+
+                if particles.len() > max_particles {
+                    particles.drain(..particles.len() - max_particles);
+                }
+            });
+
+            if !self.is_active.get_untracked() {
+                self.set_active.set(true);
+            }
+        }
+    }
+
+    /// Removes all particles immediately.
+    pub fn clear(&self) {
+        self.particles.update_value(Vec::clear);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            // the animation loop might already be stopped so it won't clear the canvas anymore
+            let _ = self
+                .canvas
+                .get_untracked()
+                .and_then(|el| prepare_canvas(&el));
+        }
+
+        self.set_active.set(false);
+    }
+}
@@ -0,0 +1,389 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// Runs async requests (like `fetch` calls) through a shared queue that limits how many of them
+/// are in flight at the same time and starts the most important ones first.
+///
+/// This prevents dozens of lazily rendered components from firing all of their requests at once.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_priority_fetch)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_priority_fetch, UsePriorityFetchReturn};
+/// #
+/// # async fn load_user_name(id: u32) -> String { String::new() }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePriorityFetchReturn { data, is_queued, .. } = use_priority_fetch(|| load_user_name(42));
+///
+/// view! {
+///     <Show when=move || !is_queued.get() fallback=|| "Waiting...">
+///         {move || data.get()}
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ### Priorities
+///
+/// Queued requests are started in the order of their `priority` and the order in which they were
+/// queued. To load what the user is currently looking at first, pass the signal returned by
+/// [`fn@crate::use_element_visibility`] as `visible`. While it is `true` the request is treated as
+/// `FetchPriority::High`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_visibility, use_priority_fetch_with_options, FetchPriority, UsePriorityFetchOptions, UsePriorityFetchReturn};
+/// #
+/// # async fn load_comments() -> Vec<String> { vec![] }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let is_visible = use_element_visibility(el);
+///
+/// let UsePriorityFetchReturn { data, .. } = use_priority_fetch_with_options(
+///     load_comments,
+///     UsePriorityFetchOptions::default()
+///         .priority(FetchPriority::Low)
+///         .visible(is_visible),
+/// );
+///
+/// view! {
+///     <div node_ref=el>{move || data.get().unwrap_or_default().join(", ")}</div>
+/// }
+/// # }
+/// ```
+///
+/// ### Queue
+///
+/// By default all requests share one queue that runs at most 6 requests at the same time.
+/// To use a different limit for (a part of) your app provide a [`FetchQueue`] as context.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::FetchQueue;
+/// #
+/// # #[component]
+/// # fn App() -> impl IntoView {
+/// provide_context(FetchQueue::new(2));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `refetch` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_priority_fetch`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the request is never started. `data` stays `None` and `refetch` does nothing.
+pub fn use_priority_fetch<T, Fut, F>(
+    fetcher: F,
+) -> UsePriorityFetchReturn<T, impl Fn() + Clone + Send + Sync>
+where
+    T: Send + Sync + 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = T> + 'static,
+{
+    use_priority_fetch_with_options(fetcher, UsePriorityFetchOptions::default())
+}
+
+/// Version of [`use_priority_fetch`] that takes a `UsePriorityFetchOptions`. See [`use_priority_fetch`] for how to use.
+pub fn use_priority_fetch_with_options<T, Fut, F>(
+    fetcher: F,
+    options: UsePriorityFetchOptions,
+) -> UsePriorityFetchReturn<T, impl Fn() + Clone + Send + Sync>
+where
+    T: Send + Sync + 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = T> + 'static,
+{
+    let UsePriorityFetchOptions {
+        priority,
+        visible,
+        immediate,
+    } = options;
+
+    let (data, set_data) = signal(None::<T>);
+    let (is_queued, set_queued) = signal(false);
+    let (is_loading, set_loading) = signal(false);
+
+    let refetch;
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let queue = use_context::<FetchQueue>().unwrap_or_else(FetchQueue::shared);
+        let fetcher = Rc::new(fetcher);
+
+        // incremented on every refetch so that results of outdated requests are ignored
+        let generation = StoredValue::new(0_u64);
+        let queued_id = StoredValue::new(None::<u64>);
+
+        let current_priority: Rc<dyn Fn() -> FetchPriority> = Rc::new(move || {
+            if visible.is_some_and(|visible| visible.get_untracked()) {
+                FetchPriority::High
+            } else {
+                priority
+            }
+        });
+
+        refetch = {
+            let queue = queue.clone();
+
+            sendwrap_fn!(move || {
+                if let Some(id) = queued_id.get_value() {
+                    queue.cancel(id);
+                }
+
+                generation.update_value(|generation| *generation += 1);
+                let current_generation = generation.get_value();
+
+                set_queued.set(true);
+                set_loading.set(false);
+
+                let fetcher = Rc::clone(&fetcher);
+
+                let id = queue.enqueue(Rc::clone(&current_priority), move || {
+                    set_queued.set(false);
+                    set_loading.set(true);
+
+                    let future = fetcher();
+
+                    Box::pin(async move {
+                        let value = future.await;
+
+                        if generation.try_get_value() == Some(current_generation) {
+                            set_data.set(Some(value));
+                            set_loading.set(false);
+                        }
+                    })
+                });
+
+                queued_id.set_value(Some(id));
+            })
+        };
+
+        on_cleanup(move || {
+            if let Some(id) = queued_id.try_get_value().flatten() {
+                queue.cancel(id);
+            }
+        });
+    }
+
+    #[cfg(feature = "ssr")]
+    {
+        refetch = || {};
+    }
+
+    if immediate {
+        refetch();
+    }
+
+    UsePriorityFetchReturn {
+        data: data.into(),
+        is_queued: is_queued.into(),
+        is_loading: is_loading.into(),
+        refetch,
+    }
+}
+
+/// Options for [`use_priority_fetch_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePriorityFetchOptions {
+    /// Priority of the request while it waits in the queue. Defaults to `FetchPriority::Normal`.
+    priority: FetchPriority,
+
+    /// While this signal is `true` the request is treated as `FetchPriority::High`. Usually this
+    /// is the return value of [`fn@crate::use_element_visibility`]. Defaults to `None`.
+    #[builder(into)]
+    visible: Option<Signal<bool>>,
+
+    /// If `true` the request is queued immediately when calling this function.
+    /// If `false` you have to call `refetch` manually.
+    /// Defaults to `true`.
+    immediate: bool,
+}
+
+impl Default for UsePriorityFetchOptions {
+    fn default() -> Self {
+        Self {
+            priority: FetchPriority::default(),
+            visible: None,
+            immediate: true,
+        }
+    }
+}
+
+/// Return type of [`use_priority_fetch`].
+pub struct UsePriorityFetchReturn<T, RefetchFn>
+where
+    T: Send + Sync + 'static,
+    RefetchFn: Fn() + Clone + Send + Sync,
+{
+    /// Result of the latest finished request.
+    pub data: Signal<Option<T>>,
+
+    /// `true` while the request waits in the queue for a free slot.
+    pub is_queued: Signal<bool>,
+
+    /// `true` while the request is running.
+    pub is_loading: Signal<bool>,
+
+    /// Queues the request again. A request of this function that is still waiting in the queue
+    /// is replaced and the result of one that is still running is discarded.
+    pub refetch: RefetchFn,
+}
+
+/// Priority of a request queued by [`fn@crate::use_priority_fetch`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FetchPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+type StartFetch = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()>>>>;
+
+struct QueuedFetch {
+    id: u64,
+    priority: Rc<dyn Fn() -> FetchPriority>,
+    start: StartFetch,
+}
+
+struct FetchQueueState {
+    max_concurrent: usize,
+    running: usize,
+    next_id: u64,
+    queued: Vec<QueuedFetch>,
+}
+
+/// Queue that limits how many requests of [`fn@crate::use_priority_fetch`] run at the same time.
+///
+/// Provide it via `provide_context` to use it for all `use_priority_fetch` calls below that
+/// component. Otherwise a shared queue with a limit of 6 concurrent requests is used.
+///
+/// The queue lives on the thread that created it and must only be used from there.
+#[derive(Clone)]
+pub struct FetchQueue {
+    state: SendWrapper<Rc<RefCell<FetchQueueState>>>,
+}
+
+impl FetchQueue {
+    /// Creates a new queue that runs at most `max_concurrent` requests at the same time.
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            state: SendWrapper::new(Rc::new(RefCell::new(FetchQueueState {
+                max_concurrent: max_concurrent.max(1),
+                running: 0,
+                next_id: 0,
+                queued: vec![],
+            }))),
+        }
+    }
+
+    /// The queue used by [`fn@crate::use_priority_fetch`] when no `FetchQueue` was provided as context.
+    pub fn shared() -> Self {
+        thread_local! {
+            static SHARED: FetchQueue = FetchQueue::new(6);
+        }
+
+        SHARED.with(Clone::clone)
+    }
+
+    /// Number of requests that are currently waiting for a free slot.
+    pub fn queued_len(&self) -> usize {
+        self.state.borrow().queued.len()
+    }
+
+    /// Number of requests that are currently running.
+    pub fn running(&self) -> usize {
+        self.state.borrow().running
+    }
+
+    pub(crate) fn enqueue(
+        &self,
+        priority: Rc<dyn Fn() -> FetchPriority>,
+        start: impl FnOnce() -> Pin<Box<dyn Future<Output = ()>>> + 'static,
+    ) -> u64 {
+        let id = {
+            let mut state = self.state.borrow_mut();
+
+            let id = state.next_id;
+            state.next_id += 1;
+
+            state.queued.push(QueuedFetch {
+                id,
+                priority,
+                start: Box::new(start),
+            });
+
+            id
+        };
+
+        self.start_next();
+
+        id
+    }
+
+    pub(crate) fn cancel(&self, id: u64) {
+        self.state
+            .borrow_mut()
+            .queued
+            .retain(|queued| queued.id != id);
+    }
+
+    fn start_next(&self) {
+        loop {
+            let start = {
+                let mut state = self.state.borrow_mut();
+
+                if state.running >= state.max_concurrent {
+                    return;
+                }
+
+                // highest priority first, same priority in the order they were queued
+                let Some(index) = state
+                    .queued
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(_, queued)| ((queued.priority)(), std::cmp::Reverse(queued.id)))
+                    .map(|(index, _)| index)
+                else {
+                    return;
+                };
+
+                state.running += 1;
+                state.queued.remove(index).start
+            };
+
+            let future = start();
+            let queue = self.clone();
+
+            leptos::task::spawn_local(async move {
+                future.await;
+
+                queue.state.borrow_mut().running -= 1;
+                queue.start_next();
+            });
+        }
+    }
+}
@@ -0,0 +1,207 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::utils::get_header;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Reactive privacy preferences of the user:
+/// [Do Not Track](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/doNotTrack),
+/// [Global Privacy Control](https://globalprivacycontrol.org/) and whether
+/// [cookies are enabled](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/cookieEnabled).
+///
+/// Use them to adapt what data your app collects, for example to not load analytics at all.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_privacy_signals)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_privacy_signals, UsePrivacySignalsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePrivacySignalsReturn {
+///     opted_out,
+///     cookies_enabled,
+///     ..
+/// } = use_privacy_signals();
+///
+/// view! {
+///     <Show when=move || !opted_out.get() && cookies_enabled.get()>
+///         <p>"Analytics enabled"</p>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// `opted_out` is `true` if the user has enabled either Do Not Track or Global Privacy Control.
+/// Browsers don't notify pages when these settings change, so they are read again whenever the
+/// page becomes visible.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the [`DNT`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/DNT) and
+/// [`Sec-GPC`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Sec-GPC) request headers
+/// are read. `cookies_enabled` is always `true`.
+///
+/// > If you're using `axum` you have to enable the `"axum"` feature in your Cargo.toml.
+/// > In case it's `actix-web` enable the feature `"actix"`, for `spin` enable `"spin"`.
+///
+/// ### Bring your own header
+///
+/// In case you're neither using Axum, Actix nor Spin, or the default implementation is not to your
+/// liking, you can provide your own way of reading the header values using the options
+/// [`crate::UsePrivacySignalsOptions::ssr_dnt_header_getter`] and
+/// [`crate::UsePrivacySignalsOptions::ssr_gpc_header_getter`].
+///
+/// ## See also
+///
+/// * [`fn@crate::storage::use_consent`]
+pub fn use_privacy_signals() -> UsePrivacySignalsReturn {
+    use_privacy_signals_with_options(UsePrivacySignalsOptions::default())
+}
+
+/// Version of [`use_privacy_signals`] that takes a `UsePrivacySignalsOptions`. See [`use_privacy_signals`] for how to use.
+#[cfg_attr(not(feature = "ssr"), allow(unused_variables))]
+pub fn use_privacy_signals_with_options(
+    options: UsePrivacySignalsOptions,
+) -> UsePrivacySignalsReturn {
+    #[cfg(feature = "ssr")]
+    let (do_not_track, global_privacy_control, cookies_enabled) = {
+        let enabled = |value: Option<String>| value.is_some_and(|value| value.trim() == "1");
+
+        let do_not_track = enabled((options.ssr_dnt_header_getter)());
+        let global_privacy_control = enabled((options.ssr_gpc_header_getter)());
+
+        (
+            Signal::stored(do_not_track),
+            Signal::stored(global_privacy_control),
+            Signal::stored(true),
+        )
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    let (do_not_track, global_privacy_control, cookies_enabled) = {
+        use crate::use_event_listener;
+
+        let (do_not_track, set_do_not_track) = signal(read_do_not_track());
+        let (global_privacy_control, set_global_privacy_control) =
+            signal(read_global_privacy_control());
+        let (cookies_enabled, set_cookies_enabled) = signal(read_cookies_enabled());
+
+        let _ = use_event_listener(document(), leptos::ev::visibilitychange, move |_| {
+            if document().hidden() {
+                return;
+            }
+
+            set_do_not_track.set(read_do_not_track());
+            set_global_privacy_control.set(read_global_privacy_control());
+            set_cookies_enabled.set(read_cookies_enabled());
+        });
+
+        (
+            do_not_track.into(),
+            global_privacy_control.into(),
+            cookies_enabled.into(),
+        )
+    };
+
+    UsePrivacySignalsReturn {
+        do_not_track,
+        global_privacy_control,
+        cookies_enabled,
+        opted_out: Signal::derive(move || do_not_track.get() || global_privacy_control.get()),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn navigator_property(name: &str) -> wasm_bindgen::JsValue {
+    js_sys::Reflect::get(&window().navigator(), &name.into()).unwrap_or_default()
+}
+
+/// Older browsers expose this on `window` or as `msDoNotTrack` and some use `"yes"` instead of `"1"`.
+#[cfg(not(feature = "ssr"))]
+fn read_do_not_track() -> bool {
+    let window_dnt = js_sys::Reflect::get(&window(), &"doNotTrack".into()).unwrap_or_default();
+
+    [
+        navigator_property("doNotTrack"),
+        window_dnt,
+        navigator_property("msDoNotTrack"),
+    ]
+    .into_iter()
+    .filter_map(|value| value.as_string())
+    .any(|value| value == "1" || value == "yes")
+}
+
+#[cfg(not(feature = "ssr"))]
+fn read_global_privacy_control() -> bool {
+    navigator_property("globalPrivacyControl").is_truthy()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn read_cookies_enabled() -> bool {
+    navigator_property("cookieEnabled")
+        .as_bool()
+        .unwrap_or(true)
+}
+
+/// Options for [`use_privacy_signals_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePrivacySignalsOptions {
+    /// Getter function to return the string value of the
+    /// [`DNT`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/DNT) header.
+    /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default
+    /// implementation provided.
+    #[allow(dead_code)]
+    pub(crate) ssr_dnt_header_getter: Arc<dyn Fn() -> Option<String> + Send + Sync>,
+
+    /// Getter function to return the string value of the
+    /// [`Sec-GPC`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Sec-GPC) header.
+    /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default
+    /// implementation provided.
+    #[allow(dead_code)]
+    pub(crate) ssr_gpc_header_getter: Arc<dyn Fn() -> Option<String> + Send + Sync>,
+}
+
+impl Default for UsePrivacySignalsOptions {
+    fn default() -> Self {
+        Self {
+            ssr_dnt_header_getter: Arc::new(move || {
+                get_header!(
+                    HeaderName::from_static("dnt"),
+                    use_privacy_signals,
+                    ssr_dnt_header_getter
+                )
+            }),
+            ssr_gpc_header_getter: Arc::new(move || {
+                get_header!(
+                    HeaderName::from_static("sec-gpc"),
+                    use_privacy_signals,
+                    ssr_gpc_header_getter
+                )
+            }),
+        }
+    }
+}
+
+/// Return type of [`use_privacy_signals`].
+#[derive(Clone, Copy)]
+pub struct UsePrivacySignalsReturn {
+    /// Whether the user has enabled Do Not Track.
+    pub do_not_track: Signal<bool>,
+
+    /// Whether the user has enabled Global Privacy Control.
+    pub global_privacy_control: Signal<bool>,
+
+    /// Whether the browser allows setting cookies.
+    pub cookies_enabled: Signal<bool>,
+
+    /// Whether the user has enabled Do Not Track or Global Privacy Control.
+    pub opted_out: Signal<bool>,
+}
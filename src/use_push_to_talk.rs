@@ -0,0 +1,199 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Push-to-talk for the audio tracks of a media stream.
+///
+/// The audio tracks of `stream` are disabled (muted) unless `key` is held down. This is the usual
+/// way to talk in voice apps that shouldn't transmit all the time.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_push_to_talk)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{
+/// #     use_push_to_talk, use_user_media_with_options, UsePushToTalkReturn, UseUserMediaOptions,
+/// #     UseUserMediaReturn,
+/// # };
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, .. } = use_user_media_with_options(
+///     UseUserMediaOptions::default().audio(true).video(false),
+/// );
+///
+/// let push_to_talk = use_push_to_talk(
+///     Signal::derive_local(move || stream.get().and_then(Result::ok)),
+///     "Space",
+/// );
+/// let UsePushToTalkReturn { is_transmitting, .. } = push_to_talk;
+///
+/// view! {
+///     <button
+///         on:pointerdown=move |_| push_to_talk.start()
+///         on:pointerup=move |_| push_to_talk.stop()
+///         on:pointerleave=move |_| push_to_talk.stop()
+///     >
+///         {move || if is_transmitting.get() { "Talking..." } else { "Hold Space to talk" }}
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// `key` is compared with both the [`code`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/code)
+/// of the key like `"Space"` or `"KeyT"` and the [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key)
+/// value like `"t"`, ignoring case.
+///
+/// Key presses while typing in an input, textarea or contenteditable element are ignored. Set the
+/// option `ignore_editable` to `false` to change that. When the window loses focus transmitting
+/// stops, because the key release can't be detected anymore.
+///
+/// `start` and `stop` can be used to transmit with an on screen button in addition to the key.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_transmitting` is always `false` and nothing is listened to.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_user_media`]
+/// * [`fn@crate::use_audio_level`]
+pub fn use_push_to_talk(
+    stream: impl Into<Signal<Option<web_sys::MediaStream>, LocalStorage>>,
+    key: impl Into<String>,
+) -> UsePushToTalkReturn {
+    use_push_to_talk_with_options(stream, key, UsePushToTalkOptions::default())
+}
+
+/// Version of [`use_push_to_talk`] that takes a `UsePushToTalkOptions`. See [`use_push_to_talk`] for how to use.
+pub fn use_push_to_talk_with_options(
+    stream: impl Into<Signal<Option<web_sys::MediaStream>, LocalStorage>>,
+    key: impl Into<String>,
+    options: UsePushToTalkOptions,
+) -> UsePushToTalkReturn {
+    let UsePushToTalkOptions { ignore_editable } = options;
+
+    let stream = stream.into();
+    let key = key.into();
+
+    let (is_transmitting, set_transmitting) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use wasm_bindgen::JsCast;
+
+        let matches_key = move |event: &web_sys::KeyboardEvent| {
+            event.code().eq_ignore_ascii_case(&key) || event.key().eq_ignore_ascii_case(&key)
+        };
+
+        let _ = use_event_listener(window(), leptos::ev::keydown, {
+            let matches_key = matches_key.clone();
+
+            move |event| {
+                if event.repeat() || !matches_key(&event) {
+                    return;
+                }
+
+                if ignore_editable && is_editable(event.target()) {
+                    return;
+                }
+
+                // for example prevents scrolling with Space
+                event.prevent_default();
+                set_transmitting.set(true);
+            }
+        });
+
+        let _ = use_event_listener(window(), leptos::ev::keyup, move |event| {
+            if matches_key(&event) {
+                set_transmitting.set(false);
+            }
+        });
+
+        let _ = use_event_listener(window(), leptos::ev::blur, move |_| {
+            set_transmitting.set(false);
+        });
+
+        let _ = Effect::watch(
+            move || (stream.get(), is_transmitting.get()),
+            move |(stream, is_transmitting), _, _| {
+                let Some(stream) = stream else {
+                    return;
+                };
+
+                for track in stream.get_audio_tracks().iter() {
+                    track
+                        .unchecked_into::<web_sys::MediaStreamTrack>()
+                        .set_enabled(*is_transmitting);
+                }
+            },
+            true,
+        );
+    }
+
+    UsePushToTalkReturn {
+        is_transmitting: is_transmitting.into(),
+        set_transmitting,
+    }
+}
+
+/// Whether `target` is an element that the user types into.
+#[cfg(not(feature = "ssr"))]
+fn is_editable(target: Option<web_sys::EventTarget>) -> bool {
+    use wasm_bindgen::JsCast;
+
+    target
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|element| {
+            element
+                .closest(
+                    "input, textarea, select, [contenteditable]:not([contenteditable='false'])",
+                )
+                .ok()
+                .flatten()
+        })
+        .is_some()
+}
+
+/// Options for [`use_push_to_talk_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePushToTalkOptions {
+    /// Whether key presses in inputs, textareas and contenteditable elements are ignored.
+    /// Defaults to `true`.
+    ignore_editable: bool,
+}
+
+impl Default for UsePushToTalkOptions {
+    fn default() -> Self {
+        Self {
+            ignore_editable: true,
+        }
+    }
+}
+
+/// Return type of [`use_push_to_talk`].
+#[derive(Clone, Copy)]
+pub struct UsePushToTalkReturn {
+    /// Whether the audio tracks are currently enabled.
+    pub is_transmitting: Signal<bool>,
+
+    set_transmitting: WriteSignal<bool>,
+}
+
+impl UsePushToTalkReturn {
+    /// Starts transmitting, like pressing the key.
+    pub fn start(&self) {
+        self.set_transmitting.set(true);
+    }
+
+    /// Stops transmitting, like releasing the key.
+    pub fn stop(&self) {
+        self.set_transmitting.set(false);
+    }
+}
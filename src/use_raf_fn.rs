@@ -35,6 +35,24 @@ use std::rc::Rc;
 /// You can use `use_raf_fn_with_options` and set `immediate` to `false`. In that case
 /// you have to call `resume()` before the `callback` is executed.
 ///
+/// To save work on high refresh rate screens you can limit how often the callback is called.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_raf_fn_with_options, UseRafFnOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let pausable = use_raf_fn_with_options(
+///     move |args| {
+///         // called at most 30 times per second. `args.delta` is the time since the last call.
+///     },
+///     UseRafFnOptions::default().fps_limit(30.0),
+/// );
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause` and `resume` are sendwrapped functions. They can
@@ -54,7 +72,12 @@ pub fn use_raf_fn_with_options(
     callback: impl Fn(UseRafFnCallbackArgs) + 'static,
     options: UseRafFnOptions,
 ) -> Pausable<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
-    let UseRafFnOptions { immediate } = options;
+    let UseRafFnOptions {
+        immediate,
+        fps_limit,
+    } = options;
+
+    let interval_limit = fps_limit.map(|fps_limit| 1000.0 / fps_limit);
 
     let raf_handle = Rc::new(Cell::new(None::<i32>));
 
@@ -107,6 +130,13 @@ pub fn use_raf_fn_with_options(
                 0.0
             };
 
+            if let Some(interval_limit) = interval_limit {
+                if prev_timestamp > 0.0 && delta < interval_limit - FPS_LIMIT_TOLERANCE {
+                    request_next_frame();
+                    return;
+                }
+            }
+
             #[cfg(debug_assertions)]
             let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
@@ -157,17 +187,31 @@ pub fn use_raf_fn_with_options(
     }
 }
 
+/// Milliseconds a frame may arrive early and still count for `fps_limit`. Frame timestamps are
+/// rounded and jitter. Without it frames that are due would be skipped and the rate would drop
+/// well below the limit.
+const FPS_LIMIT_TOLERANCE: f64 = 5.0;
+
 /// Options for [`use_raf_fn_with_options`].
 #[derive(DefaultBuilder)]
 pub struct UseRafFnOptions {
     /// Start the requestAnimationFrame loop immediately on creation. Defaults to `true`.
     /// If false, the loop will only start when you call `resume()`.
     immediate: bool,
+
+    /// Maximum number of times per second the callback is called. Frames in between are
+    /// skipped. A frame that arrives up to 5 milliseconds early isn't skipped. Defaults to `None`
+    /// which means the callback is called on every frame.
+    #[builder(into)]
+    fps_limit: Option<f64>,
 }
 
 impl Default for UseRafFnOptions {
     fn default() -> Self {
-        Self { immediate: true }
+        Self {
+            immediate: true,
+            fps_limit: None,
+        }
     }
 }
 
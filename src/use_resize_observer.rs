@@ -1,10 +1,12 @@
 use crate::core::IntoElementsMaybeSignal;
+use crate::filter_builder_methods;
+use crate::utils::{DebounceOptions, FilterOptions, ThrottleOptions};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::reactive::wrappers::read::Signal;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
-    use crate::{sendwrap_fn, use_supported};
+    use crate::{sendwrap_fn, use_supported, utils::create_filter_wrapper_with_arg};
     use std::cell::RefCell;
     use std::rc::Rc;
     use wasm_bindgen::prelude::*;
@@ -45,6 +47,31 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Throttling
+///
+/// When elements are resized continuously, e.g. while dragging a splitter, you can throttle,
+/// debounce or pause the callback with the same filters as [`fn@crate::watch_with_options`].
+///
+/// ```
+/// # use leptos::{html::Div, prelude::*};
+/// # use leptos_use::{use_resize_observer_with_options, UseResizeObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// use_resize_observer_with_options(
+///     el,
+///     move |entries, observer| {
+///         // expensive layout calculations
+///     },
+///     UseResizeObserverOptions::default().throttle(100.0),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure `stop` is a sendwrapped function. It can
@@ -72,7 +99,7 @@ where
 #[cfg_attr(feature = "ssr", allow(unused_variables, unused_mut))]
 pub fn use_resize_observer_with_options<Els, M, F>(
     target: Els,
-    mut callback: F,
+    callback: F,
     options: UseResizeObserverOptions,
 ) -> UseResizeObserverReturn<impl Fn() + Clone + Send + Sync>
 where
@@ -91,19 +118,30 @@ where
     {
         use crate::js;
 
-        let closure_js = Closure::<dyn FnMut(js_sys::Array, web_sys::ResizeObserver)>::new(
-            move |entries: js_sys::Array, observer| {
+        let callback = Rc::new(RefCell::new(callback));
+        let callback = create_filter_wrapper_with_arg(
+            options.event_filter.filter_fn(),
+            move |(entries, observer): (
+                Vec<web_sys::ResizeObserverEntry>,
+                web_sys::ResizeObserver,
+            )| {
                 #[cfg(debug_assertions)]
                 let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
-                callback(
+                (callback.borrow_mut())(entries, observer);
+            },
+        );
+
+        let closure_js = Closure::<dyn FnMut(js_sys::Array, web_sys::ResizeObserver)>::new(
+            move |entries: js_sys::Array, observer| {
+                callback((
                     entries
                         .to_vec()
                         .into_iter()
                         .map(|v| v.unchecked_into::<web_sys::ResizeObserverEntry>())
-                        .collect(),
+                        .collect::<Vec<_>>(),
                     observer,
-                );
+                ));
             },
         )
         .into_js_value();
@@ -174,6 +212,17 @@ pub struct UseResizeObserverOptions {
     /// The box that is used to determine the dimensions of the target. Defaults to `ContentBox`.
     #[builder(into)]
     pub box_: Option<web_sys::ResizeObserverBoxOptions>,
+
+    /// Allows to debounce, throttle or pause the callback. Defaults to no filter.
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    event_filter: FilterOptions,
+}
+
+impl UseResizeObserverOptions {
+    filter_builder_methods!(
+        /// the callback
+        event_filter
+    );
 }
 
 impl From<UseResizeObserverOptions> for web_sys::ResizeObserverOptions {
@@ -0,0 +1,157 @@
+use crate::{use_device_pixel_ratio, use_media_query, use_window_size, UseWindowSizeReturn};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive source of an image or video that fits the current screen.
+///
+/// Picks the best source out of several sources for the current window width, device pixel ratio
+/// and data saver setting. The source is updated when any of these change, for example when the
+/// window is resized or moved to another screen.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_responsive_source)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_responsive_source, ResponsiveSource};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let src = use_responsive_source(vec![
+///     ResponsiveSource::new("/hero-small.jpg"),
+///     ResponsiveSource::new("/hero-small@2x.jpg").density(2.0),
+///     ResponsiveSource::new("/hero-large.jpg").min_width(1024),
+///     ResponsiveSource::new("/hero-large@2x.jpg").min_width(1024).density(2.0),
+/// ]);
+///
+/// view! { <img src=src /> }
+/// # }
+/// ```
+///
+/// A source is used from its `min_width` on, which is the width of the window in CSS pixels like
+/// with `min-width` media queries. Of all sources with a `min_width` that fits, the ones with the
+/// largest `min_width` are used. If none fits, the ones with the smallest `min_width` are used.
+///
+/// Among those the source with the smallest `density` that is at least the device pixel ratio is
+/// picked, or the one with the highest density if there's none. If the user prefers reduced data
+/// usage, which is the case when the media query `(prefers-reduced-data: reduce)` matches or the
+/// browser's data saver is on, the source with the lowest density is picked instead.
+///
+/// The returned signal is `None` if there are no sources. It works with `<video>` and `<source>`
+/// elements just the same. Unlike a `srcset` this also updates a video and can be combined with
+/// other reactive logic.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the window is considered infinitely wide with a device pixel ratio of `1.0`. So
+/// the source with the largest `min_width` for a pixel ratio of `1.0` is used.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_breakpoints`]
+/// * [`fn@crate::use_device_pixel_ratio`]
+pub fn use_responsive_source(
+    sources: impl Into<Signal<Vec<ResponsiveSource>>>,
+) -> Signal<Option<String>> {
+    let sources = sources.into();
+
+    let UseWindowSizeReturn { width, .. } = use_window_size();
+    let pixel_ratio = use_device_pixel_ratio();
+    let prefers_reduced_data = use_media_query("(prefers-reduced-data: reduce)");
+
+    Signal::derive(move || {
+        let save_data = prefers_reduced_data.get() || is_data_saver_on();
+
+        sources.with(|sources| {
+            select_source(sources, width.get(), pixel_ratio.get(), save_data)
+                .map(|source| source.src.clone())
+        })
+    })
+}
+
+/// Picks the source for the window `width` and `pixel_ratio`. See [`use_responsive_source`].
+fn select_source(
+    sources: &[ResponsiveSource],
+    width: f64,
+    pixel_ratio: f64,
+    save_data: bool,
+) -> Option<&ResponsiveSource> {
+    let min_width = sources
+        .iter()
+        .map(|source| source.min_width)
+        .filter(|min_width| *min_width as f64 <= width)
+        .max()
+        .or_else(|| sources.iter().map(|source| source.min_width).min())?;
+
+    let candidates = sources
+        .iter()
+        .filter(|source| source.min_width == min_width);
+
+    let by_density = |a: &&ResponsiveSource, b: &&ResponsiveSource| a.density.total_cmp(&b.density);
+
+    if save_data {
+        return candidates.min_by(by_density);
+    }
+
+    candidates
+        .clone()
+        .filter(|source| source.density >= pixel_ratio)
+        .min_by(by_density)
+        .or_else(|| candidates.max_by(by_density))
+}
+
+/// Whether the browser's data saver is on according to `navigator.connection.saveData`.
+fn is_data_saver_on() -> bool {
+    #[cfg(feature = "ssr")]
+    {
+        false
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        js_sys::Reflect::get(&window().navigator(), &"connection".into())
+            .ok()
+            .filter(|connection| connection.is_object())
+            .and_then(|connection| js_sys::Reflect::get(&connection, &"saveData".into()).ok())
+            .and_then(|save_data| save_data.as_bool())
+            .unwrap_or(false)
+    }
+}
+
+/// A source of [`use_responsive_source`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponsiveSource {
+    /// URL of the image or video
+    pub src: String,
+
+    /// Minimum window width in CSS pixels from which on this source is used. Defaults to `0`.
+    pub min_width: u32,
+
+    /// Pixel density this source is made for, like `2.0` for an image with twice the resolution.
+    /// Defaults to `1.0`.
+    pub density: f64,
+}
+
+impl ResponsiveSource {
+    /// Creates a source for all window widths and a density of `1.0`.
+    pub fn new(src: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            min_width: 0,
+            density: 1.0,
+        }
+    }
+
+    /// Sets the minimum window width in CSS pixels from which on this source is used.
+    pub fn min_width(self, min_width: u32) -> Self {
+        Self { min_width, ..self }
+    }
+
+    /// Sets the pixel density this source is made for.
+    pub fn density(self, density: f64) -> Self {
+        Self { density, ..self }
+    }
+}
@@ -0,0 +1,552 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use leptos::prelude::*;
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Typed request/response calls on top of
+/// [`postMessage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage).
+///
+/// Both sides of the channel call `use_rpc_channel` with the same request and response types. One
+/// side registers a handler with `handle` and the other side calls it with `call`, which resolves
+/// with the response. Requests and responses are matched by a correlation id, so several calls can
+/// be in flight at the same time.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_rpc_channel)
+///
+/// ## Usage
+///
+/// Requests and responses are (en)decoded via the given codec.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_rpc_channel, RpcTarget};
+/// # use codee::string::FromToStringCodec;
+/// # use wasm_bindgen::JsCast;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let iframe = document()
+///     .get_element_by_id("child")
+///     .unwrap()
+///     .unchecked_into::<web_sys::HtmlIFrameElement>();
+///
+/// let rpc = use_rpc_channel::<i64, i64, FromToStringCodec>(RpcTarget::window(
+///     iframe.content_window().unwrap(),
+///     "https://child.example.com",
+/// ));
+///
+/// let on_click = move |_| {
+///     spawn_local(async move {
+///         let squared = rpc.call(&4).await;
+///         assert!(matches!(squared, Ok(16)));
+///     });
+/// };
+/// #
+/// # view! { <button on:click=on_click>"Call"</button> }
+/// # }
+/// ```
+///
+/// And in the page inside of the iframe:
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_rpc_channel, RpcTarget};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let rpc = use_rpc_channel::<i64, i64, FromToStringCodec>(RpcTarget::window(
+///     window().parent().unwrap().unwrap(),
+///     "https://parent.example.com",
+/// ));
+///
+/// rpc.handle(|n| async move { n * n });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Messages from a window target are only accepted if they come from that window and, unless
+/// the target origin is `"*"`, from that origin. Instead of a window a `web_sys::Worker` or a
+/// `web_sys::MessagePort` can be used as target.
+///
+/// ### Timeouts
+///
+/// If there is no response after 10 seconds, `call` resolves with
+/// `Err(UseRpcChannelError::Timeout)`. Use the option `timeout` to change that.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_rpc_channel_with_options, UseRpcChannelOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let worker = web_sys::Worker::new("worker.js").unwrap();
+/// let rpc = use_rpc_channel_with_options::<String, String, FromToStringCodec>(
+///     worker,
+///     UseRpcChannelOptions::default()
+///         .channel("greetings")
+///         .timeout(1000_u64),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The option `channel` separates several RPC channels on the same target from each other.
+///
+/// If the handler side fails to decode a request or has no handler registered, the call resolves
+/// with `Err(UseRpcChannelError::Remote(_))`. When the component is disposed, all pending calls
+/// resolve with `Err(UseRpcChannelError::Closed)`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `call` always returns `Err(UseRpcChannelError::Server)` and the handler is
+/// never called.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_web_worker`]
+/// * [`fn@crate::use_broadcast_channel`]
+pub fn use_rpc_channel<Req, Res, C>(
+    target: impl Into<RpcTarget>,
+) -> UseRpcChannelReturn<Req, Res, C>
+where
+    Req: 'static,
+    Res: 'static,
+    C: Encoder<Req, Encoded = String>
+        + Decoder<Req, Encoded = str>
+        + Encoder<Res, Encoded = String>
+        + Decoder<Res, Encoded = str>,
+{
+    use_rpc_channel_with_options(target, UseRpcChannelOptions::default())
+}
+
+/// Version of [`use_rpc_channel`] that takes a `UseRpcChannelOptions`. See [`use_rpc_channel`] for how to use.
+pub fn use_rpc_channel_with_options<Req, Res, C>(
+    target: impl Into<RpcTarget>,
+    options: UseRpcChannelOptions,
+) -> UseRpcChannelReturn<Req, Res, C>
+where
+    Req: 'static,
+    Res: 'static,
+    C: Encoder<Req, Encoded = String>
+        + Decoder<Req, Encoded = str>
+        + Encoder<Res, Encoded = String>
+        + Decoder<Res, Encoded = str>,
+{
+    let UseRpcChannelOptions { channel, timeout } = options;
+
+    let target = target.into();
+
+    let ret = UseRpcChannelReturn {
+        target: StoredValue::new_local(target.clone()),
+        channel: StoredValue::new(channel),
+        timeout,
+        pending: StoredValue::new_local(HashMap::new()),
+        handler: StoredValue::new_local(None),
+        next_id: StoredValue::new(0),
+        _marker: PhantomData,
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::task::spawn_local;
+
+        let listen_on: web_sys::EventTarget = match target {
+            RpcTarget::Window { .. } => window().into(),
+            RpcTarget::Worker(worker) => worker.into(),
+            RpcTarget::MessagePort(port) => {
+                // listening via `addEventListener` doesn't start the port implicitly
+                port.start();
+                port.into()
+            }
+        };
+
+        let _ = use_event_listener(listen_on, leptos::ev::message, move |event| {
+            if !ret.target.with_value(|target| target.accepts(&event)) {
+                return;
+            }
+
+            let Some(envelope) = ret
+                .channel
+                .with_value(|channel| Envelope::parse(&event.data(), channel))
+            else {
+                return;
+            };
+
+            match envelope.kind.as_str() {
+                "request" => {
+                    let Some(handler) = ret.handler.with_value(Clone::clone) else {
+                        let _ = ret.post(envelope.id, "error", "no handler registered");
+                        return;
+                    };
+
+                    spawn_local(async move {
+                        let _ = match handler(envelope.payload).await {
+                            Ok(payload) => ret.post(envelope.id, "response", &payload),
+                            Err(message) => ret.post(envelope.id, "error", &message),
+                        };
+                    });
+                }
+                "response" | "error" => {
+                    ret.settle(envelope.id, &envelope.kind, &envelope.payload.into());
+                }
+                _ => {}
+            }
+        });
+    }
+
+    on_cleanup(move || ret.close());
+
+    ret
+}
+
+/// Where the messages of [`fn@crate::use_rpc_channel`] are posted to and received from.
+#[derive(Clone, Debug)]
+pub enum RpcTarget {
+    /// Another window like the content window of an iframe, the parent or the opener.
+    Window {
+        window: web_sys::Window,
+        /// The origin the window must have. `"*"` accepts any origin.
+        target_origin: String,
+    },
+
+    /// A dedicated worker.
+    Worker(web_sys::Worker),
+
+    /// One end of a `MessageChannel`.
+    MessagePort(web_sys::MessagePort),
+}
+
+impl RpcTarget {
+    /// Creates a window target that only talks to `target_origin`.
+    pub fn window(window: web_sys::Window, target_origin: impl Into<String>) -> Self {
+        Self::Window {
+            window,
+            target_origin: target_origin.into(),
+        }
+    }
+
+    /// Whether the message event has been sent by this target.
+    #[cfg(not(feature = "ssr"))]
+    fn accepts(&self, event: &web_sys::MessageEvent) -> bool {
+        match self {
+            Self::Window {
+                window,
+                target_origin,
+            } => {
+                let window: &JsValue = window;
+
+                event
+                    .source()
+                    .is_some_and(|source| &JsValue::from(source) == window)
+                    && (target_origin == "*" || event.origin() == *target_origin)
+            }
+            Self::Worker(_) | Self::MessagePort(_) => true,
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn post_message(&self, message: &JsValue) -> Result<(), JsValue> {
+        match self {
+            Self::Window {
+                window,
+                target_origin,
+            } => window.post_message(message, target_origin),
+            Self::Worker(worker) => worker.post_message(message),
+            Self::MessagePort(port) => port.post_message(message),
+        }
+    }
+}
+
+impl From<web_sys::Worker> for RpcTarget {
+    fn from(worker: web_sys::Worker) -> Self {
+        Self::Worker(worker)
+    }
+}
+
+impl From<web_sys::MessagePort> for RpcTarget {
+    fn from(port: web_sys::MessagePort) -> Self {
+        Self::MessagePort(port)
+    }
+}
+
+/// A message of the RPC protocol as it is posted to the target.
+struct Envelope {
+    id: u64,
+    kind: String,
+    payload: String,
+}
+
+impl Envelope {
+    #[cfg(not(feature = "ssr"))]
+    fn parse(data: &JsValue, channel: &str) -> Option<Self> {
+        use crate::js;
+
+        if js!(data["leptosUseRpc"]).ok()?.as_string()? != channel {
+            return None;
+        }
+
+        Some(Self {
+            id: js!(data["id"]).ok()?.as_f64()? as u64,
+            kind: js!(data["kind"]).ok()?.as_string()?,
+            payload: js!(data["payload"]).ok()?.as_string()?,
+        })
+    }
+}
+
+/// Options for [`use_rpc_channel_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseRpcChannelOptions {
+    /// Name that separates several RPC channels on the same target. Both sides have to use the
+    /// same name. Defaults to `"default"`.
+    #[builder(into)]
+    channel: String,
+
+    /// Timeout in milliseconds after which a call without response fails. `None` means no
+    /// timeout. Defaults to `Some(10_000)`.
+    #[builder(into)]
+    timeout: Option<u64>,
+}
+
+impl Default for UseRpcChannelOptions {
+    fn default() -> Self {
+        Self {
+            channel: "default".to_string(),
+            timeout: Some(10_000),
+        }
+    }
+}
+
+/// A call that is waiting for its response.
+struct PendingCall {
+    resolve: js_sys::Function,
+    timeout: Option<TimeoutHandle>,
+}
+
+/// Handler that has been registered with `handle`. Works on the encoded request and response.
+type RpcHandler = Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<String, String>>>>>;
+
+/// Return type of [`use_rpc_channel`].
+pub struct UseRpcChannelReturn<Req, Res, C> {
+    target: StoredValue<RpcTarget, LocalStorage>,
+    channel: StoredValue<String>,
+    timeout: Option<u64>,
+    pending: StoredValue<HashMap<u64, PendingCall>, LocalStorage>,
+    handler: StoredValue<Option<RpcHandler>, LocalStorage>,
+    next_id: StoredValue<u64>,
+    #[allow(clippy::type_complexity)]
+    _marker: PhantomData<fn() -> (Req, Res, C)>,
+}
+
+impl<Req, Res, C> Clone for UseRpcChannelReturn<Req, Res, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Req, Res, C> Copy for UseRpcChannelReturn<Req, Res, C> {}
+
+impl<Req, Res, C> UseRpcChannelReturn<Req, Res, C>
+where
+    Req: 'static,
+    Res: 'static,
+    C: Encoder<Req, Encoded = String>
+        + Decoder<Req, Encoded = str>
+        + Encoder<Res, Encoded = String>
+        + Decoder<Res, Encoded = str>,
+{
+    /// Sends the request to the other side and resolves with its response.
+    pub async fn call(&self, req: &Req) -> Result<Res, ErrorType<Req, Res, C>> {
+        #[cfg(feature = "ssr")]
+        {
+            Err(UseRpcChannelError::Server)
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use crate::js_fut;
+            use std::time::Duration;
+            use wasm_bindgen::JsCast;
+
+            let payload = <C as Encoder<Req>>::encode(req)
+                .map_err(|err| UseRpcChannelError::Codec(CodecError::Encode(err)))?;
+
+            let id = self.next_id.get_value();
+            self.next_id.set_value(id + 1);
+
+            let mut resolve = None;
+            let promise = js_sys::Promise::new(&mut |res, _| {
+                resolve = Some(res);
+            });
+            let resolve = resolve.expect("Promise callbacks have been set");
+
+            let this = *self;
+            let timeout = self.timeout.and_then(|timeout| {
+                set_timeout_with_handle(
+                    move || this.settle(id, "timeout", &JsValue::UNDEFINED),
+                    Duration::from_millis(timeout),
+                )
+                .ok()
+            });
+
+            self.pending.update_value(|pending| {
+                pending.insert(id, PendingCall { resolve, timeout });
+            });
+
+            if let Err(err) = self.post(id, "request", &payload) {
+                self.take_pending(id);
+                return Err(UseRpcChannelError::PostMessage(err));
+            }
+
+            let result = js_fut!(promise)
+                .await
+                .unwrap_or(JsValue::UNDEFINED)
+                .unchecked_into::<js_sys::Array>();
+
+            match result.get(0).as_string().as_deref() {
+                Some("response") => match result.get(1).as_string() {
+                    Some(value) => <C as Decoder<Res>>::decode(&value)
+                        .map_err(|err| UseRpcChannelError::Codec(CodecError::Decode(err))),
+                    None => Err(UseRpcChannelError::ValueNotString),
+                },
+                Some("error") => Err(UseRpcChannelError::Remote(
+                    result.get(1).as_string().unwrap_or_default(),
+                )),
+                Some("timeout") => Err(UseRpcChannelError::Timeout),
+                _ => Err(UseRpcChannelError::Closed),
+            }
+        }
+    }
+
+    /// Registers the function that answers the requests of the other side. Replaces a previously
+    /// registered handler.
+    pub fn handle<F, Fut>(&self, handler: F)
+    where
+        F: Fn(Req) -> Fut + 'static,
+        Fut: Future<Output = Res> + 'static,
+        C: 'static,
+    {
+        let handler = Rc::new(handler);
+
+        let handler: RpcHandler = Rc::new(move |payload: String| {
+            let handler = Rc::clone(&handler);
+
+            Box::pin(async move {
+                let req = <C as Decoder<Req>>::decode(&payload)
+                    .map_err(|_| "failed to decode request".to_string())?;
+
+                let res = handler(req).await;
+
+                <C as Encoder<Res>>::encode(&res)
+                    .map_err(|_| "failed to encode response".to_string())
+            })
+        });
+
+        self.handler.set_value(Some(handler));
+    }
+}
+
+impl<Req, Res, C> UseRpcChannelReturn<Req, Res, C> {
+    /// Removes the registered handler. Requests of the other side fail from now on.
+    pub fn unhandle(&self) {
+        self.handler.set_value(None);
+    }
+
+    /// Posts a message of the protocol to the target.
+    fn post(&self, id: u64, kind: &str, payload: &str) -> Result<(), JsValue> {
+        #[cfg(feature = "ssr")]
+        {
+            Ok(())
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use crate::js;
+
+            let message = js_sys::Object::new();
+            js!(message["leptosUseRpc"] = self.channel.get_value());
+            js!(message["id"] = id as f64);
+            js!(message["kind"] = kind);
+            js!(message["payload"] = payload);
+
+            self.target
+                .try_with_value(|target| target.post_message(&message))
+                .unwrap_or(Ok(()))
+        }
+    }
+
+    fn take_pending(&self, id: u64) -> Option<PendingCall> {
+        let call = self
+            .pending
+            .try_update_value(|pending| pending.remove(&id))
+            .flatten()?;
+
+        if let Some(timeout) = call.timeout {
+            timeout.clear();
+        }
+
+        Some(call)
+    }
+
+    /// Resolves the pending call `id` (if any) with `[kind, payload]`.
+    fn settle(&self, id: u64, kind: &str, payload: &JsValue) {
+        if let Some(call) = self.take_pending(id) {
+            let _ = call.resolve.call1(
+                &JsValue::NULL,
+                &js_sys::Array::of2(&JsValue::from_str(kind), payload),
+            );
+        }
+    }
+
+    /// Resolves all pending calls as closed.
+    fn close(&self) {
+        let ids = self
+            .pending
+            .try_with_value(|pending| pending.keys().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for id in ids {
+            self.settle(id, "closed", &JsValue::UNDEFINED);
+        }
+    }
+}
+
+type ErrorType<Req, Res, C> =
+    UseRpcChannelError<<C as Encoder<Req>>::Error, <C as Decoder<Res>>::Error>;
+
+#[derive(Debug, Error)]
+pub enum UseRpcChannelError<E, D> {
+    #[error("RPC calls cannot be made on the server")]
+    Server,
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("the call took longer than the timeout")]
+    Timeout,
+    #[error("the other side failed to handle the call: {0}")]
+    Remote(String),
+    #[error("the channel has been closed")]
+    Closed,
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("returned value is not a string")]
+    ValueNotString,
+}
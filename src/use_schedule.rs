@@ -0,0 +1,285 @@
+use crate::{use_now_with_options, UseNowOptions};
+use chrono::{
+    DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc,
+    Weekday,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive open/closed state of a recurring weekly schedule like business or support hours.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_schedule)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_schedule, ScheduleRule, UseScheduleReturn};
+/// # use chrono::{NaiveTime, Weekday};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+///
+/// let UseScheduleReturn {
+///     is_open,
+///     time_until_change,
+///     ..
+/// } = use_schedule(vec![
+///     ScheduleRule::new(
+///         [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+///         hm(9, 0),
+///         hm(18, 0),
+///     ),
+///     ScheduleRule::new([Weekday::Sat], hm(10, 0), hm(14, 0)),
+/// ]);
+///
+/// view! {
+///     <Show when=move || is_open.get() fallback=|| "We're closed right now.">
+///         "We're open! Closing in "
+///         {move || time_until_change.get().map(|d| d.num_minutes())}
+///         " minutes."
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Each rule opens at `open` and closes at `close` on the given weekdays. If `close` isn't after
+/// `open` the opening hours continue until `close` on the next day. So `22:00` to `02:00` on
+/// Friday is open in the night from Friday to Saturday and `00:00` to `00:00` is open all day.
+/// Rules that overlap or touch are treated as one opening.
+///
+/// `next_change_at` is the point in time when the schedule opens or closes next. It's `None` if
+/// there are no rules or if the schedule is always open.
+///
+/// ### Time Zone
+///
+/// By default the rules are evaluated in the local time zone of the browser. For business hours
+/// you usually want the time zone of the business instead. Any [`chrono::TimeZone`] works, for
+/// example the ones of the crate [`chrono-tz`](https://docs.rs/chrono-tz/latest/chrono_tz/)
+/// which also take care of daylight saving time.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_schedule_with_options, ScheduleRule, UseScheduleOptions};
+/// # use chrono::{FixedOffset, NaiveTime};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+/// let support = use_schedule_with_options(
+///     vec![ScheduleRule::every_day(hm(8, 0), hm(20, 0))],
+///     UseScheduleOptions::default().time_zone(FixedOffset::east_opt(3600).unwrap()),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// If an opening or closing time falls into the gap when the clocks are set forward it is moved
+/// forward by an hour.
+///
+/// The rules can also be a signal, for example when they are loaded from the server.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the schedule is evaluated once at the time of the call and never updates. Keep
+/// in mind that the local time zone is the one of the server there. Set `time_zone` to get the
+/// same result on the server and on the client.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_now`]
+/// * [`fn@crate::use_countdown_to`]
+pub fn use_schedule(rules: impl Into<Signal<Vec<ScheduleRule>>>) -> UseScheduleReturn {
+    use_schedule_with_options(rules, UseScheduleOptions::default())
+}
+
+/// Version of [`use_schedule`] that takes a `UseScheduleOptions`. See [`use_schedule`] for how to use.
+pub fn use_schedule_with_options<Tz>(
+    rules: impl Into<Signal<Vec<ScheduleRule>>>,
+    options: UseScheduleOptions<Tz>,
+) -> UseScheduleReturn
+where
+    Tz: TimeZone + Send + Sync + 'static,
+{
+    let UseScheduleOptions {
+        time_zone,
+        interval,
+    } = options;
+
+    let rules = rules.into();
+
+    let now = use_now_with_options(UseNowOptions::default().interval(interval));
+    let now = Memo::new(move |_| now.get().to_utc());
+
+    let state = Memo::new(move |_| rules.with(|rules| evaluate(rules, now.get(), &time_zone)));
+
+    UseScheduleReturn {
+        is_open: Signal::derive(move || state.get().0),
+        next_change_at: Signal::derive(move || state.get().1),
+        time_until_change: Signal::derive(move || state.get().1.map(|at| at - now.get())),
+    }
+}
+
+/// How many days ahead the schedule is evaluated. Anything that stays open longer than that is
+/// considered always open.
+const DAYS_AHEAD: i64 = 7;
+
+/// Returns whether the schedule is open at `now` and when that changes next.
+fn evaluate<Tz: TimeZone>(
+    rules: &[ScheduleRule],
+    now: DateTime<Utc>,
+    time_zone: &Tz,
+) -> (bool, Option<DateTime<Utc>>) {
+    let today = now.with_timezone(time_zone).date_naive();
+
+    let mut openings = (-1..=DAYS_AHEAD)
+        .filter_map(|offset| today.checked_add_signed(TimeDelta::days(offset)))
+        .flat_map(|date| {
+            rules
+                .iter()
+                .filter(move |rule| rule.weekdays.contains(&date.weekday()))
+                .filter_map(move |rule| rule.opening_on(date, time_zone))
+        })
+        .collect::<Vec<_>>();
+
+    openings.sort();
+
+    let merged = openings.into_iter().fold(
+        Vec::<(DateTime<Utc>, DateTime<Utc>)>::new(),
+        |mut merged, (start, end)| {
+            match merged.last_mut() {
+                Some(last) if last.1 >= start => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+            merged
+        },
+    );
+
+    if let Some((_, end)) = merged
+        .iter()
+        .find(|(start, end)| *start <= now && now < *end)
+    {
+        let next_change_at = (*end - now < TimeDelta::days(DAYS_AHEAD)).then_some(*end);
+        (true, next_change_at)
+    } else {
+        let next_change_at = merged
+            .iter()
+            .map(|(start, _)| *start)
+            .find(|start| *start > now);
+        (false, next_change_at)
+    }
+}
+
+/// Converts a local date and time to UTC. Times in the gap of a daylight saving time change are
+/// moved forward by an hour.
+fn to_utc<Tz: TimeZone>(time_zone: &Tz, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+    time_zone
+        .from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            time_zone
+                .from_local_datetime(&(local + TimeDelta::hours(1)))
+                .earliest()
+        })
+        .map(|date_time| date_time.to_utc())
+}
+
+/// A recurring weekly opening of a schedule. Used in [`use_schedule`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduleRule {
+    /// The days on which the schedule opens
+    pub weekdays: Vec<Weekday>,
+
+    /// Time of the day when the schedule opens
+    pub open: NaiveTime,
+
+    /// Time of the day when the schedule closes. If this isn't after `open` it closes on the next day.
+    pub close: NaiveTime,
+}
+
+impl ScheduleRule {
+    /// Opens at `open` and closes at `close` on each of the `weekdays`.
+    pub fn new(
+        weekdays: impl IntoIterator<Item = Weekday>,
+        open: NaiveTime,
+        close: NaiveTime,
+    ) -> Self {
+        Self {
+            weekdays: weekdays.into_iter().collect(),
+            open,
+            close,
+        }
+    }
+
+    /// Opens at `open` and closes at `close` on every day of the week.
+    pub fn every_day(open: NaiveTime, close: NaiveTime) -> Self {
+        Self::new(
+            [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ],
+            open,
+            close,
+        )
+    }
+
+    /// The opening that starts on `date` as a range in UTC.
+    fn opening_on<Tz: TimeZone>(
+        &self,
+        date: NaiveDate,
+        time_zone: &Tz,
+    ) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let close_date = if self.close <= self.open {
+            date.succ_opt()?
+        } else {
+            date
+        };
+
+        let start = to_utc(time_zone, date.and_time(self.open))?;
+        let end = to_utc(time_zone, close_date.and_time(self.close))?;
+
+        (start < end).then_some((start, end))
+    }
+}
+
+/// Options for [`use_schedule_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseScheduleOptions<Tz> {
+    /// The time zone in which the rules are evaluated. Defaults to the local time zone.
+    time_zone: Tz,
+
+    /// How often the schedule is evaluated in milliseconds. Defaults to `1000`.
+    interval: u64,
+}
+
+impl Default for UseScheduleOptions<Local> {
+    fn default() -> Self {
+        Self {
+            time_zone: Local,
+            interval: 1000,
+        }
+    }
+}
+
+/// Return type of [`use_schedule`].
+#[derive(Clone, Copy)]
+pub struct UseScheduleReturn {
+    /// Whether the schedule is open right now
+    pub is_open: Signal<bool>,
+
+    /// When the schedule opens or closes next. `None` if there are no rules or it's always open.
+    pub next_change_at: Signal<Option<DateTime<Utc>>>,
+
+    /// Time until `next_change_at`. Updates every `interval`.
+    pub time_until_change: Signal<Option<TimeDelta>>,
+}
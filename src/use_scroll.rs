@@ -1,4 +1,5 @@
 use crate::core::{Direction, Directions, IntoElementMaybeSignal};
+use crate::utils::FilterOptions;
 use crate::UseEventListenerOptions;
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
@@ -8,6 +9,7 @@ use std::rc::Rc;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
 use crate::use_event_listener::use_event_listener_with_options;
+use crate::utils::create_filter_wrapper_with_arg;
 use crate::{
     sendwrap_fn, use_debounce_fn_with_arg, use_throttle_fn_with_arg_and_options, ThrottleOptions,
 };
@@ -173,6 +175,33 @@ const ARRIVED_STATE_THRESHOLD_PIXELS: f64 = 1.0;
 /// # }
 /// ```
 ///
+/// ### Event Filter
+///
+/// Besides `throttle`, the scroll handler can be debounced, throttled or paused with an
+/// `event_filter`. Because `throttle` is already an option here, the filter is passed as
+/// [`FilterOptions`] instead of through the usual builder shortcuts.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_scroll_with_options, UseScrollReturn, UseScrollOptions};
+/// # use leptos_use::utils::FilterOptions;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let element = NodeRef::<Div>::new();
+/// #
+/// let (tracking, set_tracking) = signal(true);
+///
+/// let UseScrollReturn { x, y, .. } = use_scroll_with_options(
+///     element,
+///     UseScrollOptions::default().event_filter(FilterOptions::pausable(tracking)),
+/// );
+/// #
+/// # view! { <div node_ref=element>"..."</div> }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `set_x`, `set_y` and `measure` are sendwrapped functions. They can
@@ -375,6 +404,15 @@ where
             }
         };
 
+        let on_scroll_handler = {
+            let filtered_scroll_handler =
+                create_filter_wrapper_with_arg(options.event_filter.filter_fn(), on_scroll_handler);
+
+            move |e: web_sys::Event| {
+                filtered_scroll_handler(e);
+            }
+        };
+
         let target = Signal::derive_local(move || {
             let element = signal.get();
             element.map(|element| element.unchecked_into::<web_sys::EventTarget>())
@@ -469,6 +507,10 @@ pub struct UseScrollOptions {
     /// Options passed to the `addEventListener("scroll", ...)` call
     event_listener_options: UseEventListenerOptions,
 
+    /// Allows to debounce, throttle or pause the scroll event handler. It is applied before
+    /// `throttle`. Defaults to no filter.
+    event_filter: FilterOptions,
+
     /// When changing the `x` or `y` signals this specifies the scroll behaviour.
     /// Can be `Auto` (= not smooth) or `Smooth`. Defaults to `Auto`.
     #[builder(into)]
@@ -484,6 +526,7 @@ impl Default for UseScrollOptions {
             on_scroll: Rc::new(|_| {}),
             on_stop: Rc::new(|_| {}),
             event_listener_options: Default::default(),
+            event_filter: FilterOptions::default(),
             behavior: Default::default(),
         }
     }
@@ -0,0 +1,211 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{Directions, IntoElementMaybeSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Tolerance for fractional scroll positions on high-DPI screens.
+const EDGE_THRESHOLD_PIXELS: f64 = 1.0;
+
+/// Reactive flags whether a scroll container has more content in each direction.
+///
+/// This is meant for rendering scroll shadows or fades on the sides of an overflowing container.
+/// The flags are updated when the container is scrolled, resized or its content changes.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_scroll_edges)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_scroll_edges, UseScrollEdgesReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseScrollEdgesReturn {
+///     top, bottom, ..
+/// } = use_scroll_edges(el);
+///
+/// view! {
+///     <div style="position: relative">
+///         <Show when=move || top.get()>
+///             <div class="shadow-top" />
+///         </Show>
+///         <div node_ref=el style="height: 300px; overflow: auto">
+///             // long content
+///         </div>
+///         <Show when=move || bottom.get()>
+///             <div class="shadow-bottom" />
+///         </Show>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// With the option `offset` a side is only considered to have more content if it's more than that
+/// many pixels away from the edge.
+///
+/// Flex containers with `flex-direction: row-reverse` or `column-reverse` are supported.
+///
+/// ## Server-Side Rendering
+///
+/// On the server all flags are `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_scroll`]
+pub fn use_scroll_edges<El, M>(target: El) -> UseScrollEdgesReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_scroll_edges_with_options(target, UseScrollEdgesOptions::default())
+}
+
+/// Version of [`use_scroll_edges`] that takes a `UseScrollEdgesOptions`. See [`use_scroll_edges`] for how to use.
+pub fn use_scroll_edges_with_options<El, M>(
+    target: El,
+    options: UseScrollEdgesOptions,
+) -> UseScrollEdgesReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseScrollEdgesOptions { offset } = options;
+
+    let target = target.into_element_maybe_signal();
+
+    let (top, set_top) = signal(false);
+    let (bottom, set_bottom) = signal(false);
+    let (left, set_left) = signal(false);
+    let (right, set_right) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_event_listener_with_options, use_mutation_observer_with_options,
+            use_resize_observer, UseEventListenerOptions, UseMutationObserverOptions,
+        };
+
+        let update = move || {
+            let edges = target
+                .get_untracked()
+                .map(|el| scroll_edges(&el, offset))
+                .unwrap_or_default();
+
+            for (flag, set_flag, value) in [
+                (top, set_top, edges.top),
+                (bottom, set_bottom, edges.bottom),
+                (left, set_left, edges.left),
+                (right, set_right, edges.right),
+            ] {
+                if flag.get_untracked() != value {
+                    set_flag.set(value);
+                }
+            }
+        };
+
+        let _ = use_event_listener_with_options(
+            target,
+            leptos::ev::scroll,
+            move |_| update(),
+            UseEventListenerOptions::default().passive(true),
+        );
+
+        use_resize_observer(target, move |_, _| update());
+
+        use_mutation_observer_with_options(
+            target,
+            move |_, _| update(),
+            UseMutationObserverOptions::default()
+                .subtree(true)
+                .child_list(true)
+                .character_data(true),
+        );
+    }
+
+    UseScrollEdgesReturn {
+        top: top.into(),
+        bottom: bottom.into(),
+        left: left.into(),
+        right: right.into(),
+    }
+}
+
+/// Whether there is more content than `offset` pixels beyond each side of `el`.
+#[cfg(not(feature = "ssr"))]
+fn scroll_edges(el: &web_sys::Element, offset: f64) -> Directions {
+    let (row_reverse, column_reverse) = window()
+        .get_computed_style(el)
+        .ok()
+        .flatten()
+        .map(|style| {
+            let display = style.get_property_value("display").unwrap_or_default();
+            let flex_direction = style
+                .get_property_value("flex-direction")
+                .unwrap_or_default();
+
+            (
+                display == "flex" && flex_direction == "row-reverse",
+                display == "flex" && flex_direction == "column-reverse",
+            )
+        })
+        .unwrap_or_default();
+
+    // in reversed flex containers the scroll position is negative and starts at the other side
+    let scroll_left = (el.scroll_left() as f64).abs();
+    let start_x = scroll_left > offset + EDGE_THRESHOLD_PIXELS;
+    let end_x = scroll_left + (el.client_width() as f64)
+        < el.scroll_width() as f64 - offset - EDGE_THRESHOLD_PIXELS;
+
+    let scroll_top = (el.scroll_top() as f64).abs();
+    let start_y = scroll_top > offset + EDGE_THRESHOLD_PIXELS;
+    let end_y = scroll_top + (el.client_height() as f64)
+        < el.scroll_height() as f64 - offset - EDGE_THRESHOLD_PIXELS;
+
+    let (left, right) = if row_reverse {
+        (end_x, start_x)
+    } else {
+        (start_x, end_x)
+    };
+
+    let (top, bottom) = if column_reverse {
+        (end_y, start_y)
+    } else {
+        (start_y, end_y)
+    };
+
+    Directions {
+        left,
+        right,
+        top,
+        bottom,
+    }
+}
+
+/// Options for [`use_scroll_edges_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseScrollEdgesOptions {
+    /// Distance in pixels from an edge within which the content is considered to end there.
+    /// Defaults to `0.0`.
+    offset: f64,
+}
+
+/// Return type of [`use_scroll_edges`].
+#[derive(Clone, Copy)]
+pub struct UseScrollEdgesReturn {
+    /// Whether there is more content above the visible area.
+    pub top: Signal<bool>,
+
+    /// Whether there is more content below the visible area.
+    pub bottom: Signal<bool>,
+
+    /// Whether there is more content to the left of the visible area.
+    pub left: Signal<bool>,
+
+    /// Whether there is more content to the right of the visible area.
+    pub right: Signal<bool>,
+}
@@ -0,0 +1,322 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Keeps the scroll positions of two scroll containers in sync.
+///
+/// Scrolling one of the elements scrolls the other one. Useful for diff views or an editor with
+/// a preview side by side.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_scroll_sync)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::use_scroll_sync;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let editor = NodeRef::<Div>::new();
+/// let preview = NodeRef::<Div>::new();
+///
+/// use_scroll_sync(editor, preview);
+///
+/// view! {
+///     <div node_ref=editor class="overflow-auto">...</div>
+///     <div node_ref=preview class="overflow-auto">...</div>
+/// }
+/// # }
+/// ```
+///
+/// By default the elements are synced vertically and proportionally. That means when one element
+/// is scrolled half way down, the other one is scrolled half way down as well, even if their
+/// contents have different heights.
+///
+/// ### Options
+///
+/// With `mode` set to `ScrollSyncMode::Absolute` both elements are scrolled by the same amount of
+/// pixels instead. `axis` can be `Vertical`, `Horizontal` or `Both`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_scroll_sync_with_options, ScrollSyncAxis, ScrollSyncMode, UseScrollSyncOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let left = NodeRef::<Div>::new();
+/// # let right = NodeRef::<Div>::new();
+/// let (enabled, set_enabled) = signal(true);
+///
+/// use_scroll_sync_with_options(
+///     left,
+///     right,
+///     UseScrollSyncOptions::default()
+///         .mode(ScrollSyncMode::Absolute)
+///         .axis(ScrollSyncAxis::Both)
+///         .enabled(enabled),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// All options can be signals. While `enabled` is `false` the elements are scrolled
+/// independently. They aren't synced again until one of them is scrolled.
+///
+/// ### Loop Prevention
+///
+/// Scrolling an element fires a scroll event even if it's done by this function. The scroll
+/// positions are tracked by two [`fn@crate::watch_ignorable`] watchers. The position that is
+/// applied to the other element is set inside `ignore_updates` so the resulting scroll event
+/// doesn't sync it back. This also prevents the elements from jittering because of rounding when
+/// they are synced proportionally.
+///
+/// The returned function stops syncing.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this does nothing.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_scroll`]
+pub fn use_scroll_sync<El1, M1, El2, M2>(a: El1, b: El2) -> impl Fn() + Clone + Send + Sync
+where
+    El1: IntoElementMaybeSignal<web_sys::Element, M1>,
+    El2: IntoElementMaybeSignal<web_sys::Element, M2>,
+{
+    use_scroll_sync_with_options(a, b, UseScrollSyncOptions::default())
+}
+
+/// Version of [`use_scroll_sync`] that takes a `UseScrollSyncOptions`. See [`use_scroll_sync`] for how to use.
+pub fn use_scroll_sync_with_options<El1, M1, El2, M2>(
+    a: El1,
+    b: El2,
+    options: UseScrollSyncOptions,
+) -> impl Fn() + Clone + Send + Sync
+where
+    El1: IntoElementMaybeSignal<web_sys::Element, M1>,
+    El2: IntoElementMaybeSignal<web_sys::Element, M2>,
+{
+    let stop;
+
+    #[cfg(feature = "ssr")]
+    {
+        stop = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_event_listener, watch_ignorable};
+        use leptos::ev;
+        use std::sync::Arc;
+        use wasm_bindgen::JsCast;
+
+        let UseScrollSyncOptions {
+            mode,
+            axis,
+            enabled,
+        } = options;
+
+        let a = a.into_element_maybe_signal();
+        let b = b.into_element_maybe_signal();
+
+        let (position_a, set_position_a) = signal(ScrollPosition::default());
+        let (position_b, set_position_b) = signal(ScrollPosition::default());
+
+        let listen = move |element: Signal<Option<web_sys::Element>, LocalStorage>,
+                           position: ReadSignal<ScrollPosition>,
+                           set_position: WriteSignal<ScrollPosition>| {
+            let target = Signal::derive_local(move || {
+                element
+                    .get()
+                    .map(|element| element.unchecked_into::<web_sys::EventTarget>())
+            });
+
+            let _ = use_event_listener(target, ev::scroll, move |_| {
+                if let Some(element) = element.get_untracked() {
+                    let new_position = ScrollPosition::of(&element);
+
+                    if position.get_untracked() != new_position {
+                        set_position.set(new_position);
+                    }
+                }
+            });
+        };
+
+        listen(
+            Signal::derive_local(move || a.get()),
+            position_a,
+            set_position_a,
+        );
+        listen(
+            Signal::derive_local(move || b.get()),
+            position_b,
+            set_position_b,
+        );
+
+        // scrolls `to` like `from` and returns the resulting position of `to`
+        let sync = move |from: Option<web_sys::Element>, to: Option<web_sys::Element>| {
+            if !enabled.get_untracked() {
+                return None;
+            }
+
+            let (from, to) = (from?, to?);
+            let mode = mode.get_untracked();
+            let axis = axis.get_untracked();
+
+            if axis.is_horizontal() {
+                let left = mode.map(
+                    from.scroll_left() as f64,
+                    (from.scroll_width() - from.client_width()) as f64,
+                    (to.scroll_width() - to.client_width()) as f64,
+                );
+                to.set_scroll_left(left.round() as i32);
+            }
+
+            if axis.is_vertical() {
+                let top = mode.map(
+                    from.scroll_top() as f64,
+                    (from.scroll_height() - from.client_height()) as f64,
+                    (to.scroll_height() - to.client_height()) as f64,
+                );
+                to.set_scroll_top(top.round() as i32);
+            }
+
+            Some(ScrollPosition::of(&to))
+        };
+
+        let ignore_a = StoredValue::new(None::<Arc<dyn Fn(&dyn Fn()) + Send + Sync>>);
+
+        let watcher_b = watch_ignorable(
+            move || position_b.get(),
+            move |_, _, _| {
+                if let Some(position) = sync(b.get_untracked(), a.get_untracked()) {
+                    if let Some(ignore_a) = ignore_a.get_value() {
+                        ignore_a(&|| set_position_a.set(position));
+                    }
+                }
+            },
+        );
+
+        let watcher_a = {
+            let watcher_b = watcher_b.clone();
+
+            watch_ignorable(
+                move || position_a.get(),
+                move |_, _, _| {
+                    if let Some(position) = sync(a.get_untracked(), b.get_untracked()) {
+                        watcher_b.ignore_updates(|| set_position_b.set(position));
+                    }
+                },
+            )
+        };
+
+        let stop_a = watcher_a.stop.clone();
+        let stop_b = watcher_b.stop;
+
+        ignore_a.set_value(Some(Arc::new(move |updater: &dyn Fn()| {
+            watcher_a.ignore_updates(updater)
+        })));
+
+        stop = move || {
+            stop_a();
+            stop_b();
+        };
+    }
+
+    stop
+}
+
+/// Scroll position of an element in pixels.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+struct ScrollPosition {
+    left: f64,
+    top: f64,
+}
+
+impl ScrollPosition {
+    #[cfg(not(feature = "ssr"))]
+    fn of(element: &web_sys::Element) -> Self {
+        Self {
+            left: element.scroll_left() as f64,
+            top: element.scroll_top() as f64,
+        }
+    }
+}
+
+/// Options for [`use_scroll_sync_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseScrollSyncOptions {
+    /// How the scroll position of one element is applied to the other. Defaults to `Proportional`.
+    #[builder(into)]
+    mode: Signal<ScrollSyncMode>,
+
+    /// The direction(s) that are synced. Defaults to `Vertical`.
+    #[builder(into)]
+    axis: Signal<ScrollSyncAxis>,
+
+    /// Whether the elements are synced. Defaults to `true`.
+    #[builder(into)]
+    enabled: Signal<bool>,
+}
+
+impl Default for UseScrollSyncOptions {
+    fn default() -> Self {
+        Self {
+            mode: Signal::stored(ScrollSyncMode::default()),
+            axis: Signal::stored(ScrollSyncAxis::default()),
+            enabled: Signal::stored(true),
+        }
+    }
+}
+
+/// How the scroll position is synced in [`use_scroll_sync`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ScrollSyncMode {
+    /// Both elements are scrolled by the same fraction of their scrollable distance.
+    #[default]
+    Proportional,
+
+    /// Both elements are scrolled by the same amount of pixels.
+    Absolute,
+}
+
+impl ScrollSyncMode {
+    /// Maps a scroll offset of an element with the maximum scroll offset `from_max` to an element
+    /// with the maximum scroll offset `to_max`.
+    fn map(self, offset: f64, from_max: f64, to_max: f64) -> f64 {
+        match self {
+            Self::Proportional if from_max > 0.0 => offset / from_max * to_max.max(0.0),
+            Self::Proportional => 0.0,
+            Self::Absolute => offset,
+        }
+    }
+}
+
+/// The direction(s) that are synced in [`use_scroll_sync`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ScrollSyncAxis {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl ScrollSyncAxis {
+    fn is_horizontal(self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+}
@@ -0,0 +1,270 @@
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Headless selection logic for lists, tables and galleries.
+///
+/// Manages which of the given `items` are selected with the familiar click, <kbd>Ctrl</kbd>/<kbd>Cmd</kbd>-click
+/// and <kbd>Shift</kbd>-click semantics of desktop file managers.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_selection)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_selection, UseSelectionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (items, set_items) = signal((1..=10).collect::<Vec<u32>>());
+///
+/// let selection = use_selection(items);
+/// let UseSelectionReturn { selected, .. } = selection;
+///
+/// view! {
+///     <ul>
+///         <For each=move || items.get() key=|item| *item let:item>
+///             <li
+///                 class:selected=move || selection.is_selected(&item)
+///                 on:click=move |e| selection.select_with_event(item, &e)
+///             >
+///                 {item}
+///             </li>
+///         </For>
+///     </ul>
+///     <button on:click=move |_| selection.select_all()>"Select all"</button>
+///     <button on:click=move |_| selection.invert()>"Invert"</button>
+///     <p>{move || format!("{} selected", selected.get().len())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `selected` only contains keys that are still in `items` and is ordered like `items`.
+pub fn use_selection<K>(items: impl Into<Signal<Vec<K>>>) -> UseSelectionReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    use_selection_with_options(items, UseSelectionOptions::default())
+}
+
+/// Version of [`use_selection`] that takes a `UseSelectionOptions`. See [`use_selection`] for how to use.
+pub fn use_selection_with_options<K>(
+    items: impl Into<Signal<Vec<K>>>,
+    options: UseSelectionOptions,
+) -> UseSelectionReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    let UseSelectionOptions { multiple } = options;
+
+    let items = items.into();
+
+    let (selected_set, set_selected_set) = signal(HashSet::<K>::new());
+    let (anchor, set_anchor) = signal(None::<K>);
+
+    let selected = Signal::derive(move || {
+        selected_set.with(|selected_set| {
+            items.with(|items| {
+                items
+                    .iter()
+                    .filter(|item| selected_set.contains(item))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+
+    UseSelectionReturn {
+        selected,
+        anchor: anchor.into(),
+        items,
+        multiple,
+        selected_set,
+        set_selected_set,
+        set_anchor,
+    }
+}
+
+/// How [`UseSelectionReturn::select`] changes the selection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SelectMode {
+    /// Select only the given item (plain click).
+    #[default]
+    Replace,
+
+    /// Toggle the given item and keep the rest of the selection (<kbd>Ctrl</kbd>/<kbd>Cmd</kbd>-click).
+    Toggle,
+
+    /// Select the range from the anchor to the given item (<kbd>Shift</kbd>-click).
+    Range,
+
+    /// Add the range from the anchor to the given item to the selection
+    /// (<kbd>Ctrl</kbd>/<kbd>Cmd</kbd>+<kbd>Shift</kbd>-click).
+    AddRange,
+}
+
+impl SelectMode {
+    /// Determines the mode from the modifier keys of a mouse event.
+    pub fn from_mouse_event(event: &web_sys::MouseEvent) -> Self {
+        match (event.ctrl_key() || event.meta_key(), event.shift_key()) {
+            (false, false) => Self::Replace,
+            (true, false) => Self::Toggle,
+            (false, true) => Self::Range,
+            (true, true) => Self::AddRange,
+        }
+    }
+}
+
+/// Options for [`use_selection_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSelectionOptions {
+    /// If `false` at most one item can be selected and every mode behaves like
+    /// `SelectMode::Replace`. Defaults to `true`.
+    multiple: bool,
+}
+
+impl Default for UseSelectionOptions {
+    fn default() -> Self {
+        Self { multiple: true }
+    }
+}
+
+/// Return type of [`use_selection`].
+pub struct UseSelectionReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// The selected items in the order of `items`.
+    pub selected: Signal<Vec<K>>,
+
+    /// The item that range selections start from. This is the item that was last clicked
+    /// without <kbd>Shift</kbd>.
+    pub anchor: Signal<Option<K>>,
+
+    items: Signal<Vec<K>>,
+    multiple: bool,
+    selected_set: ReadSignal<HashSet<K>>,
+    set_selected_set: WriteSignal<HashSet<K>>,
+    set_anchor: WriteSignal<Option<K>>,
+}
+
+impl<K> Clone for UseSelectionReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for UseSelectionReturn<K> where K: Eq + Hash + Clone + Send + Sync + 'static {}
+
+impl<K> UseSelectionReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Returns whether `key` is selected. Reactive if called inside a reactive context.
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected_set.with(|selected| selected.contains(key))
+    }
+
+    /// Changes the selection for a click on `key` according to `mode`.
+    pub fn select(&self, key: K, mode: SelectMode) {
+        let mode = if self.multiple {
+            mode
+        } else {
+            SelectMode::Replace
+        };
+
+        match mode {
+            SelectMode::Replace => {
+                self.set_selected_set.set(HashSet::from([key.clone()]));
+                self.set_anchor.set(Some(key));
+            }
+            SelectMode::Toggle => {
+                self.set_selected_set.update(|selected| {
+                    if !selected.remove(&key) {
+                        selected.insert(key.clone());
+                    }
+                });
+                self.set_anchor.set(Some(key));
+            }
+            SelectMode::Range | SelectMode::AddRange => {
+                let range = self.range_to(&key);
+
+                self.set_selected_set.update(|selected| {
+                    if mode == SelectMode::Range {
+                        selected.clear();
+                    }
+                    selected.extend(range);
+                });
+
+                if self.anchor.get_untracked().is_none() {
+                    self.set_anchor.set(Some(key));
+                }
+            }
+        }
+    }
+
+    /// Changes the selection for a click on `key` taking the modifier keys of `event` into
+    /// account. See [`SelectMode::from_mouse_event`].
+    pub fn select_with_event(&self, key: K, event: &web_sys::MouseEvent) {
+        self.select(key, SelectMode::from_mouse_event(event));
+    }
+
+    /// Toggles whether `key` is selected.
+    pub fn toggle(&self, key: K) {
+        self.select(key, SelectMode::Toggle);
+    }
+
+    /// Selects all items. Does nothing if `multiple` is `false`.
+    pub fn select_all(&self) {
+        if self.multiple {
+            self.set_selected_set
+                .set(self.items.get_untracked().into_iter().collect());
+        }
+    }
+
+    /// Selects exactly the items that are not selected. Does nothing if `multiple` is `false`.
+    pub fn invert(&self) {
+        if self.multiple {
+            let selected = self.selected_set.get_untracked();
+
+            self.set_selected_set.set(
+                self.items
+                    .get_untracked()
+                    .into_iter()
+                    .filter(|item| !selected.contains(item))
+                    .collect(),
+            );
+        }
+    }
+
+    /// Deselects all items.
+    pub fn clear(&self) {
+        self.set_selected_set.set(HashSet::new());
+        self.set_anchor.set(None);
+    }
+
+    /// Returns the items from the anchor to `key` (inclusive). If there is no anchor or it is
+    /// not in `items` anymore, only `key` is returned.
+    fn range_to(&self, key: &K) -> Vec<K> {
+        let anchor = self.anchor.get_untracked();
+
+        self.items.with_untracked(|items| {
+            let end = items.iter().position(|item| item == key);
+            let start = anchor
+                .and_then(|anchor| items.iter().position(|item| *item == anchor))
+                .or(end);
+
+            match (start, end) {
+                (Some(start), Some(end)) => items[start.min(end)..=start.max(end)].to_vec(),
+                _ => vec![key.clone()],
+            }
+        })
+    }
+}
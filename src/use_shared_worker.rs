@@ -0,0 +1,299 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{sendwrap_fn, use_event_listener};
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::fmt::Display;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Shared Worker](https://developer.mozilla.org/en-US/docs/Web/API/SharedWorker).
+///
+/// Connects to the `SharedWorker` running the script at `script_url`. All tabs and iframes of
+/// the same origin that connect to the same script (and name) share one worker instance. This is
+/// useful to keep a single connection like a `WebSocket` open for all tabs instead of one per tab.
+///
+/// The port to the worker is closed automatically when the component is cleaned up. The worker
+/// itself keeps running as long as other tabs are connected.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_shared_worker)
+///
+/// ## Usage
+///
+/// Values are (en)decoded via the given codec. You can use any of the string codecs or a
+/// binary codec wrapped in `Base64`.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_shared_worker, UseSharedWorkerReturn};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseSharedWorkerReturn {
+///     data,
+///     post,
+///     status,
+///     ..
+/// } = use_shared_worker::<String, String, FromToStringCodec>("/shared-worker.js");
+///
+/// post(&"Hello from this tab".to_string());
+///
+/// view! {
+///     <p>"Status: " {move || status.get().to_string()}</p>
+///     <p>"Received: " {move || data.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// The worker script receives a `connect` event for every tab that connects and talks to it
+/// through the port of that event.
+///
+/// ```js
+/// const ports = [];
+///
+/// self.onconnect = (event) => {
+///   const port = event.ports[0];
+///   ports.push(port);
+///
+///   // send every message to all connected tabs
+///   port.onmessage = (event) => ports.forEach((p) => p.postMessage(event.data));
+/// };
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `post` and `close` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_shared_worker`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is connected. `data`, `error` and `worker` are always `None`,
+/// `status` is always `SharedWorkerStatus::Closed` and `post` and `close` do nothing.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_web_worker`]
+/// * [`fn@crate::use_broadcast_channel`]
+pub fn use_shared_worker<Tx, Rx, C>(
+    script_url: &str,
+) -> UseSharedWorkerReturn<
+    Rx,
+    C,
+    Tx,
+    impl Fn(&Tx) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    Rx: Send + Sync + 'static,
+    C: Encoder<Tx, Encoded = String> + Decoder<Rx, Encoded = str>,
+    <C as Encoder<Tx>>::Error: Send + Sync + 'static,
+    <C as Decoder<Rx>>::Error: Send + Sync + 'static,
+{
+    use_shared_worker_with_options::<Tx, Rx, C>(script_url, UseSharedWorkerOptions::default())
+}
+
+/// Version of [`use_shared_worker`] that takes a `UseSharedWorkerOptions`. See [`use_shared_worker`] for how to use.
+pub fn use_shared_worker_with_options<Tx, Rx, C>(
+    script_url: &str,
+    options: UseSharedWorkerOptions,
+) -> UseSharedWorkerReturn<
+    Rx,
+    C,
+    Tx,
+    impl Fn(&Tx) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    Rx: Send + Sync + 'static,
+    C: Encoder<Tx, Encoded = String> + Decoder<Rx, Encoded = str>,
+    <C as Encoder<Tx>>::Error: Send + Sync + 'static,
+    <C as Decoder<Rx>>::Error: Send + Sync + 'static,
+{
+    let UseSharedWorkerOptions { module, name } = options;
+
+    let (worker, set_worker) = signal_local(None::<web_sys::SharedWorker>);
+    let (data, set_data) = signal(None::<Rx>);
+    let (status, set_status) = signal(SharedWorkerStatus::Closed);
+    let (error, set_error) = signal_local(
+        None::<UseSharedWorkerError<<C as Encoder<Tx>>::Error, <C as Decoder<Rx>>::Error>>,
+    );
+
+    let post = sendwrap_fn!(move |value: &Tx| {
+        if let Some(worker) = worker.get_untracked() {
+            match C::encode(value) {
+                Ok(msg) => {
+                    worker
+                        .port()
+                        .post_message(&msg.into())
+                        .map_err(|err| set_error.set(Some(UseSharedWorkerError::PostMessage(err))))
+                        .ok();
+                }
+                Err(err) => {
+                    set_error.set(Some(UseSharedWorkerError::Codec(CodecError::Encode(err))));
+                }
+            }
+        }
+    });
+
+    let close = sendwrap_fn!(move || {
+        if let Some(worker) = worker.get_untracked() {
+            worker.port().close();
+            set_worker.set(None);
+            set_status.set(SharedWorkerStatus::Closed);
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let worker_options = web_sys::WorkerOptions::new();
+        if module {
+            worker_options.set_type(web_sys::WorkerType::Module);
+        }
+        if let Some(name) = name {
+            worker_options.set_name(&name);
+        }
+
+        match web_sys::SharedWorker::new_with_worker_options(script_url, &worker_options) {
+            Ok(new_worker) => {
+                let port = new_worker.port();
+
+                let _ = use_event_listener(port.clone(), leptos::ev::message, move |event| {
+                    if let Some(msg) = event.data().as_string() {
+                        match C::decode(&msg) {
+                            Ok(msg) => set_data.set(Some(msg)),
+                            Err(err) => set_error
+                                .set(Some(UseSharedWorkerError::Codec(CodecError::Decode(err)))),
+                        }
+                    } else {
+                        set_error.set(Some(UseSharedWorkerError::ValueNotString));
+                    }
+                });
+
+                let _ = use_event_listener(port.clone(), leptos::ev::messageerror, move |event| {
+                    set_error.set(Some(UseSharedWorkerError::MessageEvent(event)));
+                });
+
+                let _ = use_event_listener(new_worker.clone(), leptos::ev::error, move |event| {
+                    set_status.set(SharedWorkerStatus::Error);
+                    set_error.set(Some(UseSharedWorkerError::Event(event.into())));
+                });
+
+                // listening via `addEventListener` doesn't start the port implicitly
+                port.start();
+
+                set_worker.set(Some(new_worker));
+                set_status.set(SharedWorkerStatus::Connected);
+            }
+            Err(err) => {
+                set_status.set(SharedWorkerStatus::Error);
+                set_error.set(Some(UseSharedWorkerError::Create(err)));
+            }
+        }
+    }
+
+    on_cleanup({
+        let close = close.clone();
+
+        move || close()
+    });
+
+    UseSharedWorkerReturn {
+        worker: worker.into(),
+        data: data.into(),
+        status: status.into(),
+        error: error.into(),
+        post,
+        close,
+    }
+}
+
+/// Options for [`use_shared_worker_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseSharedWorkerOptions {
+    /// If `true` the script is loaded as an ES module instead of a classic script.
+    /// Defaults to `false`.
+    module: bool,
+
+    /// Name of the worker. Connecting to the same script with a different name starts a
+    /// separate worker instance. Defaults to `None`.
+    #[builder(into)]
+    name: Option<String>,
+}
+
+/// Return type of [`use_shared_worker`].
+pub struct UseSharedWorkerReturn<Rx, C, Tx, PostFn, CloseFn>
+where
+    Rx: Send + Sync + 'static,
+    C: Encoder<Tx> + Decoder<Rx>,
+    <C as Encoder<Tx>>::Error: 'static,
+    <C as Decoder<Rx>>::Error: 'static,
+    PostFn: Fn(&Tx) + Clone + Send + Sync,
+    CloseFn: Fn() + Clone + Send + Sync,
+{
+    /// The `SharedWorker` that is wrapped by this function. `None` after the port has been closed.
+    pub worker: Signal<Option<web_sys::SharedWorker>, LocalStorage>,
+
+    /// Latest message received from the worker.
+    pub data: Signal<Option<Rx>>,
+
+    /// Current status of the connection to the worker.
+    pub status: Signal<SharedWorkerStatus>,
+
+    /// Latest error.
+    pub error: Signal<Option<ErrorType<Tx, Rx, C>>, LocalStorage>,
+
+    /// Sends a message to the worker.
+    pub post: PostFn,
+
+    /// Closes the port to the worker. The worker keeps running for other connected tabs.
+    pub close: CloseFn,
+}
+
+type ErrorType<Tx, Rx, C> =
+    UseSharedWorkerError<<C as Encoder<Tx>>::Error, <C as Decoder<Rx>>::Error>;
+
+/// Status of the connection managed by [`fn@crate::use_shared_worker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SharedWorkerStatus {
+    /// The port is open and messages can be exchanged with the worker.
+    Connected,
+
+    /// The worker could not be created or reported an error.
+    Error,
+
+    /// The port has been closed (or was never opened).
+    Closed,
+}
+
+impl Display for SharedWorkerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connected => write!(f, "connected"),
+            Self::Error => write!(f, "error"),
+            Self::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UseSharedWorkerError<E, D> {
+    #[error("failed to create shared worker")]
+    Create(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("shared worker error event")]
+    Event(web_sys::Event),
+    #[error("shared worker message error")]
+    MessageEvent(web_sys::MessageEvent),
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("received value is not a string")]
+    ValueNotString,
+}
@@ -0,0 +1,382 @@
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::f64::consts::PI;
+
+/// Latitudes beyond this can't be displayed in the Web Mercator projection.
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Reactive math for [slippy maps](https://wiki.openstreetmap.org/wiki/Slippy_map), the kind of
+/// map that is made of square image tiles, like OpenStreetMap.
+///
+/// Given the center, zoom and size of the viewport, this tells you which tiles are visible and
+/// where to put them and converts between geographic coordinates and pixels in the viewport.
+/// This lets you build lightweight map components without a JavaScript mapping library.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_slippy_map)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_slippy_map, LatLng, MapViewport, UseSlippyMapReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (viewport, set_viewport) = signal(MapViewport {
+///     center: LatLng::new(47.3769, 8.5417),
+///     zoom: 13.0,
+///     width: 600.0,
+///     height: 400.0,
+/// });
+///
+/// let UseSlippyMapReturn {
+///     tiles,
+///     lat_lng_to_pixel,
+///     ..
+/// } = use_slippy_map(viewport);
+///
+/// let marker = Memo::new(move |_| lat_lng_to_pixel(LatLng::new(47.3667, 8.55)));
+///
+/// view! {
+///     <div style="position: relative; overflow: hidden; width: 600px; height: 400px">
+///         {move || {
+///             tiles
+///                 .get()
+///                 .into_iter()
+///                 .map(|tile| {
+///                     view! {
+///                         <img
+///                             src=tile.url("https://tile.openstreetmap.org/{z}/{x}/{y}.png")
+///                             style:position="absolute"
+///                             style:left=format!("{}px", tile.left)
+///                             style:top=format!("{}px", tile.top)
+///                             style:width=format!("{}px", tile.size)
+///                         />
+///                     }
+///                 })
+///                 .collect_view()
+///         }}
+///         <div
+///             class="marker"
+///             style:position="absolute"
+///             style:left=move || format!("{}px", marker.get().0)
+///             style:top=move || format!("{}px", marker.get().1)
+///         />
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// All pixel coordinates are relative to the top left corner of the viewport. The map uses the
+/// [Web Mercator projection](https://en.wikipedia.org/wiki/Web_Mercator_projection) like virtually
+/// all tile servers.
+///
+/// The zoom can be fractional. Tiles are loaded for the nearest whole zoom level and scaled, so
+/// `tile.size` can be different from the option `tile_size`.
+///
+/// ### Panning and Zooming
+///
+/// `pixel_to_lat_lng` converts a position in the viewport back to geographic coordinates. Use it
+/// to move the map, e.g. together with [`fn@crate::use_draggable`], or to find out where the user
+/// clicked.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_slippy_map, LatLng, MapViewport, UseSlippyMapReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (viewport, set_viewport) = signal(MapViewport::default());
+/// let UseSlippyMapReturn {
+///     pixel_to_lat_lng, ..
+/// } = use_slippy_map(viewport);
+///
+/// // pan by 100px to the right
+/// let center = viewport.with_untracked(|viewport| {
+///     pixel_to_lat_lng((viewport.width / 2.0 + 100.0, viewport.height / 2.0))
+/// });
+/// set_viewport.update(|viewport| viewport.center = center);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// This is pure math and works the same on the server.
+#[allow(clippy::type_complexity)]
+pub fn use_slippy_map(
+    viewport: impl Into<Signal<MapViewport>>,
+) -> UseSlippyMapReturn<
+    impl Fn(LatLng) -> (f64, f64) + Clone + Send + Sync,
+    impl Fn((f64, f64)) -> LatLng + Clone + Send + Sync,
+> {
+    use_slippy_map_with_options(viewport, UseSlippyMapOptions::default())
+}
+
+/// Version of [`use_slippy_map`] that takes a `UseSlippyMapOptions`. See [`use_slippy_map`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_slippy_map_with_options(
+    viewport: impl Into<Signal<MapViewport>>,
+    options: UseSlippyMapOptions,
+) -> UseSlippyMapReturn<
+    impl Fn(LatLng) -> (f64, f64) + Clone + Send + Sync,
+    impl Fn((f64, f64)) -> LatLng + Clone + Send + Sync,
+> {
+    let UseSlippyMapOptions {
+        tile_size,
+        min_zoom,
+        max_zoom,
+    } = options;
+
+    let viewport = viewport.into();
+
+    // position of the top left corner of the viewport in world pixels at the current zoom
+    let origin = Memo::new(move |_| {
+        viewport.with(|viewport| {
+            let (x, y) = viewport.center.to_world_pixel(viewport.zoom, tile_size);
+            (x - viewport.width / 2.0, y - viewport.height / 2.0)
+        })
+    });
+
+    let tiles = Signal::derive(move || {
+        let (origin_x, origin_y) = origin.get();
+
+        viewport.with(|viewport| {
+            let z = viewport
+                .zoom
+                .round()
+                .clamp(min_zoom as f64, max_zoom as f64) as u8;
+            let size = tile_size * 2_f64.powf(viewport.zoom - z as f64);
+            let count = 2_i64.pow(z as u32);
+
+            let first_x = (origin_x / size).floor() as i64;
+            let last_x = ((origin_x + viewport.width) / size).ceil() as i64;
+            let first_y = ((origin_y / size).floor() as i64).max(0);
+            let last_y = (((origin_y + viewport.height) / size).ceil() as i64).min(count);
+
+            let mut tiles = vec![];
+
+            for y in first_y..last_y {
+                for x in first_x..last_x {
+                    tiles.push(MapTile {
+                        // the world repeats horizontally
+                        x: x.rem_euclid(count) as u32,
+                        y: y as u32,
+                        z,
+                        left: x as f64 * size - origin_x,
+                        top: y as f64 * size - origin_y,
+                        size,
+                    });
+                }
+            }
+
+            tiles
+        })
+    });
+
+    let bounds = Signal::derive(move || {
+        let (origin_x, origin_y) = origin.get();
+
+        viewport.with(|viewport| {
+            let north_west = LatLng::from_world_pixel(origin_x, origin_y, viewport.zoom, tile_size);
+            let south_east = LatLng::from_world_pixel(
+                origin_x + viewport.width,
+                origin_y + viewport.height,
+                viewport.zoom,
+                tile_size,
+            );
+
+            LatLngBounds {
+                north: north_west.lat,
+                south: south_east.lat,
+                west: north_west.lng,
+                east: south_east.lng,
+            }
+        })
+    });
+
+    let lat_lng_to_pixel = move |lat_lng: LatLng| {
+        let (origin_x, origin_y) = origin.get();
+        let (x, y) = lat_lng.to_world_pixel(viewport.with(|viewport| viewport.zoom), tile_size);
+
+        (x - origin_x, y - origin_y)
+    };
+
+    let pixel_to_lat_lng = move |(x, y): (f64, f64)| {
+        let (origin_x, origin_y) = origin.get();
+
+        LatLng::from_world_pixel(
+            origin_x + x,
+            origin_y + y,
+            viewport.with(|viewport| viewport.zoom),
+            tile_size,
+        )
+    };
+
+    UseSlippyMapReturn {
+        tiles,
+        bounds,
+        lat_lng_to_pixel,
+        pixel_to_lat_lng,
+    }
+}
+
+/// Options for [`use_slippy_map_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSlippyMapOptions {
+    /// Size of the tiles in pixels as served by the tile server. Defaults to `256.0`.
+    tile_size: f64,
+
+    /// Lowest zoom level the tile server provides. Defaults to `0`.
+    min_zoom: u8,
+
+    /// Highest zoom level the tile server provides. When zoomed in further the tiles of this
+    /// level are scaled up. Defaults to `19`.
+    max_zoom: u8,
+}
+
+impl Default for UseSlippyMapOptions {
+    fn default() -> Self {
+        Self {
+            tile_size: 256.0,
+            min_zoom: 0,
+            max_zoom: 19,
+        }
+    }
+}
+
+/// Return type of [`use_slippy_map`].
+pub struct UseSlippyMapReturn<ToPixelFn, ToLatLngFn>
+where
+    ToPixelFn: Fn(LatLng) -> (f64, f64) + Clone + Send + Sync,
+    ToLatLngFn: Fn((f64, f64)) -> LatLng + Clone + Send + Sync,
+{
+    /// The tiles that are (partially) visible in the viewport.
+    pub tiles: Signal<Vec<MapTile>>,
+
+    /// The geographic area that is visible in the viewport.
+    pub bounds: Signal<LatLngBounds>,
+
+    /// Converts geographic coordinates to the `(x, y)` position in the viewport in pixels.
+    /// Reactive when called in a reactive context.
+    pub lat_lng_to_pixel: ToPixelFn,
+
+    /// Converts an `(x, y)` position in the viewport in pixels to geographic coordinates.
+    /// Reactive when called in a reactive context.
+    pub pixel_to_lat_lng: ToLatLngFn,
+}
+
+/// What part of the map is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MapViewport {
+    /// Geographic coordinates of the center of the viewport.
+    pub center: LatLng,
+
+    /// Zoom level. At zoom `0` the whole world fits into a single tile. Every level doubles the
+    /// scale. Can be fractional.
+    pub zoom: f64,
+
+    /// Width of the viewport in pixels.
+    pub width: f64,
+
+    /// Height of the viewport in pixels.
+    pub height: f64,
+}
+
+/// Geographic coordinates in degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LatLng {
+    /// Latitude in degrees.
+    pub lat: f64,
+
+    /// Longitude in degrees.
+    pub lng: f64,
+}
+
+impl LatLng {
+    pub fn new(lat: f64, lng: f64) -> Self {
+        Self { lat, lng }
+    }
+
+    /// Position in the whole map at the given zoom level in pixels, with `(0, 0)` being the top
+    /// left corner of the world.
+    pub fn to_world_pixel(&self, zoom: f64, tile_size: f64) -> (f64, f64) {
+        let world_size = tile_size * 2_f64.powf(zoom);
+        let lat = self.lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+
+        let x = (self.lng + 180.0) / 360.0;
+        let y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / PI) / 2.0;
+
+        (x * world_size, y * world_size)
+    }
+
+    /// Inverse of [`LatLng::to_world_pixel`].
+    pub fn from_world_pixel(x: f64, y: f64, zoom: f64, tile_size: f64) -> Self {
+        let world_size = tile_size * 2_f64.powf(zoom);
+
+        let lng = x / world_size * 360.0 - 180.0;
+        let lat = (PI * (1.0 - 2.0 * y / world_size))
+            .sinh()
+            .atan()
+            .to_degrees();
+
+        Self { lat, lng }
+    }
+
+    /// Coordinates `(x, y)` of the tile that contains this position at the given zoom level.
+    pub fn to_tile(&self, zoom: u8) -> (u32, u32) {
+        let count = 2_u32.pow(zoom as u32);
+        let (x, y) = self.to_world_pixel(zoom as f64, 1.0);
+
+        (
+            (x.floor() as i64).rem_euclid(count as i64) as u32,
+            (y.floor().max(0.0) as u32).min(count - 1),
+        )
+    }
+}
+
+/// Geographic area in degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LatLngBounds {
+    pub north: f64,
+    pub south: f64,
+    pub east: f64,
+    pub west: f64,
+}
+
+/// A tile of a slippy map with its position in the viewport.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MapTile {
+    /// Column of the tile.
+    pub x: u32,
+
+    /// Row of the tile.
+    pub y: u32,
+
+    /// Zoom level of the tile.
+    pub z: u8,
+
+    /// Distance of the left edge of the tile from the left edge of the viewport in pixels.
+    pub left: f64,
+
+    /// Distance of the top edge of the tile from the top edge of the viewport in pixels.
+    pub top: f64,
+
+    /// Width and height of the tile in the viewport in pixels.
+    pub size: f64,
+}
+
+impl MapTile {
+    /// Fills in `{x}`, `{y}` and `{z}` of a tile URL template like
+    /// `"https://tile.openstreetmap.org/{z}/{x}/{y}.png"`.
+    pub fn url(&self, template: &str) -> String {
+        template
+            .replace("{x}", &self.x.to_string())
+            .replace("{y}", &self.y.to_string())
+            .replace("{z}", &self.z.to_string())
+    }
+}
@@ -0,0 +1,194 @@
+use crate::core::Position;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Snap a reactive position to a grid or to guide lines like in a design editor.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_snap)
+///
+/// ## Usage
+///
+/// Every axis is snapped to the nearest multiple of `grid`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_snap, UseSnapReturn};
+/// # use leptos_use::core::Position;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (position, set_position) = signal(Position { x: 23.0, y: 48.0 });
+///
+/// let UseSnapReturn { x, y, .. } = use_snap(position, 10.0);
+/// #
+/// # assert_eq!(x.get(), 20.0);
+/// # assert_eq!(y.get(), 50.0);
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Guides
+///
+/// You can provide guide lines that take precedence over the grid. An axis snaps to a guide if
+/// it is closer to it than `threshold`. The guides that are currently snapped to are returned
+/// as `active_guides`, e.g. to highlight them. A `grid` of `0.0` disables grid snapping.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_snap_with_options, Guide, UseSnapOptions, UseSnapReturn};
+/// # use leptos_use::core::Position;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (position, set_position) = signal(Position { x: 97.0, y: 48.0 });
+/// let (guides, set_guides) = signal(vec![Guide::Vertical(100.0), Guide::Horizontal(200.0)]);
+///
+/// let UseSnapReturn { x, y, active_guides, .. } = use_snap_with_options(
+///     position,
+///     0.0,
+///     UseSnapOptions::default().guides(guides).threshold(5.0),
+/// );
+/// #
+/// # assert_eq!(x.get(), 100.0);
+/// # assert_eq!(y.get(), 48.0);
+/// # assert_eq!(active_guides.get(), vec![Guide::Vertical(100.0)]);
+/// # view! { }
+/// # }
+/// ```
+///
+/// To use it with [`fn@crate::use_draggable`] render the element at the snapped position
+/// instead of the dragged one.
+pub fn use_snap(value: impl Into<Signal<Position>>, grid: impl Into<Signal<f64>>) -> UseSnapReturn {
+    use_snap_with_options(value, grid, UseSnapOptions::default())
+}
+
+/// Version of [`use_snap`] that takes a `UseSnapOptions`. See [`use_snap`] for how to use.
+pub fn use_snap_with_options(
+    value: impl Into<Signal<Position>>,
+    grid: impl Into<Signal<f64>>,
+    options: UseSnapOptions,
+) -> UseSnapReturn {
+    let UseSnapOptions { guides, threshold } = options;
+
+    let value = value.into();
+    let grid = grid.into();
+
+    let snapped = Memo::new(move |_| {
+        let Position { x, y } = value.get();
+        let grid = grid.get();
+
+        guides.with(|guides| {
+            let (x, x_guide) = snap_axis(
+                x,
+                grid,
+                guides.iter().filter_map(|guide| match guide {
+                    Guide::Vertical(x) => Some(*x),
+                    Guide::Horizontal(_) => None,
+                }),
+                threshold,
+            );
+
+            let (y, y_guide) = snap_axis(
+                y,
+                grid,
+                guides.iter().filter_map(|guide| match guide {
+                    Guide::Horizontal(y) => Some(*y),
+                    Guide::Vertical(_) => None,
+                }),
+                threshold,
+            );
+
+            let active_guides = x_guide
+                .map(Guide::Vertical)
+                .into_iter()
+                .chain(y_guide.map(Guide::Horizontal))
+                .collect::<Vec<_>>();
+
+            (x, y, active_guides)
+        })
+    });
+
+    let x = Signal::derive(move || snapped.with(|(x, _, _)| *x));
+    let y = Signal::derive(move || snapped.with(|(_, y, _)| *y));
+
+    UseSnapReturn {
+        x,
+        y,
+        position: Signal::derive(move || Position {
+            x: x.get(),
+            y: y.get(),
+        }),
+        active_guides: Signal::derive(move || snapped.with(|(_, _, guides)| guides.clone())),
+    }
+}
+
+/// Snaps `value` to the closest guide within `threshold` or else to the grid. Returns the
+/// snapped value and the guide that was snapped to (if any).
+fn snap_axis(
+    value: f64,
+    grid: f64,
+    guides: impl Iterator<Item = f64>,
+    threshold: f64,
+) -> (f64, Option<f64>) {
+    let closest_guide = guides
+        .map(|guide| (guide, (guide - value).abs()))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    if let Some((guide, _)) = closest_guide {
+        return (guide, Some(guide));
+    }
+
+    if grid > 0.0 {
+        ((value / grid).round() * grid, None)
+    } else {
+        (value, None)
+    }
+}
+
+/// A guide line that [`fn@crate::use_snap`] can snap to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Guide {
+    /// Vertical line at the given x coordinate. Snaps the x axis.
+    Vertical(f64),
+
+    /// Horizontal line at the given y coordinate. Snaps the y axis.
+    Horizontal(f64),
+}
+
+/// Options for [`use_snap_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSnapOptions {
+    /// Guide lines that take precedence over the grid. Defaults to no guides.
+    #[builder(into)]
+    guides: Signal<Vec<Guide>>,
+
+    /// Maximum distance at which a value snaps to a guide. Defaults to `8.0`.
+    threshold: f64,
+}
+
+impl Default for UseSnapOptions {
+    fn default() -> Self {
+        Self {
+            guides: Default::default(),
+            threshold: 8.0,
+        }
+    }
+}
+
+/// Return type of [`use_snap`].
+pub struct UseSnapReturn {
+    /// The snapped x coordinate.
+    pub x: Signal<f64>,
+
+    /// The snapped y coordinate.
+    pub y: Signal<f64>,
+
+    /// The snapped position.
+    pub position: Signal<Position>,
+
+    /// The guides that are currently snapped to.
+    pub active_guides: Signal<Vec<Guide>>,
+}
@@ -74,50 +74,202 @@ use std::ops::DerefMut;
 /// ```
 ///
 /// Please note that these two ways of sorting are equivalent.
+///
+/// ### Reactive Comparison
+///
+/// Signals that are read in the compare or key function are tracked. The list is sorted again
+/// when they change.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_sorted_by;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (numbers, set_numbers) = signal(vec![3, 1, 2]);
+/// let (descending, set_descending) = signal(false);
+///
+/// let sorted: Signal<Vec<i32>> = use_sorted_by(numbers, move |a: &i32, b: &i32| {
+///     if descending.get() {
+///         b.cmp(a)
+///     } else {
+///         a.cmp(b)
+///     }
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Performance
+///
+/// If only one element of the source has changed since the last sort, the previously sorted list
+/// is reused and only that element is moved to its new place like in an insertion sort. Otherwise
+/// the whole list is sorted again. For this the position in the source of every element of the
+/// sorted list is kept in memory.
+///
+/// Sorting is stable. Elements that are equal according to the compare function keep the order
+/// they have in the source.
 pub fn use_sorted<S, I, T>(iterable: S) -> Signal<I>
 where
     S: Into<Signal<I>>,
     T: Ord,
     I: DerefMut<Target = [T]> + Clone + PartialEq + Send + Sync + 'static,
 {
-    let iterable = iterable.into();
-
-    Signal::derive(move || {
-        let mut iterable = iterable.get();
-        iterable.sort();
-        iterable
-    })
+    use_sorted_by(iterable, |a: &T, b: &T| a.cmp(b))
 }
 
 /// Version of [`use_sorted`] with a compare function.
 pub fn use_sorted_by<S, I, T, F>(iterable: S, cmp_fn: F) -> Signal<I>
 where
     S: Into<Signal<I>>,
+    T: PartialEq,
     I: DerefMut<Target = [T]> + Clone + PartialEq + Send + Sync + 'static,
     F: FnMut(&T, &T) -> Ordering + Clone + Send + Sync + 'static,
 {
     let iterable = iterable.into();
 
-    Signal::derive(move || {
-        let mut iterable = iterable.get();
-        iterable.sort_by(cmp_fn.clone());
-        iterable
+    // the position in the source of every element of the sorted list
+    let order = StoredValue::new(Vec::<usize>::new());
+
+    Memo::new(move |prev_sorted: Option<&I>| {
+        let mut sorted = iterable.get();
+        let mut cmp_fn = cmp_fn.clone();
+
+        order.update_value(|order| {
+            match prev_sorted.and_then(|prev_sorted| changed_position(prev_sorted, &sorted, order))
+            {
+                Some(changed) => {
+                    permute(&mut sorted, order);
+
+                    if let Some(position) = changed {
+                        move_into_place(&mut sorted, order, position, &mut cmp_fn);
+                    }
+                }
+                None => {
+                    order.clear();
+                    order.extend(0..sorted.len());
+                }
+            }
+
+            // the compare function might have changed as well
+            if !is_sorted(&sorted, order, &mut cmp_fn) {
+                sort(&mut sorted, order, &mut cmp_fn);
+            }
+        });
+
+        sorted
     })
+    .into()
 }
 
 /// Version of [`use_sorted`] by key.
 pub fn use_sorted_by_key<S, I, T, K, F>(iterable: S, key_fn: F) -> Signal<I>
 where
     S: Into<Signal<I>>,
+    T: PartialEq,
     I: DerefMut<Target = [T]> + Clone + PartialEq + Send + Sync + 'static,
     K: Ord,
     F: FnMut(&T) -> K + Clone + Send + Sync + 'static,
 {
-    let iterable = iterable.into();
+    let mut key_fn = key_fn;
 
-    Signal::derive(move || {
-        let mut iterable = iterable.get();
-        iterable.sort_by_key(key_fn.clone());
-        iterable
-    })
+    use_sorted_by(iterable, move |a: &T, b: &T| key_fn(a).cmp(&key_fn(b)))
+}
+
+/// Compares the elements at the positions `a` and `b` of `sorted`. Ties are broken by their
+/// position in the source to keep the sort stable.
+fn compare<T>(
+    cmp_fn: &mut impl FnMut(&T, &T) -> Ordering,
+    sorted: &[T],
+    order: &[usize],
+    a: usize,
+    b: usize,
+) -> Ordering {
+    cmp_fn(&sorted[a], &sorted[b]).then(order[a].cmp(&order[b]))
+}
+
+/// Returns the position in `prev_sorted` of the only element that differs in `source`, `Some(None)`
+/// if no element differs and `None` if more than one element differs or the lengths don't match.
+fn changed_position<T: PartialEq>(
+    prev_sorted: &[T],
+    source: &[T],
+    order: &[usize],
+) -> Option<Option<usize>> {
+    if prev_sorted.len() != source.len() || order.len() != source.len() {
+        return None;
+    }
+
+    let mut changed = prev_sorted
+        .iter()
+        .zip(order)
+        .enumerate()
+        .filter(|(_, (prev, index))| **prev != source[**index])
+        .map(|(position, _)| position);
+
+    let position = changed.next();
+    if changed.next().is_some() {
+        return None;
+    }
+
+    Some(position)
+}
+
+/// Moves the element at `position` towards the front or back until it's in its sorted place.
+fn move_into_place<T>(
+    sorted: &mut [T],
+    order: &mut [usize],
+    mut position: usize,
+    cmp_fn: &mut impl FnMut(&T, &T) -> Ordering,
+) {
+    while position > 0 && compare(cmp_fn, sorted, order, position, position - 1).is_lt() {
+        sorted.swap(position, position - 1);
+        order.swap(position, position - 1);
+        position -= 1;
+    }
+
+    while position + 1 < sorted.len()
+        && compare(cmp_fn, sorted, order, position + 1, position).is_lt()
+    {
+        sorted.swap(position, position + 1);
+        order.swap(position, position + 1);
+        position += 1;
+    }
+}
+
+fn is_sorted<T>(
+    sorted: &[T],
+    order: &[usize],
+    cmp_fn: &mut impl FnMut(&T, &T) -> Ordering,
+) -> bool {
+    (1..sorted.len()).all(|position| compare(cmp_fn, sorted, order, position, position - 1).is_gt())
+}
+
+fn sort<T>(sorted: &mut [T], order: &mut [usize], cmp_fn: &mut impl FnMut(&T, &T) -> Ordering) {
+    let mut permutation = (0..sorted.len()).collect::<Vec<_>>();
+    permutation.sort_by(|&a, &b| compare(cmp_fn, sorted, order, a, b));
+
+    permute(sorted, &permutation);
+    permute(order, &permutation);
+}
+
+/// Rearranges `items` so that the element at position `i` afterwards is the one that was at
+/// position `permutation[i]` before.
+fn permute<E>(items: &mut [E], permutation: &[usize]) {
+    let mut is_done = vec![false; items.len()];
+
+    for start in 0..items.len() {
+        if is_done[start] {
+            continue;
+        }
+        is_done[start] = true;
+
+        let mut position = start;
+        while permutation[position] != start {
+            let next = permutation[position];
+            items.swap(position, next);
+            is_done[next] = true;
+            position = next;
+        }
+    }
 }
@@ -0,0 +1,559 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use crate::storage::use_local_storage;
+use codee::{Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// Headless split view with draggable dividers.
+///
+/// Manages the sizes of `panes` panes inside of `container` that are separated by dividers.
+/// Divider `i` sits between pane `i` and pane `i + 1`. Sizes are percentages of the container
+/// and always add up to 100. Dividers can be dragged with the pointer or moved with the keyboard
+/// and panes can be collapsed. Optionally the sizes are persisted in local storage.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_splitter)
+///
+/// ## Usage
+///
+/// Call `start_resize` when a divider is pressed and `handle_keydown` when a key is pressed on a
+/// focused divider.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::use_splitter;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let container = NodeRef::<Div>::new();
+///
+/// let splitter = use_splitter(container, 2);
+///
+/// view! {
+///     <div node_ref=container style="display: flex; height: 400px">
+///         <div style:width=move || format!("{}%", splitter.size(0))>"Left"</div>
+///         <div
+///             role="separator"
+///             tabindex="0"
+///             aria-valuenow=move || splitter.size(0).round().to_string()
+///             style="width: 4px; margin: 0 -2px; cursor: col-resize; touch-action: none"
+///             on:pointerdown=move |e| splitter.start_resize(0, &e)
+///             on:keydown=move |e| splitter.handle_keydown(0, &e)
+///         />
+///         <div style:width=move || format!("{}%", splitter.size(1))>"Right"</div>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// The panes start with equal sizes. Set `initial_sizes` to start with different sizes and
+/// `direction` to `SplitterDirection::Column` to stack the panes vertically.
+///
+/// ### Constraints
+///
+/// A pane can't be made smaller than `min_size`. If `collapse_threshold` is set, dragging a pane
+/// below that size collapses it completely. Both can be given in percent or pixels.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_splitter_with_options, UseSplitterOptions, SplitterSize};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let container = NodeRef::<Div>::new();
+/// #
+/// let splitter = use_splitter_with_options(
+///     container,
+///     3,
+///     UseSplitterOptions::default()
+///         .initial_sizes(vec![20.0, 60.0, 20.0])
+///         .min_size(SplitterSize::Px(150.0))
+///         .collapse_threshold(SplitterSize::Px(75.0)),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// `collapse`, `expand` and `toggle_collapse` collapse a pane programmatically. An expanded pane
+/// gets back the size it had before it was collapsed.
+///
+/// ### Keyboard
+///
+/// `handle_keydown` implements the
+/// [window splitter pattern](https://www.w3.org/WAI/ARIA/apg/patterns/windowsplitter/):
+///
+/// - `ArrowLeft` / `ArrowRight` (or `ArrowUp` / `ArrowDown` for `SplitterDirection::Column`)
+///   move the divider by `keyboard_step` percent.
+/// - `Home` / `End` move the divider as far as possible.
+/// - `Enter` collapses or expands the pane before the divider.
+///
+/// ### Persistence
+///
+/// If you provide a `storage_key` the sizes are stored in local storage under that key and
+/// restored on the next visit. See [`fn@crate::storage::use_local_storage`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server the panes have their initial sizes and nothing is read from storage. Pixel
+/// sizes are ignored since the container has no size.
+pub fn use_splitter<El, M>(container: El, panes: usize) -> UseSplitterReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_splitter_with_options(container, panes, UseSplitterOptions::default())
+}
+
+/// Version of [`use_splitter`] that takes a `UseSplitterOptions`. See [`use_splitter`] for how to use.
+pub fn use_splitter_with_options<El, M>(
+    container: El,
+    panes: usize,
+    options: UseSplitterOptions,
+) -> UseSplitterReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseSplitterOptions {
+        direction,
+        initial_sizes,
+        min_size,
+        collapse_threshold,
+        keyboard_step,
+        storage_key,
+    } = options;
+
+    let container = container.into_element_maybe_signal();
+
+    let initial_sizes = if initial_sizes.len() == panes {
+        initial_sizes
+    } else {
+        vec![100.0 / panes as f64; panes]
+    };
+    let initial_sizes = StoredValue::new(initial_sizes);
+
+    // empty until the sizes are changed or if the stored sizes don't fit the number of panes
+    let (stored_sizes, set_sizes) = match storage_key {
+        Some(storage_key) => {
+            let (sizes, set_sizes, _) = use_local_storage::<Vec<f64>, PaneSizesCodec>(storage_key);
+            (sizes, set_sizes)
+        }
+        None => {
+            let (sizes, set_sizes) = signal(Vec::new());
+            (sizes.into(), set_sizes)
+        }
+    };
+
+    let sizes = Signal::derive(move || {
+        let sizes = stored_sizes.get();
+
+        if sizes.len() == panes {
+            sizes
+        } else {
+            initial_sizes.get_value()
+        }
+    });
+
+    let (dragging, set_dragging) = signal(None::<usize>);
+    let drag = StoredValue::new(None::<Drag>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{pointermove, pointerup};
+
+        let _ = use_event_listener(window(), pointermove, move |event| {
+            let Some(Drag {
+                divider,
+                start_position,
+                start_sizes,
+                container_size,
+            }) = drag.get_value()
+            else {
+                return;
+            };
+
+            if container_size <= 0.0 {
+                return;
+            }
+
+            let delta = (direction.position(&event) - start_position) / container_size * 100.0;
+
+            let mut sizes = start_sizes;
+            resize_panes(
+                &mut sizes,
+                divider,
+                delta,
+                min_size.to_percent(container_size),
+                collapse_threshold.map(|threshold| threshold.to_percent(container_size)),
+            );
+
+            set_sizes.set(sizes);
+        });
+
+        let _ = use_event_listener(window(), pointerup, move |_| {
+            if drag.get_value().is_some() {
+                drag.set_value(None);
+                set_dragging.set(None);
+            }
+        });
+    }
+
+    UseSplitterReturn {
+        sizes,
+        dragging: dragging.into(),
+        set_sizes,
+        set_dragging,
+        initial_sizes,
+        sizes_before_collapse: StoredValue::new(HashMap::new()),
+        drag,
+        container,
+        direction,
+        min_size,
+        collapse_threshold,
+        keyboard_step,
+    }
+}
+
+/// Moves `divider` by `delta` percent while keeping the two adjacent panes within the
+/// constraints.
+fn resize_panes(
+    sizes: &mut [f64],
+    divider: usize,
+    delta: f64,
+    min_size: f64,
+    collapse_threshold: Option<f64>,
+) {
+    if divider + 1 >= sizes.len() {
+        return;
+    }
+
+    let total = sizes[divider] + sizes[divider + 1];
+
+    let before = constrain(sizes[divider] + delta, total, min_size, collapse_threshold);
+    let after = constrain(total - before, total, min_size, collapse_threshold);
+
+    sizes[divider] = total - after;
+    sizes[divider + 1] = after;
+}
+
+fn constrain(size: f64, total: f64, min_size: f64, collapse_threshold: Option<f64>) -> f64 {
+    let size = size.clamp(0.0, total);
+
+    match collapse_threshold {
+        Some(threshold) if size < threshold => 0.0,
+        _ => size.max(min_size.min(total)),
+    }
+}
+
+/// The divider being dragged, the pointer position, the sizes and the container size in pixels
+/// when the drag started.
+#[derive(Clone)]
+struct Drag {
+    divider: usize,
+    start_position: f64,
+    start_sizes: Vec<f64>,
+    container_size: f64,
+}
+
+/// Direction in which the panes of [`use_splitter`] are laid out.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum SplitterDirection {
+    /// Side by side. The dividers are moved horizontally.
+    #[default]
+    Row,
+
+    /// Stacked. The dividers are moved vertically.
+    Column,
+}
+
+impl SplitterDirection {
+    fn position(self, event: &web_sys::MouseEvent) -> f64 {
+        match self {
+            Self::Row => event.client_x() as f64,
+            Self::Column => event.client_y() as f64,
+        }
+    }
+
+    fn size(self, el: &web_sys::Element) -> f64 {
+        let rect = el.get_bounding_client_rect();
+
+        match self {
+            Self::Row => rect.width(),
+            Self::Column => rect.height(),
+        }
+    }
+}
+
+/// A size constraint of [`use_splitter`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SplitterSize {
+    /// Percent of the container.
+    Percent(f64),
+
+    /// Pixels.
+    Px(f64),
+}
+
+impl SplitterSize {
+    fn to_percent(self, container_size: f64) -> f64 {
+        match self {
+            Self::Percent(percent) => percent,
+            Self::Px(px) if container_size > 0.0 => px / container_size * 100.0,
+            Self::Px(_) => 0.0,
+        }
+    }
+}
+
+/// Options for [`use_splitter_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSplitterOptions {
+    /// Direction in which the panes are laid out. Defaults to `SplitterDirection::Row`.
+    direction: SplitterDirection,
+
+    /// Initial sizes of the panes in percent. Ignored if it doesn't have one entry per pane.
+    /// Defaults to equal sizes.
+    #[builder(into)]
+    initial_sizes: Vec<f64>,
+
+    /// Minimum size of a pane that isn't collapsed. Defaults to `SplitterSize::Percent(10.0)`.
+    min_size: SplitterSize,
+
+    /// If set, a pane that is dragged below this size collapses. Defaults to `None`.
+    #[builder(into)]
+    collapse_threshold: Option<SplitterSize>,
+
+    /// Percent by which the arrow keys move a divider. Defaults to `5.0`.
+    keyboard_step: f64,
+
+    /// If set, the sizes are persisted in local storage under this key. Defaults to `None`.
+    #[builder(into)]
+    storage_key: Option<String>,
+}
+
+impl Default for UseSplitterOptions {
+    fn default() -> Self {
+        Self {
+            direction: SplitterDirection::default(),
+            initial_sizes: Vec::new(),
+            min_size: SplitterSize::Percent(10.0),
+            collapse_threshold: None,
+            keyboard_step: 5.0,
+            storage_key: None,
+        }
+    }
+}
+
+/// Return type of [`use_splitter`].
+#[derive(Clone, Copy)]
+pub struct UseSplitterReturn {
+    /// The sizes of the panes in percent of the container.
+    pub sizes: Signal<Vec<f64>>,
+
+    /// The divider that is currently being dragged.
+    pub dragging: Signal<Option<usize>>,
+
+    set_sizes: WriteSignal<Vec<f64>>,
+    set_dragging: WriteSignal<Option<usize>>,
+    initial_sizes: StoredValue<Vec<f64>>,
+    sizes_before_collapse: StoredValue<HashMap<usize, f64>>,
+    drag: StoredValue<Option<Drag>>,
+    container: ElementMaybeSignal<web_sys::Element>,
+    direction: SplitterDirection,
+    min_size: SplitterSize,
+    collapse_threshold: Option<SplitterSize>,
+    keyboard_step: f64,
+}
+
+impl UseSplitterReturn {
+    /// Returns the size of `pane` in percent. Reactive if called inside a reactive context.
+    pub fn size(&self, pane: usize) -> f64 {
+        self.sizes
+            .with(|sizes| sizes.get(pane).copied().unwrap_or_default())
+    }
+
+    /// Returns whether `pane` is collapsed. Reactive if called inside a reactive context.
+    pub fn is_collapsed(&self, pane: usize) -> bool {
+        self.size(pane) <= 0.0
+    }
+
+    /// Sets the sizes of all panes in percent. Ignored if it doesn't have one entry per pane.
+    pub fn set_sizes(&self, sizes: Vec<f64>) {
+        if sizes.len() == self.sizes.with_untracked(Vec::len) {
+            self.set_sizes.set(sizes);
+        }
+    }
+
+    /// Moves `divider` by `delta` percent within the constraints.
+    pub fn move_divider(&self, divider: usize, delta: f64) {
+        let container_size = self.container_size();
+
+        let mut sizes = self.sizes.get_untracked();
+        resize_panes(
+            &mut sizes,
+            divider,
+            delta,
+            self.min_size.to_percent(container_size),
+            self.collapse_threshold
+                .map(|threshold| threshold.to_percent(container_size)),
+        );
+
+        self.set_sizes.set(sizes);
+    }
+
+    /// Starts dragging `divider`. Call this on `pointerdown` of the divider.
+    pub fn start_resize(&self, divider: usize, event: &web_sys::PointerEvent) {
+        if event.button() != 0 {
+            return;
+        }
+
+        event.prevent_default();
+
+        self.drag.set_value(Some(Drag {
+            divider,
+            start_position: self.direction.position(event),
+            start_sizes: self.sizes.get_untracked(),
+            container_size: self.container_size(),
+        }));
+        self.set_dragging.set(Some(divider));
+    }
+
+    /// Handles a `keydown` event on `divider`. See [`use_splitter`] for the supported keys.
+    pub fn handle_keydown(&self, divider: usize, event: &web_sys::KeyboardEvent) {
+        match (self.direction, event.key().as_str()) {
+            (SplitterDirection::Row, "ArrowLeft") | (SplitterDirection::Column, "ArrowUp") => {
+                self.move_divider(divider, -self.keyboard_step)
+            }
+            (SplitterDirection::Row, "ArrowRight") | (SplitterDirection::Column, "ArrowDown") => {
+                self.move_divider(divider, self.keyboard_step)
+            }
+            (_, "Home") => self.move_divider(divider, -100.0),
+            (_, "End") => self.move_divider(divider, 100.0),
+            (_, "Enter") => self.toggle_collapse(divider),
+            _ => return,
+        }
+
+        event.prevent_default();
+    }
+
+    /// Collapses `pane` and gives its size to the pane after it or, for the last pane, the pane
+    /// before it.
+    pub fn collapse(&self, pane: usize) {
+        let mut sizes = self.sizes.get_untracked();
+
+        let Some(neighbor) = neighbor(pane, sizes.len()) else {
+            return;
+        };
+
+        if sizes[pane] <= 0.0 {
+            return;
+        }
+
+        self.sizes_before_collapse.update_value(|before| {
+            before.insert(pane, sizes[pane]);
+        });
+
+        sizes[neighbor] += sizes[pane];
+        sizes[pane] = 0.0;
+
+        self.set_sizes.set(sizes);
+    }
+
+    /// Expands the collapsed `pane` to the size it had before it was collapsed. The size is
+    /// taken from the same pane that got it when collapsing.
+    pub fn expand(&self, pane: usize) {
+        let mut sizes = self.sizes.get_untracked();
+
+        let Some(neighbor) = neighbor(pane, sizes.len()) else {
+            return;
+        };
+
+        if sizes[pane] > 0.0 {
+            return;
+        }
+
+        let size = self
+            .sizes_before_collapse
+            .try_update_value(|before| before.remove(&pane))
+            .flatten()
+            .or_else(|| {
+                self.initial_sizes
+                    .with_value(|initial| initial.get(pane).copied())
+            })
+            .unwrap_or_default();
+
+        let min_size = self.min_size.to_percent(self.container_size());
+        let size = size.min(sizes[neighbor] - min_size).max(0.0);
+
+        sizes[neighbor] -= size;
+        sizes[pane] = size;
+
+        self.set_sizes.set(sizes);
+    }
+
+    /// Collapses `pane` if it's expanded and expands it otherwise.
+    pub fn toggle_collapse(&self, pane: usize) {
+        if self.is_collapsed_untracked(pane) {
+            self.expand(pane);
+        } else {
+            self.collapse(pane);
+        }
+    }
+
+    /// Resets all panes to their initial sizes.
+    pub fn reset(&self) {
+        self.sizes_before_collapse.update_value(HashMap::clear);
+        self.set_sizes.set(Vec::new());
+    }
+
+    fn is_collapsed_untracked(&self, pane: usize) -> bool {
+        self.sizes
+            .with_untracked(|sizes| sizes.get(pane).is_some_and(|size| *size <= 0.0))
+    }
+
+    fn container_size(&self) -> f64 {
+        self.container
+            .get_untracked()
+            .map(|container| self.direction.size(&container))
+            .unwrap_or_default()
+    }
+}
+
+/// The pane that takes the size of `pane` when it's collapsed.
+fn neighbor(pane: usize, panes: usize) -> Option<usize> {
+    if pane + 1 < panes {
+        Some(pane + 1)
+    } else if pane > 0 && pane < panes {
+        Some(pane - 1)
+    } else {
+        None
+    }
+}
+
+/// Stores the sizes separated by spaces.
+struct PaneSizesCodec;
+
+impl Encoder<Vec<f64>> for PaneSizesCodec {
+    type Error = ();
+    type Encoded = String;
+
+    fn encode(val: &Vec<f64>) -> Result<Self::Encoded, Self::Error> {
+        Ok(val.iter().map(f64::to_string).collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl Decoder<Vec<f64>> for PaneSizesCodec {
+    type Error = ();
+    type Encoded = str;
+
+    fn decode(val: &Self::Encoded) -> Result<Vec<f64>, Self::Error> {
+        val.split_whitespace()
+            .map(|size| size.parse::<f64>().map_err(|_| ()))
+            .collect()
+    }
+}
@@ -0,0 +1,314 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Animate a value with spring physics.
+///
+/// The returned `value` follows `source` like it's attached to it by a spring. When the source
+/// changes while the spring is still moving, the current velocity is kept which makes
+/// interrupted animations look natural.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_spring)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_spring, UseSpringReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (target, set_target) = signal(0.0);
+///
+/// let UseSpringReturn { value, is_animating } = use_spring::<f64>(target);
+///
+/// view! {
+///     <button on:click=move |_| set_target.set(100.0)>"Go"</button>
+///     <div style:transform=move || format!("translateX({}px)", value.get())></div>
+/// }
+/// # }
+/// ```
+///
+/// Besides `f64` the source can be a `Vec<f64>` or an array `[f64; N]` to animate several values
+/// like coordinates at once. Every component has its own velocity. To support your own types
+/// implement the trait [`SpringValue`].
+///
+/// ### Options
+///
+/// The behaviour of the spring is configured with `stiffness`, `damping` and `mass`. The spring
+/// comes to rest once all components are closer than `precision` to the target and move slower
+/// than `precision` per second.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_spring_with_options, UseSpringOptions, UseSpringReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (target, set_target) = signal([0.0, 0.0]);
+/// #
+/// // wobbly
+/// let UseSpringReturn { value, .. } = use_spring_with_options::<[f64; 2]>(
+///     target,
+///     UseSpringOptions::default().stiffness(180.0).damping(12.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `value` is the initial value of the source and `is_animating` is always
+/// `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_transition`]
+pub fn use_spring<T>(source: impl Into<Signal<T>>) -> UseSpringReturn<T>
+where
+    T: SpringValue,
+{
+    use_spring_with_options(source, UseSpringOptions::default())
+}
+
+/// Version of [`use_spring`] that takes a `UseSpringOptions`. See [`use_spring`] for how to use.
+pub fn use_spring_with_options<T>(
+    source: impl Into<Signal<T>>,
+    options: UseSpringOptions,
+) -> UseSpringReturn<T>
+where
+    T: SpringValue,
+{
+    let UseSpringOptions {
+        stiffness,
+        damping,
+        mass,
+        precision,
+    } = options;
+
+    let source = source.into();
+
+    let (value, set_value) = signal(source.get_untracked());
+    let (is_animating, set_animating) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::utils::Pausable;
+        use crate::{use_raf_fn_with_options, UseRafFnCallbackArgs, UseRafFnOptions};
+
+        let initial = source.with_untracked(T::components);
+        let state = StoredValue::new(SpringState {
+            velocity: vec![0.0; initial.len()],
+            position: initial.clone(),
+            target: initial,
+        });
+
+        let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+            move |UseRafFnCallbackArgs { delta, .. }| {
+                let Some((position, at_rest)) = state.try_update_value(|state| {
+                    // the first frame after resuming has no (sensible) delta
+                    let at_rest = state.step(
+                        delta.clamp(0.0, MAX_STEP_MS) / 1000.0,
+                        stiffness,
+                        damping,
+                        mass,
+                        precision,
+                    );
+
+                    (state.position.clone(), at_rest)
+                }) else {
+                    return;
+                };
+
+                set_value.set(T::from_components(&position));
+
+                if at_rest {
+                    set_animating.set(false);
+                }
+            },
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        let _ = Effect::watch(
+            move || source.with(T::components),
+            move |target, _, _| {
+                state.update_value(|state| {
+                    if state.position.len() != target.len() {
+                        // nothing sensible to animate from
+                        state.position = target.clone();
+                        state.velocity = vec![0.0; target.len()];
+                    }
+
+                    state.target = target.clone();
+                });
+
+                set_animating.set(true);
+                resume();
+            },
+            false,
+        );
+
+        let _ = Effect::watch(
+            move || is_animating.get(),
+            move |is_animating, _, _| {
+                if !*is_animating {
+                    pause();
+                }
+            },
+            false,
+        );
+    }
+
+    UseSpringReturn {
+        value: value.into(),
+        is_animating: is_animating.into(),
+    }
+}
+
+/// Longest time step in milliseconds that is simulated per frame. Longer frames (for example
+/// after the tab was in the background) are slowed down instead of letting the spring explode.
+const MAX_STEP_MS: f64 = 64.0;
+
+/// Number of simulation steps per frame for a more stable integration.
+const SUB_STEPS: usize = 4;
+
+struct SpringState {
+    position: Vec<f64>,
+    velocity: Vec<f64>,
+    target: Vec<f64>,
+}
+
+impl SpringState {
+    /// Advances the simulation by `dt` seconds. Returns `true` if the spring has come to rest in
+    /// which case it's snapped to the target.
+    fn step(&mut self, dt: f64, stiffness: f64, damping: f64, mass: f64, precision: f64) -> bool {
+        let dt = dt / SUB_STEPS as f64;
+
+        for _ in 0..SUB_STEPS {
+            for ((position, velocity), target) in self
+                .position
+                .iter_mut()
+                .zip(self.velocity.iter_mut())
+                .zip(self.target.iter())
+            {
+                let spring_force = -stiffness * (*position - target);
+                let damping_force = -damping * *velocity;
+
+                *velocity += (spring_force + damping_force) / mass * dt;
+                *position += *velocity * dt;
+            }
+        }
+
+        let at_rest = self
+            .position
+            .iter()
+            .zip(self.velocity.iter())
+            .zip(self.target.iter())
+            .all(|((position, velocity), target)| {
+                (position - target).abs() < precision && velocity.abs() < precision
+            });
+
+        if at_rest {
+            self.position.clone_from(&self.target);
+            self.velocity.fill(0.0);
+        }
+
+        at_rest
+    }
+}
+
+/// A value that can be animated by [`fn@crate::use_spring`]. It's simulated as a list of
+/// independent `f64` components.
+pub trait SpringValue: Clone + Send + Sync + 'static {
+    /// Returns the components of this value.
+    fn components(&self) -> Vec<f64>;
+
+    /// Creates a value from the components returned by [`SpringValue::components`].
+    fn from_components(components: &[f64]) -> Self;
+}
+
+impl SpringValue for f64 {
+    fn components(&self) -> Vec<f64> {
+        vec![*self]
+    }
+
+    fn from_components(components: &[f64]) -> Self {
+        components.first().copied().unwrap_or_default()
+    }
+}
+
+impl SpringValue for Vec<f64> {
+    fn components(&self) -> Vec<f64> {
+        self.clone()
+    }
+
+    fn from_components(components: &[f64]) -> Self {
+        components.to_vec()
+    }
+}
+
+impl<const N: usize> SpringValue for [f64; N] {
+    fn components(&self) -> Vec<f64> {
+        self.to_vec()
+    }
+
+    fn from_components(components: &[f64]) -> Self {
+        std::array::from_fn(|i| components.get(i).copied().unwrap_or_default())
+    }
+}
+
+/// Options for [`use_spring_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSpringOptions {
+    /// How strongly the spring pulls towards the target. Defaults to `170.0`.
+    stiffness: f64,
+
+    /// How strongly the movement is slowed down. Lower values make the spring bounce more.
+    /// Defaults to `26.0`.
+    damping: f64,
+
+    /// Mass of the moving value. Higher values make the spring slower. Defaults to `1.0`.
+    mass: f64,
+
+    /// Distance to the target and velocity below which the spring comes to rest.
+    /// Defaults to `0.01`.
+    precision: f64,
+}
+
+impl Default for UseSpringOptions {
+    fn default() -> Self {
+        Self {
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            precision: 0.01,
+        }
+    }
+}
+
+/// Return type of [`use_spring`].
+pub struct UseSpringReturn<T>
+where
+    T: SpringValue,
+{
+    /// The animated value.
+    pub value: Signal<T>,
+
+    /// Whether the spring is currently moving.
+    pub is_animating: Signal<bool>,
+}
+
+impl<T> Clone for UseSpringReturn<T>
+where
+    T: SpringValue,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseSpringReturn<T> where T: SpringValue {}
@@ -0,0 +1,340 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Process a file in a [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API)
+/// as a stream.
+///
+/// Whenever `file` changes, its content is streamed to the worker at `worker_url` which pipes it
+/// through a [`TransformStream`](https://developer.mozilla.org/en-US/docs/Web/API/TransformStream)
+/// of its own. The output of the worker is collected into a `Blob`. Because the file is never
+/// read into memory as a whole and the processing happens in the worker, this works for large
+/// files without blocking the UI.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_stream_pipeline)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_stream_pipeline, UseStreamPipelineReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (file, set_file) = signal_local(None::<web_sys::File>);
+///
+/// let UseStreamPipelineReturn {
+///     progress,
+///     result,
+///     is_running,
+///     ..
+/// } = use_stream_pipeline(file, "/uppercase-worker.js");
+///
+/// view! {
+///     <progress max="1" value=move || progress.get().to_string() />
+///     <p>"Result size: " {move || result.get().map(|blob| blob.size())}</p>
+/// }
+/// # }
+/// ```
+///
+/// The worker receives a message with the streams `readable` and `writable`. It has to pipe the
+/// first one through its transformation into the second one.
+///
+/// ```js
+/// self.onmessage = ({ data: { readable, writable } }) => {
+///   readable
+///     .pipeThrough(new TextDecoderStream())
+///     .pipeThrough(new TransformStream({
+///       transform(chunk, controller) {
+///         controller.enqueue(chunk.toUpperCase());
+///       },
+///     }))
+///     .pipeThrough(new TextEncoderStream())
+///     .pipeTo(writable);
+/// };
+/// ```
+///
+/// `progress` is the share of the file that the worker has read so far. It goes from `0.0` to
+/// `1.0`. A new file aborts the processing of the previous one. You can also abort it with
+/// `abort`.
+///
+/// This relies on [transferable streams](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Transferable_objects#supported_objects).
+/// In browsers that don't support them `error` is set as soon as processing starts.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is created. `result` and `error` are always `None`, `progress` is
+/// always `0.0` and `is_running` is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_web_worker`]
+pub fn use_stream_pipeline(
+    file: impl Into<Signal<Option<web_sys::File>, LocalStorage>>,
+    worker_url: &str,
+) -> UseStreamPipelineReturn<impl Fn() + Clone + Send + Sync> {
+    use_stream_pipeline_with_options(file, worker_url, UseStreamPipelineOptions::default())
+}
+
+/// Version of [`use_stream_pipeline`] that takes a `UseStreamPipelineOptions`. See [`use_stream_pipeline`] for how to use.
+pub fn use_stream_pipeline_with_options(
+    file: impl Into<Signal<Option<web_sys::File>, LocalStorage>>,
+    worker_url: &str,
+    options: UseStreamPipelineOptions,
+) -> UseStreamPipelineReturn<impl Fn() + Clone + Send + Sync> {
+    let UseStreamPipelineOptions { module, mime_type } = options;
+
+    let file = file.into();
+
+    let (bytes_processed, set_bytes_processed) = signal(0.0_f64);
+    let (total_bytes, set_total_bytes) = signal(0.0_f64);
+    let (result, set_result) = signal_local(None::<web_sys::Blob>);
+    let (is_running, set_running) = signal(false);
+    let (error, set_error) = signal_local(None::<UseStreamPipelineError>);
+
+    let abort_controller = StoredValue::new_local(None::<web_sys::AbortController>);
+    let run_id = StoredValue::new(0_u64);
+
+    let abort = sendwrap_fn!(move || {
+        // makes the running pipeline ignore its outcome
+        run_id.try_update_value(|id| *id += 1);
+
+        if let Some(controller) = abort_controller.try_update_value(Option::take).flatten() {
+            controller.abort();
+        }
+
+        set_running.set(false);
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::task::spawn_local;
+
+        let worker_options = web_sys::WorkerOptions::new();
+        if module {
+            worker_options.set_type(web_sys::WorkerType::Module);
+        }
+
+        let worker = match web_sys::Worker::new_with_options(worker_url, &worker_options) {
+            Ok(worker) => {
+                let _ = use_event_listener(worker.clone(), leptos::ev::error, {
+                    let abort = abort.clone();
+
+                    move |event| {
+                        abort();
+                        set_error.set(Some(UseStreamPipelineError::Event(event.into())));
+                    }
+                });
+
+                Some(worker)
+            }
+            Err(err) => {
+                set_error.set(Some(UseStreamPipelineError::Create(err)));
+                None
+            }
+        };
+        let worker = StoredValue::new_local(worker);
+
+        let _ = Effect::watch(
+            move || file.get(),
+            {
+                let abort = abort.clone();
+
+                move |file: &Option<web_sys::File>, _, _| {
+                    abort();
+
+                    let (Some(file), Some(worker)) = (file.clone(), worker.get_value()) else {
+                        return;
+                    };
+
+                    let id = run_id.get_value();
+
+                    let controller = web_sys::AbortController::new().ok();
+                    abort_controller.set_value(controller.clone());
+
+                    set_total_bytes.set(file.size());
+                    set_bytes_processed.set(0.0);
+                    set_result.set(None);
+                    set_error.set(None);
+                    set_running.set(true);
+
+                    let mime_type = mime_type.clone();
+
+                    spawn_local(async move {
+                        let outcome = run_pipeline(
+                            &worker,
+                            &file,
+                            controller.map(|controller| controller.signal()),
+                            mime_type.as_deref(),
+                            move |len| {
+                                if run_id.try_get_value() == Some(id) {
+                                    set_bytes_processed.update(|bytes| *bytes += len);
+                                }
+                            },
+                        )
+                        .await;
+
+                        if run_id.try_get_value() != Some(id) {
+                            return;
+                        }
+
+                        match outcome {
+                            Ok(blob) => set_result.set(Some(blob)),
+                            Err(err) => set_error.set(Some(UseStreamPipelineError::Stream(err))),
+                        }
+
+                        abort_controller.set_value(None);
+                        set_running.set(false);
+                    });
+                }
+            },
+            true,
+        );
+
+        on_cleanup(move || {
+            if let Some(worker) = worker.try_update_value(Option::take).flatten() {
+                worker.terminate();
+            }
+        });
+    }
+
+    on_cleanup({
+        let abort = abort.clone();
+
+        move || abort()
+    });
+
+    UseStreamPipelineReturn {
+        progress: Signal::derive(move || {
+            let total_bytes = total_bytes.get();
+
+            if total_bytes > 0.0 {
+                (bytes_processed.get() / total_bytes).min(1.0)
+            } else {
+                0.0
+            }
+        }),
+        bytes_processed: bytes_processed.into(),
+        result: result.into(),
+        is_running: is_running.into(),
+        error: error.into(),
+        abort,
+    }
+}
+
+/// Streams `file` through the worker and collects its output. `on_chunk` is called with the size
+/// of every chunk that the worker reads.
+#[cfg(not(feature = "ssr"))]
+async fn run_pipeline(
+    worker: &web_sys::Worker,
+    file: &web_sys::File,
+    abort_signal: Option<web_sys::AbortSignal>,
+    mime_type: Option<&str>,
+    on_chunk: impl Fn(f64) + 'static,
+) -> Result<web_sys::Blob, JsValue> {
+    use crate::js_fut;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    // passes the chunks on unchanged and counts them on the way
+    let transform = Closure::<dyn Fn(JsValue, web_sys::TransformStreamDefaultController)>::new(
+        move |chunk: JsValue, controller: web_sys::TransformStreamDefaultController| {
+            on_chunk(chunk.unchecked_ref::<js_sys::Uint8Array>().byte_length() as f64);
+            let _ = controller.enqueue_with_chunk(&chunk);
+        },
+    );
+
+    let transformer = js_sys::Object::new();
+    js_sys::Reflect::set(&transformer, &"transform".into(), transform.as_ref())?;
+    let counter = web_sys::TransformStream::new_with_transformer(&transformer)?;
+
+    let pipe_options = web_sys::StreamPipeOptions::new();
+    if let Some(abort_signal) = &abort_signal {
+        pipe_options.set_signal(abort_signal);
+    }
+
+    let input = file.stream().pipe_through_with_options(
+        &web_sys::ReadableWritablePair::new(&counter.readable(), &counter.writable()),
+        &pipe_options,
+    );
+
+    let output = web_sys::TransformStream::new()?;
+    let output_writable = output.writable();
+
+    let message = js_sys::Object::new();
+    js_sys::Reflect::set(&message, &"readable".into(), &input)?;
+    js_sys::Reflect::set(&message, &"writable".into(), &output_writable)?;
+
+    worker.post_message_with_transfer(&message, &js_sys::Array::of2(&input, &output_writable))?;
+
+    let response = web_sys::Response::new_with_opt_readable_stream(Some(&output.readable()))?;
+    let blob = js_fut!(response.blob()?)
+        .await?
+        .unchecked_into::<web_sys::Blob>();
+
+    // keep the closure alive until the whole file went through
+    drop(transform);
+
+    match mime_type {
+        Some(mime_type) => {
+            let options = web_sys::BlobPropertyBag::new();
+            options.set_type(mime_type);
+
+            web_sys::Blob::new_with_blob_sequence_and_options(&js_sys::Array::of1(&blob), &options)
+        }
+        None => Ok(blob),
+    }
+}
+
+/// Options for [`use_stream_pipeline_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseStreamPipelineOptions {
+    /// If `true` the worker script is loaded as an ES module instead of a classic script.
+    /// Defaults to `false`.
+    module: bool,
+
+    /// MIME type of the resulting `Blob`. Defaults to `None` which means an empty type.
+    #[builder(into)]
+    mime_type: Option<String>,
+}
+
+/// Return type of [`use_stream_pipeline`].
+pub struct UseStreamPipelineReturn<AbortFn>
+where
+    AbortFn: Fn() + Clone + Send + Sync,
+{
+    /// Share of the current file that has been read by the worker from `0.0` to `1.0`.
+    pub progress: Signal<f64>,
+
+    /// Number of bytes of the current file that have been read by the worker.
+    pub bytes_processed: Signal<f64>,
+
+    /// Output of the worker for the current file once it's complete.
+    pub result: Signal<Option<web_sys::Blob>, LocalStorage>,
+
+    /// Whether a file is currently being processed.
+    pub is_running: Signal<bool>,
+
+    /// Latest error.
+    pub error: Signal<Option<UseStreamPipelineError>, LocalStorage>,
+
+    /// Aborts the processing of the current file.
+    pub abort: AbortFn,
+}
+
+#[derive(Debug, Error)]
+pub enum UseStreamPipelineError {
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("worker error event")]
+    Event(web_sys::Event),
+    #[error("failed to stream the file through the worker")]
+    Stream(JsValue),
+}
@@ -0,0 +1,571 @@
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Headless table logic: multi-column sorting, filtering, pagination and column visibility.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_table)
+///
+/// ## Usage
+///
+/// Describe the columns with [`TableColumn`]s. A column can be sortable, filterable or both.
+/// `rows` then contains the rows of the current page after filtering and sorting.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_table, TableColumn, UseTableReturn};
+/// #
+/// #[derive(Clone)]
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (users, set_users) = signal(Vec::<User>::new());
+///
+/// let table = use_table(
+///     users,
+///     vec![
+///         TableColumn::new("name")
+///             .sort_by_key(|user: &User| user.name.clone())
+///             .filter_text(|user: &User| user.name.clone()),
+///         TableColumn::new("age").sort_by_key(|user: &User| user.age),
+///     ],
+/// );
+/// let UseTableReturn { rows, page, page_count, .. } = table;
+///
+/// view! {
+///     <input on:input=move |e| table.set_filter(event_target_value(&e)) />
+///     <table>
+///         <tr>
+///             <th on:click=move |e| table.toggle_sort("name", e.shift_key())>"Name"</th>
+///             <th on:click=move |e| table.toggle_sort("age", e.shift_key())>"Age"</th>
+///         </tr>
+///         <For each=move || rows.get() key=|user| user.name.clone() let:user>
+///             <tr>
+///                 <td>{user.name}</td>
+///                 <td>{user.age}</td>
+///             </tr>
+///         </For>
+///     </table>
+///     <button on:click=move |_| table.prev_page()>"Prev"</button>
+///     {page} " / " {page_count}
+///     <button on:click=move |_| table.next_page()>"Next"</button>
+/// }
+/// # }
+/// ```
+///
+/// ### Sorting
+///
+/// `toggle_sort` cycles a column through ascending, descending and unsorted. If `multi` is
+/// `true` the other sorted columns are kept and the column is appended as a tie breaker,
+/// otherwise it replaces them. The current sort order is available as `sort` and per column
+/// via `sort_direction`.
+///
+/// ### Filtering
+///
+/// `set_filter` sets a global search text that is matched case-insensitively against the
+/// `filter_text` of all filterable columns. `set_column_filter` sets a search text for a single
+/// column. A row is kept if it matches the global filter and all column filters. Changing a
+/// filter goes back to the first page.
+///
+/// ### Pagination
+///
+/// `page` is 1-based and always between `1` and `page_count`. Set the page size with the option
+/// `page_size` or later with `set_page_size`. A page size of `0` disables pagination.
+///
+/// ### Column visibility
+///
+/// `visible_columns` contains the ids of the columns that are not hidden, in the order they
+/// were given. Change it with `toggle_column` or `set_column_visible`.
+pub fn use_table<T>(
+    rows: impl Into<Signal<Vec<T>>>,
+    columns: Vec<TableColumn<T>>,
+) -> UseTableReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    use_table_with_options(rows, columns, UseTableOptions::default())
+}
+
+/// Version of [`use_table`] that takes a `UseTableOptions`. See [`use_table`] for how to use.
+pub fn use_table_with_options<T>(
+    rows: impl Into<Signal<Vec<T>>>,
+    columns: Vec<TableColumn<T>>,
+    options: UseTableOptions,
+) -> UseTableReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let UseTableOptions {
+        page_size,
+        sort: initial_sort,
+        hidden_columns,
+    } = options;
+
+    let all_rows = rows.into();
+
+    let sort = RwSignal::new(initial_sort);
+    let filter = RwSignal::new(String::new());
+    let column_filters = RwSignal::new(HashMap::<String, String>::new());
+    let current_page = RwSignal::new(1_usize);
+    let page_size = RwSignal::new(page_size);
+    let hidden_columns = RwSignal::new(hidden_columns.into_iter().collect::<HashSet<_>>());
+    let columns = StoredValue::new(columns);
+
+    // Indices of the rows that pass the filters in sorted order.
+    let processed = Memo::new(move |_| {
+        let filter = filter.with(|filter| filter.to_lowercase());
+        let column_filters = column_filters.with(|column_filters| {
+            column_filters
+                .iter()
+                .filter(|(_, text)| !text.is_empty())
+                .map(|(column, text)| (column.clone(), text.to_lowercase()))
+                .collect::<Vec<_>>()
+        });
+
+        columns.with_value(|columns| {
+            all_rows.with(|rows| {
+                let mut indices = (0..rows.len())
+                    .filter(|index| {
+                        matches_filters(&rows[*index], columns, &filter, &column_filters)
+                    })
+                    .collect::<Vec<_>>();
+
+                sort.with(|sort| {
+                    if !sort.is_empty() {
+                        indices.sort_by(|a, b| compare_rows(&rows[*a], &rows[*b], columns, sort));
+                    }
+                });
+
+                indices
+            })
+        })
+    });
+
+    let filtered_count = Signal::derive(move || processed.with(Vec::len));
+
+    let page_count = Signal::derive(move || {
+        let page_size = page_size.get();
+
+        if page_size == 0 {
+            1
+        } else {
+            filtered_count.get().div_ceil(page_size).max(1)
+        }
+    });
+
+    let page = Signal::derive(move || current_page.get().clamp(1, page_count.get()));
+
+    let rows = Signal::derive(move || {
+        let page_size = page_size.get();
+        let page = page.get();
+
+        processed.with(|indices| {
+            all_rows.with(|rows| {
+                let page_indices = if page_size == 0 {
+                    &indices[..]
+                } else {
+                    let start = ((page - 1) * page_size).min(indices.len());
+                    let end = (start + page_size).min(indices.len());
+                    &indices[start..end]
+                };
+
+                page_indices
+                    .iter()
+                    .map(|index| rows[*index].clone())
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+
+    let visible_columns = Signal::derive(move || {
+        hidden_columns.with(|hidden| {
+            columns.with_value(|columns| {
+                columns
+                    .iter()
+                    .filter(|column| !hidden.contains(&column.id))
+                    .map(|column| column.id.clone())
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+
+    UseTableReturn {
+        rows,
+        filtered_count,
+        page,
+        page_count,
+        page_size: page_size.into(),
+        sort: sort.into(),
+        filter: filter.into(),
+        visible_columns,
+        sort_signal: sort,
+        filter_signal: filter,
+        column_filters,
+        current_page,
+        page_size_signal: page_size,
+        hidden_columns,
+        #[cfg(feature = "use_virtual_list")]
+        all_rows,
+        #[cfg(feature = "use_virtual_list")]
+        processed,
+    }
+}
+
+fn matches_filters<T>(
+    row: &T,
+    columns: &[TableColumn<T>],
+    filter: &str,
+    column_filters: &[(String, String)],
+) -> bool {
+    let matches_global = filter.is_empty()
+        || columns.iter().any(|column| {
+            column
+                .filter_text
+                .as_ref()
+                .is_some_and(|text| text(row).to_lowercase().contains(filter))
+        });
+
+    matches_global
+        && column_filters.iter().all(|(id, text)| {
+            columns
+                .iter()
+                .find(|column| column.id == *id)
+                .and_then(|column| column.filter_text.as_ref())
+                .is_none_or(|filter_text| filter_text(row).to_lowercase().contains(text))
+        })
+}
+
+fn compare_rows<T>(a: &T, b: &T, columns: &[TableColumn<T>], sort: &[SortBy]) -> Ordering {
+    for SortBy { column, direction } in sort {
+        let Some(compare) = columns
+            .iter()
+            .find(|c| c.id == *column)
+            .and_then(|c| c.compare.as_ref())
+        else {
+            continue;
+        };
+
+        let ordering = match direction {
+            SortDirection::Ascending => compare(a, b),
+            SortDirection::Descending => compare(b, a),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+type CompareFn<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+type FilterTextFn<T> = Arc<dyn Fn(&T) -> String + Send + Sync>;
+
+/// Column definition for [`fn@crate::use_table`].
+pub struct TableColumn<T> {
+    id: String,
+    compare: Option<CompareFn<T>>,
+    filter_text: Option<FilterTextFn<T>>,
+}
+
+impl<T> TableColumn<T> {
+    /// Creates a column that is neither sortable nor filterable. `id` is used to refer to the
+    /// column in the methods of [`UseTableReturn`].
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            compare: None,
+            filter_text: None,
+        }
+    }
+
+    /// The id of the column.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Makes the column sortable with the given compare function.
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + Send + Sync + 'static) -> Self {
+        self.compare = Some(Arc::new(compare));
+        self
+    }
+
+    /// Makes the column sortable by the key returned from `key_fn`.
+    pub fn sort_by_key<K: Ord>(self, key_fn: impl Fn(&T) -> K + Send + Sync + 'static) -> Self {
+        self.sort_by(move |a, b| key_fn(a).cmp(&key_fn(b)))
+    }
+
+    /// Makes the column filterable. `filter_text` returns the text of a row that the filters
+    /// are matched against.
+    pub fn filter_text(
+        mut self,
+        filter_text: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.filter_text = Some(Arc::new(filter_text));
+        self
+    }
+}
+
+/// Sort direction of a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// A sorted column. See [`UseTableReturn::sort`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SortBy {
+    /// Id of the column.
+    pub column: String,
+
+    /// Sort direction.
+    pub direction: SortDirection,
+}
+
+impl SortBy {
+    /// Sort `column` in ascending order.
+    pub fn ascending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Sort `column` in descending order.
+    pub fn descending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// Options for [`use_table_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTableOptions {
+    /// Number of rows per page. `0` disables pagination. Defaults to `10`.
+    page_size: usize,
+
+    /// Initial sort order. The first entry has the highest priority. Defaults to unsorted.
+    sort: Vec<SortBy>,
+
+    /// Ids of the columns that are initially hidden. Defaults to none.
+    hidden_columns: Vec<String>,
+}
+
+impl Default for UseTableOptions {
+    fn default() -> Self {
+        Self {
+            page_size: 10,
+            sort: vec![],
+            hidden_columns: vec![],
+        }
+    }
+}
+
+/// Return type of [`use_table`].
+pub struct UseTableReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// The rows of the current page after filtering and sorting.
+    pub rows: Signal<Vec<T>>,
+
+    /// Number of rows that pass the filters.
+    pub filtered_count: Signal<usize>,
+
+    /// The current page starting at `1`.
+    pub page: Signal<usize>,
+
+    /// Number of pages. At least `1`.
+    pub page_count: Signal<usize>,
+
+    /// Number of rows per page.
+    pub page_size: Signal<usize>,
+
+    /// The current sort order. The first entry has the highest priority.
+    pub sort: Signal<Vec<SortBy>>,
+
+    /// The global filter text.
+    pub filter: Signal<String>,
+
+    /// Ids of the columns that are not hidden.
+    pub visible_columns: Signal<Vec<String>>,
+
+    sort_signal: RwSignal<Vec<SortBy>>,
+    filter_signal: RwSignal<String>,
+    column_filters: RwSignal<HashMap<String, String>>,
+    current_page: RwSignal<usize>,
+    page_size_signal: RwSignal<usize>,
+    hidden_columns: RwSignal<HashSet<String>>,
+    #[cfg(feature = "use_virtual_list")]
+    all_rows: Signal<Vec<T>>,
+    #[cfg(feature = "use_virtual_list")]
+    processed: Memo<Vec<usize>>,
+}
+
+impl<T> Clone for UseTableReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseTableReturn<T> where T: Send + Sync + 'static {}
+
+impl<T> UseTableReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Cycles the sort direction of `column` through ascending, descending and unsorted.
+    /// If `multi` is `false` all other columns are unsorted.
+    pub fn toggle_sort(&self, column: &str, multi: bool) {
+        self.sort_signal.update(|sort| {
+            let current = sort
+                .iter()
+                .find(|sort_by| sort_by.column == column)
+                .map(|sort_by| sort_by.direction);
+
+            if !multi {
+                sort.retain(|sort_by| sort_by.column == column);
+            }
+
+            match current {
+                None => sort.push(SortBy::ascending(column)),
+                Some(SortDirection::Ascending) => {
+                    if let Some(sort_by) = sort.iter_mut().find(|sort_by| sort_by.column == column)
+                    {
+                        sort_by.direction = SortDirection::Descending;
+                    }
+                }
+                Some(SortDirection::Descending) => {
+                    sort.retain(|sort_by| sort_by.column != column);
+                }
+            }
+        });
+    }
+
+    /// Returns the sort direction of `column` or `None` if it's not sorted. Reactive if called
+    /// inside a reactive context.
+    pub fn sort_direction(&self, column: &str) -> Option<SortDirection> {
+        self.sort_signal.with(|sort| {
+            sort.iter()
+                .find(|sort_by| sort_by.column == column)
+                .map(|sort_by| sort_by.direction)
+        })
+    }
+
+    /// Replaces the sort order.
+    pub fn set_sort(&self, sort: Vec<SortBy>) {
+        self.sort_signal.set(sort);
+    }
+
+    /// Sets the global filter text and goes to the first page.
+    pub fn set_filter(&self, text: impl Into<String>) {
+        self.filter_signal.set(text.into());
+        self.current_page.set(1);
+    }
+
+    /// Sets the filter text of a single column and goes to the first page. An empty text
+    /// removes the filter.
+    pub fn set_column_filter(&self, column: impl Into<String>, text: impl Into<String>) {
+        let column = column.into();
+        let text = text.into();
+
+        self.column_filters.update(|filters| {
+            if text.is_empty() {
+                filters.remove(&column);
+            } else {
+                filters.insert(column, text);
+            }
+        });
+        self.current_page.set(1);
+    }
+
+    /// Removes all filters.
+    pub fn clear_filters(&self) {
+        self.filter_signal.set(String::new());
+        self.column_filters.set(HashMap::new());
+        self.current_page.set(1);
+    }
+
+    /// Goes to `page` which is clamped between `1` and `page_count`.
+    pub fn set_page(&self, page: usize) {
+        self.current_page
+            .set(page.clamp(1, self.page_count.get_untracked()));
+    }
+
+    /// Goes to the next page if there is one.
+    pub fn next_page(&self) {
+        self.set_page(self.page.get_untracked() + 1);
+    }
+
+    /// Goes to the previous page if there is one.
+    pub fn prev_page(&self) {
+        self.set_page(self.page.get_untracked().saturating_sub(1));
+    }
+
+    /// Sets the number of rows per page and goes to the first page. `0` disables pagination.
+    pub fn set_page_size(&self, page_size: usize) {
+        self.page_size_signal.set(page_size);
+        self.current_page.set(1);
+    }
+
+    /// Returns whether `column` is visible. Reactive if called inside a reactive context.
+    pub fn is_column_visible(&self, column: &str) -> bool {
+        self.hidden_columns.with(|hidden| !hidden.contains(column))
+    }
+
+    /// Shows or hides `column`.
+    pub fn set_column_visible(&self, column: impl Into<String>, visible: bool) {
+        let column = column.into();
+
+        self.hidden_columns.update(|hidden| {
+            if visible {
+                hidden.remove(&column);
+            } else {
+                hidden.insert(column);
+            }
+        });
+    }
+
+    /// Hides `column` if it's visible and shows it otherwise.
+    pub fn toggle_column(&self, column: impl Into<String>) {
+        let column = column.into();
+        let visible = self
+            .hidden_columns
+            .with_untracked(|hidden| hidden.contains(&column));
+
+        self.set_column_visible(column, visible);
+    }
+}
+
+#[cfg(feature = "use_virtual_list")]
+impl<T> UseTableReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Returns the filtered and sorted rows from `start` to `end` (exclusive) ignoring the
+    /// pagination. Only clones the requested rows.
+    pub(crate) fn rows_range(&self, start: usize, end: usize) -> Vec<T> {
+        self.processed.with(|indices| {
+            self.all_rows.with(|rows| {
+                indices[start.min(indices.len())..end.min(indices.len())]
+                    .iter()
+                    .map(|index| rows[*index].clone())
+                    .collect()
+            })
+        })
+    }
+}
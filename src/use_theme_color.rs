@@ -0,0 +1,220 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::MaybeRwSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive `<meta name="theme-color">`.
+///
+/// Keeps the theme color in sync with a reactive color. Browsers use it to tint their UI, like
+/// the address bar on Android or the title bar of installed web apps.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_theme_color)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_theme_color;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (color, set_color) = use_theme_color(Some("#4f46e5".to_string()));
+///
+/// set_color.set(Some("#db2777".to_string())); // change the theme color
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Setting the color to `None` restores the theme color that was present before. The same
+/// happens when the component that called this is cleaned up.
+///
+/// ### Per Color Mode
+///
+/// Pass a `Signal` to follow the color mode of [`fn@crate::use_color_mode`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_color_mode, use_theme_color, ColorMode, UseColorModeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseColorModeReturn { mode, .. } = use_color_mode();
+///
+/// use_theme_color(Signal::derive(move || {
+///     Some(match mode.get() {
+///         ColorMode::Dark => "#111827".to_string(),
+///         _ => "#ffffff".to_string(),
+///     })
+/// }));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Alternatively you can call this once per mode with the option `media` set to
+/// `"(prefers-color-scheme: dark)"` and `"(prefers-color-scheme: light)"` respectively. Then the
+/// browser picks the color by itself, but only based on the system preference.
+///
+/// ### Windows
+///
+/// Set the option `ms_application` to `true` to also update the legacy
+/// `msapplication-navbutton-color` and `msapplication-TileColor` tags that are used by Windows
+/// for pinned sites.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_theme_color_with_options, UseThemeColorOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (color, set_color) = use_theme_color_with_options(
+///     Some("#4f46e5".to_string()),
+///     UseThemeColorOptions::default().ms_application(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server only the signals work but no tag is changed.
+pub fn use_theme_color(
+    color: impl Into<MaybeRwSignal<Option<String>>>,
+) -> (Signal<Option<String>>, WriteSignal<Option<String>>) {
+    use_theme_color_with_options(color, UseThemeColorOptions::default())
+}
+
+/// Version of [`use_theme_color`] that takes a `UseThemeColorOptions`. See [`use_theme_color`] for how to use.
+pub fn use_theme_color_with_options(
+    color: impl Into<MaybeRwSignal<Option<String>>>,
+    options: UseThemeColorOptions,
+) -> (Signal<Option<String>>, WriteSignal<Option<String>>) {
+    let UseThemeColorOptions {
+        media,
+        ms_application,
+    } = options;
+
+    let (color, set_color) = color.into().into_signal();
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let mut names = vec!["theme-color"];
+        if ms_application {
+            names.extend(["msapplication-navbutton-color", "msapplication-TileColor"]);
+        }
+
+        // only the theme color supports the media attribute
+        let tags = names
+            .into_iter()
+            .map(|name| {
+                let media = (name == "theme-color").then(|| media.clone()).flatten();
+                let original = read_meta(name, media.as_deref());
+
+                (name, media, original)
+            })
+            .collect::<Vec<_>>();
+
+        let restore = {
+            let tags = tags.clone();
+
+            move || {
+                for (name, media, original) in &tags {
+                    write_meta(name, media.as_deref(), original.as_deref());
+                }
+            }
+        };
+
+        let _ = Effect::watch(
+            move || color.get(),
+            {
+                let restore = restore.clone();
+
+                move |color: &Option<String>, _, _| match color {
+                    Some(color) => {
+                        for (name, media, _) in &tags {
+                            write_meta(name, media.as_deref(), Some(color.as_str()));
+                        }
+                    }
+                    None => restore(),
+                }
+            },
+            true,
+        );
+
+        on_cleanup(restore);
+    }
+
+    (color, set_color)
+}
+
+#[cfg(not(feature = "ssr"))]
+fn meta_selector(name: &str, media: Option<&str>) -> String {
+    match media {
+        Some(media) => format!("meta[name=\"{name}\"][media=\"{media}\"]"),
+        None => format!("meta[name=\"{name}\"]:not([media])"),
+    }
+}
+
+/// Returns the content of the meta tag or `None` if there is no such tag.
+#[cfg(not(feature = "ssr"))]
+fn read_meta(name: &str, media: Option<&str>) -> Option<String> {
+    document()
+        .query_selector(&meta_selector(name, media))
+        .ok()
+        .flatten()
+        .map(|meta| meta.get_attribute("content").unwrap_or_default())
+}
+
+/// Sets the content of the meta tag and creates it if necessary. `None` removes the tag.
+#[cfg(not(feature = "ssr"))]
+fn write_meta(name: &str, media: Option<&str>, content: Option<&str>) {
+    let document = document();
+    let meta = document
+        .query_selector(&meta_selector(name, media))
+        .ok()
+        .flatten();
+
+    match (meta, content) {
+        (Some(meta), Some(content)) => {
+            let _ = meta.set_attribute("content", content);
+        }
+        (Some(meta), None) => meta.remove(),
+        (None, Some(content)) => {
+            let Some(head) = document.head() else {
+                return;
+            };
+            let Ok(meta) = document.create_element("meta") else {
+                return;
+            };
+
+            let _ = meta.set_attribute("name", name);
+            if let Some(media) = media {
+                let _ = meta.set_attribute("media", media);
+            }
+            let _ = meta.set_attribute("content", content);
+
+            let _ = head.append_child(&meta);
+        }
+        (None, None) => {}
+    }
+}
+
+/// Options for [`use_theme_color_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseThemeColorOptions {
+    /// Value of the `media` attribute of the theme color tag, e.g.
+    /// `"(prefers-color-scheme: dark)"`. Only the tag with exactly this media query is updated.
+    /// Defaults to `None` which means the tag without a `media` attribute.
+    #[builder(into)]
+    media: Option<String>,
+
+    /// If `true` the tags `msapplication-navbutton-color` and `msapplication-TileColor` are
+    /// updated as well. Defaults to `false`.
+    ms_application: bool,
+}
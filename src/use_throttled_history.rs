@@ -0,0 +1,133 @@
+use crate::core::UseRwSignal;
+use crate::utils::ThrottleOptions;
+use crate::{use_history_with_options, UseHistoryOptions, UseHistoryReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// A throttled version of [`fn@crate::use_history`].
+///
+/// At most one snapshot is recorded every `ms` milliseconds. This bounds the memory used by the
+/// history of signals that change with a high frequency, like the position of a dragged element.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_throttled_history)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_throttled_history;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let position = RwSignal::new((0.0, 0.0));
+///
+/// let history = use_throttled_history(position, 500.0);
+///
+/// view! {
+///     <div on:pointermove=move |e| position.set((e.client_x() as f64, e.client_y() as f64))>
+///         "Draw here"
+///     </div>
+///     <button disabled=move || !history.can_undo.get() on:click=move |_| history.undo()>
+///         "Undo"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// This is only shorthand for `use_history_with_options(source, UseHistoryOptions::default().throttle(ms))`.
+/// It returns the same [`UseHistoryReturn`] with `undo`, `redo`, `commit` and so on.
+///
+/// By default the first change is recorded right away and the last change of every interval is
+/// recorded at its end. A change that is still waiting to be recorded is dropped when `undo`,
+/// `redo`, `commit` or `reset` is called in the meantime. It's also dropped if the current
+/// component is cleaned up before it's recorded.
+///
+/// There's also [`use_throttled_history_with_options`] where you can specify the capacity and
+/// whether changes are recorded at the start and the end of an interval.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_throttled_history_with_options, UseThrottledHistoryOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let position = RwSignal::new((0.0, 0.0));
+/// #
+/// let history = use_throttled_history_with_options(
+///     position,
+///     500.0,
+///     UseThrottledHistoryOptions::default()
+///         .capacity(50_usize)
+///         .trailing(false),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server changes aren't recorded automatically, just like with [`fn@crate::use_history`].
+///
+/// ## See also
+///
+/// * [`fn@crate::use_history`]
+/// * [`fn@crate::watch_throttled`]
+pub fn use_throttled_history<T>(
+    source: impl Into<UseRwSignal<T>>,
+    ms: impl Into<Signal<f64>>,
+) -> UseHistoryReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    use_throttled_history_with_options(source, ms, UseThrottledHistoryOptions::default())
+}
+
+/// Version of [`use_throttled_history`] that takes a `UseThrottledHistoryOptions`. See [`use_throttled_history`] for how to use.
+pub fn use_throttled_history_with_options<T>(
+    source: impl Into<UseRwSignal<T>>,
+    ms: impl Into<Signal<f64>>,
+    options: UseThrottledHistoryOptions,
+) -> UseHistoryReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let UseThrottledHistoryOptions {
+        capacity,
+        trailing,
+        leading,
+    } = options;
+
+    use_history_with_options(
+        source,
+        UseHistoryOptions::default()
+            .capacity::<usize>(capacity)
+            .throttle_with_options(ms, ThrottleOptions { trailing, leading }),
+    )
+}
+
+/// Options for [`use_throttled_history_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseThrottledHistoryOptions {
+    /// Maximum number of snapshots in the undo stack. Defaults to `None` (no limit).
+    #[builder(into)]
+    capacity: Option<usize>,
+
+    /// Record the last change of an interval at its end. Defaults to `true`.
+    trailing: bool,
+
+    /// Record the first change of an interval right away. Defaults to `true`.
+    leading: bool,
+}
+
+impl Default for UseThrottledHistoryOptions {
+    fn default() -> Self {
+        Self {
+            capacity: None,
+            trailing: true,
+            leading: true,
+        }
+    }
+}
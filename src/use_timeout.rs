@@ -0,0 +1,147 @@
+use crate::{use_timeout_fn, UseTimeoutFnReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Reactive boolean that becomes `true` after a delay.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_timeout)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_timeout;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let ready = use_timeout(3000.0);
+///
+/// view! {
+///     <Show when=move || !ready.get()>
+///         <div class="splash">"Loading..."</div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ### Controls
+///
+/// With `use_timeout_with_controls` the timeout can be started again and stopped. Starting
+/// sets `ready` back to `false`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_timeout_with_controls_and_options, UseTimeoutOptions, UseTimeoutReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseTimeoutReturn { ready, start, stop, .. } = use_timeout_with_controls_and_options(
+///     5000.0,
+///     UseTimeoutOptions::default().immediate(false),
+/// );
+///
+/// let show_notice = move || start();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The delay can also be a signal. A change only affects timeouts that are started afterwards.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `start` and `stop` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_timeout_with_controls`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `ready` is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_timeout_fn`]
+pub fn use_timeout(delay: impl Into<Signal<f64>>) -> Signal<bool> {
+    use_timeout_with_controls(delay).ready
+}
+
+/// Version of [`use_timeout`] that takes a `UseTimeoutOptions`. See [`use_timeout`] for how to use.
+pub fn use_timeout_with_options(
+    delay: impl Into<Signal<f64>>,
+    options: UseTimeoutOptions,
+) -> Signal<bool> {
+    use_timeout_with_controls_and_options(delay, options).ready
+}
+
+/// Version of [`use_timeout`] that returns controls. See [`use_timeout`] for how to use.
+pub fn use_timeout_with_controls(
+    delay: impl Into<Signal<f64>>,
+) -> UseTimeoutReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_timeout_with_controls_and_options(delay, UseTimeoutOptions::default())
+}
+
+/// Version of [`use_timeout`] that takes a `UseTimeoutOptions` and returns controls. See [`use_timeout`] for how to use.
+pub fn use_timeout_with_controls_and_options(
+    delay: impl Into<Signal<f64>>,
+    options: UseTimeoutOptions,
+) -> UseTimeoutReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseTimeoutOptions { immediate } = options;
+
+    let (ready, set_ready) = signal(false);
+
+    let UseTimeoutFnReturn {
+        start,
+        stop,
+        is_pending,
+        ..
+    } = use_timeout_fn(move |_: ()| set_ready.set(true), delay);
+
+    let start = move || {
+        set_ready.set(false);
+        start(());
+    };
+
+    if immediate {
+        start();
+    }
+
+    UseTimeoutReturn {
+        ready: ready.into(),
+        is_pending,
+        start,
+        stop,
+    }
+}
+
+/// Options for [`use_timeout_with_options`] and [`use_timeout_with_controls_and_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTimeoutOptions {
+    /// Start the timeout immediately. Defaults to `true`.
+    immediate: bool,
+}
+
+impl Default for UseTimeoutOptions {
+    fn default() -> Self {
+        Self { immediate: true }
+    }
+}
+
+/// Return type of [`use_timeout_with_controls`].
+pub struct UseTimeoutReturn<StartFn, StopFn>
+where
+    StartFn: Fn() + Clone + Send + Sync,
+    StopFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the delay has passed since the timeout was started.
+    pub ready: Signal<bool>,
+
+    /// Whether the timeout is pending.
+    pub is_pending: Signal<bool>,
+
+    /// Start the timeout again. Sets `ready` to `false` until the delay has passed.
+    pub start: StartFn,
+
+    /// Stop the timeout. `ready` doesn't change.
+    pub stop: StopFn,
+}
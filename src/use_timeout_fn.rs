@@ -66,8 +66,7 @@ where
             let timer = Arc::clone(&timer);
 
             move || {
-                let timer = timer.lock().unwrap();
-                if let Some(timer) = *timer {
+                if let Some(timer) = timer.lock().unwrap().take() {
                     timer.clear();
                 }
             }
@@ -85,8 +84,11 @@ where
         start = {
             let timer = Arc::clone(&timer);
             let callback = callback.clone();
+            let clear = clear.clone();
 
             sendwrap_fn!(move |arg: Arg| {
+                // restarting replaces a pending timeout instead of running both
+                clear();
                 set_pending.set(true);
 
                 let handle = set_timeout_with_handle(
@@ -143,6 +145,8 @@ where
     pub is_pending: Signal<bool>,
 
     /// Start the timeout. The `callback` will be called after `delay` milliseconds.
+    /// If the timeout is already pending it is restarted and the previous `callback` call is
+    /// cancelled.
     pub start: StartFn,
 
     /// Stop the timeout. If the timeout was still pending the `callback` is not called.
@@ -48,6 +48,37 @@ use std::sync::Arc;
 /// # }
 /// ```
 ///
+/// ### Options
+///
+/// By default the timestamp is updated on every animation frame. Pass an interval in milliseconds
+/// to update it less often. The `offset` in milliseconds is added to the timestamp and `callback`
+/// is called with every new value. With `immediate(false)` the updating only starts when
+/// `resume` is called.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_timestamp_with_controls_and_options, UseTimestampOptions, UseTimestampReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseTimestampReturn {
+///     timestamp,
+///     resume,
+///     ..
+/// } = use_timestamp_with_controls_and_options(
+///     UseTimestampOptions::default()
+///         .interval(1000)
+///         .offset(60_000.0) // one minute ahead
+///         .immediate(false)
+///         .callback(|timestamp| leptos::logging::log!("{timestamp}")),
+/// );
+///
+/// resume();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause` and `resume` of the `..._with_controls` versions are
@@ -21,6 +21,48 @@ use leptos::prelude::*;
 /// # }
 /// ```
 ///
+/// `toggle` flips the value. Use `toggle_with` or `set_value` to set it explicitly.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_toggle;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_open = use_toggle(false);
+///
+/// (is_open.toggle)(); // value is `true` now
+/// is_open.toggle_with(false); // value is `false` again
+/// is_open.toggle_with(false); // value stays `false`
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Custom Values
+///
+/// Instead of `true` and `false` the value can switch between any two values.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_toggle_with_options, UseToggleOptions, UseToggleReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseToggleReturn { toggle, value, set_value } = use_toggle_with_options(
+///     "light",
+///     UseToggleOptions::new("dark", "light"),
+/// );
+///
+/// toggle(); // value is "dark" now
+/// toggle(); // value is "light" again
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// If the value is neither of the two values, `toggle` sets it to the truthy value.
+///
 /// ## See also
 ///
 /// * [`fn@crate::use_cycle_list`]
@@ -28,11 +70,33 @@ use leptos::prelude::*;
 pub fn use_toggle(
     initial_value: impl Into<MaybeRwSignal<bool>>,
 ) -> UseToggleReturn<impl Fn() + Clone + Send + Sync + 'static> {
+    use_toggle_with_options(initial_value, UseToggleOptions::default())
+}
+
+/// Version of [`use_toggle`] that takes a `UseToggleOptions`. See [`use_toggle`] for how to use.
+pub fn use_toggle_with_options<T>(
+    initial_value: impl Into<MaybeRwSignal<T>>,
+    options: UseToggleOptions<T>,
+) -> UseToggleReturn<impl Fn() + Clone + Send + Sync + 'static, T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let UseToggleOptions {
+        truthy_value,
+        falsy_value,
+    } = options;
+
     let initial_value = initial_value.into();
     let (value, set_value) = initial_value.into_signal();
 
     let toggle = move || {
-        set_value.update(|v| *v = !*v);
+        set_value.update(|v| {
+            *v = if *v == truthy_value {
+                falsy_value.clone()
+            } else {
+                truthy_value.clone()
+            }
+        });
     };
 
     UseToggleReturn {
@@ -42,16 +106,69 @@ pub fn use_toggle(
     }
 }
 
+/// Options for [`use_toggle_with_options`].
+pub struct UseToggleOptions<T> {
+    /// The value that `toggle` switches to from any other value. Defaults to `true`.
+    truthy_value: T,
+
+    /// The value that `toggle` switches to from the truthy value. Defaults to `false`.
+    falsy_value: T,
+}
+
+impl<T> UseToggleOptions<T> {
+    /// Toggles between `truthy_value` and `falsy_value`.
+    pub fn new(truthy_value: T, falsy_value: T) -> Self {
+        Self {
+            truthy_value,
+            falsy_value,
+        }
+    }
+
+    /// The value that `toggle` switches to from any other value. Defaults to `true`.
+    pub fn truthy_value(self, truthy_value: T) -> Self {
+        Self {
+            truthy_value,
+            ..self
+        }
+    }
+
+    /// The value that `toggle` switches to from the truthy value. Defaults to `false`.
+    pub fn falsy_value(self, falsy_value: T) -> Self {
+        Self {
+            falsy_value,
+            ..self
+        }
+    }
+}
+
+impl Default for UseToggleOptions<bool> {
+    fn default() -> Self {
+        Self::new(true, false)
+    }
+}
+
 /// Return type of [`fn@crate::use_toggle`].
 // #[doc(cfg(feature = "use_toggle"))]
-pub struct UseToggleReturn<F>
+pub struct UseToggleReturn<F, T = bool>
 where
     F: Fn() + Clone + Send + Sync + 'static,
+    T: Send + Sync + 'static,
 {
-    /// Toggles the value between `true` and `false`.
+    /// Toggles the value between the truthy and the falsy value (`true` and `false` by default).
     pub toggle: F,
     /// The current value as signal.
-    pub value: Signal<bool>,
+    pub value: Signal<T>,
     /// Sets the current value to the given value.
-    pub set_value: WriteSignal<bool>,
+    pub set_value: WriteSignal<T>,
+}
+
+impl<F, T> UseToggleReturn<F, T>
+where
+    F: Fn() + Clone + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+{
+    /// Sets the current value to the given value instead of toggling it.
+    pub fn toggle_with(&self, value: T) {
+        self.set_value.set(value);
+    }
 }
@@ -0,0 +1,176 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use leptos::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive camera flashlight (torch) of a video track.
+///
+/// Turns the torch of a camera on and off by applying the `torch` constraint to the track.
+/// This is mostly supported by the back cameras of mobile phones in Chromium based browsers and
+/// is useful for scanner UIs in dark environments.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_torch)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_torch, use_user_media, UseTorchReturn, UseUserMediaReturn};
+/// # use wasm_bindgen::JsCast;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, .. } = use_user_media();
+///
+/// let video_track = Signal::derive_local(move || {
+///     stream
+///         .get()
+///         .and_then(Result::ok)
+///         .and_then(|stream| stream.get_video_tracks().get(0).dyn_into().ok())
+/// });
+///
+/// let torch = use_torch(video_track);
+/// let UseTorchReturn { is_supported, is_on, .. } = torch;
+///
+/// view! {
+///     <Show when=move || is_supported.get()>
+///         <button on:click=move |_| torch.toggle()>
+///             {move || if is_on.get() { "Light off" } else { "Light on" }}
+///         </button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// `is_supported` is updated whenever the track changes. Applying the constraint is
+/// asynchronous, so `is_on` only changes after the camera has actually switched the torch.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_on` are always `false` and the methods do nothing.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_user_media`]
+pub fn use_torch(
+    video_track: impl Into<Signal<Option<web_sys::MediaStreamTrack>, LocalStorage>>,
+) -> UseTorchReturn {
+    let video_track = video_track.into();
+
+    let (is_supported, set_supported) = signal(false);
+    let (is_on, set_on) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = Effect::watch(
+            move || video_track.get(),
+            move |track, _, _| {
+                set_supported.set(track.as_ref().is_some_and(|track| {
+                    call_method(track, "getCapabilities")
+                        .is_some_and(|capabilities| get_bool(&capabilities, "torch"))
+                }));
+
+                set_on.set(track.as_ref().is_some_and(|track| {
+                    call_method(track, "getSettings")
+                        .is_some_and(|settings| get_bool(&settings, "torch"))
+                }));
+            },
+            true,
+        );
+    }
+
+    UseTorchReturn {
+        is_supported: is_supported.into(),
+        is_on: is_on.into(),
+        set_on,
+        video_track,
+    }
+}
+
+/// Calls a method without arguments that isn't (stably) available in `web_sys`.
+#[cfg(not(feature = "ssr"))]
+fn call_method(target: &JsValue, name: &str) -> Option<JsValue> {
+    js_sys::Reflect::get(target, &JsValue::from_str(name))
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?
+        .call0(target)
+        .ok()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn get_bool(target: &JsValue, name: &str) -> bool {
+    js_sys::Reflect::get(target, &JsValue::from_str(name))
+        .ok()
+        .and_then(|value| value.as_bool())
+        .unwrap_or_default()
+}
+
+/// Return type of [`use_torch`].
+#[derive(Clone, Copy)]
+pub struct UseTorchReturn {
+    /// Whether the camera of the track has a torch that can be controlled.
+    pub is_supported: Signal<bool>,
+
+    /// Whether the torch is currently on.
+    pub is_on: Signal<bool>,
+
+    set_on: WriteSignal<bool>,
+    video_track: Signal<Option<web_sys::MediaStreamTrack>, LocalStorage>,
+}
+
+impl UseTorchReturn {
+    /// Turns the torch on.
+    pub fn turn_on(&self) {
+        self.apply(true);
+    }
+
+    /// Turns the torch off.
+    pub fn turn_off(&self) {
+        self.apply(false);
+    }
+
+    /// Turns the torch on if it's off and off if it's on.
+    pub fn toggle(&self) {
+        self.apply(!self.is_on.get_untracked());
+    }
+
+    fn apply(&self, on: bool) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !self.is_supported.get_untracked() {
+                return;
+            }
+
+            let Some(track) = self.video_track.get_untracked() else {
+                return;
+            };
+
+            let set_on = self.set_on;
+
+            leptos::task::spawn_local(async move {
+                let torch = js_sys::Object::new();
+                let _ = js_sys::Reflect::set(&torch, &"torch".into(), &JsValue::from_bool(on));
+
+                let constraints = js_sys::Object::new();
+                let _ = js_sys::Reflect::set(
+                    &constraints,
+                    &"advanced".into(),
+                    &js_sys::Array::of1(&torch),
+                );
+
+                let Ok(promise) = track.apply_constraints_with_constraints(
+                    constraints.unchecked_ref::<web_sys::MediaTrackConstraints>(),
+                ) else {
+                    return;
+                };
+
+                if wasm_bindgen_futures::JsFuture::from(promise).await.is_ok() {
+                    set_on.set(on);
+                }
+            });
+        }
+    }
+}
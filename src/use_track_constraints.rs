@@ -0,0 +1,336 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut};
+use leptos::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive zoom, focus and exposure controls of a camera track.
+///
+/// Reads the [capabilities](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/getCapabilities)
+/// and current settings of a video track and changes them by calling
+/// [`applyConstraints`](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/applyConstraints).
+/// Which controls are available depends on the camera and the browser.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_track_constraints)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_track_constraints, use_user_media, UseTrackConstraintsReturn, UseUserMediaReturn};
+/// # use wasm_bindgen::JsCast;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, .. } = use_user_media();
+///
+/// let video_track = Signal::derive_local(move || {
+///     stream
+///         .get()
+///         .and_then(Result::ok)
+///         .and_then(|stream| stream.get_video_tracks().get(0).dyn_into().ok())
+/// });
+///
+/// let constraints = use_track_constraints(video_track);
+/// let UseTrackConstraintsReturn { zoom_range, zoom, .. } = constraints;
+///
+/// view! {
+///     <Show when=move || zoom_range.get().is_some()>
+///         <input
+///             type="range"
+///             min=move || zoom_range.get().map(|range| range.min)
+///             max=move || zoom_range.get().map(|range| range.max)
+///             step=move || zoom_range.get().map(|range| range.step)
+///             prop:value=move || zoom.get()
+///             on:input=move |e| {
+///                 if let Ok(value) = event_target_value(&e).parse() {
+///                     constraints.set_zoom(value);
+///                 }
+///             }
+///         />
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// A range is `None` if the camera doesn't support that control. The current values are updated
+/// after the constraints have been applied successfully.
+///
+/// Setting the focus distance switches to manual focus and setting the exposure compensation
+/// switches to continuous exposure. The available modes are in `focus_modes` and
+/// `exposure_modes` and can be set with `set_focus_mode` and `set_exposure_mode`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server all ranges and values are `None`, the modes are empty and the setters do
+/// nothing.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_user_media`]
+/// * [`fn@crate::use_torch`]
+pub fn use_track_constraints(
+    video_track: impl Into<Signal<Option<web_sys::MediaStreamTrack>, LocalStorage>>,
+) -> UseTrackConstraintsReturn {
+    let video_track = video_track.into();
+
+    let (capabilities, set_capabilities) = signal(TrackCapabilities::default());
+    let (settings, set_settings) = signal(TrackSettings::default());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = Effect::watch(
+            move || video_track.get(),
+            move |track, _, _| {
+                set_capabilities.set(
+                    track
+                        .as_ref()
+                        .and_then(|track| call_method(track, "getCapabilities"))
+                        .map(|capabilities| TrackCapabilities::from_js(&capabilities))
+                        .unwrap_or_default(),
+                );
+
+                set_settings.set(
+                    track
+                        .as_ref()
+                        .and_then(|track| call_method(track, "getSettings"))
+                        .map(|settings| TrackSettings::from_js(&settings))
+                        .unwrap_or_default(),
+                );
+            },
+            true,
+        );
+    }
+
+    UseTrackConstraintsReturn {
+        zoom_range: Signal::derive(move || capabilities.read().zoom),
+        focus_distance_range: Signal::derive(move || capabilities.read().focus_distance),
+        exposure_compensation_range: Signal::derive(move || {
+            capabilities.read().exposure_compensation
+        }),
+        focus_modes: Signal::derive(move || capabilities.read().focus_mode.clone()),
+        exposure_modes: Signal::derive(move || capabilities.read().exposure_mode.clone()),
+        zoom: Signal::derive(move || settings.read().zoom),
+        focus_distance: Signal::derive(move || settings.read().focus_distance),
+        exposure_compensation: Signal::derive(move || settings.read().exposure_compensation),
+        focus_mode: Signal::derive(move || settings.read().focus_mode.clone()),
+        exposure_mode: Signal::derive(move || settings.read().exposure_mode.clone()),
+        set_settings,
+        video_track,
+    }
+}
+
+/// Calls a method without arguments that isn't (stably) available in `web_sys`.
+#[cfg(not(feature = "ssr"))]
+fn call_method(target: &JsValue, name: &str) -> Option<JsValue> {
+    js_sys::Reflect::get(target, &JsValue::from_str(name))
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?
+        .call0(target)
+        .ok()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn get(target: &JsValue, name: &str) -> JsValue {
+    js_sys::Reflect::get(target, &JsValue::from_str(name)).unwrap_or(JsValue::UNDEFINED)
+}
+
+/// Range of a numeric camera control.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MediaTrackRange {
+    pub min: f64,
+    pub max: f64,
+    /// Smallest possible change. `0.0` if the browser doesn't tell.
+    pub step: f64,
+}
+
+impl MediaTrackRange {
+    #[cfg(not(feature = "ssr"))]
+    fn from_js(value: &JsValue) -> Option<Self> {
+        if !value.is_object() {
+            return None;
+        }
+
+        Some(Self {
+            min: get(value, "min").as_f64()?,
+            max: get(value, "max").as_f64()?,
+            step: get(value, "step").as_f64().unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TrackCapabilities {
+    zoom: Option<MediaTrackRange>,
+    focus_distance: Option<MediaTrackRange>,
+    exposure_compensation: Option<MediaTrackRange>,
+    focus_mode: Vec<String>,
+    exposure_mode: Vec<String>,
+}
+
+impl TrackCapabilities {
+    #[cfg(not(feature = "ssr"))]
+    fn from_js(capabilities: &JsValue) -> Self {
+        let modes = |name: &str| {
+            get(capabilities, name)
+                .dyn_into::<js_sys::Array>()
+                .map(|modes| modes.iter().filter_map(|mode| mode.as_string()).collect())
+                .unwrap_or_default()
+        };
+
+        Self {
+            zoom: MediaTrackRange::from_js(&get(capabilities, "zoom")),
+            focus_distance: MediaTrackRange::from_js(&get(capabilities, "focusDistance")),
+            exposure_compensation: MediaTrackRange::from_js(&get(
+                capabilities,
+                "exposureCompensation",
+            )),
+            focus_mode: modes("focusMode"),
+            exposure_mode: modes("exposureMode"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TrackSettings {
+    zoom: Option<f64>,
+    focus_distance: Option<f64>,
+    exposure_compensation: Option<f64>,
+    focus_mode: Option<String>,
+    exposure_mode: Option<String>,
+}
+
+impl TrackSettings {
+    #[cfg(not(feature = "ssr"))]
+    fn from_js(settings: &JsValue) -> Self {
+        Self {
+            zoom: get(settings, "zoom").as_f64(),
+            focus_distance: get(settings, "focusDistance").as_f64(),
+            exposure_compensation: get(settings, "exposureCompensation").as_f64(),
+            focus_mode: get(settings, "focusMode").as_string(),
+            exposure_mode: get(settings, "exposureMode").as_string(),
+        }
+    }
+}
+
+/// Return type of [`use_track_constraints`].
+#[derive(Clone, Copy)]
+pub struct UseTrackConstraintsReturn {
+    /// Supported zoom levels or `None` if zooming isn't supported.
+    pub zoom_range: Signal<Option<MediaTrackRange>>,
+
+    /// Supported focus distances in meters or `None` if manual focus isn't supported.
+    pub focus_distance_range: Signal<Option<MediaTrackRange>>,
+
+    /// Supported exposure compensation or `None` if it isn't supported.
+    pub exposure_compensation_range: Signal<Option<MediaTrackRange>>,
+
+    /// Supported focus modes like `"manual"`, `"single-shot"` or `"continuous"`.
+    pub focus_modes: Signal<Vec<String>>,
+
+    /// Supported exposure modes like `"manual"`, `"single-shot"` or `"continuous"`.
+    pub exposure_modes: Signal<Vec<String>>,
+
+    /// Current zoom level
+    pub zoom: Signal<Option<f64>>,
+
+    /// Current focus distance in meters
+    pub focus_distance: Signal<Option<f64>>,
+
+    /// Current exposure compensation
+    pub exposure_compensation: Signal<Option<f64>>,
+
+    /// Current focus mode
+    pub focus_mode: Signal<Option<String>>,
+
+    /// Current exposure mode
+    pub exposure_mode: Signal<Option<String>>,
+
+    set_settings: WriteSignal<TrackSettings>,
+    video_track: Signal<Option<web_sys::MediaStreamTrack>, LocalStorage>,
+}
+
+impl UseTrackConstraintsReturn {
+    /// Sets the zoom level. Values outside of `zoom_range` are clamped.
+    pub fn set_zoom(&self, zoom: f64) {
+        let zoom = clamp_to(zoom, self.zoom_range.get_untracked());
+        self.apply(&[("zoom", zoom.into())]);
+    }
+
+    /// Switches to manual focus and sets the focus distance in meters. Values outside of
+    /// `focus_distance_range` are clamped.
+    pub fn set_focus_distance(&self, focus_distance: f64) {
+        let focus_distance = clamp_to(focus_distance, self.focus_distance_range.get_untracked());
+        self.apply(&[
+            ("focusMode", "manual".into()),
+            ("focusDistance", focus_distance.into()),
+        ]);
+    }
+
+    /// Sets the exposure compensation. Values outside of `exposure_compensation_range` are
+    /// clamped.
+    pub fn set_exposure_compensation(&self, exposure_compensation: f64) {
+        let exposure_compensation = clamp_to(
+            exposure_compensation,
+            self.exposure_compensation_range.get_untracked(),
+        );
+        self.apply(&[
+            ("exposureMode", "continuous".into()),
+            ("exposureCompensation", exposure_compensation.into()),
+        ]);
+    }
+
+    /// Sets the focus mode, e.g. `"continuous"` to go back to autofocus.
+    pub fn set_focus_mode(&self, focus_mode: &str) {
+        self.apply(&[("focusMode", focus_mode.into())]);
+    }
+
+    /// Sets the exposure mode, e.g. `"continuous"` to go back to automatic exposure.
+    pub fn set_exposure_mode(&self, exposure_mode: &str) {
+        self.apply(&[("exposureMode", exposure_mode.into())]);
+    }
+
+    /// Applies the constraints as an advanced constraint set and re-reads the settings afterwards.
+    fn apply(&self, constraints: &[(&str, JsValue)]) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(track) = self.video_track.get_untracked() else {
+                return;
+            };
+
+            let constraint_set = js_sys::Object::new();
+            for (name, value) in constraints {
+                let _ = js_sys::Reflect::set(&constraint_set, &JsValue::from_str(name), value);
+            }
+
+            let track_constraints = js_sys::Object::new();
+            js!(track_constraints["advanced"] = js_sys::Array::of1(&constraint_set));
+
+            let set_settings = self.set_settings;
+
+            leptos::task::spawn_local(async move {
+                let Ok(promise) = track.apply_constraints_with_constraints(
+                    track_constraints.unchecked_ref::<web_sys::MediaTrackConstraints>(),
+                ) else {
+                    return;
+                };
+
+                if js_fut!(promise).await.is_ok() {
+                    if let Some(settings) = call_method(&track, "getSettings") {
+                        set_settings.set(TrackSettings::from_js(&settings));
+                    }
+                }
+            });
+        }
+    }
+}
+
+fn clamp_to(value: f64, range: Option<MediaTrackRange>) -> f64 {
+    match range {
+        Some(MediaTrackRange { min, max, .. }) if min <= max => value.clamp(min, max),
+        _ => value,
+    }
+}
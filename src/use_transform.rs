@@ -0,0 +1,265 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Two-way binding of an element's CSS `transform`.
+///
+/// Exposes translation, scale and rotation as individual signals that are composed into the
+/// `transform` style of `target`. When the element becomes available its existing transform is
+/// parsed to initialize the signals. This makes it easy to build pan/zoom canvases together
+/// with [`fn@crate::use_draggable`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_transform)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_transform, UseTransformReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseTransformReturn {
+///     set_translate_x,
+///     scale,
+///     set_scale,
+///     set_rotate,
+///     ..
+/// } = use_transform(el);
+///
+/// view! {
+///     <div node_ref=el style="transform: translate(10px, 20px)">"Hello"</div>
+///
+///     <button on:click=move |_| set_scale.set(scale.get_untracked() * 1.5)>"Zoom in"</button>
+///     <button on:click=move |_| set_rotate.update(|r| *r += 90.0)>"Rotate"</button>
+///     <button on:click=move |_| set_translate_x.set(0.0)>"Align left"</button>
+/// }
+/// # }
+/// ```
+///
+/// The transform is always written in the order `translate(..) rotate(..) scale(..)`. Existing
+/// transforms are parsed from the computed matrix so any skew they might contain is lost.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signals keep their initial values and the element isn't touched. You can use
+/// the returned `transform` signal to render the style yourself, e.g. `style:transform=transform`.
+pub fn use_transform<El, M>(target: El) -> UseTransformReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_transform_with_options(target, UseTransformOptions::default())
+}
+
+/// Version of [`use_transform`] that takes a `UseTransformOptions`. See [`use_transform`] for how to use.
+pub fn use_transform_with_options<El, M>(
+    target: El,
+    options: UseTransformOptions,
+) -> UseTransformReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseTransformOptions {
+        initial_value,
+        parse_existing,
+    } = options;
+
+    let (translate_x, set_translate_x) = signal(initial_value.translate_x);
+    let (translate_y, set_translate_y) = signal(initial_value.translate_y);
+    let (scale, set_scale) = signal(initial_value.scale);
+    let (rotate, set_rotate) = signal(initial_value.rotate);
+
+    let transform = Signal::derive(move || {
+        Transform {
+            translate_x: translate_x.get(),
+            translate_y: translate_y.get(),
+            scale: scale.get(),
+            rotate: rotate.get(),
+        }
+        .to_css()
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let target = target.into_element_maybe_signal();
+
+        // the element whose transform has already been parsed
+        let parsed_el = StoredValue::new_local(None::<web_sys::Element>);
+
+        Effect::new(move |_| {
+            let Some(el) = target.get() else {
+                return;
+            };
+            let transform = transform.get();
+
+            if parsed_el.with_value(|parsed_el| parsed_el.as_ref() != Some(&el)) {
+                parsed_el.set_value(Some(el.clone()));
+
+                if parse_existing {
+                    let existing = window()
+                        .get_computed_style(&el)
+                        .ok()
+                        .flatten()
+                        .and_then(|style| style.get_property_value("transform").ok())
+                        .and_then(|value| Transform::from_matrix(&value));
+
+                    if let Some(existing) = existing {
+                        set_translate_x.set(existing.translate_x);
+                        set_translate_y.set(existing.translate_y);
+                        set_scale.set(existing.scale);
+                        set_rotate.set(existing.rotate);
+
+                        // the effect runs again with the parsed values
+                        return;
+                    }
+                }
+            }
+
+            let _ = el
+                .unchecked_into::<web_sys::HtmlElement>()
+                .style()
+                .set_property("transform", &transform);
+        });
+    }
+
+    UseTransformReturn {
+        translate_x: translate_x.into(),
+        set_translate_x,
+        translate_y: translate_y.into(),
+        set_translate_y,
+        scale: scale.into(),
+        set_scale,
+        rotate: rotate.into(),
+        set_rotate,
+        transform,
+    }
+}
+
+/// Options for [`use_transform_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTransformOptions {
+    /// The values of the signals before the element is available or if it has no transform.
+    /// Defaults to the identity transform.
+    initial_value: Transform,
+
+    /// If `true` the existing transform of the element is parsed to initialize the signals.
+    /// If `false` it is overwritten with `initial_value`. Defaults to `true`.
+    parse_existing: bool,
+}
+
+impl Default for UseTransformOptions {
+    fn default() -> Self {
+        Self {
+            initial_value: Transform::default(),
+            parse_existing: true,
+        }
+    }
+}
+
+/// The parts of a transform handled by [`fn@crate::use_transform`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// Horizontal translation in pixels.
+    pub translate_x: f64,
+
+    /// Vertical translation in pixels.
+    pub translate_y: f64,
+
+    /// Uniform scale factor.
+    pub scale: f64,
+
+    /// Rotation in degrees (clockwise).
+    pub rotate: f64,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            translate_x: 0.0,
+            translate_y: 0.0,
+            scale: 1.0,
+            rotate: 0.0,
+        }
+    }
+}
+
+impl Transform {
+    /// Returns the CSS `transform` value.
+    pub fn to_css(&self) -> String {
+        format!(
+            "translate({}px, {}px) rotate({}deg) scale({})",
+            self.translate_x, self.translate_y, self.rotate, self.scale
+        )
+    }
+
+    /// Decomposes a computed transform like `matrix(a, b, c, d, e, f)` or `matrix3d(...)`.
+    /// Returns `None` for `none` or values that can't be parsed.
+    pub fn from_matrix(value: &str) -> Option<Self> {
+        let value = value.trim();
+
+        if value == "none" {
+            return Some(Self::default());
+        }
+
+        let (values, (a, b, e, f)) = if let Some(values) = value.strip_prefix("matrix3d(") {
+            (values, (0, 1, 12, 13))
+        } else if let Some(values) = value.strip_prefix("matrix(") {
+            (values, (0, 1, 4, 5))
+        } else {
+            return None;
+        };
+
+        let values = values
+            .strip_suffix(')')?
+            .split(',')
+            .map(|v| v.trim().parse::<f64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        let (a, b) = (*values.get(a)?, *values.get(b)?);
+
+        Some(Self {
+            translate_x: *values.get(e)?,
+            translate_y: *values.get(f)?,
+            scale: a.hypot(b),
+            rotate: b.atan2(a).to_degrees(),
+        })
+    }
+}
+
+/// Return type of [`use_transform`].
+pub struct UseTransformReturn {
+    /// Horizontal translation in pixels.
+    pub translate_x: Signal<f64>,
+
+    /// Sets the horizontal translation.
+    pub set_translate_x: WriteSignal<f64>,
+
+    /// Vertical translation in pixels.
+    pub translate_y: Signal<f64>,
+
+    /// Sets the vertical translation.
+    pub set_translate_y: WriteSignal<f64>,
+
+    /// Uniform scale factor.
+    pub scale: Signal<f64>,
+
+    /// Sets the scale factor.
+    pub set_scale: WriteSignal<f64>,
+
+    /// Rotation in degrees.
+    pub rotate: Signal<f64>,
+
+    /// Sets the rotation.
+    pub set_rotate: WriteSignal<f64>,
+
+    /// The composed CSS `transform` value.
+    pub transform: Signal<String>,
+}
@@ -0,0 +1,417 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Transition between values.
+///
+/// The returned signal follows `source` but instead of jumping to a new value it animates
+/// towards it over the configured duration with the configured easing.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_transition)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_transition;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (source, set_source) = signal(0.0);
+///
+/// let output: Signal<f64> = use_transition(source);
+///
+/// set_source.set(100.0); // `output` now goes from 0 to 100 within one second
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Easing
+///
+/// The default easing is linear. There are presets for the usual cubic bézier curves from
+/// [easings.net](https://easings.net/), any other cubic bézier curve or a custom function can be
+/// used as well.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_transition_with_options, TransitionEasing, UseTransitionOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (source, set_source) = signal(0.0);
+/// #
+/// let output: Signal<f64> = use_transition_with_options(
+///     source,
+///     UseTransitionOptions::default()
+///         .duration(500.0)
+///         .easing(TransitionEasing::EASE_IN_OUT_CUBIC),
+/// );
+///
+/// let bounce: Signal<f64> = use_transition_with_options(
+///     source,
+///     UseTransitionOptions::default().easing(TransitionEasing::custom(|t| {
+///         1.0 - (1.0 - t).powi(2) * (t * 12.0).cos().abs()
+///     })),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Multiple Values
+///
+/// Besides `f64` the source can be a `Vec<f64>` or an array `[f64; N]`. Every value is
+/// transitioned independently, which is handy for coordinates or colors.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_transition;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (color, set_color) = signal([255.0, 0.0, 0.0]);
+///
+/// let output = use_transition(color);
+///
+/// let style = move || {
+///     let [r, g, b] = output.get();
+///     format!("background-color: rgb({r}, {g}, {b})")
+/// };
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// To support your own types implement the trait [`TransitionValue`].
+///
+/// ### Callbacks and Toggling
+///
+/// `on_started` is called when a transition starts (after the optional `delay`) and
+/// `on_finished` when it has reached the target value. A transition that is interrupted by a new
+/// source value doesn't finish but the new transition starts from the current output value.
+///
+/// While the option `enabled` is `false` the output follows the source without transitioning.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_transition_with_options, UseTransitionOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (source, set_source) = signal(0.0);
+/// let (enabled, set_enabled) = signal(true);
+///
+/// let output: Signal<f64> = use_transition_with_options(
+///     source,
+///     UseTransitionOptions::default()
+///         .enabled(enabled)
+///         .on_started(|| leptos::logging::log!("started"))
+///         .on_finished(|| leptos::logging::log!("finished")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the output is the initial value of the source and is never transitioned.
+pub fn use_transition<T>(source: impl Into<Signal<T>>) -> Signal<T>
+where
+    T: TransitionValue,
+{
+    use_transition_with_options(source, UseTransitionOptions::default())
+}
+
+/// Version of [`use_transition`] that takes a `UseTransitionOptions`. See [`use_transition`] for how to use.
+pub fn use_transition_with_options<T>(
+    source: impl Into<Signal<T>>,
+    options: UseTransitionOptions,
+) -> Signal<T>
+where
+    T: TransitionValue,
+{
+    let UseTransitionOptions {
+        duration,
+        delay,
+        easing,
+        enabled,
+        on_started,
+        on_finished,
+    } = options;
+
+    let source = source.into();
+
+    let (output, set_output) = signal(source.get_untracked());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::utils::Pausable;
+        use crate::{use_raf_fn_with_options, UseRafFnCallbackArgs, UseRafFnOptions};
+
+        let transition = StoredValue::new(None::<Transition<T>>);
+        let (is_transitioning, set_transitioning) = signal(false);
+
+        let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+            move |UseRafFnCallbackArgs { timestamp, .. }| {
+                let Some((just_started, value, finished)) = transition
+                    .try_update_value(|transition| {
+                        let transition = transition.as_mut()?;
+                        let start = *transition.start.get_or_insert(timestamp + delay);
+
+                        if timestamp < start {
+                            return None;
+                        }
+
+                        let progress = if duration > 0.0 {
+                            ((timestamp - start) / duration).clamp(0.0, 1.0)
+                        } else {
+                            1.0
+                        };
+
+                        Some((
+                            !std::mem::replace(&mut transition.started, true),
+                            T::interpolate(
+                                &transition.from,
+                                &transition.to,
+                                easing.apply(progress),
+                            ),
+                            progress >= 1.0,
+                        ))
+                    })
+                    .flatten()
+                else {
+                    return;
+                };
+
+                if just_started {
+                    on_started();
+                }
+
+                set_output.set(value);
+
+                if finished {
+                    transition.set_value(None);
+                    set_transitioning.set(false);
+                    on_finished();
+                }
+            },
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        let _ = Effect::watch(
+            move || (source.get(), enabled.get()),
+            move |(to, enabled), _, _| {
+                if *enabled && output.get_untracked() != *to {
+                    transition.set_value(Some(Transition {
+                        from: output.get_untracked(),
+                        to: to.clone(),
+                        start: None,
+                        started: false,
+                    }));
+                    set_transitioning.set(true);
+                    resume();
+                } else {
+                    transition.set_value(None);
+                    set_transitioning.set(false);
+                    set_output.set(to.clone());
+                }
+            },
+            false,
+        );
+
+        // the animation frame loop is only needed while transitioning
+        let _ = Effect::watch(
+            move || is_transitioning.get(),
+            move |is_transitioning, _, _| {
+                if !*is_transitioning && transition.with_value(Option::is_none) {
+                    pause();
+                }
+            },
+            false,
+        );
+    }
+
+    output.into()
+}
+
+/// A running transition of [`use_transition`].
+struct Transition<T> {
+    from: T,
+    to: T,
+    /// Timestamp when the transition starts. Set on the first animation frame.
+    start: Option<f64>,
+    started: bool,
+}
+
+/// A value that can be transitioned by [`fn@crate::use_transition`].
+pub trait TransitionValue: Clone + PartialEq + Send + Sync + 'static {
+    /// Returns the value between `from` and `to`. `alpha` is `0.0` at the start and `1.0` at the
+    /// end of the transition. It can leave that range for easings that overshoot.
+    fn interpolate(from: &Self, to: &Self, alpha: f64) -> Self;
+}
+
+impl TransitionValue for f64 {
+    fn interpolate(from: &Self, to: &Self, alpha: f64) -> Self {
+        from + (to - from) * alpha
+    }
+}
+
+impl TransitionValue for Vec<f64> {
+    /// Values without counterpart in `from` jump to their target value.
+    fn interpolate(from: &Self, to: &Self, alpha: f64) -> Self {
+        to.iter()
+            .enumerate()
+            .map(|(i, to)| match from.get(i) {
+                Some(from) => f64::interpolate(from, to, alpha),
+                None => *to,
+            })
+            .collect()
+    }
+}
+
+impl<const N: usize> TransitionValue for [f64; N] {
+    fn interpolate(from: &Self, to: &Self, alpha: f64) -> Self {
+        std::array::from_fn(|i| f64::interpolate(&from[i], &to[i], alpha))
+    }
+}
+
+/// Easing function of [`fn@crate::use_transition`] that maps the linear progress between `0.0`
+/// and `1.0` to the progress of the value.
+#[derive(Clone)]
+pub enum TransitionEasing {
+    /// Cubic bézier curve with the control points `(x1, y1)` and `(x2, y2)` like the CSS
+    /// function `cubic-bezier(x1, y1, x2, y2)`.
+    CubicBezier(f64, f64, f64, f64),
+
+    /// Custom easing function.
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+impl TransitionEasing {
+    pub const LINEAR: Self = Self::CubicBezier(0.0, 0.0, 1.0, 1.0);
+    pub const EASE_IN_SINE: Self = Self::CubicBezier(0.12, 0.0, 0.39, 0.0);
+    pub const EASE_OUT_SINE: Self = Self::CubicBezier(0.61, 1.0, 0.88, 1.0);
+    pub const EASE_IN_OUT_SINE: Self = Self::CubicBezier(0.37, 0.0, 0.63, 1.0);
+    pub const EASE_IN_QUAD: Self = Self::CubicBezier(0.11, 0.0, 0.5, 0.0);
+    pub const EASE_OUT_QUAD: Self = Self::CubicBezier(0.5, 1.0, 0.89, 1.0);
+    pub const EASE_IN_OUT_QUAD: Self = Self::CubicBezier(0.45, 0.0, 0.55, 1.0);
+    pub const EASE_IN_CUBIC: Self = Self::CubicBezier(0.32, 0.0, 0.67, 0.0);
+    pub const EASE_OUT_CUBIC: Self = Self::CubicBezier(0.33, 1.0, 0.68, 1.0);
+    pub const EASE_IN_OUT_CUBIC: Self = Self::CubicBezier(0.65, 0.0, 0.35, 1.0);
+    pub const EASE_IN_QUART: Self = Self::CubicBezier(0.5, 0.0, 0.75, 0.0);
+    pub const EASE_OUT_QUART: Self = Self::CubicBezier(0.25, 1.0, 0.5, 1.0);
+    pub const EASE_IN_OUT_QUART: Self = Self::CubicBezier(0.76, 0.0, 0.24, 1.0);
+    pub const EASE_IN_QUINT: Self = Self::CubicBezier(0.64, 0.0, 0.78, 0.0);
+    pub const EASE_OUT_QUINT: Self = Self::CubicBezier(0.22, 1.0, 0.36, 1.0);
+    pub const EASE_IN_OUT_QUINT: Self = Self::CubicBezier(0.83, 0.0, 0.17, 1.0);
+    pub const EASE_IN_EXPO: Self = Self::CubicBezier(0.7, 0.0, 0.84, 0.0);
+    pub const EASE_OUT_EXPO: Self = Self::CubicBezier(0.16, 1.0, 0.3, 1.0);
+    pub const EASE_IN_OUT_EXPO: Self = Self::CubicBezier(0.87, 0.0, 0.13, 1.0);
+    pub const EASE_IN_CIRC: Self = Self::CubicBezier(0.55, 0.0, 1.0, 0.45);
+    pub const EASE_OUT_CIRC: Self = Self::CubicBezier(0.0, 0.55, 0.45, 1.0);
+    pub const EASE_IN_OUT_CIRC: Self = Self::CubicBezier(0.85, 0.0, 0.15, 1.0);
+    pub const EASE_IN_BACK: Self = Self::CubicBezier(0.36, 0.0, 0.66, -0.56);
+    pub const EASE_OUT_BACK: Self = Self::CubicBezier(0.34, 1.56, 0.64, 1.0);
+    pub const EASE_IN_OUT_BACK: Self = Self::CubicBezier(0.68, -0.6, 0.32, 1.6);
+
+    /// Creates a custom easing from a function.
+    pub fn custom(easing: impl Fn(f64) -> f64 + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(easing))
+    }
+
+    /// Maps the linear progress `t` to the eased progress.
+    pub fn apply(&self, t: f64) -> f64 {
+        match self {
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                if x1 == y1 && x2 == y2 {
+                    return t;
+                }
+
+                bezier(solve_bezier_t(t, *x1, *x2), *y1, *y2)
+            }
+            Self::Custom(easing) => easing(t),
+        }
+    }
+}
+
+impl Default for TransitionEasing {
+    fn default() -> Self {
+        Self::LINEAR
+    }
+}
+
+/// Value of the one dimensional cubic bézier curve from `0` over `p1` and `p2` to `1` at `t`.
+fn bezier(t: f64, p1: f64, p2: f64) -> f64 {
+    ((bezier_a(p1, p2) * t + bezier_b(p1, p2)) * t + 3.0 * p1) * t
+}
+
+fn bezier_slope(t: f64, p1: f64, p2: f64) -> f64 {
+    3.0 * bezier_a(p1, p2) * t * t + 2.0 * bezier_b(p1, p2) * t + 3.0 * p1
+}
+
+fn bezier_a(p1: f64, p2: f64) -> f64 {
+    1.0 - 3.0 * p2 + 3.0 * p1
+}
+
+fn bezier_b(p1: f64, p2: f64) -> f64 {
+    3.0 * p2 - 6.0 * p1
+}
+
+/// Finds the `t` for which the curve over `x1` and `x2` has the value `x` with a few iterations
+/// of Newton's method.
+fn solve_bezier_t(x: f64, x1: f64, x2: f64) -> f64 {
+    let mut t = x;
+
+    for _ in 0..4 {
+        let slope = bezier_slope(t, x1, x2);
+        if slope == 0.0 {
+            break;
+        }
+
+        t -= (bezier(t, x1, x2) - x) / slope;
+    }
+
+    t
+}
+
+/// Options for [`use_transition_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTransitionOptions {
+    /// Duration of a transition in milliseconds. Defaults to `1000.0`.
+    duration: f64,
+
+    /// Delay in milliseconds before a transition starts. Defaults to `0.0`.
+    delay: f64,
+
+    /// Easing of the transition. Defaults to [`TransitionEasing::LINEAR`].
+    easing: TransitionEasing,
+
+    /// While `false` the output follows the source without transitioning. Defaults to `true`.
+    #[builder(into)]
+    enabled: Signal<bool>,
+
+    /// Called when a transition starts.
+    on_started: Arc<dyn Fn() + Send + Sync>,
+
+    /// Called when a transition has reached the target value.
+    on_finished: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl Default for UseTransitionOptions {
+    fn default() -> Self {
+        Self {
+            duration: 1000.0,
+            delay: 0.0,
+            easing: TransitionEasing::default(),
+            enabled: Signal::stored(true),
+            on_started: Arc::new(|| {}),
+            on_finished: Arc::new(|| {}),
+        }
+    }
+}
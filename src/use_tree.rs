@@ -0,0 +1,543 @@
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Headless state for tree views: expanded nodes, selection, keyboard navigation and lazy
+/// loading of children.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_tree)
+///
+/// ## Usage
+///
+/// The tree is given as a list of root [`TreeNode`]s. `visible` contains the nodes that are
+/// currently visible (i.e. all of their ancestors are expanded) in display order, which you can
+/// render as a flat list indented by `depth`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_tree, TreeNode, UseTreeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let nodes = vec![
+///     TreeNode::branch("src", vec![TreeNode::leaf("lib.rs"), TreeNode::leaf("main.rs")]),
+///     TreeNode::leaf("Cargo.toml"),
+/// ];
+///
+/// let tree = use_tree(nodes);
+/// let UseTreeReturn { visible, .. } = tree;
+///
+/// view! {
+///     <ul tabindex="0" on:keydown=move |e| tree.handle_keydown(&e)>
+///         <For each=move || visible.get() key=|node| node.id let:node>
+///             <li
+///                 style:padding-left=format!("{}em", node.depth)
+///                 class:selected=move || tree.is_selected(&node.id)
+///                 on:click=move |_| {
+///                     tree.select(node.id);
+///                     tree.toggle(node.id);
+///                 }
+///             >
+///                 {node.id}
+///             </li>
+///         </For>
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// Use the reactive methods like `is_expanded` or `is_selected` inside the rendered nodes
+/// instead of the fields of [`VisibleNode`]. Keyed lists like `<For>` don't re-render a node
+/// when only its state changes.
+///
+/// ### Keyboard navigation
+///
+/// `handle_keydown` implements the keyboard interaction of the
+/// [WAI-ARIA tree view pattern](https://www.w3.org/WAI/ARIA/apg/patterns/treeview/):
+///
+/// - <kbd>↓</kbd> / <kbd>↑</kbd> select the next / previous visible node.
+/// - <kbd>→</kbd> expands the selected node or selects its first child if it's already expanded.
+/// - <kbd>←</kbd> collapses the selected node or selects its parent if it's already collapsed.
+/// - <kbd>Home</kbd> / <kbd>End</kbd> select the first / last visible node.
+/// - <kbd>Enter</kbd> / <kbd>Space</kbd> toggle the selected node.
+///
+/// ### Lazy loading
+///
+/// Nodes created with [`TreeNode::lazy`] load their children with `load_children` the first
+/// time they are expanded. While loading, `is_loading` returns `true` for that node.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_tree_with_options, TreeNode, UseTreeOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let tree = use_tree_with_options(
+///     vec![TreeNode::lazy(1), TreeNode::lazy(2)],
+///     UseTreeOptions::default().load_children(|id: u32| async move {
+///         // fetch the children from somewhere
+///         vec![TreeNode::lazy(id * 10), TreeNode::leaf(id * 10 + 1)]
+///     }),
+/// );
+///
+/// let is_loading = move || tree.is_loading(&1);
+/// #
+/// # view! { }
+/// # }
+/// ```
+pub fn use_tree<K>(nodes: impl Into<Signal<Vec<TreeNode<K>>>>) -> UseTreeReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    use_tree_with_options(nodes, UseTreeOptions::default())
+}
+
+/// Version of [`use_tree`] that takes a `UseTreeOptions`. See [`use_tree`] for how to use.
+pub fn use_tree_with_options<K>(
+    nodes: impl Into<Signal<Vec<TreeNode<K>>>>,
+    options: UseTreeOptions<K>,
+) -> UseTreeReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    let UseTreeOptions { load_children } = options;
+
+    let nodes = nodes.into();
+
+    let expanded = RwSignal::new(HashSet::<K>::new());
+    let selected = RwSignal::new(None::<K>);
+    let loaded = RwSignal::new(HashMap::<K, Vec<TreeNode<K>>>::new());
+    let loading = RwSignal::new(HashSet::<K>::new());
+
+    let visible = Memo::new(move |_| {
+        let mut visible = vec![];
+
+        nodes.with(|nodes| {
+            expanded.with(|expanded| {
+                loaded.with(|loaded| {
+                    flatten(nodes, None, 0, expanded, loaded, &mut visible);
+                })
+            })
+        });
+
+        visible
+    });
+
+    UseTreeReturn {
+        visible: visible.into(),
+        selected: selected.into(),
+        nodes,
+        expanded,
+        selected_id: selected,
+        loaded,
+        loading,
+        load_children: StoredValue::new(load_children),
+    }
+}
+
+/// A node of the tree given to [`fn@crate::use_tree`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeNode<K> {
+    /// Unique id of the node.
+    pub id: K,
+
+    /// The child nodes. Ignored for lazy nodes.
+    pub children: Vec<TreeNode<K>>,
+
+    /// If `true` the children are loaded with `load_children` when the node is expanded.
+    pub lazy: bool,
+}
+
+impl<K> TreeNode<K> {
+    /// Node without children.
+    pub fn leaf(id: K) -> Self {
+        Self {
+            id,
+            children: vec![],
+            lazy: false,
+        }
+    }
+
+    /// Node with the given children.
+    pub fn branch(id: K, children: Vec<TreeNode<K>>) -> Self {
+        Self {
+            id,
+            children,
+            lazy: false,
+        }
+    }
+
+    /// Node whose children are loaded when it's expanded for the first time.
+    pub fn lazy(id: K) -> Self {
+        Self {
+            id,
+            children: vec![],
+            lazy: true,
+        }
+    }
+}
+
+/// A node as it is displayed. See [`UseTreeReturn::visible`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VisibleNode<K> {
+    /// Id of the node.
+    pub id: K,
+
+    /// Id of the parent node. `None` for root nodes.
+    pub parent: Option<K>,
+
+    /// Nesting level starting at `0` for root nodes.
+    pub depth: usize,
+
+    /// Whether the node has children or is a lazy node that hasn't been loaded yet.
+    pub is_expandable: bool,
+
+    /// Whether the node is expanded.
+    pub is_expanded: bool,
+}
+
+type LoadChildrenFn<K> =
+    Arc<dyn Fn(K) -> Pin<Box<dyn Future<Output = Vec<TreeNode<K>>>>> + Send + Sync>;
+
+/// Options for [`use_tree_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTreeOptions<K> {
+    /// Loads the children of lazy nodes. See [`UseTreeOptions::load_children`].
+    #[builder(skip)]
+    load_children: Option<LoadChildrenFn<K>>,
+}
+
+impl<K> Default for UseTreeOptions<K> {
+    fn default() -> Self {
+        Self {
+            load_children: None,
+        }
+    }
+}
+
+impl<K> UseTreeOptions<K>
+where
+    K: 'static,
+{
+    /// Async function that receives the id of a lazy node and returns its children.
+    /// Without it lazy nodes never get any children.
+    pub fn load_children<F, Fut>(mut self, load_children: F) -> Self
+    where
+        F: Fn(K) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<TreeNode<K>>> + 'static,
+    {
+        self.load_children = Some(Arc::new(move |id| Box::pin(load_children(id))));
+        self
+    }
+}
+
+/// Return type of [`use_tree`].
+pub struct UseTreeReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// The currently visible nodes in display order.
+    pub visible: Signal<Vec<VisibleNode<K>>>,
+
+    /// The id of the selected node.
+    pub selected: Signal<Option<K>>,
+
+    nodes: Signal<Vec<TreeNode<K>>>,
+    expanded: RwSignal<HashSet<K>>,
+    selected_id: RwSignal<Option<K>>,
+    loaded: RwSignal<HashMap<K, Vec<TreeNode<K>>>>,
+    loading: RwSignal<HashSet<K>>,
+    load_children: StoredValue<Option<LoadChildrenFn<K>>>,
+}
+
+impl<K> Clone for UseTreeReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for UseTreeReturn<K> where K: Eq + Hash + Clone + Send + Sync + 'static {}
+
+impl<K> UseTreeReturn<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Returns whether the node is expanded. Reactive if called inside a reactive context.
+    pub fn is_expanded(&self, id: &K) -> bool {
+        self.expanded.with(|expanded| expanded.contains(id))
+    }
+
+    /// Returns whether the node is selected. Reactive if called inside a reactive context.
+    pub fn is_selected(&self, id: &K) -> bool {
+        self.selected_id
+            .with(|selected| selected.as_ref() == Some(id))
+    }
+
+    /// Returns whether the children of the node are being loaded. Reactive if called inside a
+    /// reactive context.
+    pub fn is_loading(&self, id: &K) -> bool {
+        self.loading.with(|loading| loading.contains(id))
+    }
+
+    /// Expands the node and starts loading its children if it's a lazy node that hasn't been
+    /// loaded yet.
+    pub fn expand(&self, id: K) {
+        let needs_loading = self.nodes.with_untracked(|nodes| {
+            self.loaded.with_untracked(|loaded| {
+                find_node(nodes, &id, loaded)
+                    .is_some_and(|node| node.lazy && !loaded.contains_key(&id))
+            })
+        }) && !self.loading.with_untracked(|loading| loading.contains(&id));
+
+        self.expanded.update(|expanded| {
+            expanded.insert(id.clone());
+        });
+
+        if needs_loading {
+            self.load(id);
+        }
+    }
+
+    /// Collapses the node.
+    pub fn collapse(&self, id: &K) {
+        self.expanded.update(|expanded| {
+            expanded.remove(id);
+        });
+    }
+
+    /// Expands the node if it's collapsed and collapses it otherwise.
+    pub fn toggle(&self, id: K) {
+        if self
+            .expanded
+            .with_untracked(|expanded| expanded.contains(&id))
+        {
+            self.collapse(&id);
+        } else {
+            self.expand(id);
+        }
+    }
+
+    /// Expands all nodes that have been loaded. Lazy nodes are not loaded by this.
+    pub fn expand_all(&self) {
+        let mut ids = HashSet::new();
+
+        self.nodes.with_untracked(|nodes| {
+            self.loaded
+                .with_untracked(|loaded| collect_ids(nodes, loaded, &mut ids))
+        });
+
+        self.expanded.set(ids);
+    }
+
+    /// Collapses all nodes.
+    pub fn collapse_all(&self) {
+        self.expanded.set(HashSet::new());
+    }
+
+    /// Selects the node.
+    pub fn select(&self, id: K) {
+        self.selected_id.set(Some(id));
+    }
+
+    /// Clears the selection.
+    pub fn clear_selection(&self) {
+        self.selected_id.set(None);
+    }
+
+    /// Discards the loaded children of a lazy node and loads them again.
+    pub fn reload(&self, id: K) {
+        self.loaded.update(|loaded| {
+            loaded.remove(&id);
+        });
+
+        if !self.loading.with_untracked(|loading| loading.contains(&id)) {
+            self.load(id);
+        }
+    }
+
+    /// Handles keyboard navigation. Call this in the `keydown` handler of the tree element.
+    /// The default of handled keys is prevented. See [`fn@crate::use_tree`] for the supported keys.
+    pub fn handle_keydown(&self, event: &web_sys::KeyboardEvent) {
+        let visible = self.visible.get_untracked();
+
+        let Some(last) = visible.len().checked_sub(1) else {
+            return;
+        };
+
+        let current = self.selected_id.with_untracked(|selected| {
+            selected
+                .as_ref()
+                .and_then(|selected| visible.iter().position(|node| node.id == *selected))
+        });
+
+        let handled = match (event.key().as_str(), current) {
+            ("ArrowDown", Some(index)) => {
+                self.select(visible[(index + 1).min(last)].id.clone());
+                true
+            }
+            ("ArrowUp", Some(index)) => {
+                self.select(visible[index.saturating_sub(1)].id.clone());
+                true
+            }
+            ("ArrowDown" | "ArrowUp" | "Home", _) => {
+                self.select(visible[0].id.clone());
+                true
+            }
+            ("End", _) => {
+                self.select(visible[last].id.clone());
+                true
+            }
+            ("ArrowRight", Some(index)) => {
+                let node = &visible[index];
+
+                if node.is_expanded {
+                    if let Some(child) = visible
+                        .get(index + 1)
+                        .filter(|child| child.parent.as_ref() == Some(&node.id))
+                    {
+                        self.select(child.id.clone());
+                    }
+                } else if node.is_expandable {
+                    self.expand(node.id.clone());
+                }
+                true
+            }
+            ("ArrowLeft", Some(index)) => {
+                let node = &visible[index];
+
+                if node.is_expanded {
+                    self.collapse(&node.id);
+                } else if let Some(parent) = &node.parent {
+                    self.select(parent.clone());
+                }
+                true
+            }
+            ("Enter" | " ", Some(index)) => {
+                if visible[index].is_expandable {
+                    self.toggle(visible[index].id.clone());
+                }
+                true
+            }
+            _ => false,
+        };
+
+        if handled {
+            event.prevent_default();
+        }
+    }
+
+    fn load(&self, id: K) {
+        let Some(load_children) = self.load_children.get_value() else {
+            return;
+        };
+
+        let UseTreeReturn {
+            loaded, loading, ..
+        } = *self;
+
+        loading.update(|loading| {
+            loading.insert(id.clone());
+        });
+
+        leptos::task::spawn_local(async move {
+            let children = load_children(id.clone()).await;
+
+            loading.try_update(|loading| loading.remove(&id));
+            loaded.try_update(|loaded| loaded.insert(id, children));
+        });
+    }
+}
+
+/// Returns the children of `node` taking lazily loaded children into account.
+fn node_children<'a, K>(
+    node: &'a TreeNode<K>,
+    loaded: &'a HashMap<K, Vec<TreeNode<K>>>,
+) -> &'a [TreeNode<K>]
+where
+    K: Eq + Hash,
+{
+    if node.lazy {
+        loaded.get(&node.id).map(Vec::as_slice).unwrap_or_default()
+    } else {
+        &node.children
+    }
+}
+
+fn flatten<K>(
+    nodes: &[TreeNode<K>],
+    parent: Option<&K>,
+    depth: usize,
+    expanded: &HashSet<K>,
+    loaded: &HashMap<K, Vec<TreeNode<K>>>,
+    visible: &mut Vec<VisibleNode<K>>,
+) where
+    K: Eq + Hash + Clone,
+{
+    for node in nodes {
+        let children = node_children(node, loaded);
+        let is_expandable = !children.is_empty() || (node.lazy && !loaded.contains_key(&node.id));
+        let is_expanded = is_expandable && expanded.contains(&node.id);
+
+        visible.push(VisibleNode {
+            id: node.id.clone(),
+            parent: parent.cloned(),
+            depth,
+            is_expandable,
+            is_expanded,
+        });
+
+        if is_expanded {
+            flatten(
+                children,
+                Some(&node.id),
+                depth + 1,
+                expanded,
+                loaded,
+                visible,
+            );
+        }
+    }
+}
+
+fn find_node<'a, K>(
+    nodes: &'a [TreeNode<K>],
+    id: &K,
+    loaded: &'a HashMap<K, Vec<TreeNode<K>>>,
+) -> Option<&'a TreeNode<K>>
+where
+    K: Eq + Hash,
+{
+    nodes.iter().find_map(|node| {
+        if node.id == *id {
+            Some(node)
+        } else {
+            find_node(node_children(node, loaded), id, loaded)
+        }
+    })
+}
+
+fn collect_ids<K>(
+    nodes: &[TreeNode<K>],
+    loaded: &HashMap<K, Vec<TreeNode<K>>>,
+    ids: &mut HashSet<K>,
+) where
+    K: Eq + Hash + Clone,
+{
+    for node in nodes {
+        let children = node_children(node, loaded);
+
+        if !children.is_empty() {
+            ids.insert(node.id.clone());
+            collect_ids(children, loaded, ids);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::{use_geolocation_with_options, UseGeolocationOptions, UseGeolocationReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Mean radius of the earth in meters.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// Track a trip with the [Geolocation API](https://developer.mozilla.org/en-US/docs/Web/API/Geolocation_API)
+/// and derive the distance travelled, the current speed and heading from the successive positions.
+///
+/// Useful for fitness or delivery apps. The positions are watched with
+/// [`fn@crate::use_geolocation`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_trip_tracking)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_trip_tracking, UseTripTrackingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseTripTrackingReturn {
+///     distance,
+///     speed,
+///     heading,
+///     track,
+///     pause,
+///     resume,
+///     ..
+/// } = use_trip_tracking();
+///
+/// view! {
+///     <p>"Distance: " {move || format!("{:.0} m", distance.get())}</p>
+///     <p>"Speed: " {move || speed.get().map(|speed| format!("{:.1} km/h", speed * 3.6))}</p>
+///     <p>"Heading: " {move || heading.get().map(|heading| format!("{heading:.0}°"))}</p>
+///     <p>"Points: " {move || track.with(Vec::len)}</p>
+/// }
+/// # }
+/// ```
+///
+/// Distances are calculated with the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula)
+/// and are in meters. Speeds are in meters per second and headings in degrees clockwise from true
+/// north.
+///
+/// ### Noise
+///
+/// Positions are never exact. To keep a standing device from accumulating distance, a new point is
+/// only added to the `track` once it is at least `min_distance` meters away from the last one.
+/// Positions with an accuracy worse than `max_accuracy` are ignored entirely.
+///
+/// When the device reports a speed or heading itself (usually from GPS) that is used. Otherwise
+/// they are derived from the last two points of the `track`. The speed is smoothed by blending it
+/// with the previous speed according to `smoothing`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_trip_tracking_with_options, UseTripTrackingOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let trip = use_trip_tracking_with_options(
+///     UseTripTrackingOptions::default()
+///         .min_distance(10.0)
+///         .max_accuracy(30.0)
+///         .smoothing(0.8),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Pausing
+///
+/// `pause` stops watching the position. The distance between the position where the trip has been
+/// paused and the one where it has been resumed is not counted. `reset` clears the trip.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `distance` is always `0.0`, `track` is always empty, `speed` and `heading` are
+/// always `None` and the functions do nothing.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_geolocation`]
+pub fn use_trip_tracking() -> UseTripTrackingReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    use_trip_tracking_with_options(UseTripTrackingOptions::default())
+}
+
+/// Version of [`use_trip_tracking`] that takes a `UseTripTrackingOptions`. See [`use_trip_tracking`] for how to use.
+pub fn use_trip_tracking_with_options(
+    options: UseTripTrackingOptions,
+) -> UseTripTrackingReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    let UseTripTrackingOptions {
+        immediate,
+        enable_high_accuracy,
+        min_distance,
+        max_accuracy,
+        smoothing,
+    } = options;
+
+    let UseGeolocationReturn {
+        coords,
+        located_at,
+        error,
+        resume: resume_watch,
+        pause: pause_watch,
+    } = use_geolocation_with_options(
+        UseGeolocationOptions::default()
+            .immediate(immediate)
+            .enable_high_accuracy(enable_high_accuracy)
+            .maximum_age(0),
+    );
+
+    let (distance, set_distance) = signal(0.0_f64);
+    let (speed, set_speed) = signal(None::<f64>);
+    let (heading, set_heading) = signal(None::<f64>);
+    let (track, set_track) = signal(Vec::<TrackPoint>::new());
+    let (is_tracking, set_tracking) = signal(immediate);
+
+    // the next point doesn't continue the last one, e.g. after resuming
+    let starts_segment = StoredValue::new(true);
+
+    let _ = Effect::watch(
+        move || coords.get(),
+        move |coords, _, _| {
+            let Some(coords) = coords else {
+                return;
+            };
+
+            if max_accuracy.is_some_and(|max_accuracy| coords.accuracy() > max_accuracy) {
+                return;
+            }
+
+            let point = TrackPoint {
+                latitude: coords.latitude(),
+                longitude: coords.longitude(),
+                timestamp: located_at.get_untracked().unwrap_or_default(),
+            };
+            let reported_speed = coords.speed().filter(|speed| speed.is_finite());
+            let reported_heading = coords.heading().filter(|heading| heading.is_finite());
+
+            let last = if starts_segment.get_value() {
+                None
+            } else {
+                track.with_untracked(|track| track.last().copied())
+            };
+
+            let Some(last) = last else {
+                starts_segment.set_value(false);
+                set_track.update(|track| track.push(point));
+                set_speed.set(reported_speed);
+                set_heading.set(reported_heading);
+                return;
+            };
+
+            let step = last.distance_to(&point);
+            let is_moving = step >= min_distance;
+
+            let derived_speed = {
+                let seconds = (point.timestamp - last.timestamp) / 1000.0;
+                (seconds > 0.0).then(|| if is_moving { step / seconds } else { 0.0 })
+            };
+
+            if let Some(new_speed) = reported_speed.or(derived_speed) {
+                set_speed.update(|speed| {
+                    *speed = Some(match *speed {
+                        Some(speed) => speed * smoothing + new_speed * (1.0 - smoothing),
+                        None => new_speed,
+                    });
+                });
+            }
+
+            if !is_moving {
+                return;
+            }
+
+            set_heading.set(reported_heading.or_else(|| Some(last.bearing_to(&point))));
+            set_distance.update(|distance| *distance += step);
+            set_track.update(|track| track.push(point));
+        },
+        false,
+    );
+
+    let resume = move || {
+        if is_tracking.get_untracked() {
+            return;
+        }
+
+        starts_segment.set_value(true);
+        set_tracking.set(true);
+        resume_watch();
+    };
+
+    let pause = move || {
+        pause_watch();
+        set_tracking.set(false);
+        set_speed.set(None);
+    };
+
+    let reset = move || {
+        starts_segment.set_value(true);
+        set_distance.set(0.0);
+        set_speed.set(None);
+        set_heading.set(None);
+        set_track.set(vec![]);
+    };
+
+    UseTripTrackingReturn {
+        distance: distance.into(),
+        speed: speed.into(),
+        heading: heading.into(),
+        track: track.into(),
+        is_tracking: is_tracking.into(),
+        error,
+        pause,
+        resume,
+        reset,
+    }
+}
+
+/// Options for [`use_trip_tracking_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTripTrackingOptions {
+    /// If `true` tracking starts when this function is called.
+    /// If `false` you have to call `resume` manually to start it. Defaults to `true`.
+    immediate: bool,
+
+    /// Request the most accurate positions the device can provide. This can increase power
+    /// consumption. Defaults to `true`.
+    enable_high_accuracy: bool,
+
+    /// Minimum distance in meters from the last point before a new point is added to the track.
+    /// Defaults to `5.0`.
+    min_distance: f64,
+
+    /// Positions with an accuracy worse than this many meters are ignored.
+    /// Defaults to `None` which means all positions are used.
+    #[builder(into)]
+    max_accuracy: Option<f64>,
+
+    /// How much of the previous speed is kept when a new speed is measured, between `0.0` and
+    /// `1.0`. `0.0` disables smoothing. Defaults to `0.5`.
+    smoothing: f64,
+}
+
+impl Default for UseTripTrackingOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            enable_high_accuracy: true,
+            min_distance: 5.0,
+            max_accuracy: None,
+            smoothing: 0.5,
+        }
+    }
+}
+
+/// Return type of [`use_trip_tracking`].
+pub struct UseTripTrackingReturn<PauseFn, ResumeFn, ResetFn>
+where
+    PauseFn: Fn() + Clone + Send + Sync,
+    ResumeFn: Fn() + Clone + Send + Sync,
+    ResetFn: Fn() + Clone + Send + Sync,
+{
+    /// Distance travelled in meters.
+    pub distance: Signal<f64>,
+
+    /// Current speed in meters per second. `None` until it can be determined.
+    pub speed: Signal<Option<f64>>,
+
+    /// Current direction of travel in degrees clockwise from true north. `None` until the device
+    /// has moved.
+    pub heading: Signal<Option<f64>>,
+
+    /// The points of the trip that can be drawn as a polyline.
+    pub track: Signal<Vec<TrackPoint>>,
+
+    /// Whether the position is being tracked (not paused).
+    pub is_tracking: Signal<bool>,
+
+    /// The last error received from `navigator.geolocation`.
+    pub error: Signal<Option<web_sys::PositionError>, LocalStorage>,
+
+    /// Pause tracking.
+    pub pause: PauseFn,
+
+    /// Resume tracking.
+    pub resume: ResumeFn,
+
+    /// Clear the distance, speed, heading and track.
+    pub reset: ResetFn,
+}
+
+/// A position of a trip tracked with [`fn@crate::use_trip_tracking`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackPoint {
+    /// Latitude in degrees.
+    pub latitude: f64,
+
+    /// Longitude in degrees.
+    pub longitude: f64,
+
+    /// Time the position was determined at in milliseconds since the Unix epoch.
+    pub timestamp: f64,
+}
+
+impl TrackPoint {
+    /// Great-circle distance to `other` in meters.
+    pub fn distance_to(&self, other: &TrackPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_lat = lat2 - lat1;
+        let delta_lng = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lng / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+
+    /// Initial bearing towards `other` in degrees clockwise from true north.
+    pub fn bearing_to(&self, other: &TrackPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_lng = (other.longitude - self.longitude).to_radians();
+
+        let y = delta_lng.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lng.cos();
+
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+}
@@ -0,0 +1,333 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::HistoryWriteMode;
+use crate::use_event_listener;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::collections::BTreeMap;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [URLSearchParams](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams)
+/// of the current URL.
+///
+/// Changes made through the returned methods are written back to the URL with the
+/// [History API](https://developer.mozilla.org/en-US/docs/Web/API/History_API). Navigating back
+/// and forth (`popstate` and `hashchange` events) updates the params. This works without
+/// `leptos_router`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_url_search_params)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_url_search_params, UrlSearchParamsMode};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let search_params = use_url_search_params(UrlSearchParamsMode::History);
+///
+/// // `?q=leptos&tag=rust&tag=web`
+/// let query = Signal::derive(move || search_params.get("q")); // Some("leptos")
+/// let tags = Signal::derive(move || search_params.get_all("tag")); // vec!["rust", "web"]
+///
+/// search_params.set("q", "signals"); // `?q=signals&tag=rust&tag=web`
+/// search_params.append("tag", "wasm"); // `?q=signals&tag=rust&tag=web&tag=wasm`
+/// search_params.remove("tag"); // `?q=signals`
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// All params are available as a map of keys to their values through `params`. Keys can have
+/// multiple values like `tag` above.
+///
+/// ### Modes
+///
+/// - `UrlSearchParamsMode::History` uses the search part of the URL like in `/page?foo=bar`.
+/// - `UrlSearchParamsMode::Hash` uses the params after the hash like in `/#/page?foo=bar`.
+/// - `UrlSearchParamsMode::HashParams` uses the whole hash like in `/#foo=bar`.
+///
+/// ### Options
+///
+/// By default the current history entry is replaced. To create a new entry for every change use
+/// `UseUrlSearchParamsOptions::default().write_mode(HistoryWriteMode::Push)`.
+///
+/// Please note that the History API doesn't notify `leptos_router` about the change. If you
+/// use the router consider using its `use_query_map` instead.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the params are empty and changes are not written anywhere.
+pub fn use_url_search_params(mode: UrlSearchParamsMode) -> UseUrlSearchParamsReturn {
+    use_url_search_params_with_options(mode, UseUrlSearchParamsOptions::default())
+}
+
+/// Version of [`use_url_search_params`] that takes a `UseUrlSearchParamsOptions`. See [`use_url_search_params`] for how to use.
+pub fn use_url_search_params_with_options(
+    mode: UrlSearchParamsMode,
+    options: UseUrlSearchParamsOptions,
+) -> UseUrlSearchParamsReturn {
+    let UseUrlSearchParamsOptions {
+        write,
+        write_mode,
+        remove_empty_values,
+    } = options;
+
+    #[cfg(feature = "ssr")]
+    let initial_params = UrlParams::new();
+
+    #[cfg(not(feature = "ssr"))]
+    let initial_params = read_params(mode);
+
+    let (params, set_params) = signal(initial_params);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let update_from_url = move || {
+            let new_params = read_params(mode);
+
+            if params.with_untracked(|params| *params != new_params) {
+                set_params.set(new_params);
+            }
+        };
+
+        let _ = use_event_listener(window(), leptos::ev::popstate, move |_| update_from_url());
+        let _ = use_event_listener(window(), leptos::ev::hashchange, move |_| update_from_url());
+    }
+
+    UseUrlSearchParamsReturn {
+        params: params.into(),
+        set_params,
+        mode,
+        write,
+        write_mode,
+        remove_empty_values,
+    }
+}
+
+/// Map of query parameter names to their values. Parameters that appear multiple times in the
+/// URL like `?tag=a&tag=b` have multiple values.
+pub type UrlParams = BTreeMap<String, Vec<String>>;
+
+/// Which part of the URL contains the params. See [`use_url_search_params`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum UrlSearchParamsMode {
+    /// `/page?foo=bar`
+    #[default]
+    History,
+
+    /// `/#/page?foo=bar`
+    Hash,
+
+    /// `/#foo=bar`
+    HashParams,
+}
+
+/// Options for [`use_url_search_params_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseUrlSearchParamsOptions {
+    /// Whether changes are written to the URL. If `false` only the returned `params` are updated.
+    /// Defaults to `true`.
+    write: bool,
+
+    /// How changes are written to the browser history. Defaults to `HistoryWriteMode::Replace`.
+    write_mode: HistoryWriteMode,
+
+    /// Whether params without a value or with an empty value are removed. Defaults to `true`.
+    remove_empty_values: bool,
+}
+
+impl Default for UseUrlSearchParamsOptions {
+    fn default() -> Self {
+        Self {
+            write: true,
+            write_mode: HistoryWriteMode::default(),
+            remove_empty_values: true,
+        }
+    }
+}
+
+/// Return type of [`use_url_search_params`].
+#[derive(Clone, Copy)]
+pub struct UseUrlSearchParamsReturn {
+    /// All params of the URL
+    pub params: Signal<UrlParams>,
+
+    set_params: WriteSignal<UrlParams>,
+    mode: UrlSearchParamsMode,
+    write: bool,
+    write_mode: HistoryWriteMode,
+    remove_empty_values: bool,
+}
+
+impl UseUrlSearchParamsReturn {
+    /// Returns the first value of the param `key`.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.params
+            .with(|params| params.get(key).and_then(|values| values.first().cloned()))
+    }
+
+    /// Returns all values of the param `key`.
+    pub fn get_all(&self, key: &str) -> Vec<String> {
+        self.params
+            .with(|params| params.get(key).cloned().unwrap_or_default())
+    }
+
+    /// Sets the param `key` to `value` replacing all its previous values.
+    pub fn set(&self, key: impl Into<String>, value: impl Into<String>) {
+        self.set_all(key, [value]);
+    }
+
+    /// Sets the param `key` to `values` replacing all its previous values.
+    pub fn set_all(
+        &self,
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) {
+        let key = key.into();
+        let values = values.into_iter().map(Into::into).collect();
+
+        self.update(|params| {
+            params.insert(key, values);
+        });
+    }
+
+    /// Adds `value` to the values of the param `key`.
+    pub fn append(&self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        let value = value.into();
+
+        self.update(|params| params.entry(key).or_default().push(value));
+    }
+
+    /// Removes the param `key` with all its values.
+    pub fn remove(&self, key: &str) {
+        self.update(|params| {
+            params.remove(key);
+        });
+    }
+
+    /// Removes all params.
+    pub fn clear(&self) {
+        self.update(UrlParams::clear);
+    }
+
+    /// Updates all params at once and writes them to the URL.
+    pub fn update(&self, f: impl FnOnce(&mut UrlParams)) {
+        let remove_empty_values = self.remove_empty_values;
+
+        self.set_params.update(|params| {
+            f(params);
+
+            if remove_empty_values {
+                params.retain(|_, values| {
+                    values.retain(|value| !value.is_empty());
+                    !values.is_empty()
+                });
+            }
+        });
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            if self.write {
+                self.params.with_untracked(|params| {
+                    write_params(self.mode, self.write_mode, params);
+                });
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn read_params(mode: UrlSearchParamsMode) -> UrlParams {
+    let location = window().location();
+    let hash = location.hash().unwrap_or_default();
+
+    let query = match mode {
+        UrlSearchParamsMode::History => location.search().unwrap_or_default(),
+        UrlSearchParamsMode::Hash => hash
+            .split_once('?')
+            .map(|(_, query)| query.to_string())
+            .unwrap_or_default(),
+        UrlSearchParamsMode::HashParams => hash.trim_start_matches('#').to_string(),
+    };
+
+    let mut params = UrlParams::new();
+
+    let Ok(search_params) = web_sys::UrlSearchParams::new_with_str(&query) else {
+        return params;
+    };
+
+    if let Ok(Some(entries)) = js_sys::try_iter(&search_params) {
+        for entry in entries.flatten() {
+            let entry = entry.unchecked_into::<js_sys::Array>();
+
+            if let (Some(key), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) {
+                params.entry(key).or_default().push(value);
+            }
+        }
+    }
+
+    params
+}
+
+#[cfg(not(feature = "ssr"))]
+fn write_params(mode: UrlSearchParamsMode, write_mode: HistoryWriteMode, params: &UrlParams) {
+    let Ok(search_params) = web_sys::UrlSearchParams::new() else {
+        return;
+    };
+
+    for (key, values) in params {
+        for value in values {
+            search_params.append(key, value);
+        }
+    }
+
+    let query = String::from(search_params.to_string());
+    let location = window().location();
+    let pathname = location.pathname().unwrap_or_default();
+    let search = location.search().unwrap_or_default();
+    let hash = location.hash().unwrap_or_default();
+
+    let url = match mode {
+        UrlSearchParamsMode::History => {
+            let query = if query.is_empty() {
+                query
+            } else {
+                format!("?{query}")
+            };
+
+            format!("{pathname}{query}{hash}")
+        }
+        UrlSearchParamsMode::Hash => {
+            let hash_path = hash.split('?').next().unwrap_or_default();
+
+            if query.is_empty() {
+                format!("{pathname}{search}{hash_path}")
+            } else if hash_path.is_empty() {
+                format!("{pathname}{search}#?{query}")
+            } else {
+                format!("{pathname}{search}{hash_path}?{query}")
+            }
+        }
+        UrlSearchParamsMode::HashParams => {
+            if query.is_empty() {
+                format!("{pathname}{search}")
+            } else {
+                format!("{pathname}{search}#{query}")
+            }
+        }
+    };
+
+    let Ok(history) = window().history() else {
+        return;
+    };
+
+    let _ = match write_mode {
+        HistoryWriteMode::Replace => history.replace_state_with_url(&JsValue::NULL, "", Some(&url)),
+        HistoryWriteMode::Push => history.push_state_with_url(&JsValue::NULL, "", Some(&url)),
+    };
+}
@@ -0,0 +1,414 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use crate::{use_element_size, UseElementSizeReturn, UseTableReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// Renders only the visible part of a long list.
+///
+/// Items that haven't been measured yet are assumed to be `item_height` pixels high. Rendered items
+/// can be measured with `measure_element` so items of different heights are supported.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_virtual_list)
+///
+/// ## Usage
+///
+/// The container needs a fixed height and `overflow: auto`. Inside of it render a wrapper that is
+/// as high as all items together and offset the rendered items by `offset_top`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_virtual_list, UseVirtualListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (items, set_items) = signal((0..10_000).collect::<Vec<u32>>());
+/// let container = NodeRef::<Div>::new();
+///
+/// let virtual_list = use_virtual_list(items, container);
+/// let UseVirtualListReturn {
+///     list,
+///     total_height,
+///     offset_top,
+///     ..
+/// } = virtual_list;
+///
+/// view! {
+///     <div node_ref=container style="height: 300px; overflow: auto">
+///         <div
+///             style:height=move || format!("{}px", total_height.get())
+///             style:padding-top=move || format!("{}px", offset_top.get())
+///             style="box-sizing: border-box"
+///         >
+///             <For each=move || list.get() key=|item| item.index let:item>
+///                 {
+///                     let row = NodeRef::<Div>::new();
+///
+///                     // measure the item once it's rendered
+///                     Effect::new(move || {
+///                         if let Some(el) = row.get() {
+///                             virtual_list.measure_element(item.index, &el);
+///                         }
+///                     });
+///
+///                     view! { <div node_ref=row>"Row " {item.data}</div> }
+///                 }
+///             </For>
+///         </div>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// Use `scroll_to_index` to scroll an item into view.
+///
+/// ### Tables
+///
+/// To virtualize the rows of [`fn@crate::use_table`] use [`use_virtual_table`]. Since elements
+/// inside `<tbody>` can't be offset with padding render spacer rows that are `offset_top` and
+/// `offset_bottom` high before and after the rows.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the container has no size so only the first `overscan` items are rendered.
+pub fn use_virtual_list<T, El, M>(
+    items: impl Into<Signal<Vec<T>>>,
+    container: El,
+) -> UseVirtualListReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_virtual_list_with_options(items, container, UseVirtualListOptions::default())
+}
+
+/// Version of [`use_virtual_list`] that takes a `UseVirtualListOptions`. See [`use_virtual_list`] for how to use.
+pub fn use_virtual_list_with_options<T, El, M>(
+    items: impl Into<Signal<Vec<T>>>,
+    container: El,
+    options: UseVirtualListOptions,
+) -> UseVirtualListReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let items = items.into();
+
+    virtual_list(
+        Signal::derive(move || items.with(Vec::len)),
+        move |start, end| {
+            items.with(|items| items[start.min(items.len())..end.min(items.len())].to_vec())
+        },
+        container.into_element_maybe_signal(),
+        options,
+    )
+}
+
+/// Virtualizes `len` items. `slice` returns the items from `start` to `end` (exclusive).
+fn virtual_list<T>(
+    len: Signal<usize>,
+    slice: impl Fn(usize, usize) -> Vec<T> + Send + Sync + 'static,
+    container: ElementMaybeSignal<web_sys::Element>,
+    options: UseVirtualListOptions,
+) -> UseVirtualListReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    let UseVirtualListOptions {
+        item_height,
+        overscan,
+    } = options;
+
+    let (scroll_top, set_scroll_top) = signal(0.0);
+    let heights = RwSignal::new(HashMap::<usize, f64>::new());
+
+    let UseElementSizeReturn {
+        height: viewport_height,
+        ..
+    } = use_element_size(container);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_event_listener_with_options, UseEventListenerOptions};
+
+        let _ = use_event_listener_with_options(
+            container,
+            leptos::ev::scroll,
+            move |_| {
+                if let Some(container) = container.get_untracked() {
+                    set_scroll_top.set(container.scroll_top() as f64);
+                }
+            },
+            UseEventListenerOptions::default().passive(true),
+        );
+    }
+
+    // `offsets[i]` is where item `i` starts and `offsets[len]` is the total height
+    let offsets = Memo::new(move |_| {
+        let len = len.get();
+
+        heights.with(|heights| {
+            let mut offsets = Vec::with_capacity(len + 1);
+            let mut offset = 0.0;
+            offsets.push(offset);
+
+            for index in 0..len {
+                offset += heights.get(&index).copied().unwrap_or(item_height);
+                offsets.push(offset);
+            }
+
+            offsets
+        })
+    });
+
+    let range = Memo::new(move |_| {
+        let top = scroll_top.get();
+        let bottom = top + viewport_height.get();
+
+        offsets.with(|offsets| {
+            let len = offsets.len() - 1;
+
+            // first item that ends below the top and first item that starts below the bottom
+            let start = offsets[1..].partition_point(|end| *end <= top);
+            let end = offsets[..len].partition_point(|start| *start < bottom);
+
+            (
+                start.min(end).saturating_sub(overscan),
+                (end + overscan).min(len),
+            )
+        })
+    });
+
+    let list = Signal::derive(move || {
+        let (start, end) = range.get();
+        let items = slice(start, end);
+
+        offsets.with(|offsets| {
+            items
+                .into_iter()
+                .zip(start..)
+                .map(|(data, index)| VirtualItem {
+                    index,
+                    start: offsets[index],
+                    data,
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let total_height = Signal::derive(move || offsets.with(|offsets| offsets[offsets.len() - 1]));
+
+    let offset_top = Signal::derive(move || {
+        let (start, _) = range.get();
+        offsets.with(|offsets| offsets[start])
+    });
+
+    let offset_bottom = Signal::derive(move || {
+        let (_, end) = range.get();
+        offsets.with(|offsets| offsets[offsets.len() - 1] - offsets[end])
+    });
+
+    UseVirtualListReturn {
+        list,
+        total_height,
+        offset_top,
+        offset_bottom,
+        range: range.into(),
+        heights,
+        offsets,
+        container,
+    }
+}
+
+/// Virtualizes the rows of a [`fn@crate::use_table`].
+///
+/// This renders all filtered and sorted rows of the table through [`use_virtual_list`] instead
+/// of the current page. Only the rendered rows are cloned. The list scrolls back to the top
+/// whenever the sorting or filtering changes.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_table, use_virtual_table, TableColumn, UseVirtualListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (numbers, set_numbers) = signal((0..100_000).collect::<Vec<u32>>());
+/// let table = use_table(
+///     numbers,
+///     vec![TableColumn::new("number").sort_by_key(|n: &u32| *n)],
+/// );
+///
+/// let container = NodeRef::<Div>::new();
+/// let UseVirtualListReturn {
+///     list,
+///     offset_top,
+///     offset_bottom,
+///     ..
+/// } = use_virtual_table(table, container);
+///
+/// view! {
+///     <div node_ref=container style="height: 400px; overflow: auto">
+///         <table>
+///             <tbody>
+///                 <tr style:height=move || format!("{}px", offset_top.get())></tr>
+///                 <For each=move || list.get() key=|row| row.data let:row>
+///                     <tr>
+///                         <td>{row.data}</td>
+///                     </tr>
+///                 </For>
+///                 <tr style:height=move || format!("{}px", offset_bottom.get())></tr>
+///             </tbody>
+///         </table>
+///     </div>
+/// }
+/// # }
+/// ```
+pub fn use_virtual_table<T, El, M>(
+    table: UseTableReturn<T>,
+    container: El,
+) -> UseVirtualListReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_virtual_table_with_options(table, container, UseVirtualListOptions::default())
+}
+
+/// Version of [`use_virtual_table`] that takes a `UseVirtualListOptions`. See [`use_virtual_table`] for how to use.
+pub fn use_virtual_table_with_options<T, El, M>(
+    table: UseTableReturn<T>,
+    container: El,
+    options: UseVirtualListOptions,
+) -> UseVirtualListReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let virtual_list = virtual_list(
+        table.filtered_count,
+        move |start, end| table.rows_range(start, end),
+        container.into_element_maybe_signal(),
+        options,
+    );
+
+    let _ = Effect::watch(
+        move || (table.sort.get(), table.filter.get()),
+        move |_, _, _| virtual_list.scroll_to_index(0),
+        false,
+    );
+
+    virtual_list
+}
+
+/// Options for [`use_virtual_list_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseVirtualListOptions {
+    /// Height in pixels of items that haven't been measured yet. Defaults to `40.0`.
+    item_height: f64,
+
+    /// Number of items to render above and below the visible ones. Defaults to `5`.
+    overscan: usize,
+}
+
+impl Default for UseVirtualListOptions {
+    fn default() -> Self {
+        Self {
+            item_height: 40.0,
+            overscan: 5,
+        }
+    }
+}
+
+/// An item that is rendered by [`use_virtual_list`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VirtualItem<T> {
+    /// Index of the item in the list.
+    pub index: usize,
+
+    /// Distance in pixels from the top of the list to the item.
+    pub start: f64,
+
+    /// The item.
+    pub data: T,
+}
+
+/// Return type of [`use_virtual_list`].
+pub struct UseVirtualListReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// The items to render.
+    pub list: Signal<Vec<VirtualItem<T>>>,
+
+    /// Height in pixels of all items together.
+    pub total_height: Signal<f64>,
+
+    /// Height in pixels of the items above the rendered ones.
+    pub offset_top: Signal<f64>,
+
+    /// Height in pixels of the items below the rendered ones.
+    pub offset_bottom: Signal<f64>,
+
+    /// Range of indices of the rendered items (end is exclusive).
+    pub range: Signal<(usize, usize)>,
+
+    heights: RwSignal<HashMap<usize, f64>>,
+    offsets: Memo<Vec<f64>>,
+    container: ElementMaybeSignal<web_sys::Element>,
+}
+
+impl<T> Clone for UseVirtualListReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseVirtualListReturn<T> where T: Send + Sync + 'static {}
+
+impl<T> UseVirtualListReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Sets the height in pixels of the item at `index`.
+    pub fn measure(&self, index: usize, height: f64) {
+        let changed = self.heights.with_untracked(|heights| {
+            heights
+                .get(&index)
+                .is_none_or(|current| (current - height).abs() > 0.5)
+        });
+
+        if changed {
+            self.heights.update(|heights| {
+                heights.insert(index, height);
+            });
+        }
+    }
+
+    /// Measures the rendered element of the item at `index`.
+    pub fn measure_element(&self, index: usize, el: &web_sys::Element) {
+        self.measure(index, el.get_bounding_client_rect().height());
+    }
+
+    /// Scrolls the container so that the item at `index` is at the top.
+    pub fn scroll_to_index(&self, index: usize) {
+        let Some(offset) = self
+            .offsets
+            .with_untracked(|offsets| offsets.get(index).copied())
+        else {
+            return;
+        };
+
+        if let Some(container) = self.container.get_untracked() {
+            container.set_scroll_top(offset as i32);
+        }
+    }
+}
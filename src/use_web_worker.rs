@@ -0,0 +1,272 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{sendwrap_fn, use_event_listener};
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::fmt::Display;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API).
+///
+/// Starts a `Worker` running the script at `script_url` and terminates it automatically when the
+/// component is cleaned up.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_worker)
+///
+/// ## Usage
+///
+/// Values are (en)decoded via the given codec. You can use any of the string codecs or a
+/// binary codec wrapped in `Base64`.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_web_worker, UseWebWorkerReturn};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebWorkerReturn {
+///     data,
+///     post,
+///     terminate,
+///     status,
+///     ..
+/// } = use_web_worker::<String, String, FromToStringCodec>("/worker.js");
+///
+/// post(&"Hello worker".to_string());
+///
+/// view! {
+///     <p>"Status: " {move || status.get().to_string()}</p>
+///     <p>"Received: " {move || data.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `post` and `terminate` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_web_worker`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is started. `data`, `error` and `worker` are always `None`,
+/// `status` is always `WebWorkerStatus::Terminated` and `post` and `terminate` do nothing.
+pub fn use_web_worker<Tx, Rx, C>(
+    script_url: &str,
+) -> UseWebWorkerReturn<
+    Rx,
+    C,
+    Tx,
+    impl Fn(&Tx) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    Rx: Send + Sync + 'static,
+    C: Encoder<Tx, Encoded = String> + Decoder<Rx, Encoded = str>,
+    <C as Encoder<Tx>>::Error: Send + Sync + 'static,
+    <C as Decoder<Rx>>::Error: Send + Sync + 'static,
+{
+    use_web_worker_with_options::<Tx, Rx, C>(script_url, UseWebWorkerOptions::default())
+}
+
+/// Version of [`use_web_worker`] that takes a `UseWebWorkerOptions`. See [`use_web_worker`] for how to use.
+pub fn use_web_worker_with_options<Tx, Rx, C>(
+    script_url: &str,
+    options: UseWebWorkerOptions,
+) -> UseWebWorkerReturn<
+    Rx,
+    C,
+    Tx,
+    impl Fn(&Tx) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    Rx: Send + Sync + 'static,
+    C: Encoder<Tx, Encoded = String> + Decoder<Rx, Encoded = str>,
+    <C as Encoder<Tx>>::Error: Send + Sync + 'static,
+    <C as Decoder<Rx>>::Error: Send + Sync + 'static,
+{
+    let UseWebWorkerOptions { module, name } = options;
+
+    let (worker, set_worker) = signal_local(None::<web_sys::Worker>);
+    let (data, set_data) = signal(None::<Rx>);
+    let (status, set_status) = signal(WebWorkerStatus::Terminated);
+    let (error, set_error) = signal_local(
+        None::<UseWebWorkerError<<C as Encoder<Tx>>::Error, <C as Decoder<Rx>>::Error>>,
+    );
+
+    let post = sendwrap_fn!(move |value: &Tx| {
+        if let Some(worker) = worker.get_untracked() {
+            match C::encode(value) {
+                Ok(msg) => {
+                    worker
+                        .post_message(&msg.into())
+                        .map_err(|err| set_error.set(Some(UseWebWorkerError::PostMessage(err))))
+                        .ok();
+                }
+                Err(err) => {
+                    set_error.set(Some(UseWebWorkerError::Codec(CodecError::Encode(err))));
+                }
+            }
+        }
+    });
+
+    let terminate = sendwrap_fn!(move || {
+        if let Some(worker) = worker.get_untracked() {
+            worker.terminate();
+            set_worker.set(None);
+            set_status.set(WebWorkerStatus::Terminated);
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let worker_options = web_sys::WorkerOptions::new();
+        if module {
+            worker_options.set_type(web_sys::WorkerType::Module);
+        }
+        if let Some(name) = name {
+            worker_options.set_name(&name);
+        }
+
+        match web_sys::Worker::new_with_options(script_url, &worker_options) {
+            Ok(new_worker) => {
+                let _ = use_event_listener(new_worker.clone(), leptos::ev::message, move |event| {
+                    if let Some(msg) = event.data().as_string() {
+                        match C::decode(&msg) {
+                            Ok(msg) => set_data.set(Some(msg)),
+                            Err(err) => set_error
+                                .set(Some(UseWebWorkerError::Codec(CodecError::Decode(err)))),
+                        }
+                    } else {
+                        set_error.set(Some(UseWebWorkerError::ValueNotString));
+                    }
+                });
+
+                let _ = use_event_listener(
+                    new_worker.clone(),
+                    leptos::ev::messageerror,
+                    move |event| {
+                        set_error.set(Some(UseWebWorkerError::MessageEvent(event)));
+                    },
+                );
+
+                let _ = use_event_listener(new_worker.clone(), leptos::ev::error, move |event| {
+                    set_status.set(WebWorkerStatus::Error);
+                    set_error.set(Some(UseWebWorkerError::Event(event.into())));
+                });
+
+                set_worker.set(Some(new_worker));
+                set_status.set(WebWorkerStatus::Running);
+            }
+            Err(err) => {
+                set_status.set(WebWorkerStatus::Error);
+                set_error.set(Some(UseWebWorkerError::Create(err)));
+            }
+        }
+    }
+
+    on_cleanup({
+        let terminate = terminate.clone();
+
+        move || terminate()
+    });
+
+    UseWebWorkerReturn {
+        worker: worker.into(),
+        data: data.into(),
+        status: status.into(),
+        error: error.into(),
+        post,
+        terminate,
+    }
+}
+
+/// Options for [`use_web_worker_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseWebWorkerOptions {
+    /// If `true` the script is loaded as an ES module instead of a classic script.
+    /// Defaults to `false`.
+    module: bool,
+
+    /// A name for the worker that is mainly useful for debugging. Defaults to `None`.
+    #[builder(into)]
+    name: Option<String>,
+}
+
+/// Return type of [`use_web_worker`].
+pub struct UseWebWorkerReturn<Rx, C, Tx, PostFn, TerminateFn>
+where
+    Rx: Send + Sync + 'static,
+    C: Encoder<Tx> + Decoder<Rx>,
+    <C as Encoder<Tx>>::Error: 'static,
+    <C as Decoder<Rx>>::Error: 'static,
+    PostFn: Fn(&Tx) + Clone + Send + Sync,
+    TerminateFn: Fn() + Clone + Send + Sync,
+{
+    /// The `Worker` that is wrapped by this function. `None` after it has been terminated.
+    pub worker: Signal<Option<web_sys::Worker>, LocalStorage>,
+
+    /// Latest message received from the worker.
+    pub data: Signal<Option<Rx>>,
+
+    /// Current status of the worker.
+    pub status: Signal<WebWorkerStatus>,
+
+    /// Latest error.
+    pub error: Signal<Option<ErrorType<Tx, Rx, C>>, LocalStorage>,
+
+    /// Sends a message to the worker.
+    pub post: PostFn,
+
+    /// Terminates the worker immediately.
+    pub terminate: TerminateFn,
+}
+
+type ErrorType<Tx, Rx, C> = UseWebWorkerError<<C as Encoder<Tx>>::Error, <C as Decoder<Rx>>::Error>;
+
+/// Status of the worker managed by [`fn@crate::use_web_worker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WebWorkerStatus {
+    /// The worker is running and can receive messages.
+    Running,
+
+    /// The worker could not be created or reported an uncaught error.
+    Error,
+
+    /// The worker has been terminated (or never started).
+    Terminated,
+}
+
+impl Display for WebWorkerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Running => write!(f, "running"),
+            Self::Error => write!(f, "error"),
+            Self::Terminated => write!(f, "terminated"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UseWebWorkerError<E, D> {
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("worker error event")]
+    Event(web_sys::Event),
+    #[error("worker message error")]
+    MessageEvent(web_sys::MessageEvent),
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("received value is not a string")]
+    ValueNotString,
+}
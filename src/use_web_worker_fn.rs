@@ -0,0 +1,453 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use leptos::prelude::*;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Run a function in a [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API)
+/// without having to provide a separate worker script.
+///
+/// The worker is generated from an inline blob that contains the given JavaScript `function`.
+/// Every call to `run` starts a fresh worker, calls the function with the encoded argument and
+/// resolves with the decoded return value, so heavy computations don't block the UI.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_worker_fn)
+///
+/// ## Usage
+///
+/// The argument and return value are (en)decoded via the given codec. The function receives the
+/// encoded argument as a string and has to return (a `Promise` of) the encoded return value.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::use_web_worker_fn;
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let sort = use_web_worker_fn::<Vec<i32>, Vec<i32>, JsonSerdeCodec>(
+///     "(input) => JSON.stringify(JSON.parse(input).sort((a, b) => a - b))",
+/// );
+///
+/// let on_click = move |_| {
+///     spawn_local(async move {
+///         let sorted = sort.run(&vec![3, 1, 2]).await;
+///         assert!(matches!(sorted.as_deref(), Ok([1, 2, 3])));
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=on_click>"Sort"</button>
+///     <p>"Status: " {move || sort.status.get().to_string()}</p>
+/// }
+/// # }
+/// ```
+///
+/// ### Running Rust Code
+///
+/// To run Rust code in the worker, compile it into a separate crate with
+/// `wasm-bindgen --target no-modules`, add the generated JS glue script to the `dependencies`
+/// option and call the exported function from within `function` after initializing the module.
+///
+/// The worker is loaded from a `blob:` URL, so relative URLs can't be resolved inside of it. The
+/// `dependencies` are resolved against the URL of the page before they're imported. URLs inside
+/// of `function` have to be absolute, for example by prefixing them with `self.location.origin`
+/// which is the origin of the page in the worker.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_web_worker_fn_with_options, UseWebWorkerFnOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let fib = use_web_worker_fn_with_options::<u64, u64, FromToStringCodec>(
+///     r#"async (input) => {
+///         await wasm_bindgen(self.location.origin + "/fib_worker_bg.wasm");
+///         return wasm_bindgen.fib(BigInt(input)).toString();
+///     }"#,
+///     UseWebWorkerFnOptions::default()
+///         .dependencies(vec!["/fib_worker.js".to_string()])
+///         .timeout(5000_u64),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Only one call can run at a time. Calling `run` while another call is still running returns
+/// `Err(UseWebWorkerFnError::AlreadyRunning)`. A running call can be cancelled with `abort`,
+/// which terminates the worker immediately.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `run` always returns `Err(UseWebWorkerFnError::Server)` and `status` stays
+/// `WebWorkerFnStatus::Pending`.
+pub fn use_web_worker_fn<Args, Ret, C>(function: &str) -> UseWebWorkerFnReturn<Args, Ret, C>
+where
+    Args: 'static,
+    Ret: 'static,
+    C: Encoder<Args, Encoded = String> + Decoder<Ret, Encoded = str>,
+{
+    use_web_worker_fn_with_options(function, UseWebWorkerFnOptions::default())
+}
+
+/// Version of [`use_web_worker_fn`] that takes a `UseWebWorkerFnOptions`. See [`use_web_worker_fn`] for how to use.
+pub fn use_web_worker_fn_with_options<Args, Ret, C>(
+    function: &str,
+    options: UseWebWorkerFnOptions,
+) -> UseWebWorkerFnReturn<Args, Ret, C>
+where
+    Args: 'static,
+    Ret: 'static,
+    C: Encoder<Args, Encoded = String> + Decoder<Ret, Encoded = str>,
+{
+    let UseWebWorkerFnOptions {
+        dependencies,
+        timeout,
+    } = options;
+
+    let (status, set_status) = signal(WebWorkerFnStatus::Pending);
+
+    let ret = UseWebWorkerFnReturn {
+        status: status.into(),
+        set_status,
+        script: StoredValue::new(create_worker_script(function, &dependencies)),
+        timeout,
+        running: StoredValue::new_local(None),
+        next_id: StoredValue::new(0),
+        _marker: PhantomData,
+    };
+
+    on_cleanup(move || ret.stop(WebWorkerFnStatus::Aborted));
+
+    ret
+}
+
+/// Builds the source of the worker that calls `function` with the data of every message it
+/// receives and posts back `["SUCCESS", result]` or `["ERROR", message]`.
+fn create_worker_script(function: &str, dependencies: &[String]) -> String {
+    let import_scripts = if dependencies.is_empty() {
+        String::new()
+    } else {
+        let urls = dependencies
+            .iter()
+            .map(|url| js_string_literal(&absolute_url(url)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("importScripts({urls});\n")
+    };
+
+    format!(
+        r#"{import_scripts}const __leptosUseFn = ({function});
+self.onmessage = async (event) => {{
+  try {{
+    const result = await __leptosUseFn(event.data);
+    self.postMessage(["SUCCESS", result]);
+  }} catch (error) {{
+    self.postMessage(["ERROR", String(error)]);
+  }}
+}};
+"#
+    )
+}
+
+/// Resolves `url` against the URL of the page because the worker itself has a `blob:` URL.
+fn absolute_url(url: &str) -> String {
+    #[cfg(not(feature = "ssr"))]
+    if let Ok(url) = window()
+        .location()
+        .href()
+        .and_then(|base| web_sys::Url::new_with_base(url, &base))
+    {
+        return url.href();
+    }
+
+    url.to_string()
+}
+
+/// Quotes and escapes `value` so it can be embedded into JavaScript source.
+fn js_string_literal(value: &str) -> String {
+    #[cfg(not(feature = "ssr"))]
+    if let Some(literal) = js_sys::JSON::stringify(&JsValue::from_str(value))
+        .ok()
+        .and_then(|literal| literal.as_string())
+    {
+        return literal;
+    }
+
+    format!("{value:?}")
+}
+
+/// Options for [`use_web_worker_fn_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseWebWorkerFnOptions {
+    /// URLs of scripts that are loaded into the worker with `importScripts` before the function
+    /// is defined. Relative URLs are resolved against the URL of the page. Defaults to no
+    /// dependencies.
+    dependencies: Vec<String>,
+
+    /// Timeout in milliseconds after which a running call is terminated. Defaults to `None`
+    /// which means no timeout.
+    #[builder(into)]
+    timeout: Option<u64>,
+}
+
+/// A worker that is currently executing a call to `run`.
+struct RunningWorker {
+    id: u64,
+    worker: web_sys::Worker,
+    url: String,
+    reject: js_sys::Function,
+    timeout: Option<TimeoutHandle>,
+}
+
+/// Return type of [`use_web_worker_fn`].
+pub struct UseWebWorkerFnReturn<Args, Ret, C> {
+    /// Status of the latest call to `run`.
+    pub status: Signal<WebWorkerFnStatus>,
+
+    set_status: WriteSignal<WebWorkerFnStatus>,
+    script: StoredValue<String>,
+    timeout: Option<u64>,
+    running: StoredValue<Option<RunningWorker>, LocalStorage>,
+    next_id: StoredValue<u64>,
+    #[allow(clippy::type_complexity)]
+    _marker: PhantomData<fn() -> (Args, Ret, C)>,
+}
+
+impl<Args, Ret, C> Clone for UseWebWorkerFnReturn<Args, Ret, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Args, Ret, C> Copy for UseWebWorkerFnReturn<Args, Ret, C> {}
+
+impl<Args, Ret, C> UseWebWorkerFnReturn<Args, Ret, C>
+where
+    Args: 'static,
+    Ret: 'static,
+    C: Encoder<Args, Encoded = String> + Decoder<Ret, Encoded = str>,
+{
+    /// Runs the function in a new worker with the given argument and resolves with its return
+    /// value. The worker is terminated afterwards.
+    pub async fn run(&self, args: &Args) -> Result<Ret, ErrorType<Args, Ret, C>> {
+        #[cfg(feature = "ssr")]
+        {
+            Err(UseWebWorkerFnError::Server)
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use crate::js_fut;
+            use crate::utils::create_inline_worker;
+            use std::time::Duration;
+            use wasm_bindgen::JsCast;
+
+            if self.running.with_value(Option::is_some) {
+                return Err(UseWebWorkerFnError::AlreadyRunning);
+            }
+
+            let msg = C::encode(args)
+                .map_err(|err| UseWebWorkerFnError::Codec(CodecError::Encode(err)))?;
+
+            let (worker, url) = match self
+                .script
+                .with_value(|script| create_inline_worker(script))
+            {
+                Ok(worker) => worker,
+                Err(err) => {
+                    self.set_status.set(WebWorkerFnStatus::Error);
+                    return Err(UseWebWorkerFnError::Create(err));
+                }
+            };
+
+            let mut callbacks = None;
+            let promise = js_sys::Promise::new(&mut |resolve, reject| {
+                callbacks = Some((resolve, reject));
+            });
+            let (resolve, reject) = callbacks.expect("Promise callbacks have been set");
+
+            worker.set_onmessage(Some(&resolve));
+            worker.set_onmessageerror(Some(&reject));
+            worker.set_onerror(Some(&reject));
+
+            let id = self.next_id.get_value();
+            self.next_id.set_value(id + 1);
+
+            let this = *self;
+            let timeout = self.timeout.and_then(|timeout| {
+                set_timeout_with_handle(
+                    move || {
+                        if this.is_current(id) {
+                            this.stop(WebWorkerFnStatus::TimeoutExpired);
+                        }
+                    },
+                    Duration::from_millis(timeout),
+                )
+                .ok()
+            });
+
+            self.running.set_value(Some(RunningWorker {
+                id,
+                worker: worker.clone(),
+                url,
+                reject,
+                timeout,
+            }));
+            self.set_status.set(WebWorkerFnStatus::Running);
+
+            if let Err(err) = worker.post_message(&msg.into()) {
+                self.stop(WebWorkerFnStatus::Error);
+                return Err(UseWebWorkerFnError::PostMessage(err));
+            }
+
+            let result = js_fut!(promise).await;
+
+            if !self.is_current(id) {
+                // stopped by `abort` or the timeout which already updated the status
+                return Err(match self.status.get_untracked() {
+                    WebWorkerFnStatus::TimeoutExpired => UseWebWorkerFnError::TimeoutExpired,
+                    _ => UseWebWorkerFnError::Aborted,
+                });
+            }
+
+            let result = result
+                .map_err(UseWebWorkerFnError::Event)
+                .and_then(|event| {
+                    let data = event
+                        .unchecked_into::<web_sys::MessageEvent>()
+                        .data()
+                        .unchecked_into::<js_sys::Array>();
+
+                    match data.get(0).as_string().as_deref() {
+                        Some("SUCCESS") => match data.get(1).as_string() {
+                            Some(value) => C::decode(&value)
+                                .map_err(|err| UseWebWorkerFnError::Codec(CodecError::Decode(err))),
+                            None => Err(UseWebWorkerFnError::ValueNotString),
+                        },
+                        _ => Err(UseWebWorkerFnError::Thrown(
+                            data.get(1).as_string().unwrap_or_default(),
+                        )),
+                    }
+                });
+
+            self.stop(if result.is_ok() {
+                WebWorkerFnStatus::Success
+            } else {
+                WebWorkerFnStatus::Error
+            });
+
+            result
+        }
+    }
+}
+
+impl<Args, Ret, C> UseWebWorkerFnReturn<Args, Ret, C> {
+    /// Terminates the currently running call (if any). The pending `run` resolves with
+    /// `Err(UseWebWorkerFnError::Aborted)`.
+    pub fn abort(&self) {
+        self.stop(WebWorkerFnStatus::Aborted);
+    }
+
+    fn is_current(&self, id: u64) -> bool {
+        self.running
+            .try_with_value(|running| running.as_ref().map(|running| running.id) == Some(id))
+            .unwrap_or_default()
+    }
+
+    /// Terminates the running worker (if any) and sets the status.
+    fn stop(&self, status: WebWorkerFnStatus) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if let Some(running) = self.running.try_update_value(Option::take).flatten() {
+                running.worker.terminate();
+                if let Some(timeout) = running.timeout {
+                    timeout.clear();
+                }
+                let _ = web_sys::Url::revoke_object_url(&running.url);
+
+                self.set_status.try_set(status);
+
+                // settles the pending promise in case it hasn't been settled by the worker yet
+                let _ = running.reject.call0(&JsValue::NULL);
+            }
+        }
+    }
+}
+
+type ErrorType<Args, Ret, C> =
+    UseWebWorkerFnError<<C as Encoder<Args>>::Error, <C as Decoder<Ret>>::Error>;
+
+/// Status of the latest call to `run` of [`fn@crate::use_web_worker_fn`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WebWorkerFnStatus {
+    /// `run` hasn't been called yet.
+    #[default]
+    Pending,
+
+    /// The function is currently running in a worker.
+    Running,
+
+    /// The function returned successfully.
+    Success,
+
+    /// The worker could not be created, the function threw or the value couldn't be (de)encoded.
+    Error,
+
+    /// The call took longer than the configured timeout and has been terminated.
+    TimeoutExpired,
+
+    /// The call has been terminated by `abort`.
+    Aborted,
+}
+
+impl Display for WebWorkerFnStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Running => write!(f, "running"),
+            Self::Success => write!(f, "success"),
+            Self::Error => write!(f, "error"),
+            Self::TimeoutExpired => write!(f, "timeout expired"),
+            Self::Aborted => write!(f, "aborted"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UseWebWorkerFnError<E, D> {
+    #[error("workers cannot be run on the server")]
+    Server,
+    #[error("the function is already running")]
+    AlreadyRunning,
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("worker error event")]
+    Event(JsValue),
+    #[error("the function threw: {0}")]
+    Thrown(String),
+    #[error("the call has been aborted")]
+    Aborted,
+    #[error("the call took longer than the timeout")]
+    TimeoutExpired,
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("returned value is not a string")]
+    ValueNotString,
+}
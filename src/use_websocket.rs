@@ -1,6 +1,7 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
-use crate::{core::ConnectionReadyState, use_interval_fn, ReconnectLimit};
+use crate::core::{BackoffPolicy, BackoffStrategy, ConnectionReadyState, ReconnectLimit};
+use crate::use_interval_fn;
 use cfg_if::cfg_if;
 use codee::{CodecError, Decoder, Encoder, HybridCoderError, HybridDecoder, HybridEncoder};
 use default_struct_builder::DefaultBuilder;
@@ -8,7 +9,6 @@ use js_sys::Array;
 use leptos::{leptos_dom::helpers::TimeoutHandle, prelude::*};
 use std::marker::PhantomData;
 use std::sync::{atomic::AtomicBool, Arc};
-use std::time::Duration;
 use thiserror::Error;
 use wasm_bindgen::prelude::*;
 use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
@@ -148,6 +148,37 @@ use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
 /// }
 /// ```
 ///
+/// ### Auto-Reconnection
+///
+/// When the connection is closed or fails without calling `close` it is reopened automatically.
+/// By default there are at most 3 attempts that are 3 seconds apart. This can be configured
+/// with a [`BackoffPolicy`]. Once all attempts failed, `on_failed` is called.
+///
+/// ```
+/// # use leptos::*;
+/// # use codee::string::FromToStringCodec;
+/// # use leptos_use::{use_websocket_with_options, BackoffPolicy, ReconnectLimit, UseWebSocketOptions, UseWebSocketReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebSocketReturn { send, message, .. } =
+///     use_websocket_with_options::<String, String, FromToStringCodec, _, _>(
+///         "wss://echo.websocket.events/",
+///         UseWebSocketOptions::default()
+///             // wait 1s, 2s, 4s, ... (randomized by 20%) but at most 30s between attempts
+///             .reconnect_policy(
+///                 BackoffPolicy::exponential(1000)
+///                     .max_delay(30_000_u64)
+///                     .jitter(0.2)
+///                     .max_attempts(ReconnectLimit::Limited(10)),
+///             )
+///             .on_failed(|| leptos::logging::warn!("Giving up reconnecting")),
+///     );
+/// #
+/// # view! {}
+/// }
+/// ```
+///
 /// ## Relative Paths
 ///
 /// If the provided `url` is relative, it will be resolved relative to the current page.
@@ -327,8 +358,8 @@ where
         on_message_raw_bytes,
         on_error,
         on_close,
-        reconnect_limit,
-        reconnect_interval,
+        reconnect_policy,
+        on_failed,
         immediate,
         protocols,
         heartbeat,
@@ -342,6 +373,7 @@ where
 
     let reconnect_times_ref: StoredValue<u64> = StoredValue::new(0);
     let manually_closed_ref: StoredValue<bool> = StoredValue::new(false);
+    let failed_ref: StoredValue<bool> = StoredValue::new(false);
 
     let unmounted = Arc::new(AtomicBool::new(false));
 
@@ -428,17 +460,21 @@ where
             StoredValue::new(None);
         reconnect_ref.set_value({
             let unmounted = Arc::clone(&unmounted);
+            let on_failed = Arc::clone(&on_failed);
 
             Some(Arc::new(move || {
                 let unmounted = Arc::clone(&unmounted);
 
-                if !manually_closed_ref.get_value()
-                    && !reconnect_limit.is_exceeded_by(reconnect_times_ref.get_value())
-                    && ws_signal
+                if manually_closed_ref.get_value()
+                    || reconnect_timer_ref.get_value().is_some()
+                    || ws_signal
                         .get_untracked()
-                        .is_some_and(|ws: WebSocket| ws.ready_state() != WebSocket::OPEN)
-                    && reconnect_timer_ref.get_value().is_none()
+                        .is_none_or(|ws: WebSocket| ws.ready_state() == WebSocket::OPEN)
                 {
+                    return;
+                }
+
+                if let Some(delay) = reconnect_policy.delay(reconnect_times_ref.get_value()) {
                     reconnect_timer_ref.set_value(
                         set_timeout_with_handle(
                             move || {
@@ -450,10 +486,18 @@ where
                                     reconnect_times_ref.update_value(|current| *current += 1);
                                 }
                             },
-                            Duration::from_millis(reconnect_interval),
+                            delay,
                         )
                         .ok(),
                     );
+                } else if !failed_ref.get_value() {
+                    // both the error and the close event end up here so make sure to only notify once
+                    failed_ref.set_value(true);
+
+                    #[cfg(debug_assertions)]
+                    let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                    on_failed();
                 }
             }))
         });
@@ -513,6 +557,9 @@ where
 
                             set_ready_state.set(ConnectionReadyState::Open);
 
+                            // the connection succeeded so the backoff starts from scratch next time
+                            reconnect_times_ref.set_value(0);
+
                             start_heartbeat();
                         }
                     })
@@ -680,6 +727,7 @@ where
     // Open connection
     let open = move || {
         reconnect_times_ref.set_value(0);
+        failed_ref.set_value(false);
         if let Some(connect) = connect_ref.get_value() {
             connect();
         }
@@ -776,11 +824,12 @@ where
     on_error: Arc<dyn Fn(UseWebSocketError<E, D>) + Send + Sync>,
     /// `WebSocket` close callback.
     on_close: Arc<dyn Fn(CloseEvent) + Send + Sync>,
-    /// Retry times. Defaults to `ReconnectLimit::Limited(3)`. Use `ReconnectLimit::Infinite` for
-    /// infinite retries.
-    reconnect_limit: ReconnectLimit,
-    /// Retry interval in ms. Defaults to 3000.
-    reconnect_interval: u64,
+    /// Delay between and maximum number of reconnection attempts.
+    /// Defaults to 3 attempts with a fixed interval of 3000ms. See [`BackoffPolicy`].
+    #[builder(into)]
+    reconnect_policy: BackoffPolicy,
+    /// Called once the maximum number of reconnection attempts is reached without success.
+    on_failed: Arc<dyn Fn() + Send + Sync>,
     /// If `true` the `WebSocket` connection will immediately be opened when calling this function.
     /// If `false` you have to manually call the `open` function.
     /// Defaults to `true`.
@@ -827,6 +876,26 @@ where
         }
     }
 
+    /// Retry times. Defaults to `ReconnectLimit::Limited(3)`. Use `ReconnectLimit::Infinite` for
+    /// infinite retries. Shorthand for setting the maximum attempts of the `reconnect_policy`.
+    pub fn reconnect_limit(self, limit: ReconnectLimit) -> Self {
+        Self {
+            reconnect_policy: self.reconnect_policy.max_attempts(limit),
+            ..self
+        }
+    }
+
+    /// Retry interval in ms. Defaults to 3000. Shorthand for setting a fixed delay as the
+    /// strategy of the `reconnect_policy`.
+    pub fn reconnect_interval(self, interval: u64) -> Self {
+        Self {
+            reconnect_policy: self
+                .reconnect_policy
+                .strategy(BackoffStrategy::Fixed(interval)),
+            ..self
+        }
+    }
+
     /// Set the data, codec and interval at which the heartbeat is sent. The heartbeat
     /// is the default value of the `NewHb` type.
     pub fn heartbeat<NewHb, NewHbCodec>(
@@ -854,8 +923,8 @@ where
             on_message_raw_bytes: self.on_message_raw_bytes,
             on_close: self.on_close,
             on_error: self.on_error,
-            reconnect_limit: self.reconnect_limit,
-            reconnect_interval: self.reconnect_interval,
+            reconnect_policy: self.reconnect_policy,
+            on_failed: self.on_failed,
             immediate: self.immediate,
             protocols: self.protocols,
         }
@@ -872,8 +941,8 @@ impl<Rx: ?Sized, E, D> Default for UseWebSocketOptions<Rx, E, D, (), DummyEncode
             on_message_raw_bytes: Arc::new(|_| {}),
             on_error: Arc::new(|_| {}),
             on_close: Arc::new(|_| {}),
-            reconnect_limit: ReconnectLimit::default(),
-            reconnect_interval: 3000,
+            reconnect_policy: BackoffPolicy::default(),
+            on_failed: Arc::new(|| {}),
             immediate: true,
             protocols: Default::default(),
         }
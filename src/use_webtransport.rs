@@ -1,27 +1,27 @@
-use crate::core::ConnectionReadyState;
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{BackoffPolicy, BackoffStrategy, ConnectionReadyState, ReconnectLimit};
+use crate::{js, js_fut};
 use async_trait::async_trait;
+use codee::{Decoder, Encoder};
 use default_struct_builder::DefaultBuilder;
-use js_sys::Reflect;
 use leptos::leptos_dom::helpers::TimeoutHandle;
 use leptos::prelude::*;
-use std::cell::{Cell, RefCell};
-use std::rc::Rc;
-use std::time::Duration;
-use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::JsFuture;
-
-#[cfg(any(feature = "bincode", feature = "msgpack"))]
-use serde::{Deserialize, Serialize};
-
-#[cfg(feature = "msgpack")]
-use rmp_serde::{from_slice, to_vec};
+use leptos::task::spawn_local;
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use thiserror::Error;
-use web_sys::WebTransportBidirectionalStream;
-
-#[cfg(feature = "bincode")]
-use bincode::serde::{decode_from_slice as from_slice, encode_to_vec as to_vec};
+use wasm_bindgen::prelude::*;
 
-/// This still under development and will not arrive before Leptos 0.7.
+/// Reactive [WebTransport](https://developer.mozilla.org/en-US/docs/Web/API/WebTransport_API).
+///
+/// Connects to an HTTP/3 server and provides unreliable datagrams as well as reliable
+/// unidirectional and bidirectional streams. This is meant for low-latency apps where a
+/// `WebSocket` isn't enough.
+///
+/// WebTransport is still an unstable API in `web_sys`. That's why this function is only available
+/// if you compile with `--cfg=web_sys_unstable_apis`.
 ///
 /// ## Demo
 ///
@@ -35,16 +35,86 @@ use bincode::serde::{decode_from_slice as from_slice, encode_to_vec as to_vec};
 /// #
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
-/// use_webtransport();
+/// let transport = use_webtransport("https://echo.webtransport.day");
+///
+/// view! {
+///     <p>"Ready state: " {move || transport.ready_state.get().to_string()}</p>
+///     <button on:click=move |_| transport.send_datagrams(b"Hello")>"Send"</button>
+///     <p>
+///         "Received: "
+///         {move || {
+///             transport
+///                 .datagrams
+///                 .get()
+///                 .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+///         }}
+///     </p>
+/// }
+/// # }
+/// ```
+///
+/// `datagrams` contains the latest datagram that has been received. Datagrams are sent with
+/// `send_datagrams`. Both work with raw bytes.
+///
+/// ### Streams
+///
+/// Open streams to the server with `open_send_stream` and `open_bidir_stream`. Streams that are
+/// opened by the server are passed to the options `on_receive_stream` and `on_bidir_stream`.
+///
+/// Streams send and receive raw bytes with the methods of the traits [`SendableStream`] and
+/// [`ReceivableStream`]. With a codec from the crate `codee` you can send and receive typed values
+/// instead.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_webtransport, ReceivableStream, SendableStream};
+/// # use codee::binary::FromToBytesCodec;
 /// #
-/// # view! { }
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let transport = use_webtransport("https://echo.webtransport.day");
+///
+/// let (message, set_message) = signal(None::<Signal<Option<String>>>);
+///
+/// let on_click = move |_| {
+///     spawn_local(async move {
+///         if let Ok(stream) = transport.open_bidir_stream().await {
+///             stream.send::<_, FromToBytesCodec>(&"Hello".to_string());
+///             set_message.set(Some(stream.receive::<String, FromToBytesCodec>()));
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=on_click>"Open stream"</button>
+///     <p>"Received: " {move || message.get().and_then(|message| message.get())}</p>
+/// }
 /// # }
 /// ```
+///
+/// ### Reconnection
+///
+/// If the connection fails or is lost it's re-established according to the option
+/// `reconnect_policy`. By default three attempts are made with an interval of three seconds.
+/// Calling `close` stops reconnecting until `open` is called again.
+///
+/// With the option `immediate` set to `false` the connection is only established when you call
+/// `open`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no connection is established. `ready_state` is always `Closed`, `open` and
+/// `close` are no-ops and opening a stream returns [`WebTransportError::NotConnected`].
+///
+/// ## See also
+///
+/// * [`fn@crate::use_websocket`]
 pub fn use_webtransport(url: &str) -> UseWebTransportReturn {
     use_webtransport_with_options(url, UseWebTransportOptions::default())
 }
 
-/// Version of [`use_webtransport`] that takes a `UseWebtransportOptions`. See [`use_webtransport`] for how to use.
+/// Version of [`use_webtransport`] that takes a `UseWebTransportOptions`. See [`use_webtransport`] for how to use.
 pub fn use_webtransport_with_options(
     url: &str,
     options: UseWebTransportOptions,
@@ -55,8 +125,7 @@ pub fn use_webtransport_with_options(
         on_close,
         on_receive_stream,
         on_bidir_stream,
-        reconnect_limit,
-        reconnect_interval,
+        reconnect_policy,
         immediate,
     } = options;
     let url = url.to_string();
@@ -64,79 +133,86 @@ pub fn use_webtransport_with_options(
     let (ready_state, set_ready_state) = signal(ConnectionReadyState::Closed);
     let ready_state: Signal<_> = ready_state.into();
 
-    let transport = Rc::new(RefCell::new(None::<web_sys::WebTransport>));
-    let datagrams_reader_initialized = Rc::new(Cell::new(false));
-    let datagrams_writer = Rc::new(RefCell::new(None::<web_sys::WritableStreamDefaultWriter>));
-
-    let reconnect_timer = Rc::new(Cell::new(None::<TimeoutHandle>));
-    let reconnect_count = Rc::new(Cell::new(0_u64));
+    let transport = StoredValue::new_local(None::<web_sys::WebTransport>);
+    let datagrams_reader_initialized = StoredValue::new(false);
+    let datagrams_writer = StoredValue::new_local(None::<web_sys::WritableStreamDefaultWriter>);
 
-    let unmounted = Rc::new(Cell::new(false));
+    #[cfg(not(feature = "ssr"))]
+    let (open, close): (ArcFn, ArcFn) = {
+        let reconnect_timer = StoredValue::new(None::<TimeoutHandle>);
+        let reconnect_count = StoredValue::new(0_u64);
+        let manually_closed = StoredValue::new(false);
 
-    let connect_ref = StoredValue::new(None::<Rc<dyn Fn()>>);
+        let unmounted = Arc::new(AtomicBool::new(false));
 
-    let reconnect = Rc::new({
-        let reconnect_timer = Rc::clone(&reconnect_timer);
-        let reconnect_count = Rc::clone(&reconnect_count);
+        let connect_ref = StoredValue::new(None::<ArcFn>);
 
-        move || {
-            if reconnect_count.get() < reconnect_limit
-                && ready_state.get_untracked() == ConnectionReadyState::Open
+        let reconnect = Arc::new(move || {
+            if manually_closed.get_value()
+                || reconnect_timer.get_value().is_some()
+                || ready_state.get_untracked() == ConnectionReadyState::Open
             {
-                reconnect_timer.set(
+                return;
+            }
+
+            if let Some(delay) = reconnect_policy.delay(reconnect_count.get_value()) {
+                reconnect_timer.set_value(
                     set_timeout_with_handle(
                         move || {
                             if let Some(connect) = connect_ref.get_value() {
                                 connect();
-                                reconnect_count.set(reconnect_count.get() + 1);
+                                reconnect_count.update_value(|count| *count += 1);
                             }
                         },
-                        Duration::from_millis(reconnect_interval),
+                        delay,
                     )
                     .ok(),
                 )
             }
-        }
-    });
-
-    connect_ref.set_value(Some(Rc::new({
-        let transport = Rc::clone(&transport);
-        let reconnect_timer = Rc::clone(&reconnect_timer);
-        let on_open = Rc::clone(&on_open);
-        let on_bidir_stream = Rc::clone(&on_bidir_stream);
-        let on_receive_stream = Rc::clone(&on_receive_stream);
-
-        move || {
-            reconnect_timer.set(None);
-
-            if let Some(transport) = transport.borrow().as_ref() {
-                transport.close();
-            }
-
-            let options = web_sys::WebTransportOptions::new();
-            transport.replace(Some(
-                web_sys::WebTransport::new_with_options(&url, &options).unwrap_throw(),
-            ));
+        });
 
-            set_ready_state.set(ConnectionReadyState::Connecting);
+        connect_ref.set_value(Some(Arc::new({
+            let on_close = Arc::clone(&on_close);
+            let unmounted = Arc::clone(&unmounted);
 
-            spawn_local({
-                let transport = Rc::clone(&transport);
-                let on_open = Rc::clone(&on_open);
-                let on_bidir_stream = Rc::clone(&on_bidir_stream);
-                let on_receive_stream = Rc::clone(&on_receive_stream);
+            move || {
+                reconnect_timer.set_value(None);
 
-                async move {
-                    let transport = transport.borrow();
-                    let transport = transport.as_ref().expect("Transport should be set");
+                if let Some(transport) = transport.get_value() {
+                    transport.close();
+                }
+                datagrams_writer.set_value(None);
 
-                    match js_fut!(transport.ready()).await {
-                        Ok(_) => {
+                let Ok(new_transport) = web_sys::WebTransport::new_with_options(
+                    &url,
+                    &web_sys::WebTransportOptions::new(),
+                ) else {
+                    set_ready_state.set(ConnectionReadyState::Closed);
+                    reconnect();
+                    return;
+                };
+                transport.set_value(Some(new_transport.clone()));
+
+                set_ready_state.set(ConnectionReadyState::Connecting);
+
+                spawn_local({
+                    let on_open = Arc::clone(&on_open);
+                    let on_close = Arc::clone(&on_close);
+                    let on_bidir_stream = Arc::clone(&on_bidir_stream);
+                    let on_receive_stream = Arc::clone(&on_receive_stream);
+                    let reconnect = Arc::clone(&reconnect);
+                    let unmounted = Arc::clone(&unmounted);
+
+                    async move {
+                        let opened = js_fut!(new_transport.ready()).await.is_ok();
+
+                        if opened {
+                            reconnect_count.set_value(0);
                             set_ready_state.set(ConnectionReadyState::Open);
                             on_open();
 
                             listen_to_stream(
-                                transport.incoming_bidirectional_streams(),
+                                new_transport.incoming_bidirectional_streams(),
                                 move |value| {
                                     let stream: web_sys::WebTransportBidirectionalStream =
                                         value.unchecked_into();
@@ -148,159 +224,149 @@ pub fn use_webtransport_with_options(
                                 || {},
                             );
                             listen_to_stream(
-                                transport.incoming_unidirectional_streams(),
+                                new_transport.incoming_unidirectional_streams(),
                                 move |value| {
                                     let stream: web_sys::ReadableStream = value.unchecked_into();
 
-                                    let (state, set_state, bytes) = create_state_and_bytes_signal(
-                                        stream.unchecked_into(),
-                                        ready_state,
-                                    );
+                                    let (state, _, bytes) =
+                                        create_state_and_bytes_signal(stream, ready_state);
 
-                                    on_receive_stream(ReceiveStream {
-                                        bytes,
-                                        state,
-                                        set_state,
-                                    });
+                                    on_receive_stream(ReceiveStream { bytes, state });
                                 },
                                 || {},
                             );
+
+                            // resolves or rejects when the connection has been closed or lost
+                            let _ = js_fut!(new_transport.closed()).await;
                         }
-                        Err(e) => {
-                            // TODO : handle error?
-                            set_ready_state.set(ConnectionReadyState::Closed);
+
+                        // closing by hand or connecting again takes care of the replaced transport
+                        if unmounted.load(Ordering::Relaxed)
+                            || transport.try_get_value().flatten().as_ref() != Some(&new_transport)
+                        {
+                            return;
                         }
-                    }
-                }
-            });
-        }
-    })));
 
-    let open = {
-        let reconnect_count = Rc::clone(&reconnect_count);
+                        transport.set_value(None);
+                        datagrams_writer.set_value(None);
+                        set_ready_state.set(ConnectionReadyState::Closed);
 
-        move || {
-            reconnect_count.set(0);
+                        if opened {
+                            on_close();
+                        }
+
+                        reconnect();
+                    }
+                });
+            }
+        })));
+
+        let open = Arc::new(move || {
+            reconnect_count.set_value(0);
+            manually_closed.set_value(false);
             if let Some(connect) = connect_ref.get_value() {
                 connect();
             }
-        }
-    };
+        });
 
-    let on_closed = {
-        let reconnect = Rc::clone(&reconnect);
-        let unmounted = Rc::clone(&unmounted);
+        let close = Arc::new({
+            let unmounted = Arc::clone(&unmounted);
 
-        move || {
-            if unmounted.get() {
-                return;
-            }
+            move || {
+                manually_closed.set_value(true);
 
-            // TODO
-            // reconnect();
-        }
-    };
+                if let Some(timer) = reconnect_timer.try_update_value(Option::take).flatten() {
+                    timer.clear();
+                }
 
-    let close = {
-        let transport = Rc::clone(&transport);
-        let reconnect_count = Rc::clone(&reconnect_count);
+                if let Some(transport) = transport.try_update_value(Option::take).flatten() {
+                    datagrams_writer.set_value(None);
+                    transport.close();
+                    set_ready_state.set(ConnectionReadyState::Closing);
 
-        move || {
-            reconnect_count.set(reconnect_limit);
+                    let on_close = Arc::clone(&on_close);
+                    let unmounted = Arc::clone(&unmounted);
 
-            if let Some(transport) = transport.take() {
-                transport.close();
-                set_ready_state.set(ConnectionReadyState::Closing);
+                    spawn_local(async move {
+                        // TODO : handle error?
+                        let _ = js_fut!(transport.closed()).await;
 
-                spawn_local(async move {
-                    let result = js_fut!(transport.closed()).await;
-                    set_ready_state.set(ConnectionReadyState::Closed);
+                        if unmounted.load(Ordering::Relaxed) {
+                            return;
+                        }
 
-                    on_closed();
+                        set_ready_state.set(ConnectionReadyState::Closed);
+                        on_close();
+                    });
+                }
+            }
+        });
 
-                    match result {
-                        Ok(_) => {}
-                        Err(e) => {
-                            // TODO : handle error?
-                        }
-                    }
-                });
+        on_cleanup({
+            let close = Arc::clone(&close);
+
+            move || {
+                unmounted.store(true, Ordering::Relaxed);
+                close();
             }
-        }
-    };
+        });
 
-    let (datagrams_signal, set_datagrams) = signal(None::<Vec<u8>>);
-
-    let datagrams = Signal::derive({
-        let transport = Rc::clone(&transport);
-        let datagrams_reader_initialized = Rc::clone(&datagrams_reader_initialized);
-
-        move || {
-            let transport = Rc::clone(&transport);
-
-            lazy_initialize_u8_reader(
-                ready_state,
-                Rc::clone(&datagrams_reader_initialized),
-                move || {
-                    transport
-                        .borrow()
-                        .as_ref()
-                        .expect("transport should be set a this point")
-                        .datagrams()
-                        .readable()
-                },
-                set_datagrams,
-                || {},
-            );
-
-            datagrams_signal.get()
-        }
-    });
+        (open, close)
+    };
 
-    {
-        let unmounted = Rc::clone(&unmounted);
+    #[cfg(feature = "ssr")]
+    let (open, close): (ArcFn, ArcFn) = (Arc::new(|| {}), Arc::new(|| {}));
+
+    let (datagrams, set_datagrams) = signal(None::<Vec<u8>>);
+
+    let datagrams = Signal::derive(move || {
+        lazy_initialize_u8_reader(
+            ready_state,
+            datagrams_reader_initialized,
+            move || {
+                transport
+                    .get_value()
+                    .map(|transport| transport.datagrams().readable())
+            },
+            set_datagrams,
+            || {},
+        );
 
-        on_cleanup(move || {
-            unmounted.set(true);
-            close();
-        });
-    }
+        datagrams.get()
+    });
 
     if immediate {
         open();
     }
 
     UseWebTransportReturn {
-        transport,
         ready_state,
         datagrams,
+        transport,
         datagrams_writer,
+        open: StoredValue::new(open),
+        close: StoredValue::new(close),
     }
 }
 
 fn get_or_create_datagrams_writer(
-    datagrams_writer: Rc<RefCell<Option<web_sys::WritableStreamDefaultWriter>>>,
+    datagrams_writer: StoredValue<Option<web_sys::WritableStreamDefaultWriter>, LocalStorage>,
     transport: &web_sys::WebTransport,
-) -> web_sys::WritableStreamDefaultWriter {
-    let writer = datagrams_writer.borrow().clone();
-
-    if let Some(writer) = writer {
-        writer
-    } else {
-        let writer = transport
-            .datagrams()
-            .writable()
-            .get_writer()
-            .expect("should be able to get the writer");
-        datagrams_writer.replace(Some(writer.clone()));
-        writer
+) -> Option<web_sys::WritableStreamDefaultWriter> {
+    if let Some(writer) = datagrams_writer.get_value() {
+        return Some(writer);
     }
+
+    let writer = transport.datagrams().writable().get_writer().ok()?;
+    datagrams_writer.set_value(Some(writer.clone()));
+
+    Some(writer)
 }
 
 fn lazy_initialize_u8_reader(
     ready_state: Signal<ConnectionReadyState>,
-    initialized: Rc<Cell<bool>>,
-    get_readable_stream: impl Fn() -> web_sys::ReadableStream,
+    initialized: StoredValue<bool>,
+    get_readable_stream: impl Fn() -> Option<web_sys::ReadableStream>,
     set_signal: WriteSignal<Option<Vec<u8>>>,
     on_done: impl Fn() + 'static,
 ) {
@@ -309,29 +375,33 @@ fn lazy_initialize_u8_reader(
         initialized,
         get_readable_stream,
         move |value| {
-            let value: js_sys::Uint8Array = value.into();
-            set_signal.set(Some(value.to_vec()));
+            let value: js_sys::Uint8Array = value.unchecked_into();
+            set_signal.try_set(Some(value.to_vec()));
         },
         on_done,
     );
 }
 
+/// Starts reading the stream the first time this is called while the connection is open.
+/// Reading can be started again once the stream is done.
 fn lazy_initialize_reader(
     ready_state: Signal<ConnectionReadyState>,
-    initialized: Rc<Cell<bool>>,
-    get_readable_stream: impl Fn() -> web_sys::ReadableStream,
+    initialized: StoredValue<bool>,
+    get_readable_stream: impl Fn() -> Option<web_sys::ReadableStream>,
     on_value: impl Fn(JsValue) + 'static,
     on_done: impl Fn() + 'static,
 ) {
-    if ready_state.get() == ConnectionReadyState::Open {
-        if !initialized.get() {
-            initialized.set(true);
-
-            listen_to_stream(get_readable_stream(), on_value, move || {
-                initialized.set(false);
-                on_done();
-            });
-        }
+    if ready_state.get() != ConnectionReadyState::Open || initialized.get_value() {
+        return;
+    }
+
+    if let Some(readable_stream) = get_readable_stream() {
+        initialized.set_value(true);
+
+        listen_to_stream(readable_stream, on_value, move || {
+            initialized.try_set_value(false);
+            on_done();
+        });
     }
 }
 
@@ -344,28 +414,20 @@ fn listen_to_stream(
         readable_stream.get_reader().unchecked_into();
 
     spawn_local(async move {
-        loop {
-            let result = js_fut!(reader.read()).await;
-            match result {
-                Ok(result) => {
-                    let done = js!(result["done"])
-                        .expect("done should always be there")
-                        .as_bool()
-                        .unwrap_or(true);
-
-                    if done {
-                        // TODO : close connection?
-                        break;
-                    }
-
-                    let value = js!(result["value"]).expect("if not done there should be a value");
+        // TODO : error handling?
+        while let Ok(result) = js_fut!(reader.read()).await {
+            let done = js!(result["done"])
+                .ok()
+                .and_then(|done| done.as_bool())
+                .unwrap_or(true);
+
+            if done {
+                // TODO : close connection?
+                break;
+            }
 
-                    on_value(value);
-                }
-                Err(..) => {
-                    // TODO : error handling?
-                    break;
-                }
+            if let Ok(value) = js!(result["value"]) {
+                on_value(value);
             }
         }
 
@@ -377,27 +439,24 @@ fn listen_to_stream(
 #[derive(DefaultBuilder)]
 pub struct UseWebTransportOptions {
     /// Callback when `WebTransport` is ready.
-    on_open: Rc<dyn Fn()>,
-
-    /// Error callback.
-    // TODO : ? on_error: Rc<dyn Fn(WebTransportError)>,
+    on_open: Arc<dyn Fn() + Send + Sync>,
 
+    // TODO : ? on_error: Arc<dyn Fn(WebTransportError) + Send + Sync>,
     /// Callback when `WebTransport` is closed.
-    on_close: Rc<dyn Fn()>,
+    on_close: Arc<dyn Fn() + Send + Sync>,
 
     /// Callback when the server opens a one-way stream.
-    on_receive_stream: Rc<dyn Fn(ReceiveStream)>,
+    on_receive_stream: Arc<dyn Fn(ReceiveStream) + Send + Sync>,
 
     /// Callback when the server opens a bidirectional stream.
-    on_bidir_stream: Rc<dyn Fn(BidirStream)>,
-
-    /// Retry times. Defaults to 3.
-    reconnect_limit: u64,
+    on_bidir_stream: Arc<dyn Fn(BidirStream) + Send + Sync>,
 
-    /// Retry interval in ms. Defaults to 3000.
-    reconnect_interval: u64,
+    /// Delay between and maximum number of reconnection attempts.
+    /// Defaults to 3 attempts with a fixed interval of 3000ms. See [`BackoffPolicy`].
+    #[builder(into)]
+    reconnect_policy: BackoffPolicy,
 
-    /// If `true` the `WebSocket` connection will immediately be opened when calling this function.
+    /// If `true` the `WebTransport` connection will immediately be opened when calling this function.
     /// If `false` you have to manually call the `open` function.
     /// Defaults to `true`.
     immediate: bool,
@@ -406,18 +465,39 @@ pub struct UseWebTransportOptions {
 impl Default for UseWebTransportOptions {
     fn default() -> Self {
         Self {
-            on_open: Rc::new(|| {}),
-            // on_error: Rc::new(|_| {}),
-            on_close: Rc::new(|| {}),
-            on_receive_stream: Rc::new(|_| {}),
-            on_bidir_stream: Rc::new(|_| {}),
-            reconnect_limit: 3,
-            reconnect_interval: 3000,
+            on_open: Arc::new(|| {}),
+            // on_error: Arc::new(|_| {}),
+            on_close: Arc::new(|| {}),
+            on_receive_stream: Arc::new(|_| {}),
+            on_bidir_stream: Arc::new(|_| {}),
+            reconnect_policy: BackoffPolicy::default(),
             immediate: true,
         }
     }
 }
 
+impl UseWebTransportOptions {
+    /// Retry times. Defaults to `ReconnectLimit::Limited(3)`. Use `ReconnectLimit::Infinite` for
+    /// infinite retries. Shorthand for setting the maximum attempts of the `reconnect_policy`.
+    pub fn reconnect_limit(self, limit: ReconnectLimit) -> Self {
+        Self {
+            reconnect_policy: self.reconnect_policy.max_attempts(limit),
+            ..self
+        }
+    }
+
+    /// Retry interval in ms. Defaults to 3000. Shorthand for setting a fixed delay as the
+    /// strategy of the `reconnect_policy`.
+    pub fn reconnect_interval(self, interval: u64) -> Self {
+        Self {
+            reconnect_policy: self
+                .reconnect_policy
+                .strategy(BackoffStrategy::Fixed(interval)),
+            ..self
+        }
+    }
+}
+
 /// Wether the stream is open or closed
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum StreamState {
@@ -446,7 +526,7 @@ pub trait SendableStream: CloseableStream {
 
     /// Send data in the form of bytes ignoring potential errors
     fn send_bytes(&self, data: &[u8]) {
-        if self.state().get() == StreamState::Open {
+        if self.state().get_untracked() == StreamState::Open {
             let arr = js_sys::Uint8Array::from(data);
             let _ = self.writer().write_with_chunk(&arr);
         }
@@ -454,48 +534,75 @@ pub trait SendableStream: CloseableStream {
 
     /// Send data in the form of bytes asynchronously with a result providing potential errors
     async fn send_bytes_async(&self, data: &[u8]) -> Result<(), SendError> {
-        if self.state().get() != StreamState::Open {
-            return Err(SendError::StreamNotOpen);
-        }
+        write_bytes(self, data).await
+    }
 
-        let arr = js_sys::Uint8Array::from(data);
-        let _ = js_fut!(self.writer().write_with_chunk(&arr))
-            .await
-            .map_err(|e| SendError::FailedToWrite(e));
+    /// Send a value encoded with the codec `C` ignoring potential errors
+    fn send<T, C>(&self, data: &T)
+    where
+        C: Encoder<T, Encoded = Vec<u8>>,
+    {
+        if let Ok(bytes) = C::encode(data) {
+            self.send_bytes(&bytes);
+        }
+    }
 
-        Ok(())
+    /// Send a value encoded with the codec `C` asynchronously with a result providing potential
+    /// errors
+    async fn send_async<T, C>(&self, data: &T) -> Result<(), SendError<<C as Encoder<T>>::Error>>
+    where
+        C: Encoder<T, Encoded = Vec<u8>>,
+    {
+        let bytes = C::encode(data).map_err(SendError::Codec)?;
+        write_bytes(self, &bytes).await
     }
+}
 
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Send data in the form of a serializable object ignoring potential errors
-    /// Requires the feature `msgpack` or `bincode`
-    fn send<T: Serialize>(&self, data: &T) {
-        self.send_bytes(
-            to_vec(data)
-                .expect("Serialization should not fail")
-                .as_slice(),
-        );
+/// Trait to receive data in a stream
+pub trait ReceivableStream {
+    /// Getter for the latest bytes received
+    fn bytes(&self) -> Signal<Option<Vec<u8>>>;
+
+    /// Receive values decoded with the codec `C` ignoring values that fail to decode
+    fn receive<T, C>(&self) -> Signal<Option<T>>
+    where
+        T: Send + Sync + 'static,
+        C: Decoder<T, Encoded = [u8]> + 'static,
+    {
+        let bytes = self.bytes();
+
+        Signal::derive(move || {
+            bytes.with(|bytes| bytes.as_deref().and_then(|bytes| C::decode(bytes).ok()))
+        })
     }
 
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Send data in the form of a serializable object asynchronously with a result providing potential errors
-    /// Requires the feature `msgpack` or `bincode`
-    async fn send_async<T: Serialize>(&self, data: &T) -> Result<(), SendError> {
-        let serialized = to_vec(data)?;
-        self.send_bytes_async(&serialized).await
+    /// Receive values decoded with the codec `C` together with potential decoding errors
+    fn try_receive<T, C>(&self) -> Signal<Option<Result<T, <C as Decoder<T>>::Error>>>
+    where
+        T: Send + Sync + 'static,
+        C: Decoder<T, Encoded = [u8]> + 'static,
+        <C as Decoder<T>>::Error: Send + Sync + 'static,
+    {
+        let bytes = self.bytes();
+
+        Signal::derive(move || bytes.with(|bytes| bytes.as_deref().map(C::decode)))
     }
 }
 
-#[async_trait(?Send)]
-/// Trait to receive data in a stream
-pub trait ReceivableStream: CloseableStream {
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Receive data in the form of a serializable object ignoring potential errors
-    fn receive<T: for<'a> Deserialize<'a>>(&self) -> Signal<Option<T>>;
-
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Receive data in the form of a serializable object asynchronously with a result providing potential errors
-    fn try_receive<T: for<'a> Deserialize<'a>>(&self) -> Signal<Option<Result<T, ReceiveError>>>;
+async fn write_bytes<E>(
+    stream: &(impl SendableStream + ?Sized),
+    data: &[u8],
+) -> Result<(), SendError<E>> {
+    if stream.state().get_untracked() != StreamState::Open {
+        return Err(SendError::StreamNotOpen);
+    }
+
+    let arr = js_sys::Uint8Array::from(data);
+    js_fut!(stream.writer().write_with_chunk(&arr))
+        .await
+        .map_err(SendError::FailedToWrite)?;
+
+    Ok(())
 }
 
 #[derive(Clone, Debug)]
@@ -508,14 +615,17 @@ pub struct SendStream {
 
 #[derive(Clone, Debug)]
 /// Stream for receiving data
-#[allow(dead_code)]
 pub struct ReceiveStream {
     pub bytes: Signal<Option<Vec<u8>>>,
     state: Signal<StreamState>,
-    set_state: WriteSignal<StreamState>,
 }
 
-// TODO : implement ReceiveStream
+impl ReceiveStream {
+    /// Getter for the stream state. The stream is closed once the server has finished sending.
+    pub fn state(&self) -> Signal<StreamState> {
+        self.state
+    }
+}
 
 #[derive(Clone, Debug)]
 /// Bidirectional stream for sending and receiving data
@@ -528,35 +638,10 @@ pub struct BidirStream {
 
 macro_rules! impl_receivable_stream {
     ($ty:ty) => {
-        impl BidirStream {
-            #[cfg(any(feature = "msgpack", feature = "bincode"))]
-            pub fn receive<T: for<'a> Deserialize<'a>>(&self) -> Signal<Option<T>> {
-                let bytes = self.bytes;
-
-                Signal::derive(move || {
-                    if self.state.get() != StreamState::Open {
-                        None
-                    } else {
-                        bytes
-                            .get()
-                            .and_then(|bytes| from_slice(bytes.as_slice()).ok())
-                    }
-                })
-            }
-
-            #[cfg(any(feature = "msgpack", feature = "bincode"))]
-            pub fn try_receive<T: for<'a> Deserialize<'a>>(
-                &self,
-            ) -> Signal<Option<Result<T, ReceiveError>>> {
-                let bytes = self.bytes;
-
-                Signal::derive(move || {
-                    if self.state.get() != StreamState::Open {
-                        None
-                    } else {
-                        bytes.get().map(|bytes| Ok(from_slice(bytes.as_slice())?))
-                    }
-                })
+        impl ReceivableStream for $ty {
+            #[inline(always)]
+            fn bytes(&self) -> Signal<Option<Vec<u8>>> {
+                self.bytes
             }
         }
     };
@@ -591,21 +676,19 @@ macro_rules! impl_closable_stream {
 
             #[inline(always)]
             fn close(&self) {
-                spawn_local(async {
-                    self.close_async().await.ok();
+                let stream = self.clone();
+
+                spawn_local(async move {
+                    stream.close_async().await.ok();
                 })
             }
 
             async fn close_async(&self) -> Result<(), WebTransportError> {
-                let _ = js_fut!(self.writer.close()).await.map_err(|e| {
-                    let error = WebTransportError::OnCloseWriter(e);
-                    self.set_state.set(StreamState::Closed);
-                    error
-                })?;
+                let result = js_fut!(self.writer.close()).await;
 
                 self.set_state.set(StreamState::Closed);
 
-                Ok(())
+                result.map(|_| ()).map_err(WebTransportError::OnCloseWriter)
             }
         }
     };
@@ -615,30 +698,45 @@ impl_closable_stream!(SendStream);
 impl_closable_stream!(BidirStream);
 
 /// Return type of [`use_webtransport`].
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy)]
 pub struct UseWebTransportReturn {
-    transport: Rc<RefCell<Option<web_sys::WebTransport>>>,
-    datagrams_writer: Rc<RefCell<Option<web_sys::WritableStreamDefaultWriter>>>,
-
     /// The current state of the `WebTransport` connection.
     pub ready_state: Signal<ConnectionReadyState>,
 
     /// Latest datagrams message received
     pub datagrams: Signal<Option<Vec<u8>>>,
+
+    transport: StoredValue<Option<web_sys::WebTransport>, LocalStorage>,
+    datagrams_writer: StoredValue<Option<web_sys::WritableStreamDefaultWriter>, LocalStorage>,
+    open: StoredValue<ArcFn>,
+    close: StoredValue<ArcFn>,
 }
 
+type ArcFn = Arc<dyn Fn() + Send + Sync>;
+
 impl UseWebTransportReturn {
     /// Access to the underlying `WebTransport`
-    pub async fn transport(&self) -> Option<web_sys::WebTransport> {
-        self.transport.borrow().clone()
+    pub fn transport(&self) -> Option<web_sys::WebTransport> {
+        self.transport.get_value()
+    }
+
+    /// Opens the connection. Closes the current connection first if there is one.
+    pub fn open(&self) {
+        (self.open.get_value())();
+    }
+
+    /// Closes the connection. There are no reconnection attempts until `open` is called again.
+    pub fn close(&self) {
+        (self.close.get_value())();
     }
 
     /// Sends binary data through the datagrams stream
     pub fn send_datagrams(&self, data: &[u8]) {
-        if let Some(transport) = self.transport.borrow().as_ref() {
-            let writer =
-                get_or_create_datagrams_writer(Rc::clone(&self.datagrams_writer), transport);
+        let Some(transport) = self.transport.get_value() else {
+            return;
+        };
 
+        if let Some(writer) = get_or_create_datagrams_writer(self.datagrams_writer, &transport) {
             let arr = js_sys::Uint8Array::from(data);
             let _ = writer.write_with_chunk(&arr);
         }
@@ -648,40 +746,39 @@ impl UseWebTransportReturn {
 
     /// Open a unidirectional send stream
     pub async fn open_send_stream(&self) -> Result<SendStream, WebTransportError> {
-        if let Some(transport) = self.transport.borrow().as_ref() {
-            let result = js_fut!(transport.create_unidirectional_stream())
-                .await
-                .map_err(|e| WebTransportError::FailedToOpenStream(e))?;
-            let stream: web_sys::WritableStream = result.unchecked_into();
-            let writer = stream
-                .get_writer()
-                .map_err(|e| WebTransportError::FailedToOpenWriter(e))?;
-
-            let (state, set_state) = signal(StreamState::Open);
-
-            Ok(SendStream {
-                writer,
-                state: state.into(),
-                set_state,
-            })
-        } else {
-            Err(WebTransportError::NotConnected)
-        }
+        let Some(transport) = self.transport.get_value() else {
+            return Err(WebTransportError::NotConnected);
+        };
+
+        let result = js_fut!(transport.create_unidirectional_stream())
+            .await
+            .map_err(WebTransportError::FailedToOpenStream)?;
+        let stream: web_sys::WritableStream = result.unchecked_into();
+        let writer = stream
+            .get_writer()
+            .map_err(WebTransportError::FailedToOpenWriter)?;
+
+        let (state, set_state) = signal(StreamState::Open);
+
+        Ok(SendStream {
+            writer,
+            state: state.into(),
+            set_state,
+        })
     }
 
     /// Open a bidirectional stream
     pub async fn open_bidir_stream(&self) -> Result<BidirStream, WebTransportError> {
-        if let Some(transport) = self.transport.borrow().as_ref() {
-            let result = js_fut!(transport.create_bidirectional_stream())
-                .await
-                .map_err(|e| WebTransportError::FailedToOpenStream(e))?;
-            let stream: web_sys::WebTransportBidirectionalStream = result.unchecked_into();
-            let ready_state = self.ready_state;
-
-            create_bidir_stream(stream, ready_state)
-        } else {
-            Err(WebTransportError::NotConnected)
-        }
+        let Some(transport) = self.transport.get_value() else {
+            return Err(WebTransportError::NotConnected);
+        };
+
+        let result = js_fut!(transport.create_bidirectional_stream())
+            .await
+            .map_err(WebTransportError::FailedToOpenStream)?;
+        let stream: web_sys::WebTransportBidirectionalStream = result.unchecked_into();
+
+        create_bidir_stream(stream, self.ready_state)
     }
 }
 
@@ -694,41 +791,37 @@ fn create_state_and_bytes_signal(
     Signal<Option<Vec<u8>>>,
 ) {
     let (state, set_state) = signal(StreamState::Open);
+    let (message, set_message) = signal(None::<Vec<u8>>);
+
+    let reader_initialized = StoredValue::new(false);
+    // a stream can only be read once
+    let stream = StoredValue::new_local(Some(stream));
+
+    let bytes = Signal::derive(move || {
+        lazy_initialize_u8_reader(
+            ready_state,
+            reader_initialized,
+            move || stream.try_update_value(Option::take).flatten(),
+            set_message,
+            move || {
+                set_state.try_set(StreamState::Closed);
+            },
+        );
 
-    let bytes = Signal::derive({
-        let reader_initialized = Rc::new(Cell::new(false));
-        let (message_signal, set_message) = signal(None::<Vec<u8>>);
-
-        let stream = stream.clone();
-
-        move || {
-            let stream = stream.clone();
-
-            lazy_initialize_u8_reader(
-                ready_state,
-                Rc::clone(&reader_initialized),
-                move || stream.clone().unchecked_into(),
-                set_message,
-                move || {
-                    set_state.set(StreamState::Closed);
-                },
-            );
-
-            message_signal.get()
-        }
+        message.get()
     });
 
     (state.into(), set_state, bytes)
 }
 
 fn create_bidir_stream(
-    stream: WebTransportBidirectionalStream,
+    stream: web_sys::WebTransportBidirectionalStream,
     ready_state: Signal<ConnectionReadyState>,
 ) -> Result<BidirStream, WebTransportError> {
     let writer = stream
         .writable()
         .get_writer()
-        .map_err(|e| WebTransportError::FailedToOpenWriter(e))?;
+        .map_err(WebTransportError::FailedToOpenWriter)?;
 
     let (state, set_state, bytes) =
         create_state_and_bytes_signal(stream.readable().unchecked_into(), ready_state);
@@ -743,7 +836,7 @@ fn create_bidir_stream(
     Ok(bidir_stream)
 }
 
-/// Error enum for [`UseWebTransportOptions::on_error`]
+/// Error enum for [`UseWebTransportReturn`] and [`CloseableStream`]
 #[derive(Debug, Clone, Error)]
 pub enum WebTransportError {
     #[error("The `WebTransport` is not connected yet. Call `open` first.")]
@@ -762,31 +855,15 @@ pub enum WebTransportError {
     OnCloseReader(JsValue),
 }
 
-/// Error enum for [`SendStream::send`]
+/// Error enum for [`SendableStream::send_bytes_async`] and [`SendableStream::send_async`]
 #[derive(Error, Debug)]
-pub enum SendError {
+pub enum SendError<E = Infallible> {
     #[error("Stream is not open")]
     StreamNotOpen,
 
-    #[error("Failed to write to stream")]
+    #[error("Failed to write to stream: {0:?}")]
     FailedToWrite(JsValue),
 
-    #[cfg(feature = "bincode")]
-    #[error("Serialization failed: {0}")]
-    SerializationFailed(#[from] bincode::Error),
-
-    #[cfg(feature = "msgpack")]
-    #[error("Serialization failed: {0}")]
-    SerializationFailed(#[from] rmp_serde::encode::Error),
-}
-
-#[derive(Error, Debug)]
-pub enum ReceiveError {
-    #[cfg(feature = "bincode")]
-    #[error("Serialization failed: {0}")]
-    DeserializationFailed(#[from] bincode::Error),
-
-    #[cfg(feature = "msgpack")]
-    #[error("Serialization failed: {0}")]
-    DeserializationFailed(#[from] rmp_serde::decode::Error),
+    #[error("Failed to encode data: {0:?}")]
+    Codec(E),
 }
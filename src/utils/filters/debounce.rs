@@ -4,27 +4,64 @@ use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::leptos_dom::helpers::TimeoutHandle;
 use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-#[derive(Copy, Clone, DefaultBuilder, Default)]
+#[derive(Copy, Clone, DefaultBuilder)]
 pub struct DebounceOptions {
     /// The maximum time allowed to be delayed before it's invoked.
     /// In milliseconds.
     #[builder(into)]
     pub max_wait: Signal<Option<f64>>,
+
+    /// Invoke on the leading edge of the timeout (=immediately on the first call after a pause).
+    /// Defaults to `false`.
+    pub leading: bool,
+
+    /// Invoke on the trailing edge of the timeout. Defaults to `true`.
+    pub trailing: bool,
+}
+
+impl Default for DebounceOptions {
+    fn default() -> Self {
+        Self {
+            max_wait: Default::default(),
+            leading: false,
+            trailing: true,
+        }
+    }
 }
 
 pub fn debounce_filter<R>(
     ms: impl Into<Signal<f64>>,
     options: DebounceOptions,
 ) -> impl Fn(Arc<dyn Fn() -> R>) -> Arc<Mutex<Option<R>>> + Clone
+where
+    R: 'static,
+{
+    debounce_filter_with_cancel(ms, options).0
+}
+
+/// Version of [`debounce_filter`] that also returns a function which cancels the pending call.
+#[allow(clippy::type_complexity)]
+pub fn debounce_filter_with_cancel<R>(
+    ms: impl Into<Signal<f64>>,
+    options: DebounceOptions,
+) -> (
+    impl Fn(Arc<dyn Fn() -> R>) -> Arc<Mutex<Option<R>>> + Clone,
+    impl Fn() + Clone,
+)
 where
     R: 'static,
 {
     let timer = Arc::new(Mutex::new(None::<TimeoutHandle>));
     let max_timer = Arc::new(Mutex::new(None::<TimeoutHandle>));
     let last_return_value: Arc<Mutex<Option<R>>> = Arc::new(Mutex::new(None));
+    // the call since the last invocation (if any) which holds on to the arguments
+    let pending = Rc::new(RefCell::new(None::<Rc<dyn Fn()>>));
 
     let clear_timeout = move |timer: &Arc<Mutex<Option<TimeoutHandle>>>| {
         let mut timer = timer.lock().unwrap();
@@ -34,18 +71,32 @@ where
         }
     };
 
-    on_cleanup({
+    let cancel = {
         let timer = Arc::clone(&timer);
+        let max_timer = Arc::clone(&max_timer);
+        let pending = Rc::clone(&pending);
 
         move || {
             clear_timeout(&timer);
+            clear_timeout(&max_timer);
+            pending.borrow_mut().take();
         }
+    };
+
+    on_cleanup({
+        let cancel = SendWrapper::new(cancel.clone());
+        #[allow(clippy::redundant_closure)]
+        move || cancel()
     });
 
     let ms = ms.into();
-    let max_wait_signal = options.max_wait;
+    let DebounceOptions {
+        max_wait: max_wait_signal,
+        leading,
+        trailing,
+    } = options;
 
-    move |_invoke: Arc<dyn Fn() -> R>| {
+    let filter = move |_invoke: Arc<dyn Fn() -> R>| {
         let duration = ms.get_untracked();
         let max_duration = max_wait_signal.get_untracked();
 
@@ -63,27 +114,44 @@ where
             *val_mut = Some(return_value);
         };
 
+        // no timer running means this is the first call after a pause
+        let is_idle = timer.lock().unwrap().is_none() && max_timer.lock().unwrap().is_none();
+
         clear_timeout(&timer);
 
         if duration <= 0.0 || max_duration.is_some_and(|d| d <= 0.0) {
             clear_timeout(&max_timer);
+            pending.borrow_mut().take();
 
             invoke();
             return Arc::clone(&last_return_value);
         }
 
+        if leading && is_idle {
+            pending.borrow_mut().take();
+            invoke();
+        } else {
+            pending.borrow_mut().replace(Rc::new(invoke));
+        }
+
         cfg_if! { if #[cfg(not(feature = "ssr"))] {
             // Create the max_timer. Clears the regular timer on invoke
             if let Some(max_duration) = max_duration {
+                let this_max_timer = Arc::clone(&max_timer);
                 let mut max_timer = max_timer.lock().unwrap();
 
                 if max_timer.is_none() {
                     let timer = Arc::clone(&timer);
-                    let invok = invoke.clone();
+                    let pending = Rc::clone(&pending);
                     *max_timer = set_timeout_with_handle(
                         move || {
                             clear_timeout(&timer);
-                            invok();
+                            *this_max_timer.lock().unwrap() = None;
+
+                            let invoke = pending.borrow_mut().take();
+                            if let Some(invoke) = invoke.filter(|_| trailing) {
+                                invoke();
+                            }
                         },
                         Duration::from_millis(max_duration as u64),
                     )
@@ -92,12 +160,19 @@ where
             }
 
             let max_timer = Arc::clone(&max_timer);
+            let this_timer = Arc::clone(&timer);
+            let pending = Rc::clone(&pending);
 
             // Create the regular timer. Clears the max timer on invoke
             *timer.lock().unwrap() = set_timeout_with_handle(
                 move || {
                     clear_timeout(&max_timer);
-                    invoke();
+                    *this_timer.lock().unwrap() = None;
+
+                    let invoke = pending.borrow_mut().take();
+                    if let Some(invoke) = invoke.filter(|_| trailing) {
+                        invoke();
+                    }
                 },
                 Duration::from_millis(duration as u64),
             )
@@ -105,5 +180,7 @@ where
         }}
 
         Arc::clone(&last_return_value)
-    }
+    };
+
+    (filter, cancel)
 }
@@ -5,7 +5,7 @@ pub use debounce::*;
 pub use throttle::*;
 
 use crate::sendwrap_fn;
-use leptos::prelude::Signal;
+use leptos::prelude::{GetUntracked, Signal};
 use std::sync::{Arc, Mutex};
 
 macro_rules! ArcFilterFn {
@@ -40,8 +40,11 @@ where
     })
 }
 
-/// Specify a debounce or throttle filter with their respective options or no filter
-#[derive(Default)]
+/// A custom filter receives a function for every call and decides if and when to call it.
+pub type CustomFilterFn = Arc<dyn Fn(Arc<dyn Fn()>) + Send + Sync>;
+
+/// Specify a debounce, throttle, pausable or custom filter with their respective options or no filter
+#[derive(Clone, Default)]
 pub enum FilterOptions {
     #[default]
     None,
@@ -53,6 +56,11 @@ pub enum FilterOptions {
         ms: Signal<f64>,
         options: ThrottleOptions,
     },
+    /// Only lets calls through while `is_active` is `true`. The others are dropped.
+    Pausable {
+        is_active: Signal<bool>,
+    },
+    Custom(CustomFilterFn),
 }
 
 impl FilterOptions {
@@ -70,6 +78,16 @@ impl FilterOptions {
         }
     }
 
+    pub fn pausable(is_active: impl Into<Signal<bool>>) -> Self {
+        Self::Pausable {
+            is_active: is_active.into(),
+        }
+    }
+
+    pub fn custom(filter: impl Fn(Arc<dyn Fn()>) + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(filter))
+    }
+
     pub fn filter_fn<R>(&self) -> ArcFilterFn!(R)
     where
         R: 'static,
@@ -77,6 +95,27 @@ impl FilterOptions {
         match self {
             FilterOptions::Debounce { ms, options } => Arc::new(debounce_filter(*ms, *options)),
             FilterOptions::Throttle { ms, options } => Arc::new(throttle_filter(*ms, *options)),
+            FilterOptions::Pausable { is_active } => {
+                let is_active = *is_active;
+
+                Arc::new(move |invoke: Arc<dyn Fn() -> R>| {
+                    Arc::new(Mutex::new(is_active.get_untracked().then(|| invoke())))
+                })
+            }
+            FilterOptions::Custom(filter) => {
+                let filter = Arc::clone(filter);
+
+                Arc::new(move |invoke: Arc<dyn Fn() -> R>| {
+                    let result = Arc::new(Mutex::new(None));
+
+                    filter(Arc::new({
+                        let result = Arc::clone(&result);
+                        move || *result.lock().unwrap() = Some(invoke())
+                    }));
+
+                    result
+                })
+            }
             FilterOptions::None => {
                 Arc::new(|invoke: Arc<dyn Fn() -> R>| Arc::new(Mutex::new(Some(invoke()))))
             }
@@ -138,5 +177,28 @@ macro_rules! filter_builder_methods {
                 ..self
             }
         }
+
+        /// Pause
+        #[$filter_docs]
+        /// while `is_active` is `false`.
+        pub fn pausable(self, is_active: impl Into<Signal<bool>>) -> Self {
+            Self {
+                $filter_field_name: FilterOptions::pausable(is_active),
+                ..self
+            }
+        }
+
+        /// Filter
+        #[$filter_docs]
+        /// with a custom function that receives every call and decides if and when to make it.
+        pub fn custom_filter(
+            self,
+            filter: impl Fn(::std::sync::Arc<dyn Fn()>) + Send + Sync + 'static,
+        ) -> Self {
+            Self {
+                $filter_field_name: FilterOptions::custom(filter),
+                ..self
+            }
+        }
     };
 }
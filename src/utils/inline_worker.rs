@@ -0,0 +1,22 @@
+use wasm_bindgen::JsValue;
+
+/// Creates a worker that runs the JavaScript source `script`. Returns the worker and the object
+/// URL of the script which should be revoked once the worker has been terminated.
+pub(crate) fn create_inline_worker(script: &str) -> Result<(web_sys::Worker, String), JsValue> {
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("text/javascript");
+
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(
+        &js_sys::Array::of1(&JsValue::from_str(script)),
+        &options,
+    )?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    match web_sys::Worker::new(&url) {
+        Ok(worker) => Ok((worker, url)),
+        Err(err) => {
+            let _ = web_sys::Url::revoke_object_url(&url);
+            Err(err)
+        }
+    }
+}
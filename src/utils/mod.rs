@@ -5,6 +5,15 @@ mod filters;
 ))]
 mod header;
 mod header_macro;
+#[cfg(all(
+    not(feature = "ssr"),
+    any(
+        feature = "use_dominant_color",
+        feature = "use_image_transform",
+        feature = "use_web_worker_fn"
+    )
+))]
+mod inline_worker;
 #[cfg(feature = "is")]
 mod is;
 mod js;
@@ -22,6 +31,15 @@ pub use filters::*;
 pub use header::*;
 #[allow(unused_imports)]
 pub(crate) use header_macro::*;
+#[cfg(all(
+    not(feature = "ssr"),
+    any(
+        feature = "use_dominant_color",
+        feature = "use_image_transform",
+        feature = "use_web_worker_fn"
+    )
+))]
+pub(crate) use inline_worker::*;
 #[cfg(feature = "is")]
 pub use is::*;
 #[allow(unused_imports)]
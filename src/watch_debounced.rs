@@ -2,7 +2,7 @@ use crate::{utils::DebounceOptions, watch_with_options, WatchOptions};
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 
-/// A debounced version of [`watch`].
+/// A debounced version of [`fn@crate::watch_with_options`].
 ///
 /// ## Demo
 ///
@@ -34,6 +34,10 @@ use leptos::prelude::*;
 ///
 /// Please note that if the current component is cleaned up before the debounced callback is called, the debounced callback will not be called.
 ///
+/// The previous value that is passed to the callback is the value of the last time the callback
+/// has been called. This makes it easy to compare the settled values, e.g. to autosave only what
+/// has actually changed.
+///
 /// There's also [`watch_debounced_with_options`] where you can specify the other watch options (except `filter`).
 ///
 /// ```
@@ -70,7 +74,7 @@ use leptos::prelude::*;
 ///
 /// ## See also
 ///
-/// * `leptos::watch`
+/// * [`fn@crate::watch_with_options`]
 /// * [`fn@crate::watch_throttled`]
 pub fn watch_debounced<W, T, DFn, CFn>(
     deps: DFn,
@@ -0,0 +1,167 @@
+use crate::{watch_with_options, WatchOptions};
+use leptos::prelude::*;
+
+/// Watch that lets you ignore the changes you make yourself.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/watch_ignorable)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{watch_ignorable, WatchIgnorableReturn};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (source, set_source) = signal("foo".to_string());
+///
+/// let watcher = watch_ignorable(
+///     move || source.get(),
+///     |v, _, _| {
+///         log!("Changed to {}", v);
+///     },
+/// );
+///
+/// set_source.set("bar".to_string()); // > "Changed to bar"
+///
+/// watcher.ignore_updates(|| {
+///     set_source.set("foobar".to_string()); // (nothing happens)
+/// });
+///
+/// set_source.set("hello".to_string()); // > "Changed to hello"
+/// #    view! { }
+/// # }
+/// ```
+///
+/// This is essential for two-way syncing. Changes that come from the other side can be applied
+/// inside `ignore_updates` without being sent back.
+///
+/// The value that was set inside `ignore_updates` becomes the previous value that is passed to the
+/// callback on the next change.
+///
+/// Effects run asynchronously. All changes to `deps` that happen before the watcher has processed
+/// the changes made inside `ignore_updates` are ignored as well. That is changes made in the same
+/// event handler or task right after calling `ignore_updates`. The watcher stops ignoring changes
+/// once it has processed them, so the `updater` should change `deps`. Otherwise the next change
+/// is ignored.
+///
+/// There's also [`watch_ignorable_with_options`] which takes the same options as
+/// [`fn@crate::watch_with_options`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server this works just fine except if you throttle or debounce in which case the callback
+/// will never be called except if you set `immediate` to `true` in which case the callback will be
+/// called exactly once.
+///
+/// ## See also
+///
+/// * [`fn@crate::watch_pausable`]
+pub fn watch_ignorable<W, T, DFn, CFn>(
+    deps: DFn,
+    callback: CFn,
+) -> WatchIgnorableReturn<impl Fn() + Clone + Send + Sync>
+where
+    DFn: Fn() -> W + 'static,
+    CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
+    W: Clone + 'static,
+    T: Clone + 'static,
+{
+    watch_ignorable_with_options(deps, callback, WatchOptions::default())
+}
+
+/// Version of `watch_ignorable` that accepts `WatchOptions`. See [`watch_ignorable`] for how to use.
+pub fn watch_ignorable_with_options<W, T, DFn, CFn>(
+    deps: DFn,
+    callback: CFn,
+    options: WatchOptions,
+) -> WatchIgnorableReturn<impl Fn() + Clone + Send + Sync>
+where
+    DFn: Fn() -> W + 'static,
+    CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
+    W: Clone + 'static,
+    T: Clone + 'static,
+{
+    let ignore_next_change = StoredValue::new(false);
+
+    let stop = watch_ignoring(ignore_next_change, deps, callback, options);
+
+    WatchIgnorableReturn {
+        stop,
+        ignore_next_change,
+    }
+}
+
+/// Watcher that skips the changes it processes while `ignore_next_change` is set and resets it
+/// after that. See [`ignore_updates`] for how to set it.
+pub(crate) fn watch_ignoring<W, T, DFn, CFn>(
+    ignore_next_change: StoredValue<bool>,
+    deps: DFn,
+    callback: CFn,
+    options: WatchOptions,
+) -> impl Fn() + Clone + Send + Sync
+where
+    DFn: Fn() -> W + 'static,
+    CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
+    W: Clone + 'static,
+    T: Clone + 'static,
+{
+    // the flag is read on every run, also on the first one which doesn't call the callback
+    let deps = move || {
+        let ignored = ignore_next_change
+            .try_update_value(std::mem::take)
+            .unwrap_or_default();
+
+        (deps(), ignored)
+    };
+
+    let ignorable_callback = move |(val, ignored): &(W, bool),
+                                   prev_val: Option<&(W, bool)>,
+                                   prev_ret: Option<Option<T>>| {
+        let prev_ret = prev_ret.flatten();
+
+        if *ignored {
+            prev_ret
+        } else {
+            Some(callback(
+                val,
+                prev_val.map(|(prev_val, _)| prev_val),
+                prev_ret,
+            ))
+        }
+    };
+
+    watch_with_options(deps, ignorable_callback, options)
+}
+
+/// Runs `updater` so the watcher created with `ignore_next_change` by [`watch_ignoring`] skips
+/// the changes it makes.
+pub(crate) fn ignore_updates(ignore_next_change: StoredValue<bool>, updater: impl FnOnce()) {
+    ignore_next_change.set_value(true);
+
+    updater();
+}
+
+/// Return type of [`watch_ignorable`]
+#[derive(Clone)]
+pub struct WatchIgnorableReturn<StopFn>
+where
+    StopFn: Fn() + Clone + Send + Sync,
+{
+    /// Stops the watcher
+    pub stop: StopFn,
+
+    ignore_next_change: StoredValue<bool>,
+}
+
+impl<StopFn> WatchIgnorableReturn<StopFn>
+where
+    StopFn: Fn() + Clone + Send + Sync,
+{
+    /// Runs `updater`. Changes it makes to the watched signals don't trigger the callback.
+    pub fn ignore_updates(&self, updater: impl FnOnce()) {
+        ignore_updates(self.ignore_next_change, updater);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{watch_with_options, WatchOptions};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// A [`fn@crate::watch_with_options`] that only triggers once.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/watch_once)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::watch_once;
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (source, set_source) = signal(0);
+///
+/// watch_once(
+///     move || source.get(),
+///     |v, _| {
+///         log!("Changed to {}", v);
+///     },
+/// );
+///
+/// set_source.set(1); // > "Changed to 1"
+///
+/// set_source.set(2); // (nothing happens)
+/// #    view! { }
+/// # }
+/// ```
+///
+/// The callback is called on the first change of `deps`. Then the watcher stops itself.
+/// The returned function stops the watcher earlier so the callback is never called.
+///
+/// There's also [`watch_once_with_options`] which takes the same options as
+/// [`fn@crate::watch_with_options`]. With `immediate` set to `true` the callback is called
+/// right away and never again.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the callback will never be called except if you set `immediate` to `true`.
+///
+/// ## See also
+///
+/// * [`fn@crate::watch_with_options`]
+/// * [`fn@crate::watch_pausable`]
+pub fn watch_once<W, DFn, CFn>(deps: DFn, callback: CFn) -> impl Fn() + Clone + Send + Sync
+where
+    DFn: Fn() -> W + 'static,
+    CFn: FnOnce(&W, Option<&W>) + 'static,
+    W: Clone + 'static,
+{
+    watch_once_with_options(deps, callback, WatchOptions::default())
+}
+
+/// Version of `watch_once` that accepts `WatchOptions`. See [`watch_once`] for how to use.
+pub fn watch_once_with_options<W, DFn, CFn>(
+    deps: DFn,
+    callback: CFn,
+    options: WatchOptions,
+) -> impl Fn() + Clone + Send + Sync
+where
+    DFn: Fn() -> W + 'static,
+    CFn: FnOnce(&W, Option<&W>) + 'static,
+    W: Clone + 'static,
+{
+    let callback = StoredValue::new_local(Some(callback));
+    let stop_watch = StoredValue::new(None::<Arc<dyn Fn() + Send + Sync>>);
+
+    let stop = move || {
+        if let Some(stop) = stop_watch.try_get_value().flatten() {
+            stop();
+        }
+    };
+
+    let once_callback = move |val: &W, prev_val: Option<&W>, _: Option<()>| {
+        if let Some(callback) = callback.try_update_value(Option::take).flatten() {
+            callback(val, prev_val);
+            stop();
+        }
+    };
+
+    stop_watch.set_value(Some(Arc::new(watch_with_options(
+        deps,
+        once_callback,
+        options,
+    ))));
+
+    stop
+}
@@ -42,6 +42,38 @@ use leptos::prelude::*;
 /// # }
 /// ```
 ///
+/// While paused the watcher doesn't track `deps` at all. So changes that happen while the watcher
+/// is paused never trigger the callback, even if the watcher is resumed right away. This makes it
+/// easy to apply remote updates to state that is watched locally without reacting to them.
+///
+/// Resuming starts tracking `deps` again. The value at that time is passed as the previous value
+/// on the next change. Like all effects the watcher resumes asynchronously after the current
+/// synchronous code has run. Changes made right after calling `resume` in the same event handler
+/// or task are therefore treated like changes made while paused and don't trigger the callback.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{watch_pausable, WatchPausableReturn};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (form, set_form) = signal(String::new());
+///
+/// let WatchPausableReturn { pause, resume, .. } = watch_pausable(
+///     move || form.get(),
+///     |form, _, _| {
+///         // send the local change to the server
+///     },
+/// );
+///
+/// let apply_remote_update = move |value: String| {
+///     pause();
+///     set_form.set(value); // doesn't trigger the callback
+///     resume();
+/// };
+/// #    view! { }
+/// # }
+/// ```
+///
 /// There's also [`watch_pausable_with_options`] which takes the same options as [`watch`].
 ///
 /// ## Server-Side Rendering
@@ -88,22 +120,51 @@ where
 {
     let (is_active, set_active) = signal(true);
 
-    let pausable_callback = move |val: &W, prev_val: Option<&W>, prev_ret: Option<Option<T>>| {
-        if is_active.get_untracked() {
-            Some(callback(val, prev_val, prev_ret.unwrap_or(None)))
-        } else {
-            None
+    // number of times the watcher has been paused
+    let pause_count = StoredValue::new(0_usize);
+
+    // `deps` isn't tracked while paused, so changes made while paused never reach the watcher
+    let deps = move || {
+        let value = is_active.get().then(&deps);
+
+        (pause_count.get_value(), value)
+    };
+
+    let pausable_callback = move |(pauses, val): &(usize, Option<W>),
+                                  prev_val: Option<&(usize, Option<W>)>,
+                                  prev_ret: Option<Option<T>>| {
+        let prev_ret = prev_ret.flatten();
+
+        match (val, prev_val) {
+            // paused or resumed since the last run. Resuming only starts tracking `deps` again.
+            (None, _) => prev_ret,
+            (Some(_), Some((prev_pauses, prev_val)))
+                if prev_pauses != pauses || prev_val.is_none() =>
+            {
+                prev_ret
+            }
+            (Some(val), prev_val) => Some(callback(
+                val,
+                prev_val.and_then(|(_, prev_val)| prev_val.as_ref()),
+                prev_ret,
+            )),
         }
     };
 
     let stop = watch_with_options(deps, pausable_callback, options);
 
+    // only notify when the state changes, so the watcher doesn't run in vain
     let pause = move || {
-        set_active.set(false);
+        if is_active.get_untracked() {
+            pause_count.update_value(|count| *count += 1);
+            set_active.set(false);
+        }
     };
 
     let resume = move || {
-        set_active.set(true);
+        if !is_active.get_untracked() {
+            set_active.set(true);
+        }
     };
 
     WatchPausableReturn {
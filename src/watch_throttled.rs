@@ -1,5 +1,6 @@
 use crate::{utils::ThrottleOptions, watch_with_options, WatchOptions};
 use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
 
 /// A throttled version of `leptos::watch`.
 ///
@@ -29,10 +30,33 @@ use default_struct_builder::DefaultBuilder;
 /// # }
 /// ```
 ///
-/// This really is only shorthand shorthand for `watch_with_options(deps, callback, WatchOptions::default().throttle(ms))`.
+/// This really is only shorthand for `watch_with_options(deps, callback, WatchOptions::default().throttle(ms))`.
 ///
 /// Please note that if the current component is cleaned up before the throttled callback is called, the throttled callback will not be called.
 ///
+/// The interval can also be a signal. Changing it takes effect from the next call on.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::watch_throttled;
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// #     let (source, set_source) = signal(0);
+/// let (interval, set_interval) = signal(500.0);
+///
+/// watch_throttled(
+///     move || source.get(),
+///     move |_, _, _| {
+///         log!("changed!");
+///     },
+///     interval,
+/// );
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
 /// There's also `watch_throttled_with_options` where you can specify the other watch options (except `filter`).
 ///
 /// ```
@@ -56,6 +80,11 @@ use default_struct_builder::DefaultBuilder;
 /// # }
 /// ```
 ///
+/// With the defaults the callback is called right away on the first change and then at most once
+/// per interval with the latest value. This is handy to keep e.g. analytics events of fast changing
+/// signals from flooding. Setting `leading` to `false` skips the immediate call and setting
+/// `trailing` to `false` drops the changes that happen during the interval.
+///
 /// ## Recommended Reading
 ///
 /// - [**Debounce vs Throttle**: Definitive Visual Guide](https://redd.one/blog/debounce-vs-throttle)
@@ -74,7 +103,7 @@ use default_struct_builder::DefaultBuilder;
 pub fn watch_throttled<W, T, DFn, CFn>(
     deps: DFn,
     callback: CFn,
-    ms: f64,
+    ms: impl Into<Signal<f64>>,
 ) -> impl Fn() + Clone + Send + Sync
 where
     DFn: Fn() -> W + 'static,
@@ -89,7 +118,7 @@ where
 pub fn watch_throttled_with_options<W, T, DFn, CFn>(
     deps: DFn,
     callback: CFn,
-    ms: f64,
+    ms: impl Into<Signal<f64>>,
     options: WatchThrottledOptions,
 ) -> impl Fn() + Clone + Send + Sync
 where
@@ -117,7 +146,7 @@ where
 pub struct WatchThrottledOptions {
     /// If `immediate` is false, the `callback` will not run immediately but only after
     /// the first change is detected of any signal that is accessed in `deps`.
-    /// Defaults to `true`.
+    /// Defaults to `false`.
     immediate: bool,
 
     /// Invoke on the trailing edge of the timeout. Defaults to `true`.
@@ -2,14 +2,14 @@ use crate::filter_builder_methods;
 use crate::utils::{create_filter_wrapper, DebounceOptions, FilterOptions, ThrottleOptions};
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
-/// A version of `leptos::watch` but with additional options.
+/// A version of `Effect::watch` but with additional options.
 ///
 /// ## Immediate
 ///
-/// This is the same as for `leptos::watch`. But you don't have to specify it.
+/// This is the same as for `Effect::watch`. But you don't have to specify it.
 /// By default its set to `false`.
 /// If `immediate` is `true`, the `callback` will run immediately (this is also true if throttled/debounced).
 /// If it's `false`, the `callback` will run only after
@@ -36,6 +36,38 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// ## Previous Values and Multiple Sources
+///
+/// The callback receives the new value of `deps`, the previous one (`None` the first time) and
+/// the value the callback returned last time. If the callback is throttled or debounced the
+/// previous value is the one of the last time the callback has been called, not the one before
+/// the latest change. To watch several signals at once return a tuple
+/// from `deps`. The callback runs whenever any of them changes.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{watch_with_options, WatchOptions};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (first, set_first) = signal("Ada".to_string());
+/// let (last, set_last) = signal("Lovelace".to_string());
+///
+/// watch_with_options(
+///     move || (first.get(), last.get()),
+///     move |(first, last), prev, _| {
+///         if let Some((prev_first, prev_last)) = prev {
+///             log!("{prev_first} {prev_last} -> {first} {last}");
+///         }
+///     },
+///     WatchOptions::default(),
+/// );
+///
+/// set_last.set("Byron".to_string()); // > "Ada Lovelace -> Ada Byron"
+/// #    view! { }
+/// # }
+/// ```
+///
 /// ## Filters
 ///
 /// The callback can be throttled or debounced. Please see [`fn@crate::watch_throttled`]
@@ -79,6 +111,67 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// With `pausable` the callback is only called while a signal is `true`. For anything else you can
+/// provide a custom filter. It receives every call of the callback and decides if and when to
+/// make it. The same filters are available for event handlers like in [`fn@crate::use_mouse`] and
+/// [`fn@crate::use_resize_observer`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{watch_with_options, WatchOptions};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// # let (num, set_num) = signal(0);
+/// let (is_active, set_active) = signal(true);
+///
+/// watch_with_options(
+///     move || num.get(),
+///     move |num, _, _| {
+///         log!("number {}", num);
+///     },
+///     WatchOptions::default().pausable(is_active),
+/// );
+///
+/// watch_with_options(
+///     move || num.get(),
+///     move |num, _, _| {
+///         log!("number {}", num);
+///     },
+///     WatchOptions::default().custom_filter(|invoke| {
+///         // call it on the next animation frame
+///         request_animation_frame(move || invoke());
+///     }),
+/// );
+/// #    view! { }
+/// # }
+/// ```
+///
+/// ## Once
+///
+/// With `once` the watcher stops itself after the callback has been called for the first time.
+/// This works together with `immediate` and the filters. A throttled or debounced call that is
+/// still pending after that is dropped.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{watch_with_options, WatchOptions};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// # let (num, set_num) = signal(0);
+/// #
+/// watch_with_options(
+///     move || num.get(),
+///     move |num, _, _| {
+///         log!("Number {}", num);
+///     },
+///     WatchOptions::default().once(true).debounce(100.0),
+/// );
+/// #    view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this works just fine except if you throttle or debounce in which case the callback
@@ -104,12 +197,23 @@ where
     let prev_deps_value: Rc<RefCell<Option<W>>> = Rc::new(RefCell::new(None));
     let prev_callback_value: Rc<RefCell<Option<T>>> = Rc::new(RefCell::new(None));
 
+    let once = options.once;
+    let did_run = Rc::new(Cell::new(false));
+    let effect_to_stop: Rc<RefCell<Option<Effect<LocalStorage>>>> = Rc::new(RefCell::new(None));
+
     let wrapped_callback = {
         let cur_deps_value = Rc::clone(&cur_deps_value);
         let prev_deps_value = Rc::clone(&prev_deps_value);
         let prev_callback_val = Rc::clone(&prev_callback_value);
+        let did_run = Rc::clone(&did_run);
+        let effect_to_stop = Rc::clone(&effect_to_stop);
 
         move || {
+            // a throttled or debounced call can still be pending
+            if once && did_run.get() {
+                return None;
+            }
+
             #[cfg(debug_assertions)]
             let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
@@ -122,7 +226,18 @@ where
                 prev_callback_val.take(),
             );
 
-            ret
+            // with a filter several changes can happen between two calls of the callback
+            prev_deps_value.replace(cur_deps_value.borrow().clone());
+
+            if once {
+                did_run.set(true);
+
+                if let Some(effect) = effect_to_stop.take() {
+                    effect.stop();
+                }
+            }
+
+            Some(ret)
         }
     };
 
@@ -133,12 +248,14 @@ where
         deps,
         move |deps_value, previous_deps_value, did_run_before| {
             cur_deps_value.replace(Some(deps_value.clone()));
-            prev_deps_value.replace(previous_deps_value.cloned());
+            if prev_deps_value.borrow().is_none() {
+                prev_deps_value.replace(previous_deps_value.cloned());
+            }
 
             let callback_value = if options.immediate && did_run_before.is_none() {
-                Some(wrapped_callback())
+                wrapped_callback()
             } else {
-                filtered_callback().lock().unwrap().take()
+                filtered_callback().lock().unwrap().take().flatten()
             };
 
             prev_callback_value.replace(callback_value);
@@ -146,6 +263,12 @@ where
         options.immediate,
     );
 
+    if once && did_run.get() {
+        effect.stop();
+    } else {
+        effect_to_stop.replace(Some(effect));
+    }
+
     move || effect.stop()
 
     // create_effect(move |did_run_before| {
@@ -178,8 +301,12 @@ pub struct WatchOptions {
     /// Defaults to `false`.
     immediate: bool,
 
-    /// Allows to debounce or throttle the callback. Defaults to no filter.
+    /// Allows to debounce, throttle or pause the callback. Defaults to no filter.
     filter: FilterOptions,
+
+    /// If `true`, the watcher stops after the `callback` has been called for the first time.
+    /// Defaults to `false`.
+    pub(crate) once: bool,
 }
 
 impl WatchOptions {
@@ -1,4 +1,6 @@
 use crate::{watch_with_options, WatchOptions};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 /// Shorthand for watching a signal to be `true`.
 ///
@@ -59,7 +61,8 @@ use crate::{watch_with_options, WatchOptions};
 ///
 /// ### Options
 ///
-/// Options and defaults are same as [`fn@watch_with_options`].
+/// Options and defaults are same as [`fn@watch_with_options`]. With `once` the callback is only
+/// called the first time the source becomes `true`.
 ///
 /// ```
 /// # use leptos::prelude::*;
@@ -73,11 +76,41 @@ use crate::{watch_with_options, WatchOptions};
 ///     |_, _, _| log!("counter is 7 now!"),
 ///     WatchOptions::default().immediate(true),
 /// );
+///
+/// whenever_with_options(
+///     move || counter.get() > 100,
+///     |_, _, _| log!("counter is over 100 for the first time!"),
+///     WatchOptions::default().once(true),
+/// );
 /// #
 /// #     view! { }
 /// # }
 /// ```
 ///
+/// Changes while the source is `false` don't count. With `once` and `immediate` the callback is
+/// called right away if the source is `true` already. Otherwise the watcher keeps watching until
+/// the source becomes `true` for the first time.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{WatchOptions, whenever_with_options};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (score, set_score) = signal(0);
+///
+/// whenever_with_options(
+///     move || score.get() >= 1000,
+///     |_, _, _| log!("Achievement unlocked!"),
+///     WatchOptions::default().once(true).immediate(true),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| set_score.update(|score| *score += 100)>"Score"</button>
+/// }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this works just fine except if you throttle or debounce in which case the callback
@@ -103,19 +136,47 @@ where
     CFn: Fn(bool, Option<bool>, Option<T>) -> T + Clone + 'static,
     T: Clone + 'static,
 {
-    watch_with_options(
+    // `once` only counts the calls where the source is `true`
+    let once = options.once;
+    let did_run = Rc::new(Cell::new(false));
+    let stop_watch = Rc::new(RefCell::new(None::<Box<dyn Fn()>>));
+
+    let stop = watch_with_options(
         source,
-        move |value, prev_value, prev_return| {
-            if *value {
-                Some(callback(
-                    *value,
-                    prev_value.copied(),
-                    prev_return.unwrap_or_default(),
-                ))
-            } else {
-                None
+        {
+            let did_run = Rc::clone(&did_run);
+            let stop_watch = Rc::clone(&stop_watch);
+
+            move |value, prev_value, prev_return| {
+                if *value && !(once && did_run.get()) {
+                    let ret =
+                        callback(*value, prev_value.copied(), prev_return.unwrap_or_default());
+
+                    if once {
+                        did_run.set(true);
+
+                        if let Some(stop) = stop_watch.borrow().as_ref() {
+                            stop();
+                        }
+                    }
+
+                    Some(ret)
+                } else {
+                    prev_return.flatten()
+                }
             }
         },
-        options,
-    )
+        options.once(false),
+    );
+
+    if once {
+        if did_run.get() {
+            // the callback has already been called because of `immediate`
+            stop();
+        } else {
+            stop_watch.replace(Some(Box::new(stop.clone())));
+        }
+    }
+
+    stop
 }